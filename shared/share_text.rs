@@ -0,0 +1,127 @@
+// Share captions are rendered from templates so they can be localized and overridden at
+// build time. This file is shared by the app and the worker's /api/share-text so both
+// produce the same caption. Placeholders are `{casts}`, `{likes}`, `{recasts}`, `{month}`,
+// `{emoji}`, `{tarot}`, `{url}` and `{year}`; text inside `[...]` is dropped when any
+// placeholder in it has no value (no report, no tarot card yet, ...). Inside `[...]`, `|`
+// separates list items: the items with all their values are joined with the locale's
+// separator, so none is left dangling when an item is dropped. `\n` in a build-time
+// override is a newline.
+
+/// Caption templates: `public` for a normal share, `private` when stats are hidden
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShareTextTemplate {
+    pub public: String,
+    pub private: String,
+    /// Joins the items of a `[a|b|c]` list
+    pub separator: String,
+}
+
+/// Values substituted into a share template; `None` drops the optional part using it
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ShareTextValues {
+    pub casts: Option<String>,
+    pub likes: Option<String>,
+    pub recasts: Option<String>,
+    pub month: Option<String>,
+    pub emoji: Option<String>,
+    pub tarot: Option<String>,
+    pub url: Option<String>,
+    pub year: Option<String>,
+}
+
+impl ShareTextValues {
+    fn get(&self, name: &str) -> Option<&str> {
+        match name {
+            "casts" => self.casts.as_deref(),
+            "likes" => self.likes.as_deref(),
+            "recasts" => self.recasts.as_deref(),
+            "month" => self.month.as_deref(),
+            "emoji" => self.emoji.as_deref(),
+            "tarot" => self.tarot.as_deref(),
+            "url" => self.url.as_deref(),
+            "year" => self.year.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+const EN_PUBLIC: &str = "My Annual Report: This year I [Published {casts} Casts this year|Received {likes} likes|Received {recasts} recasts|Most active month: {month}|Most used emoji: {emoji}]\n\n[My Annual Tarot Card is {tarot}\n\n][url: {url}\n\n][#MyFarcaster{year} ]#polyjuice";
+const EN_PRIVATE: &str = "My Annual Report: [Most active month: {month}|Most used emoji: {emoji}]\n\n[My Annual Tarot Card is {tarot}\n\n][url: {url}\n\n][#MyFarcaster{year} ]#polyjuice";
+const EN_SEPARATOR: &str = ", ";
+
+const ZH_PUBLIC: &str = "我的年度报告：[今年发布了 {casts} 条 Cast|收到 {likes} 个赞|被转发 {recasts} 次|最活跃的月份：{month}|最常用的表情：{emoji}]\n\n[我的年度塔罗牌是 {tarot}\n\n][链接：{url}\n\n][#MyFarcaster{year} ]#polyjuice";
+const ZH_PRIVATE: &str = "我的年度报告：[最活跃的月份：{month}|最常用的表情：{emoji}]\n\n[我的年度塔罗牌是 {tarot}\n\n][链接：{url}\n\n][#MyFarcaster{year} ]#polyjuice";
+const ZH_SEPARATOR: &str = "，";
+
+/// Built-in templates for a locale ("zh-CN" matches "zh"); English for anything else
+pub fn builtin_share_template(locale: &str) -> ShareTextTemplate {
+    let language = locale
+        .split(['-', '_'])
+        .next()
+        .unwrap_or("")
+        .to_ascii_lowercase();
+    let (public, private, separator) = match language.as_str() {
+        "zh" => (ZH_PUBLIC, ZH_PRIVATE, ZH_SEPARATOR),
+        _ => (EN_PUBLIC, EN_PRIVATE, EN_SEPARATOR),
+    };
+    ShareTextTemplate {
+        public: public.to_string(),
+        private: private.to_string(),
+        separator: separator.to_string(),
+    }
+}
+
+/// Render `template`: substitute placeholders, drop `[...]` parts and list items missing a
+/// value and join the remaining list items with `separator`
+/// Unknown placeholders count as missing; unmatched brackets are kept as text
+pub fn render_share_template(template: &str, separator: &str, values: &ShareTextValues) -> String {
+    let mut out = String::new();
+    let mut rest = template;
+    while let Some(open) = rest.find('[') {
+        out.push_str(&substitute(&rest[..open], values).0);
+        match rest[open + 1..].find(']') {
+            Some(len) => {
+                let items: Vec<String> = rest[open + 1..open + 1 + len]
+                    .split('|')
+                    .map(|item| substitute(item, values))
+                    .filter(|(_, complete)| *complete)
+                    .map(|(text, _)| text)
+                    .collect();
+                out.push_str(&items.join(separator));
+                rest = &rest[open + 1 + len + 1..];
+            }
+            None => {
+                out.push_str(&substitute(&rest[open..], values).0);
+                rest = "";
+            }
+        }
+    }
+    out.push_str(&substitute(rest, values).0);
+    out
+}
+
+// Replace `{name}` placeholders; the flag is false when any of them had no value
+// (those are left empty)
+fn substitute(text: &str, values: &ShareTextValues) -> (String, bool) {
+    let mut out = String::new();
+    let mut complete = true;
+    let mut rest = text;
+    while let Some(open) = rest.find('{') {
+        out.push_str(&rest[..open]);
+        match rest[open + 1..].find('}') {
+            Some(len) => {
+                match values.get(&rest[open + 1..open + 1 + len]) {
+                    Some(value) => out.push_str(value),
+                    None => complete = false,
+                }
+                rest = &rest[open + 1 + len + 1..];
+            }
+            None => {
+                out.push_str(&rest[open..]);
+                rest = "";
+            }
+        }
+    }
+    out.push_str(rest);
+    (out, complete)
+}
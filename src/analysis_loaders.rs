@@ -2,6 +2,7 @@ use wasm_bindgen_futures::spawn_local;
 use yew::prelude::*;
 
 use crate::dashboard::Dashboard;
+use crate::icons;
 use crate::models::*;
 use crate::services::*;
 use crate::wallet::WalletAccount;
@@ -25,8 +26,9 @@ pub struct ProfileLoaderProps {
 pub fn ProfileLoader(props: &ProfileLoaderProps) -> Html {
     let profile_data = use_state(|| None::<ProfileData>);
     let loading = use_state(|| true);
-    let error = use_state(|| None::<String>);
+    let error = use_state(|| None::<RequestError>);
     let loaded_query = use_state(|| None::<String>); // Track which query we've loaded
+    let refresh_stamp = use_state(|| None::<u64>); // Set by the refresh button to bypass cached profile/avatar
 
     // Clone values for use in both effect and render
     let api_url_for_render = props.api_url.clone();
@@ -49,12 +51,15 @@ pub fn ProfileLoader(props: &ProfileLoaderProps) -> Html {
                 props.search_query.clone(),
                 props.is_fid,
                 props.api_url.clone(),
+                *refresh_stamp,
             ),
-            move |_| {
-                // Check if we need to load - only load if query changed or we don't have data
+            move |(_, _, _, refresh_stamp)| {
+                // Check if we need to load - only load if query changed, we don't have data, or a refresh was requested
                 let current_query = search_query_for_effect.clone();
                 let query_changed = (*loaded_query_clone).as_ref() != Some(&current_query);
-                let needs_load = (*profile_data).is_none() || query_changed;
+                let refresh_stamp = *refresh_stamp;
+                let needs_load =
+                    (*profile_data).is_none() || query_changed || refresh_stamp.is_some();
 
                 if needs_load {
                     loading.set(true);
@@ -70,8 +75,11 @@ pub fn ProfileLoader(props: &ProfileLoaderProps) -> Html {
                     let on_profile_loaded = on_profile_loaded_callback.clone();
 
                     spawn_local(async move {
-                        let endpoint =
+                        let mut endpoint =
                             create_profile_endpoint(&search_query_clone, is_fid_for_effect);
+                        if let Some(stamp) = refresh_stamp {
+                            endpoint.path = with_cache_buster(&endpoint.path, stamp);
+                        }
 
                         let result = make_request_with_payment::<ProfileData>(
                             &api_url_clone,
@@ -120,15 +128,27 @@ pub fn ProfileLoader(props: &ProfileLoaderProps) -> Html {
         );
     }
 
+    let on_refresh = {
+        let refresh_stamp = refresh_stamp.clone();
+        Callback::from(move |_: MouseEvent| {
+            refresh_stamp.set(Some(js_sys::Date::now() as u64));
+        })
+    };
+
     // Render based on state
     if let Some(profile) = (*profile_data).as_ref() {
+        // After a manual refresh, force the browser to refetch the avatar too
+        let avatar_url = profile.pfp_url.as_ref().map(|url| match *refresh_stamp {
+            Some(stamp) => with_cache_buster(url, stamp),
+            None => url.clone(),
+        });
         html! {
             <div class="card profile-card">
                 <div class="card-content">
                     <div class="profile-info">
                         <div class="profile-picture">
-                            if let Some(pfp_url) = &profile.pfp_url {
-                                <img src={pfp_url.clone()} alt="Profile" />
+                            if let Some(pfp_url) = avatar_url {
+                                <img src={pfp_url} alt="Profile" />
                             } else {
                                 <div class="profile-picture-placeholder">
                                     {"👤"}
@@ -141,7 +161,27 @@ pub fn ProfileLoader(props: &ProfileLoaderProps) -> Html {
                             if let Some(username) = &profile.username {
                                 <p class="username">{"@"}{username}</p>
                             }
-                            <div class="fid-badge">{"FID: "}{profile.fid}</div>
+                            <div style="display: flex; align-items: center; gap: 8px;">
+                                <div class="fid-badge">{"FID: "}{profile.fid}</div>
+                                <button
+                                    class="refresh-profile-button"
+                                    onclick={on_refresh}
+                                    disabled={*loading}
+                                    title="Refresh profile and avatar"
+                                    aria-label="Refresh profile and avatar"
+                                    style={format!("
+                                        background: none;
+                                        border: none;
+                                        padding: 4px;
+                                        font-size: 14px;
+                                        color: rgba(255, 255, 255, 0.7);
+                                        cursor: pointer;
+                                        opacity: {};
+                                    ", if *loading { "0.4" } else { "1" })}
+                                >
+                                    {icons::refresh()}
+                                </button>
+                            </div>
 
                             if let Some(bio) = &profile.bio {
                                 <p class="bio">{bio}</p>
@@ -168,14 +208,31 @@ pub fn ProfileLoader(props: &ProfileLoaderProps) -> Html {
             </div>
         }
     } else if let Some(err) = (*error).as_ref() {
-        html! {
-            <div class="card profile-card">
-                <div class="card-content">
-                    <div class="error-message">
-                        <p>{"Error loading profile: "}{err}</p>
+        if err.is_not_found() {
+            html! {
+                <div class="card profile-card">
+                    <div class="card-content">
+                        <div class="error-message">
+                            <p>{format!("No Farcaster user found for '{}'", props.search_query)}</p>
+                            if props.is_fid {
+                                <p>{"Double-check the FID, or try searching by username instead."}</p>
+                            } else {
+                                <p>{"Check the spelling, or try searching by FID instead."}</p>
+                            }
+                        </div>
                     </div>
                 </div>
-            </div>
+            }
+        } else {
+            html! {
+                <div class="card profile-card">
+                    <div class="card-content">
+                        <div class="error-message">
+                            <p>{"Error loading profile: "}{err}</p>
+                        </div>
+                    </div>
+                </div>
+            }
         }
     } else if *loading {
         html! {
@@ -286,7 +343,7 @@ pub fn SocialAnalysisLoader(props: &SocialAnalysisLoaderProps) -> Html {
                     Err(e) => {
                         // Check for JOB_STATUS error format
                         if let Some((status, job_key, message)) =
-                            parse_job_status_error(&e, format!("social:{}", fid_clone))
+                            parse_job_status_error(&e.to_string(), format!("social:{}", fid_clone))
                         {
                             web_sys::console::log_1(
                                 &format!(
@@ -365,7 +422,7 @@ pub fn SocialAnalysisLoader(props: &SocialAnalysisLoaderProps) -> Html {
                                         Err(e) => {
                                             // Check if still pending/processing
                                             if let Some((new_status, _, _)) =
-                                                parse_job_status_error(&e, job_key_for_poll.clone())
+                                                parse_job_status_error(&e.to_string(), job_key_for_poll.clone())
                                             {
                                                 if new_status == "pending" || new_status == "processing" {
                                                     // Still processing, continue polling
@@ -503,7 +560,7 @@ pub fn MbtiAnalysisLoader(props: &MbtiAnalysisLoaderProps) -> Html {
                     Err(e) => {
                         // Check for JOB_STATUS error format
                         if let Some((status, job_key, message)) =
-                            parse_job_status_error(&e, format!("mbti:{}", fid_clone))
+                            parse_job_status_error(&e.to_string(), format!("mbti:{}", fid_clone))
                         {
                             web_sys::console::log_1(
                                 &format!(
@@ -582,7 +639,7 @@ pub fn MbtiAnalysisLoader(props: &MbtiAnalysisLoaderProps) -> Html {
                                         Err(e) => {
                                             // Check if still pending/processing
                                             if let Some((new_status, _, _)) =
-                                                parse_job_status_error(&e, job_key_for_poll.clone())
+                                                parse_job_status_error(&e.to_string(), job_key_for_poll.clone())
                                             {
                                                 if new_status == "pending" || new_status == "processing" {
                                                     // Still processing, continue polling
@@ -1,5 +1,6 @@
 use base64::engine::general_purpose;
 use base64::Engine as _;
+use futures::future::Either;
 use hmac::Hmac;
 use hmac::Mac;
 use serde::Deserialize;
@@ -130,24 +131,110 @@ pub fn add_auth_headers(
     }
 }
 
+/// Default time to wait for an API response, overridable at build time via API_REQUEST_TIMEOUT_MS
+const DEFAULT_REQUEST_TIMEOUT_MS: u32 = 20_000;
+
+/// Shorter timeout for a single poll of a pending job; a timed-out poll is simply retried
+pub const POLL_REQUEST_TIMEOUT_MS: u32 = 10_000;
+
+/// Request timeout from the build-time API_REQUEST_TIMEOUT_MS, or the 20s default
+pub fn default_request_timeout_ms() -> u32 {
+    option_env!("API_REQUEST_TIMEOUT_MS")
+        .and_then(|v| v.trim().parse().ok())
+        .filter(|ms| *ms > 0)
+        .unwrap_or(DEFAULT_REQUEST_TIMEOUT_MS)
+}
+
+/// Make an API request with the default timeout
 pub async fn make_request(
     base_url: &str,
     endpoint: &EndpointInfo,
     body: Option<String>,
     payment_header: Option<String>,
 ) -> Result<ApiResponse, String> {
-    let url = format!("{}{}", base_url, endpoint.path);
+    make_request_with_timeout(
+        base_url,
+        endpoint,
+        body,
+        payment_header,
+        default_request_timeout_ms(),
+    )
+    .await
+}
 
-    // Save body reference for signing (before it's moved)
-    let body_for_signing = body.as_deref();
+/// Aborts its controller when dropped, so abandoning a request future cancels the fetch
+struct AbortOnDrop(web_sys::AbortController);
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+/// Make an API request, giving up after `timeout_ms` if the server never responds
+/// On timeout, or if the caller drops this future, the fetch is aborted, so no request is
+/// left pending in the background
+pub async fn make_request_with_timeout(
+    base_url: &str,
+    endpoint: &EndpointInfo,
+    body: Option<String>,
+    payment_header: Option<String>,
+    timeout_ms: u32,
+) -> Result<ApiResponse, String> {
+    let controller = AbortOnDrop(
+        web_sys::AbortController::new()
+            .map_err(|e| format!("Failed to create AbortController: {:?}", e))?,
+    );
+    let signal = controller.0.signal();
+
+    let request = Box::pin(send_request(
+        base_url,
+        endpoint,
+        body,
+        payment_header,
+        &signal,
+    ));
+    let timeout = gloo_timers::future::TimeoutFuture::new(timeout_ms);
+
+    let outcome = futures::future::select(request, timeout).await;
+    match outcome {
+        // Dropping the unfinished TimeoutFuture clears its timer
+        Either::Left((result, _timeout)) => result,
+        Either::Right(((), _request)) => {
+            controller.0.abort();
+            web_sys::console::warn_1(
+                &format!(
+                    "⏱️ Request timed out after {}ms: {} {}",
+                    timeout_ms, endpoint.method, endpoint.path
+                )
+                .into(),
+            );
+            Err(format!(
+                "Request timed out after {}s. Please check your connection and try again.",
+                timeout_ms / 1000
+            ))
+        }
+    }
+}
+
+/// Build a signed fetch `Request` for `endpoint`: method, JSON body, X-PAYMENT and auth headers
+pub fn build_request(
+    base_url: &str,
+    endpoint: &EndpointInfo,
+    body: Option<&str>,
+    payment_header: Option<&str>,
+    signal: &web_sys::AbortSignal,
+) -> Result<Request, String> {
+    let url = format!("{}{}", base_url, endpoint.path);
 
     let opts = RequestInit::new();
     opts.set_method(&endpoint.method);
     opts.set_mode(RequestMode::Cors);
+    opts.set_signal(Some(signal));
 
     // Add body for POST requests
     if endpoint.method == "POST" {
-        if let Some(body_str) = &body {
+        if let Some(body_str) = body {
             opts.set_body(&wasm_bindgen::JsValue::from_str(body_str));
         }
     }
@@ -162,7 +249,7 @@ pub async fn make_request(
         .map_err(|e| format!("Failed to set Content-Type: {:?}", e))?;
 
     // Add payment header if provided
-    if let Some(ref payment) = payment_header {
+    if let Some(payment) = payment_header {
         web_sys::console::log_1(
             &format!("🔐 Setting X-PAYMENT header (length: {})", payment.len()).into(),
         );
@@ -192,7 +279,7 @@ pub async fn make_request(
         let timestamp = js_sys::Date::now() as i64 / 1000; // Convert to seconds
 
         // Sign the request
-        let body_hash = body_for_signing.map(compute_body_hash).unwrap_or_default();
+        let body_hash = body.map(compute_body_hash).unwrap_or_default();
         let sig_string =
             build_signature_string(&endpoint.method, &path, &query, &body_hash, timestamp);
         web_sys::console::log_1(
@@ -207,7 +294,7 @@ pub async fn make_request(
             &endpoint.method,
             &path,
             &query,
-            body_for_signing,
+            body,
             secret,
             timestamp,
         ) {
@@ -241,6 +328,25 @@ pub async fn make_request(
         );
     }
 
+    Ok(request)
+}
+
+async fn send_request(
+    base_url: &str,
+    endpoint: &EndpointInfo,
+    body: Option<String>,
+    payment_header: Option<String>,
+    signal: &web_sys::AbortSignal,
+) -> Result<ApiResponse, String> {
+    let url = format!("{}{}", base_url, endpoint.path);
+    let request = build_request(
+        base_url,
+        endpoint,
+        body.as_deref(),
+        payment_header.as_deref(),
+        signal,
+    )?;
+
     let window = web_sys::window().ok_or("No window object")?;
     web_sys::console::log_1(&format!("🌐 Making request: {} {}", endpoint.method, url).into());
     let resp_value = JsFuture::from(window.fetch_with_request(&request))
@@ -2,8 +2,15 @@ use web_sys::InputEvent;
 use yew::prelude::*;
 
 use crate::icons;
+use crate::markdown;
 use crate::models::*;
 
+/// Messages rendered at once; older history stays in state behind "Load earlier messages"
+const CHAT_RENDER_WINDOW: usize = 50;
+
+/// How close to the bottom (px) the reader must be for streamed replies to keep scrolling
+const STICK_TO_BOTTOM_PX: i32 = 48;
+
 // ============================================================================
 // Chat View Component
 // ============================================================================
@@ -19,6 +26,8 @@ pub struct ChatViewProps {
     pub on_input_change: Callback<InputEvent>,
     pub on_keypress: Callback<web_sys::KeyboardEvent>,
     pub on_send_message: Callback<()>,
+    /// Abort the in-flight reply
+    pub on_stop: Callback<()>,
 }
 
 /// Chat view component
@@ -36,6 +45,7 @@ pub fn ChatView(props: &ChatViewProps) -> Html {
                         on_input_change={props.on_input_change.clone()}
                         on_keypress={props.on_keypress.clone()}
                         on_send_message={props.on_send_message.clone()}
+                        on_stop={props.on_stop.clone()}
                     />
                     if let Some(error) = &props.chat_error {
                         <div class="error-message">
@@ -127,16 +137,119 @@ pub struct ChatMessagesProps {
 }
 
 /// Chat messages component
+/// Only the latest `CHAT_RENDER_WINDOW` messages (plus any loaded on request) are in the DOM
 #[function_component]
 fn ChatMessages(props: &ChatMessagesProps) -> Html {
+    let visible_count = use_state(|| CHAT_RENDER_WINDOW);
+    let container_ref = use_node_ref();
+    // scrollHeight captured before loading earlier messages, to keep the view anchored
+    let height_before_load = use_mut_ref(|| None::<i32>);
+    // Whether the reader was at the bottom as of the last scroll event
+    let at_bottom = use_mut_ref(|| true);
+
+    // Auto-scroll to the newest message when messages arrive or the typing indicator toggles
+    {
+        let container_ref = container_ref.clone();
+        use_effect_with((props.messages.len(), props.is_loading), move |_| {
+            if let Some(element) = container_ref.cast::<web_sys::Element>() {
+                element.set_scroll_top(element.scroll_height());
+            }
+            || ()
+        });
+    }
+
+    // Streamed replies grow the last message in place; follow them while the reader is at
+    // the bottom, without pulling them back down if they scrolled up to read
+    {
+        let container_ref = container_ref.clone();
+        let at_bottom = at_bottom.clone();
+        let last_length = props
+            .messages
+            .last()
+            .map_or(0, |message| message.content.len());
+        use_effect_with(last_length, move |_| {
+            if *at_bottom.borrow() {
+                if let Some(element) = container_ref.cast::<web_sys::Element>() {
+                    element.set_scroll_top(element.scroll_height());
+                }
+            }
+            || ()
+        });
+    }
+
+    let on_scroll = {
+        let container_ref = container_ref.clone();
+        let at_bottom = at_bottom.clone();
+        Callback::from(move |_: Event| {
+            if let Some(element) = container_ref.cast::<web_sys::Element>() {
+                let distance =
+                    element.scroll_height() - element.scroll_top() - element.client_height();
+                *at_bottom.borrow_mut() = distance <= STICK_TO_BOTTOM_PX;
+            }
+        })
+    };
+
+    // After prepending earlier messages, shift by the added height so the view doesn't jump
+    {
+        let container_ref = container_ref.clone();
+        let height_before_load = height_before_load.clone();
+        use_effect_with(*visible_count, move |_| {
+            if let Some(previous_height) = height_before_load.borrow_mut().take() {
+                if let Some(element) = container_ref.cast::<web_sys::Element>() {
+                    let added = element.scroll_height() - previous_height;
+                    element.set_scroll_top(element.scroll_top() + added);
+                }
+            }
+            || ()
+        });
+    }
+
+    let total = props.messages.len();
+    let first_visible = total.saturating_sub(*visible_count);
+    let on_load_earlier = {
+        let visible_count = visible_count.clone();
+        let container_ref = container_ref.clone();
+        let height_before_load = height_before_load.clone();
+        Callback::from(move |_: MouseEvent| {
+            if let Some(element) = container_ref.cast::<web_sys::Element>() {
+                *height_before_load.borrow_mut() = Some(element.scroll_height());
+            }
+            visible_count.set(*visible_count + CHAT_RENDER_WINDOW);
+        })
+    };
+
     html! {
-        <div class="chat-messages">
-            {for props.messages.iter().map(|message| {
+        <div class="chat-messages" ref={container_ref} onscroll={on_scroll}>
+            if first_visible > 0 {
+                <div style="display: flex; justify-content: center; padding: 8px 0;">
+                    <button
+                        class="chat-load-earlier"
+                        onclick={on_load_earlier}
+                        style="
+                            padding: 6px 14px;
+                            font-size: 13px;
+                            border-radius: 999px;
+                            border: 1px solid rgba(0, 0, 0, 0.15);
+                            background: rgba(255, 255, 255, 0.7);
+                            cursor: pointer;
+                        "
+                    >
+                        {format!("Load earlier messages ({})", first_visible)}
+                    </button>
+                </div>
+            }
+            {for props.messages[first_visible..].iter().map(|message| {
                 html! {
                     <div class={if message.role == "user" { "user-message" } else { "assistant-message" }}>
-                        <div class="message-content">
-                            {&message.content}
-                        </div>
+                        if message.role == "user" {
+                            <div class="message-content">
+                                {&message.content}
+                            </div>
+                        } else {
+                            <div class="message-content markdown">
+                                {markdown::render_markdown(&message.content)}
+                            </div>
+                        }
                         <div class="message-time">
                             {"Now"}
                         </div>
@@ -171,6 +284,7 @@ pub struct ChatInputProps {
     pub on_input_change: Callback<InputEvent>,
     pub on_keypress: Callback<web_sys::KeyboardEvent>,
     pub on_send_message: Callback<()>,
+    pub on_stop: Callback<()>,
 }
 
 /// Chat input component
@@ -187,13 +301,24 @@ fn ChatInput(props: &ChatInputProps) -> Html {
                     oninput={props.on_input_change.clone()}
                     onkeypress={props.on_keypress.clone()}
                 />
-                <button
-                    class="chat-send-button"
-                    onclick={props.on_send_message.clone().reform(|_| ())}
-                    disabled={props.is_loading}
-                >
-                    {icons::send()}
-                </button>
+                if props.is_loading {
+                    <button
+                        class="chat-send-button chat-stop-button"
+                        onclick={props.on_stop.clone().reform(|_| ())}
+                        title="Stop generating"
+                        aria-label="Stop generating"
+                        style="background: #ff3b30;"
+                    >
+                        {icons::stop()}
+                    </button>
+                } else {
+                    <button
+                        class="chat-send-button"
+                        onclick={props.on_send_message.clone().reform(|_| ())}
+                    >
+                        {icons::send()}
+                    </button>
+                }
             </div>
         </div>
     }
@@ -1,9 +1,254 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+
+use futures::channel::oneshot;
+use wasm_bindgen::JsCast;
+use web_sys::HtmlElement;
 use web_sys::InputEvent;
 use yew::prelude::*;
 
 use crate::icons;
 use crate::wallet::DiscoveredWallet;
 
+/// Elements that take keyboard focus inside a modal
+const FOCUSABLE_SELECTOR: &str = "button:not([disabled]), [href], input:not([disabled]), \
+     select:not([disabled]), textarea:not([disabled]), [tabindex]:not([tabindex=\"-1\"])";
+
+thread_local! {
+    // The mounted ConfirmHost; `confirm` hands it the question and a channel for the answer
+    static CONFIRM_HOST: RefCell<Option<Callback<PendingConfirm>>> = const { RefCell::new(None) };
+}
+
+/// Where Tab should send focus to keep it inside a modal with `count` focusable elements, or
+/// None to let the browser move it. `current` is the focused element's index among them
+pub(crate) fn focus_trap_target(
+    count: usize,
+    current: Option<usize>,
+    backwards: bool,
+) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+    match (current, backwards) {
+        (None, false) => Some(0),
+        (None, true) => Some(count - 1),
+        (Some(index), false) if index + 1 >= count => Some(0),
+        (Some(0), true) => Some(count - 1),
+        _ => None,
+    }
+}
+
+fn focusable_elements(panel: &web_sys::Element) -> Vec<HtmlElement> {
+    let Ok(nodes) = panel.query_selector_all(FOCUSABLE_SELECTOR) else {
+        return Vec::new();
+    };
+    (0..nodes.length())
+        .filter_map(|i| nodes.item(i))
+        .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+        .collect()
+}
+
+fn active_element() -> Option<web_sys::Element> {
+    web_sys::window()?.document()?.active_element()
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ModalProps {
+    /// Called on Escape, a click outside the panel, or by the modal's own close buttons
+    pub on_close: Callback<()>,
+    /// Accessible name of the dialog
+    pub label: AttrValue,
+    #[prop_or_default]
+    pub overlay_class: Classes,
+    /// Extra overlay styles, applied after the defaults (e.g. a darker backdrop)
+    #[prop_or_default]
+    pub overlay_style: AttrValue,
+    #[prop_or_default]
+    pub panel_class: Classes,
+    #[prop_or_default]
+    pub panel_style: AttrValue,
+    #[prop_or(10000)]
+    pub z_index: u32,
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Overlay shared by the app's modals: focuses the first control (or the panel) on open, keeps
+/// Tab cycling inside the panel, closes on Escape or a click outside it, and hands focus back
+/// to whatever had it before once closed
+#[function_component]
+pub fn Modal(props: &ModalProps) -> Html {
+    let panel_ref = use_node_ref();
+
+    {
+        let panel_ref = panel_ref.clone();
+        use_effect_with((), move |_| {
+            let previous = active_element().and_then(|el| el.dyn_into::<HtmlElement>().ok());
+            if let Some(panel) = panel_ref.cast::<HtmlElement>() {
+                let target = focusable_elements(&panel).into_iter().next();
+                let _ = target.unwrap_or(panel).focus();
+            }
+            move || {
+                if let Some(previous) = previous {
+                    let _ = previous.focus();
+                }
+            }
+        });
+    }
+
+    let on_keydown = {
+        let panel_ref = panel_ref.clone();
+        let on_close = props.on_close.clone();
+        Callback::from(move |e: KeyboardEvent| match e.key().as_str() {
+            "Escape" => {
+                e.prevent_default();
+                on_close.emit(());
+            }
+            "Tab" => {
+                let Some(panel) = panel_ref.cast::<web_sys::Element>() else {
+                    return;
+                };
+                let focusable = focusable_elements(&panel);
+                if focusable.is_empty() {
+                    // Nothing to cycle through; keep focus on the panel
+                    e.prevent_default();
+                    return;
+                }
+                let active = active_element();
+                let current = focusable
+                    .iter()
+                    .position(|el| active.as_ref().is_some_and(|a| el.is_same_node(Some(a))));
+                if let Some(target) = focus_trap_target(focusable.len(), current, e.shift_key()) {
+                    e.prevent_default();
+                    let _ = focusable[target].focus();
+                }
+            }
+            _ => {}
+        })
+    };
+
+    html! {
+        <div
+            class={props.overlay_class.clone()}
+            onclick={props.on_close.reform(|_| ())}
+            onkeydown={on_keydown}
+            style={format!("position: fixed; top: 0; left: 0; right: 0; bottom: 0; background: rgba(0, 0, 0, 0.5); z-index: {}; display: flex; align-items: center; justify-content: center; {}", props.z_index, props.overlay_style)}
+        >
+            <div
+                ref={panel_ref}
+                class={props.panel_class.clone()}
+                role="dialog"
+                aria-modal="true"
+                aria-label={props.label.clone()}
+                tabindex="-1"
+                onclick={Callback::from(|e: web_sys::MouseEvent| e.stop_propagation())}
+                style={props.panel_style.clone()}
+            >
+                {for props.children.iter()}
+            </div>
+        </div>
+    }
+}
+
+/// A question for the in-app confirmation dialog
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfirmRequest {
+    pub title: String,
+    pub message: String,
+    pub confirm_label: String,
+}
+
+struct PendingConfirm {
+    request: ConfirmRequest,
+    respond: oneshot::Sender<bool>,
+}
+
+/// Ask the user through the in-app dialog; `window.confirm` is blocked or always false in
+/// Farcaster mini-app webviews and sandboxed iframes. Questions asked while another is open
+/// wait their turn. Resolves false when declined, dismissed, or when no `ConfirmHost` is mounted
+pub async fn confirm(request: ConfirmRequest) -> bool {
+    let Some(host) = CONFIRM_HOST.with(|host| host.borrow().clone()) else {
+        web_sys::console::warn_1(&"⚠️ No confirmation dialog mounted, declining".into());
+        return false;
+    };
+    let (respond, answer) = oneshot::channel();
+    host.emit(PendingConfirm { request, respond });
+    answer.await.unwrap_or(false)
+}
+
+/// Renders the dialog for `confirm`; mount exactly one, at the app root
+#[function_component]
+pub fn ConfirmHost() -> Html {
+    // Questions are answered in the order asked; the front one is on screen
+    let queue = use_mut_ref(VecDeque::<PendingConfirm>::new);
+    let request = use_state(|| None::<ConfirmRequest>);
+    // Keys the dialog so each queued question mounts fresh and takes focus
+    let answered = use_mut_ref(|| 0u64);
+
+    {
+        let queue = queue.clone();
+        let request = request.clone();
+        use_effect_with((), move |_| {
+            let host = Callback::from(move |pending: PendingConfirm| {
+                let mut queue = queue.borrow_mut();
+                if queue.is_empty() {
+                    request.set(Some(pending.request.clone()));
+                }
+                queue.push_back(pending);
+            });
+            CONFIRM_HOST.with(|slot| *slot.borrow_mut() = Some(host));
+            // Dropping the queued senders on unmount resolves their `confirm`s as declined
+            || CONFIRM_HOST.with(|slot| *slot.borrow_mut() = None)
+        });
+    }
+
+    let on_answer = {
+        let queue = queue.clone();
+        let request = request.clone();
+        let answered = answered.clone();
+        Callback::from(move |confirmed: bool| {
+            let mut queue = queue.borrow_mut();
+            if let Some(pending) = queue.pop_front() {
+                let _ = pending.respond.send(confirmed);
+            }
+            *answered.borrow_mut() += 1;
+            request.set(queue.front().map(|next| next.request.clone()));
+        })
+    };
+
+    let Some(current) = (*request).clone() else {
+        return html! {};
+    };
+    let key = *answered.borrow();
+    html! {
+        <Modal
+            {key}
+            on_close={on_answer.reform(|_| false)}
+            label={current.title.clone()}
+            panel_class="confirm-dialog"
+            panel_style="background: white; border-radius: 16px; padding: 24px; max-width: 420px; width: 90%; color: #000;"
+            z_index={20000}
+        >
+            <h2 style="margin: 0 0 12px 0; font-size: 20px; font-weight: 600;">{current.title}</h2>
+            <p style="margin: 0 0 20px 0; color: #444; font-size: 14px; white-space: pre-line; overflow-wrap: anywhere;">{current.message}</p>
+            <div style="display: flex; justify-content: flex-end; gap: 8px;">
+                <button
+                    onclick={on_answer.reform(|_| false)}
+                    style="padding: 8px 16px; border: 1px solid #e0e0e0; border-radius: 8px; background: white; cursor: pointer; font-size: 14px;"
+                >
+                    {"Cancel"}
+                </button>
+                <button
+                    onclick={on_answer.reform(|_| true)}
+                    style="padding: 8px 16px; border: none; border-radius: 8px; background: #667eea; color: white; cursor: pointer; font-size: 14px; font-weight: 600;"
+                >
+                    {current.confirm_label}
+                </button>
+            </div>
+        </Modal>
+    }
+}
+
 #[derive(Properties, Clone)]
 pub struct WalletListProps {
     pub wallets: Vec<DiscoveredWallet>,
@@ -27,57 +272,61 @@ impl PartialEq for WalletListProps {
 #[function_component]
 pub fn WalletList(props: &WalletListProps) -> Html {
     html! {
-        <div class="wallet-list-overlay" onclick={props.on_close.clone().reform(|_| ())} style="position: fixed; top: 0; left: 0; right: 0; bottom: 0; background: rgba(0, 0, 0, 0.5); z-index: 10000; display: flex; align-items: center; justify-content: center;">
-            <div class="wallet-list-modal" onclick={Callback::from(|e: web_sys::MouseEvent| e.stop_propagation())} style="background: white; border-radius: 16px; padding: 24px; max-width: 400px; width: 90%; max-height: 80vh; overflow-y: auto; color: #000;">
-                <div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: 16px;">
-                    <h2 style="margin: 0; font-size: 20px; font-weight: 600;">{"Connect Wallet"}</h2>
-                    <button onclick={props.on_close.clone().reform(|_| ())} style="background: none; border: none; font-size: 24px; cursor: pointer; padding: 0; width: 32px; height: 32px; display: flex; align-items: center; justify-content: center;">{"✕"}</button>
-                </div>
-                <p style="margin: 0 0 16px 0; color: #666; font-size: 14px;">{"Please select a wallet to connect"}</p>
-                <div class="wallet-list">
-                    {
-                        if props.wallets.is_empty() {
-                            html! {
-                                <p style="color: #999; text-align: center; padding: 20px;">{"No wallets found. Please install a wallet extension like MetaMask."}</p>
-                            }
-                        } else {
-                            html! {
-                                <>
-                                    {
-                                        for props.wallets.iter().map(|wallet| {
-                                            let uuid = wallet.info.uuid.clone();
-                                            let name = wallet.info.name.clone();
-                                            let icon = wallet.info.icon.clone();
-
-                                            html! {
-                                                <button
-                                                    class="wallet-list-item"
-                                                    onclick={props.on_select_wallet.clone().reform(move |_| uuid.clone())}
-                                                    style="width: 100%; padding: 12px 16px; margin-bottom: 8px; border: 1px solid #e0e0e0; border-radius: 8px; background: white; cursor: pointer; display: flex; align-items: center; gap: 12px; transition: background-color 0.2s;"
-                                                >
-                                                    {
-                                                        if !icon.is_empty() {
-                                                            html! {
-                                                                <img src={icon.clone()} alt={name.clone()} style="width: 32px; height: 32px; border-radius: 4px;" />
-                                                            }
-                                                        } else {
-                                                            html! {
-                                                                <div style="width: 32px; height: 32px; border-radius: 4px; background: #f0f0f0; display: flex; align-items: center; justify-content: center; font-size: 18px;">{"🔷"}</div>
-                                                            }
+        <Modal
+            on_close={props.on_close.clone()}
+            label="Connect Wallet"
+            overlay_class="wallet-list-overlay"
+            panel_class="wallet-list-modal"
+            panel_style="background: white; border-radius: 16px; padding: 24px; max-width: 400px; width: 90%; max-height: 80vh; overflow-y: auto; color: #000;"
+        >
+            <div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: 16px;">
+                <h2 style="margin: 0; font-size: 20px; font-weight: 600;">{"Connect Wallet"}</h2>
+                <button onclick={props.on_close.clone().reform(|_| ())} aria-label="Close" style="background: none; border: none; font-size: 24px; cursor: pointer; padding: 0; width: 32px; height: 32px; display: flex; align-items: center; justify-content: center;">{"✕"}</button>
+            </div>
+            <p style="margin: 0 0 16px 0; color: #666; font-size: 14px;">{"Please select a wallet to connect"}</p>
+            <div class="wallet-list">
+                {
+                    if props.wallets.is_empty() {
+                        html! {
+                            <p style="color: #999; text-align: center; padding: 20px;">{"No wallets found. Please install a wallet extension like MetaMask."}</p>
+                        }
+                    } else {
+                        html! {
+                            <>
+                                {
+                                    for props.wallets.iter().map(|wallet| {
+                                        let uuid = wallet.info.uuid.clone();
+                                        let name = wallet.info.name.clone();
+                                        let icon = wallet.info.icon.clone();
+
+                                        html! {
+                                            <button
+                                                class="wallet-list-item"
+                                                onclick={props.on_select_wallet.clone().reform(move |_| uuid.clone())}
+                                                style="width: 100%; padding: 12px 16px; margin-bottom: 8px; border: 1px solid #e0e0e0; border-radius: 8px; background: white; cursor: pointer; display: flex; align-items: center; gap: 12px; transition: background-color 0.2s;"
+                                            >
+                                                {
+                                                    if !icon.is_empty() {
+                                                        html! {
+                                                            <img src={icon.clone()} alt={name.clone()} style="width: 32px; height: 32px; border-radius: 4px;" />
+                                                        }
+                                                    } else {
+                                                        html! {
+                                                            <div style="width: 32px; height: 32px; border-radius: 4px; background: #f0f0f0; display: flex; align-items: center; justify-content: center; font-size: 18px;">{"🔷"}</div>
                                                         }
                                                     }
-                                                    <span style="font-size: 16px; font-weight: 500; flex: 1; text-align: left;">{name}</span>
-                                                </button>
-                                            }
-                                        })
-                                    }
-                                </>
-                            }
+                                                }
+                                                <span style="font-size: 16px; font-weight: 500; flex: 1; text-align: left;">{name}</span>
+                                            </button>
+                                        }
+                                    })
+                                }
+                            </>
                         }
                     }
-                </div>
+                }
             </div>
-        </div>
+        </Modal>
     }
 }
 
@@ -194,6 +443,9 @@ pub fn FloatingChatButton(props: &FloatingChatButtonProps) -> Html {
 pub struct BottomTabProps {
     pub active_tab: String,
     pub on_tab_change: Callback<String>,
+    /// Show the leaderboard tab (only when the API serves it)
+    #[prop_or_default]
+    pub show_leaderboard: bool,
 }
 
 /// Bottom tab navigation component
@@ -217,6 +469,15 @@ pub fn BottomTab(props: &BottomTabProps) -> Html {
                 <span class="tab-icon">{icons::search()}</span>
                 <span class="tab-label">{"Search"}</span>
             </button>
+            if props.show_leaderboard {
+                <button
+                    class={if active_tab == "leaderboard" { "tab-item active" } else { "tab-item" }}
+                    onclick={props.on_tab_change.clone().reform(|_| "leaderboard".to_string())}
+                >
+                    <span class="tab-icon">{icons::trophy()}</span>
+                    <span class="tab-label">{"Top"}</span>
+                </button>
+            }
             <button
                 class={if active_tab == "about" { "tab-item active" } else { "tab-item" }}
                 onclick={props.on_tab_change.clone().reform(|_| "about".to_string())}
@@ -238,43 +499,290 @@ pub struct AnnualReportModalProps {
 #[function_component]
 pub fn AnnualReportModal(props: &AnnualReportModalProps) -> Html {
     html! {
-        <div 
-            class="annual-report-modal-overlay" 
-            onclick={props.on_close.clone().reform(|_| ())} 
-            style="position: fixed; top: 0; left: 0; right: 0; bottom: 0; background: rgba(0, 0, 0, 0.5); z-index: 10001; display: flex; align-items: center; justify-content: center;"
+        <Modal
+            on_close={props.on_close.clone()}
+            label="Annual Report"
+            overlay_class="annual-report-modal-overlay"
+            panel_class="annual-report-modal"
+            panel_style="background: white; border-radius: 16px; padding: 0; max-width: 400px; width: 90%; position: relative; overflow: hidden;"
+            z_index={10001}
         >
-            <div 
-                class="annual-report-modal" 
-                onclick={Callback::from(|e: web_sys::MouseEvent| e.stop_propagation())} 
-                style="background: white; border-radius: 16px; padding: 0; max-width: 400px; width: 90%; position: relative; overflow: hidden;"
+            // Close button (X) in top-left
+            <button 
+                onclick={props.on_close.clone().reform(|_| ())} 
+                aria-label="Close"
+                style="position: absolute; top: 12px; left: 12px; background: rgba(0, 0, 0, 0.5); border: none; color: white; font-size: 20px; cursor: pointer; padding: 4px 10px; border-radius: 50%; width: 32px; height: 32px; display: flex; align-items: center; justify-content: center; z-index: 10; transition: background-color 0.2s;"
             >
-                // Close button (X) in top-left
+                {"✕"}
+            </button>
+            
+            // Preview image
+            <div style="width: 100%;">
+                <img 
+                    src="/imgs/preview.png" 
+                    alt="Annual Report Preview" 
+                    style="width: 100%; height: auto; display: block;"
+                />
+            </div>
+            
+            // Purple button
+            <div style="padding: 24px;">
                 <button 
-                    onclick={props.on_close.clone().reform(|_| ())} 
-                    style="position: absolute; top: 12px; left: 12px; background: rgba(0, 0, 0, 0.5); border: none; color: white; font-size: 20px; cursor: pointer; padding: 4px 10px; border-radius: 50%; width: 32px; height: 32px; display: flex; align-items: center; justify-content: center; z-index: 10; transition: background-color 0.2s;"
+                    onclick={props.on_claim.clone().reform(|_| ())} 
+                    style="width: 100%; padding: 14px 24px; background: #8B5CF6; border: none; border-radius: 8px; color: white; font-size: 16px; font-weight: 600; cursor: pointer; transition: background-color 0.2s;"
                 >
-                    {"✕"}
+                    {"Claim Your Annual Report"}
                 </button>
-                
-                // Preview image
-                <div style="width: 100%;">
-                    <img 
-                        src="/imgs/preview.png" 
-                        alt="Annual Report Preview" 
-                        style="width: 100%; height: auto; display: block;"
+            </div>
+        </Modal>
+    }
+}
+
+/// Session payment total with a popover to set the opt-in auto-pay threshold
+#[function_component]
+pub fn PaymentSpendControl() -> Html {
+    let session_total = crate::payment::use_session_paid_total();
+    let is_open = use_state(|| false);
+    let limit = use_state(crate::payment::load_auto_pay_limit);
+    let limit_input = use_state(String::new);
+    let limit_error = use_state(|| None::<String>);
+
+    let on_toggle = {
+        let is_open = is_open.clone();
+        let limit = limit.clone();
+        let limit_input = limit_input.clone();
+        let limit_error = limit_error.clone();
+        Callback::from(move |_: MouseEvent| {
+            if !*is_open {
+                // Prefill with the saved limit, without the unit
+                limit_input.set(
+                    limit
+                        .map(|l| {
+                            crate::payment::format_usdc_amount(l)
+                                .trim_end_matches(" USDC")
+                                .to_string()
+                        })
+                        .unwrap_or_default(),
+                );
+                limit_error.set(None);
+            }
+            is_open.set(!*is_open);
+        })
+    };
+
+    let on_input = {
+        let limit_input = limit_input.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                limit_input.set(input.value());
+            }
+        })
+    };
+
+    let save_limit = {
+        let is_open = is_open.clone();
+        let limit = limit.clone();
+        let limit_error = limit_error.clone();
+        move |value: Option<u128>| match crate::payment::save_auto_pay_limit(value) {
+            Ok(()) => {
+                limit.set(value);
+                is_open.set(false);
+            }
+            Err(e) => limit_error.set(Some(e)),
+        }
+    };
+
+    let on_save = {
+        let limit_input = limit_input.clone();
+        let limit_error = limit_error.clone();
+        let save_limit = save_limit.clone();
+        Callback::from(
+            move |_: MouseEvent| match crate::payment::parse_usdc_amount(&limit_input) {
+                Some(value) => save_limit(Some(value)),
+                None => limit_error.set(Some("Enter an amount like 0.05".to_string())),
+            },
+        )
+    };
+
+    let on_disable = Callback::from(move |_: MouseEvent| save_limit(None));
+
+    let pill_label = if session_total > 0 {
+        crate::payment::format_usdc_amount(session_total)
+    } else {
+        "Payments".to_string()
+    };
+
+    html! {
+        <div class="payment-spend-control" style="position: relative;">
+            <button
+                onclick={on_toggle}
+                title="Paid this session"
+                style="background: rgba(255, 255, 255, 0.15); border: 1px solid rgba(255, 255, 255, 0.3); border-radius: 999px; color: white; font-size: 12px; font-weight: 600; padding: 6px 10px; cursor: pointer; white-space: nowrap;"
+            >
+                {format!("💳 {}", pill_label)}
+            </button>
+            if *is_open {
+                <div style="position: absolute; top: calc(100% + 8px); right: 0; width: 240px; padding: 12px; border-radius: 12px; background: rgba(30, 30, 60, 0.95); color: white; box-shadow: 0 8px 24px rgba(0, 0, 0, 0.3); z-index: 1100; font-size: 13px; box-sizing: border-box;">
+                    <div style="margin-bottom: 8px;">
+                        {format!("Paid this session: {}", crate::payment::format_usdc_amount(session_total))}
+                    </div>
+                    <label style="display: block; margin-bottom: 4px; opacity: 0.8;">
+                        {"Auto-pay requests up to (USDC)"}
+                    </label>
+                    <input
+                        type="text"
+                        inputmode="decimal"
+                        placeholder="Off: confirm every payment"
+                        value={(*limit_input).clone()}
+                        oninput={on_input}
+                        style="width: 100%; padding: 6px 8px; border-radius: 8px; border: 1px solid rgba(255, 255, 255, 0.3); background: rgba(255, 255, 255, 0.1); color: white; box-sizing: border-box;"
                     />
+                    if let Some(error) = &*limit_error {
+                        <div style="margin-top: 4px; color: #ff8a8a; font-size: 12px;">{error.clone()}</div>
+                    }
+                    <div style="margin-top: 6px; font-size: 11px; opacity: 0.7;">
+                        {"Payments above this amount always ask first."}
+                    </div>
+                    <div style="display: flex; gap: 8px; margin-top: 10px;">
+                        <button
+                            onclick={on_save}
+                            style="flex: 1; background: white; border: none; border-radius: 8px; color: #5a4fcf; padding: 6px 10px; font-weight: 600; cursor: pointer;"
+                        >{"Save"}</button>
+                        if limit.is_some() {
+                            <button
+                                onclick={on_disable}
+                                style="flex: 1; background: none; border: 1px solid rgba(255, 255, 255, 0.4); border-radius: 8px; color: white; padding: 6px 10px; cursor: pointer;"
+                            >{"Turn off"}</button>
+                        }
+                    </div>
                 </div>
-                
-                // Purple button
-                <div style="padding: 24px;">
-                    <button 
-                        onclick={props.on_claim.clone().reform(|_| ())} 
-                        style="width: 100%; padding: 14px 24px; background: #8B5CF6; border: none; border-radius: 8px; color: white; font-size: 16px; font-weight: 600; cursor: pointer; transition: background-color 0.2s;"
-                    >
-                        {"Claim Your Annual Report"}
-                    </button>
-                </div>
-            </div>
+            }
         </div>
     }
 }
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct ApiSettingsPanelProps {
+    /// Currently active API server
+    pub api_url: String,
+    /// Called with the normalized URL once it has answered a health ping
+    pub on_apply: Callback<String>,
+    /// Go back to the build-time default server
+    pub on_reset: Callback<()>,
+    pub on_close: Callback<()>,
+}
+
+/// Quick setting to point the app at a different API server
+#[function_component]
+pub fn ApiSettingsPanel(props: &ApiSettingsPanelProps) -> Html {
+    let url_input = use_state(|| props.api_url.clone());
+    let error = use_state(|| None::<String>);
+    let is_checking = use_state(|| false);
+
+    let on_input = {
+        let url_input = url_input.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
+                url_input.set(input.value());
+            }
+        })
+    };
+
+    let on_apply = {
+        let url_input = url_input.clone();
+        let error = error.clone();
+        let is_checking = is_checking.clone();
+        let on_apply = props.on_apply.clone();
+        Callback::from(move |_: MouseEvent| {
+            if *is_checking {
+                return;
+            }
+            let url = match crate::services::normalize_api_url(&url_input) {
+                Ok(url) => url,
+                Err(e) => {
+                    error.set(Some(e));
+                    return;
+                }
+            };
+            error.set(None);
+            is_checking.set(true);
+            let error = error.clone();
+            let is_checking = is_checking.clone();
+            let on_apply = on_apply.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                match crate::wallet::ping_endpoint_service(&url).await {
+                    Ok(_) => on_apply.emit(url),
+                    Err(e) => error.set(Some(format!("Server did not respond ({})", e))),
+                }
+                is_checking.set(false);
+            });
+        })
+    };
+
+    html! {
+        <Modal
+            on_close={props.on_close.clone()}
+            label="API server"
+            overlay_class="api-settings-overlay"
+            panel_class="api-settings-panel"
+            panel_style="background: rgba(30, 30, 60, 0.97); color: white; border-radius: 16px; padding: 20px; width: min(420px, calc(100% - 32px)); box-sizing: border-box; box-shadow: 0 8px 24px rgba(0, 0, 0, 0.3);"
+        >
+            <div style="display: flex; align-items: center; justify-content: space-between; margin-bottom: 12px;">
+                <h3 style="margin: 0; font-size: 18px;">{"API server"}</h3>
+                <button
+                    onclick={props.on_close.clone().reform(|_| ())}
+                    aria-label="Close"
+                    style="background: none; border: none; color: white; font-size: 18px; cursor: pointer; padding: 4px 8px;"
+                >
+                    {icons::close()}
+                </button>
+            </div>
+            <input
+                type="url"
+                placeholder="https://your-server.example"
+                value={(*url_input).clone()}
+                oninput={on_input}
+                style="width: 100%; padding: 10px 12px; border-radius: 8px; border: 1px solid rgba(255, 255, 255, 0.3); background: rgba(255, 255, 255, 0.1); color: white; font-size: 14px; box-sizing: border-box;"
+            />
+            if let Some(error) = &*error {
+                <div style="margin-top: 8px; color: #ff8a8a; font-size: 13px;">{error.clone()}</div>
+            }
+            <div style="margin-top: 8px; font-size: 12px; opacity: 0.7;">
+                {format!("Currently using {}", props.api_url)}
+            </div>
+            <div style="display: flex; gap: 8px; margin-top: 16px;">
+                <button
+                    onclick={on_apply}
+                    disabled={*is_checking}
+                    style="flex: 1; background: white; border: none; border-radius: 8px; color: #5a4fcf; padding: 10px; font-size: 14px; font-weight: 600; cursor: pointer;"
+                >
+                    {if *is_checking { "Checking..." } else { "Use this server" }}
+                </button>
+                <button
+                    onclick={props.on_reset.clone().reform(|_| ())}
+                    style="background: none; border: 1px solid rgba(255, 255, 255, 0.4); border-radius: 8px; color: white; padding: 10px; font-size: 14px; cursor: pointer;"
+                >
+                    {"Reset to default"}
+                </button>
+            </div>
+        </Modal>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_focus_trap_target() {
+        assert_eq!(focus_trap_target(0, None, false), None);
+        assert_eq!(focus_trap_target(3, None, false), Some(0));
+        assert_eq!(focus_trap_target(3, None, true), Some(2));
+        // Wraps at either end, otherwise the browser moves focus
+        assert_eq!(focus_trap_target(3, Some(2), false), Some(0));
+        assert_eq!(focus_trap_target(3, Some(0), true), Some(2));
+        assert_eq!(focus_trap_target(3, Some(1), false), None);
+        assert_eq!(focus_trap_target(3, Some(1), true), None);
+        assert_eq!(focus_trap_target(1, Some(0), false), Some(0));
+    }
+}
@@ -1,3 +1,5 @@
+use std::cell::RefCell;
+
 use js_sys::Object;
 use js_sys::Promise;
 use js_sys::Reflect;
@@ -13,6 +15,9 @@ pub struct MiniAppContext {
     pub user: Option<ContextUser>,
     pub cast: Option<ContextCast>,
     pub channel: Option<ContextChannel>,
+    /// Host client details; older hosts omit it
+    #[serde(default)]
+    pub client: Option<ContextClient>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -38,6 +43,121 @@ pub struct ContextChannel {
     pub image_url: Option<String>,
 }
 
+/// Host client as reported in `context.client`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ContextClient {
+    pub client_fid: Option<i64>,
+    pub platform_type: Option<String>,
+}
+
+// FID of the Warpcast client, used to name it in logs
+const WARPCAST_CLIENT_FID: i64 = 9152;
+
+// `sdk.getCapabilities()` entry for composeCast, whose options include `embeds` and `channelKey`
+const COMPOSE_CAST_CAPABILITY: &str = "actions.composeCast";
+
+/// Host client plus the SDK capabilities it reports, recorded by `App` once the context loads
+/// so share code can tell which composeCast options are safe
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ClientInfo {
+    pub client_fid: Option<i64>,
+    pub platform_type: Option<String>,
+    pub capabilities: Vec<String>,
+    /// Channel the app was opened from; informational only, never a default share target
+    pub channel_key: Option<String>,
+}
+
+impl ClientInfo {
+    pub fn from_context(context: &MiniAppContext, capabilities: Vec<String>) -> Self {
+        let client = context.client.as_ref();
+        Self {
+            client_fid: client.and_then(|c| c.client_fid),
+            platform_type: client.and_then(|c| c.platform_type.clone()),
+            capabilities,
+            channel_key: context
+                .channel
+                .as_ref()
+                .and_then(|c| c.id.clone())
+                .filter(|id| !id.is_empty()),
+        }
+    }
+
+    /// Display name for logs: a known client FID, else the raw FID, else "unknown"
+    pub fn label(&self) -> String {
+        match self.client_fid {
+            Some(WARPCAST_CLIENT_FID) => "Warpcast".to_string(),
+            Some(fid) => format!("client FID {}", fid),
+            None => "unknown".to_string(),
+        }
+    }
+
+    pub fn has_capability(&self, capability: &str) -> bool {
+        self.capabilities.iter().any(|c| c == capability)
+    }
+
+    /// Whether composeCast's `embeds`/`channelKey` options can be used; hosts that don't
+    /// report capabilities get a plain URL embed and no channel
+    pub fn supports_compose_cast(&self) -> bool {
+        self.has_capability(COMPOSE_CAST_CAPABILITY)
+    }
+}
+
+thread_local! {
+    // Host client of the running Mini App, set by `set_client_info`
+    static CLIENT_INFO: RefCell<Option<ClientInfo>> = const { RefCell::new(None) };
+}
+
+/// Record the host client once the Mini App context is known
+pub fn set_client_info(info: ClientInfo) {
+    CLIENT_INFO.with(|cell| *cell.borrow_mut() = Some(info));
+}
+
+/// Host client of the running Mini App, `None` outside Farcaster or before the context loads
+pub fn client_info() -> Option<ClientInfo> {
+    CLIENT_INFO.with(|cell| cell.borrow().clone())
+}
+
+/// Embeds for a shared cast: the page URL, plus its card image on clients that report
+/// composeCast. Unknown clients get only the URL, whose preview shows the same image.
+pub fn share_embeds_for(
+    client: Option<&ClientInfo>,
+    page_url: Option<String>,
+    image_url: Option<String>,
+) -> Option<Vec<String>> {
+    let mut embeds: Vec<String> = page_url.into_iter().collect();
+    if client.is_some_and(|c| c.supports_compose_cast()) {
+        embeds.extend(image_url);
+    }
+    (!embeds.is_empty()).then_some(embeds)
+}
+
+/// Warpcast's web composer, for sharing to Farcaster from a regular browser
+pub const WARPCAST_COMPOSE_URL: &str = "https://warpcast.com/~/compose";
+
+/// Warpcast accepts at most this many embeds in a compose link
+const WARPCAST_COMPOSE_MAX_EMBEDS: usize = 2;
+
+/// Compose deep link prefilled with `text` and `embeds`; `encode` URL-encodes each value
+/// (js_sys::encode_uri_component in the browser)
+pub fn warpcast_compose_url(
+    text: &str,
+    embeds: &[String],
+    encode: impl Fn(&str) -> String,
+) -> String {
+    let mut url = format!("{}?text={}", WARPCAST_COMPOSE_URL, encode(text));
+    for embed in embeds.iter().take(WARPCAST_COMPOSE_MAX_EMBEDS) {
+        url.push_str("&embeds[]=");
+        url.push_str(&encode(embed));
+    }
+    url
+}
+
+/// `share_embeds_for` the current host client
+pub fn share_embeds(page_url: Option<String>, image_url: Option<String>) -> Option<Vec<String>> {
+    share_embeds_for(client_info().as_ref(), page_url, image_url)
+}
+
 // Helper function to get window object
 fn get_window() -> Result<web_sys::Window, String> {
     window().ok_or("No window object".to_string())
@@ -248,6 +368,24 @@ pub async fn initialize() -> Result<(), String> {
     Ok(())
 }
 
+/// Call `initialize()`, retrying once after `retry_delay_ms` if `ready()` fails
+pub async fn initialize_with_retry(retry_delay_ms: u32) -> Result<(), String> {
+    match initialize().await {
+        Ok(()) => Ok(()),
+        Err(e) => {
+            web_sys::console::warn_1(
+                &format!(
+                    "⚠️ sdk.actions.ready() failed, retrying in {}ms: {}",
+                    retry_delay_ms, e
+                )
+                .into(),
+            );
+            gloo_timers::future::TimeoutFuture::new(retry_delay_ms).await;
+            initialize().await
+        }
+    }
+}
+
 /// Validate that user has FID in Farcaster environment
 /// In Farcaster Mini App, user.fid must always exist if user exists
 pub fn validate_farcaster_user(user: &ContextUser) -> Result<(), String> {
@@ -265,6 +403,37 @@ pub fn validate_farcaster_user(user: &ContextUser) -> Result<(), String> {
     Ok(())
 }
 
+/// SDK actions the host supports, from `sdk.getCapabilities()`
+/// Empty when the SDK or host doesn't implement it, so callers fall back to the basics
+pub async fn get_capabilities() -> Vec<String> {
+    let capabilities = async {
+        let window = get_window()?;
+        let sdk = get_farcaster_sdk(&window)?;
+        let get_capabilities_fn = Reflect::get(&sdk, &"getCapabilities".into())
+            .ok()
+            .and_then(|f| f.dyn_ref::<js_sys::Function>().cloned())
+            .ok_or("getCapabilities is not a function".to_string())?;
+        let promise = get_capabilities_fn
+            .call0(&sdk)
+            .map_err(|e| format!("Failed to call getCapabilities: {:?}", e))?;
+        JsFuture::from(Promise::from(promise))
+            .await
+            .map_err(|e| format!("Failed to await getCapabilities: {:?}", e))
+    }
+    .await;
+
+    match capabilities {
+        Ok(value) => js_sys::Array::from(&value)
+            .iter()
+            .filter_map(|capability| capability.as_string())
+            .collect(),
+        Err(e) => {
+            web_sys::console::warn_1(&format!("⚠️ No host capabilities: {}", e).into());
+            Vec::new()
+        }
+    }
+}
+
 /// Get the current Mini App context (user, cast, channel)
 /// In Farcaster environment, user.fid must always exist
 pub async fn get_context() -> Result<MiniAppContext, String> {
@@ -355,10 +524,26 @@ pub async fn get_context() -> Result<MiniAppContext, String> {
         None
     };
 
+    // Parse client
+    let client = if let Ok(client_value) = Reflect::get(context_obj, &"client".into()) {
+        if !client_value.is_null() && !client_value.is_undefined() {
+            let client_str = js_sys::JSON::stringify(&client_value)
+                .map_err(|_| "Failed to stringify client".to_string())?
+                .as_string()
+                .ok_or("Client stringify failed".to_string())?;
+            serde_json::from_str::<ContextClient>(&client_str).ok()
+        } else {
+            None
+        }
+    } else {
+        None
+    };
+
     Ok(MiniAppContext {
         user,
         cast,
         channel,
+        client,
     })
 }
 
@@ -408,7 +593,12 @@ pub fn haptic_impact(style: &str) -> Result<(), String> {
 /// Compose a cast using Farcaster SDK
 /// This opens the native compose UI with pre-filled text
 /// embeds is an optional array of URLs to embed (e.g., images)
-pub async fn compose_cast(text: &str, embeds: Option<Vec<String>>) -> Result<(), String> {
+/// channel_key posts into that channel; pass None to let the user pick in the composer
+pub async fn compose_cast(
+    text: &str,
+    embeds: Option<Vec<String>>,
+    channel_key: Option<&str>,
+) -> Result<(), String> {
     let window = get_window()?;
     let sdk = get_farcaster_sdk(&window)?;
 
@@ -426,6 +616,24 @@ pub async fn compose_cast(text: &str, embeds: Option<Vec<String>>) -> Result<(),
     Reflect::set(&options, &"text".into(), &text.into())
         .map_err(|_| "Failed to set text option".to_string())?;
 
+    // Only target a channel the caller asked for, on clients that report composeCast
+    if let Some(channel_key) = channel_key {
+        let info = client_info().unwrap_or_default();
+        if info.supports_compose_cast() {
+            Reflect::set(&options, &"channelKey".into(), &channel_key.into())
+                .map_err(|_| "Failed to set channelKey option".to_string())?;
+        } else {
+            web_sys::console::log_1(
+                &format!(
+                    "ℹ️ {} doesn't report composeCast, sharing without channel {}",
+                    info.label(),
+                    channel_key
+                )
+                .into(),
+            );
+        }
+    }
+
     // Add embeds if provided
     if let Some(embed_urls) = embeds {
         let embed_array = js_sys::Array::new();
@@ -465,6 +673,7 @@ mod tests {
             }),
             cast: None,
             channel: None,
+            client: None,
         };
 
         let json = serde_json::to_string(&context).unwrap();
@@ -495,6 +704,10 @@ mod tests {
                 name: Some("Test Channel".to_string()),
                 image_url: Some("https://example.com/channel.png".to_string()),
             }),
+            client: Some(ContextClient {
+                client_fid: Some(9152),
+                platform_type: Some("mobile".to_string()),
+            }),
         };
 
         let json = serde_json::to_string(&context).unwrap();
@@ -593,10 +806,106 @@ mod tests {
             user: None,
             cast: None,
             channel: None,
+            client: None,
         };
 
         let json = serde_json::to_string(&context).unwrap();
         let parsed: MiniAppContext = serde_json::from_str(&json).unwrap();
         assert_eq!(context, parsed);
     }
+
+    #[test]
+    fn test_context_without_client_field() {
+        let parsed: MiniAppContext =
+            serde_json::from_str(r#"{"user":null,"cast":null,"channel":null}"#).unwrap();
+        assert_eq!(parsed.client, None);
+    }
+
+    #[test]
+    fn test_client_info_capabilities() {
+        let context = MiniAppContext {
+            user: None,
+            cast: None,
+            channel: Some(ContextChannel {
+                id: Some("polyjuice".to_string()),
+                name: None,
+                image_url: None,
+            }),
+            client: Some(ContextClient {
+                client_fid: Some(9152),
+                platform_type: Some("mobile".to_string()),
+            }),
+        };
+        let capabilities = vec![
+            "actions.ready".to_string(),
+            "actions.composeCast".to_string(),
+        ];
+        let info = ClientInfo::from_context(&context, capabilities);
+        assert_eq!(info.label(), "Warpcast");
+        assert_eq!(info.channel_key.as_deref(), Some("polyjuice"));
+        assert!(info.has_capability("actions.ready"));
+        assert!(info.supports_compose_cast());
+
+        // Nothing reported: composeCast options aren't assumed
+        let unreported = ClientInfo {
+            client_fid: Some(1),
+            ..ClientInfo::default()
+        };
+        assert_eq!(unreported.label(), "client FID 1");
+        assert!(!unreported.supports_compose_cast());
+    }
+
+    #[test]
+    fn test_share_embeds_for() {
+        let page = Some("https://example.com/annual-report/1".to_string());
+        let image = Some("https://example.com/api/generate?params=abc".to_string());
+        let composer = ClientInfo {
+            capabilities: vec!["actions.composeCast".to_string()],
+            ..ClientInfo::default()
+        };
+        let unreported = ClientInfo {
+            client_fid: Some(9152),
+            ..ClientInfo::default()
+        };
+
+        assert_eq!(
+            share_embeds_for(Some(&composer), page.clone(), image.clone()),
+            Some(vec![page.clone().unwrap(), image.clone().unwrap()])
+        );
+        assert_eq!(
+            share_embeds_for(Some(&unreported), page.clone(), image.clone()),
+            Some(vec![page.clone().unwrap()])
+        );
+        assert_eq!(
+            share_embeds_for(None, page.clone(), image.clone()),
+            Some(vec![page.unwrap()])
+        );
+        assert_eq!(share_embeds_for(None, None, image), None);
+    }
+
+    #[test]
+    fn test_warpcast_compose_url() {
+        // Stand-in for encodeURIComponent, enough to see every value goes through it
+        let encode = |value: &str| {
+            value
+                .replace(' ', "%20")
+                .replace('/', "%2F")
+                .replace(':', "%3A")
+        };
+        let embeds = vec![
+            "https://a.co/r".to_string(),
+            "https://a.co/i".to_string(),
+            "https://a.co/extra".to_string(),
+        ];
+
+        assert_eq!(
+            warpcast_compose_url("gm all", &embeds, encode),
+            "https://warpcast.com/~/compose?text=gm%20all\
+             &embeds[]=https%3A%2F%2Fa.co%2Fr&embeds[]=https%3A%2F%2Fa.co%2Fi"
+        );
+        assert_eq!(
+            warpcast_compose_url("gm", &[], encode),
+            "https://warpcast.com/~/compose?text=gm"
+        );
+    }
 }
@@ -1,3 +1,6 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
 use wasm_bindgen_futures::spawn_local;
 use web_sys::InputEvent;
 use web_sys::KeyboardEvent;
@@ -5,6 +8,7 @@ use yew::prelude::*;
 
 use crate::models::*;
 use crate::services::*;
+use crate::toast::Toaster;
 use crate::wallet;
 use crate::wallet::WalletAccount;
 
@@ -26,7 +30,9 @@ pub async fn perform_search(
     is_fid_state: UseStateHandle<bool>,
     loading_tasks: UseStateHandle<std::collections::HashSet<String>>,
     error_message: UseStateHandle<Option<String>>,
-    _api_url: String, // Not used anymore, kept for URL path update
+    api_url: String,
+    search_api_url: UseStateHandle<Option<String>>,
+    toaster: Toaster,
     current_view: UseStateHandle<String>,
 ) {
     // Set loading state
@@ -35,6 +41,25 @@ pub async fn perform_search(
     ]));
     error_message.set(None);
 
+    // Pre-flight: make sure a user-selected endpoint is still alive, otherwise
+    // load this search from the default; the saved selection is left alone
+    let default_api_url = default_api_url();
+    let mut fallback_api_url = None;
+    if api_url != default_api_url {
+        if let Err(e) = check_endpoint_health(&api_url).await {
+            web_sys::console::warn_1(
+                &format!(
+                    "⚠️ Endpoint {} unreachable ({}), using {} for this search",
+                    api_url, e, default_api_url
+                )
+                .into(),
+            );
+            toaster.info("Selected endpoint is unreachable — using the default for this search");
+            fallback_api_url = Some(default_api_url);
+        }
+    }
+    search_api_url.set(fallback_api_url);
+
     // Set search query - ProfileLoader will handle the loading
     search_query_state.set(Some(search_query.clone()));
     is_fid_state.set(is_fid);
@@ -73,17 +98,16 @@ pub async fn perform_search(
 
 /// Create wallet select handler
 pub fn create_wallet_select_handler(
-    wallet_error: UseStateHandle<Option<String>>,
+    toaster: Toaster,
     wallet_account: UseStateHandle<Option<WalletAccount>>,
     api_url: UseStateHandle<String>,
 ) -> Callback<String> {
     Callback::from(move |uuid: String| {
-        let wallet_error = wallet_error.clone();
+        let toaster = toaster.clone();
         let wallet_account = wallet_account.clone();
         let api_url = (*api_url).clone();
         spawn_local(async move {
             web_sys::console::log_1(&format!("🔌 Connecting to wallet: {}", uuid).into());
-            wallet_error.set(None);
 
             match crate::wallet::connect_to_wallet(&uuid).await {
                 Ok(_) => {
@@ -109,14 +133,16 @@ pub fn create_wallet_select_handler(
                         if let Ok(account) = crate::wallet::get_account().await {
                             connected_account = Some(account);
                         } else {
-                            wallet_error
-                                .set(Some("Connection timeout. Please try again.".to_string()));
+                            toaster.error("Connection timeout. Please try again.");
                             return;
                         }
                     }
 
                     // Get FID for the connected address
                     if let Some(ref account) = connected_account {
+                        if account.is_connected {
+                            toaster.success("Wallet connected");
+                        }
                         if let Some(ref address) = account.address {
                             // Save wallet connection to localStorage
                             if let Err(e) = crate::wallet::save_wallet_to_storage(&uuid, address) {
@@ -141,6 +167,7 @@ pub fn create_wallet_select_handler(
                                         web_sys::console::log_1(
                                             &"ℹ️ No FID found for this address".into(),
                                         );
+                                        toaster.info("No Farcaster account found for this wallet");
                                     }
                                 }
                                 Err(e) => {
@@ -158,7 +185,7 @@ pub fn create_wallet_select_handler(
                 }
                 Err(e) => {
                     web_sys::console::log_1(&format!("❌ Wallet connection failed: {}", e).into());
-                    wallet_error.set(Some(e));
+                    toaster.error(e);
                 }
             }
         });
@@ -189,6 +216,8 @@ pub fn create_search_handler(
     loading_tasks: UseStateHandle<std::collections::HashSet<String>>,
     error_message: UseStateHandle<Option<String>>,
     api_url: UseStateHandle<String>,
+    search_api_url: UseStateHandle<Option<String>>,
+    toaster: Toaster,
     current_view: UseStateHandle<String>,
 ) -> Callback<()> {
     Callback::from(move |_| {
@@ -202,6 +231,8 @@ pub fn create_search_handler(
         let loading_tasks = loading_tasks.clone();
         let error_message = error_message.clone();
         let api_url_clone = (*api_url).clone();
+        let search_api_url = search_api_url.clone();
+        let toaster = toaster.clone();
         let current_view = current_view.clone();
 
         spawn_local(async move {
@@ -224,6 +255,8 @@ pub fn create_search_handler(
                 loading_tasks,
                 error_message,
                 api_url_clone,
+                search_api_url,
+                toaster,
                 current_view,
             )
             .await;
@@ -300,7 +333,7 @@ pub async fn create_chat_session_after_search(
         }
         Err(e) => {
             web_sys::console::log_1(&format!("❌ Chat session creation failed: {}", e).into());
-            chat_error.set(Some(e));
+            chat_error.set(Some(e.to_string()));
         }
     }
 
@@ -308,6 +341,7 @@ pub async fn create_chat_session_after_search(
 }
 
 /// Create chat message send handler
+#[allow(clippy::too_many_arguments)]
 pub fn create_chat_message_handler(
     chat_session: UseStateHandle<Option<ChatSession>>,
     chat_message: UseStateHandle<String>,
@@ -316,13 +350,28 @@ pub fn create_chat_message_handler(
     chat_error: UseStateHandle<Option<String>>,
     api_url: UseStateHandle<String>,
     wallet_account: UseStateHandle<Option<WalletAccount>>,
+    chat_abort: Rc<RefCell<Option<web_sys::AbortController>>>,
 ) -> Callback<()> {
     Callback::from(move |_| {
         let message = (*chat_message).clone();
-        if message.trim().is_empty() {
+        // A reply is still in flight (Enter bypasses the hidden send button)
+        if message.trim().is_empty() || *is_chat_loading {
             return;
         }
 
+        // One controller per message; the stop button aborts whatever is in flight
+        let controller = match web_sys::AbortController::new() {
+            Ok(controller) => controller,
+            Err(e) => {
+                chat_error.set(Some(format!("Failed to create AbortController: {:?}", e)));
+                return;
+            }
+        };
+        let signal = controller.signal();
+        if let Some(previous) = chat_abort.borrow_mut().replace(controller) {
+            previous.abort();
+        }
+
         if let Some(session) = (*chat_session).clone() {
             let chat_message = chat_message.clone();
             let chat_messages = chat_messages.clone();
@@ -357,21 +406,85 @@ pub fn create_chat_message_handler(
                 // Remember the initial message count before sending (includes the user message we just added)
                 let initial_message_count = messages.len();
 
-                match make_request_with_payment::<ChatMessageResponse>(
-                    &api_url,
-                    &chat_endpoint,
-                    Some(request_json.clone()),
-                    wallet_account.as_ref(),
-                    None,
-                    None,
-                )
-                .await
-                {
-                    Ok(chat_data) => {
+                // Prefer streaming so the reply renders as it's generated; the assistant
+                // message is appended on the first delta and grown in place
+                let mut streamed_messages = messages.clone();
+                let mut received_delta = false;
+                let stream_result = stream_chat(&api_url, &request_json, &signal, |delta| {
+                    if !received_delta {
+                        received_delta = true;
+                        streamed_messages.push(ChatMessage {
+                            role: "assistant".to_string(),
+                            content: String::new(),
+                            timestamp: 0,
+                        });
+                    }
+                    if let Some(last) = streamed_messages.last_mut() {
+                        last.content.push_str(delta);
+                    }
+                    chat_messages.set(streamed_messages.clone());
+                })
+                .await;
+
+                let reply = match stream_result {
+                    // Stopped by the user: keep whatever was streamed, no error
+                    _ if signal.aborted() => {
+                        web_sys::console::log_1(&"⏹️ Chat reply stopped by user".into());
+                        chat_message.set(String::new());
+                        return;
+                    }
+                    Ok(ChatStreamOutcome::Completed) => {
+                        if !received_delta {
+                            chat_error.set(Some("The assistant sent an empty reply".to_string()));
+                        }
+                        chat_message.set(String::new());
+                        is_chat_loading.set(false);
+                        return;
+                    }
+                    Err(e) if received_delta => {
+                        // Keep the partial reply and say it was cut off
+                        chat_error.set(Some(format!("Reply interrupted: {}", e)));
+                        chat_message.set(String::new());
+                        is_chat_loading.set(false);
+                        return;
+                    }
+                    Ok(ChatStreamOutcome::Reply(message)) => Ok(message),
+                    // The message may already have reached the model, so don't resend it
+                    Err(e) => Err(e),
+                    Ok(ChatStreamOutcome::Unsupported) => {
+                        let buffered = Box::pin(make_request_with_payment::<ChatMessageResponse>(
+                            &api_url,
+                            &chat_endpoint,
+                            Some(request_json.clone()),
+                            wallet_account.as_ref(),
+                            None,
+                            None,
+                        ));
+                        // Aborting drops the buffered request, which cancels its fetch
+                        match futures::future::select(buffered, Box::pin(until_aborted(&signal)))
+                            .await
+                        {
+                            futures::future::Either::Left((result, _)) => result
+                                .map(|chat_data| chat_data.message)
+                                .map_err(|e| e.to_string()),
+                            futures::future::Either::Right(_) => {
+                                Err("Chat request cancelled".to_string())
+                            }
+                        }
+                    }
+                };
+
+                match reply {
+                    // Stopped, superseded or unmounted: drop the result
+                    _ if signal.aborted() => {
+                        chat_message.set(String::new());
+                        return;
+                    }
+                    Ok(reply_message) => {
                         // Check if response indicates pending status
                         // According to api.md, chat message API returns pending with message containing "Processing... Please check back later or poll for result"
-                        if chat_data.message.contains("Processing... Please check back later") || 
-                           chat_data.message.contains("Please check back later or poll for result") {
+                        if reply_message.contains("Processing... Please check back later") || 
+                           reply_message.contains("Please check back later or poll for result") {
                             web_sys::console::log_1(
                                 &"💬 Chat message API returned pending status, starting polling...".into(),
                             );
@@ -387,6 +500,7 @@ pub fn create_chat_message_handler(
                             let is_chat_loading_clone = is_chat_loading.clone();
                             let chat_error_clone = chat_error.clone();
                             let initial_message_count_clone = initial_message_count;
+                            let signal = signal.clone();
                             
                             spawn_local(async move {
                                 // Poll the chat session endpoint with exponential backoff
@@ -396,6 +510,11 @@ pub fn create_chat_message_handler(
                                 let mut current_interval = initial_interval_ms;
                                 
                                 for attempt in 0..max_attempts {
+                                    if signal.aborted() {
+                                        web_sys::console::log_1(&"⏹️ Chat polling stopped by user".into());
+                                        return;
+                                    }
+
                                     // Wait before polling (except first attempt)
                                     if attempt > 0 {
                                         current_interval = (current_interval * 2).min(max_interval_ms);
@@ -459,7 +578,7 @@ pub fn create_chat_message_handler(
                                                 continue;
                                             } else {
                                                 // Final attempt failed
-                                                chat_error_clone.set(Some(e));
+                                                chat_error_clone.set(Some(e.to_string()));
                                                 is_chat_loading_clone.set(false);
                                                 return;
                                             }
@@ -477,7 +596,7 @@ pub fn create_chat_message_handler(
                             // Not pending, use the response directly
                             let assistant_message = ChatMessage {
                                 role: "assistant".to_string(),
-                                content: chat_data.message,
+                                content: reply_message,
                                 timestamp: 0,
                             };
                             messages.push(assistant_message);
@@ -497,6 +616,20 @@ pub fn create_chat_message_handler(
     })
 }
 
+/// Create handler for the chat "Stop" button: aborts the in-flight request, keeping any
+/// partially streamed reply
+pub fn create_chat_stop_handler(
+    chat_abort: Rc<RefCell<Option<web_sys::AbortController>>>,
+    is_chat_loading: UseStateHandle<bool>,
+) -> Callback<()> {
+    Callback::from(move |_| {
+        if let Some(controller) = chat_abort.borrow_mut().take() {
+            controller.abort();
+        }
+        is_chat_loading.set(false);
+    })
+}
+
 /// Create enter key handler for search
 pub fn create_search_keypress_handler(on_search: Callback<()>) -> Callback<KeyboardEvent> {
     Callback::from(move |e: KeyboardEvent| {
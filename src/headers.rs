@@ -1,6 +1,7 @@
 use wasm_bindgen_futures::spawn_local;
 use yew::prelude::*;
 
+use crate::components::PaymentSpendControl;
 use crate::farcaster;
 use crate::icons;
 use crate::models::ProfileData;
@@ -10,7 +11,6 @@ use crate::wallet::WalletAccount;
 pub struct HeaderProps {
     pub wallet_account: Option<WalletAccount>,
     pub wallet_initialized: bool,
-    pub wallet_error: Option<String>,
     pub on_disconnect: Callback<()>,
     pub on_connect: Callback<()>,
     pub api_url: String,
@@ -18,6 +18,9 @@ pub struct HeaderProps {
     pub left_action: Option<Html>,
     pub is_farcaster_env: bool,
     pub farcaster_context: Option<farcaster::MiniAppContext>,
+    /// Open the API server settings (shows a gear button when set)
+    #[prop_or_default]
+    pub on_open_settings: Option<Callback<()>>,
 }
 
 /// Global header component
@@ -143,230 +146,183 @@ pub fn Header(props: &HeaderProps) -> Html {
         });
     }
 
-    html! {
-        <header class="global-header" style="position: sticky; top: 0; z-index: 1000; background: rgba(255, 255, 255, 0.1); backdrop-filter: blur(10px); -webkit-backdrop-filter: blur(10px); border-bottom: 1px solid rgba(255, 255, 255, 0.2); padding: 12px 16px; display: flex; align-items: center; justify-content: space-between; min-height: 60px; box-sizing: border-box;">
-            <div class="header-left" style="display: flex; flex-direction: column; align-items: flex-start; gap: 4px;">
-                {
-                    // Show error message if there's an error
-                    if let Some(error) = &props.wallet_error {
-                        html! {
-                            <div style="font-size: 12px; color: white; margin-bottom: 4px; max-width: 300px; text-align: left;">
-                                {error}
-                            </div>
-                        }
-                    } else {
-                        html! {}
-                    }
+    let is_menu_open = use_state(|| false);
+    let on_toggle_menu = {
+        let is_menu_open = is_menu_open.clone();
+        Callback::from(move |_: MouseEvent| is_menu_open.set(!*is_menu_open))
+    };
+    let on_close_menu = {
+        let is_menu_open = is_menu_open.clone();
+        Callback::from(move |_: MouseEvent| is_menu_open.set(false))
+    };
+    let on_disconnect = {
+        let on_disconnect = props.on_disconnect.clone();
+        let is_menu_open = is_menu_open.clone();
+        Callback::from(move |_: MouseEvent| {
+            is_menu_open.set(false);
+            on_disconnect.emit(());
+        })
+    };
+
+    // Who to show on the left: the Farcaster user in a mini app, otherwise the connected wallet
+    let identity = if props.is_farcaster_env {
+        props
+            .farcaster_context
+            .as_ref()
+            .and_then(|context| context.user.as_ref())
+            .map(|user| {
+                if user.pfp_url.as_deref().is_some_and(str::is_empty) {
+                    web_sys::console::warn_1(&"⚠️ Header: pfp_url is empty string".into());
+                } else if user.pfp_url.is_none() {
+                    web_sys::console::warn_1(&"⚠️ Header: pfp_url is None".into());
                 }
-                {
-                    // If in Farcaster environment, show Farcaster user info directly
-                    if props.is_farcaster_env {
-                        if let Some(context) = &props.farcaster_context {
-                            if let Some(user) = &context.user {
-                                html! {
-                                    <div class="user-info" style="display: flex; align-items: center; gap: 12px;">
-                                        // Avatar with circular border
-                                        <div class="avatar-container" style="width: 40px; height: 40px; border-radius: 50%; border: 2px solid #007AFF; padding: 2px; display: flex; align-items: center; justify-content: center; background: white;">
-                                            {
-                                                if let Some(pfp_url) = &user.pfp_url {
-                                                    if !pfp_url.is_empty() {
-                                                        html! {
-                                                            <img
-                                                                src={pfp_url.clone()}
-                                                                alt="Avatar"
-                                                                style="width: 100%; height: 100%; border-radius: 50%; object-fit: cover;"
-                                                            />
-                                                        }
-                                                    } else {
-                                                        web_sys::console::warn_1(
-                                                            &"⚠️ Header: pfp_url is empty string".into()
-                                                        );
-                                                        html! {
-                                                            <div style="width: 100%; height: 100%; border-radius: 50%; background: #f0f0f0; display: flex; align-items: center; justify-content: center; font-size: 20px;">
-                                                                {"👤"}
-                                                            </div>
-                                                        }
-                                                    }
-                                                } else {
-                                                    web_sys::console::warn_1(
-                                                        &"⚠️ Header: pfp_url is None".into()
-                                                    );
-                                                    html! {
-                                                        <div style="width: 100%; height: 100%; border-radius: 50%; background: #f0f0f0; display: flex; align-items: center; justify-content: center; font-size: 20px;">
-                                                            {"👤"}
-                                                        </div>
-                                                    }
-                                                }
-                                            }
-                                        </div>
-                                        // Username and FID
-                                        <div class="user-details" style="display: flex; flex-direction: column; align-items: flex-start;">
+                HeaderIdentity {
+                    pfp_url: user.pfp_url.clone().filter(|url| !url.is_empty()),
+                    username: user.username.clone(),
+                    fid: user.fid,
+                    address: None,
+                    is_loading: false,
+                    can_disconnect: false,
+                }
+            })
+    } else if !props.wallet_initialized {
+        None
+    } else {
+        props
+            .wallet_account
+            .as_ref()
+            .filter(|account| account.is_connected)
+            .map(|account| match (*user_profile).as_ref() {
+                Some(profile) => HeaderIdentity {
+                    pfp_url: profile.pfp_url.clone(),
+                    username: profile.username.clone(),
+                    fid: Some(profile.fid),
+                    address: account.address.clone(),
+                    is_loading: false,
+                    can_disconnect: true,
+                },
+                // Connected but no profile yet (loading, or fall back to the address)
+                None => HeaderIdentity {
+                    pfp_url: None,
+                    username: None,
+                    fid: account.fid,
+                    address: account.address.clone(),
+                    is_loading: *is_loading_profile,
+                    can_disconnect: true,
+                },
+            })
+    };
+    let show_connect = !props.is_farcaster_env && props.wallet_initialized && identity.is_none();
+
+    html! {
+        <>
+            // Backdrop closes the compact popover on any outside tap. It sits outside the header:
+            // the header's backdrop-filter would make it the containing block of a fixed child
+            if *is_menu_open {
+                <div onclick={on_close_menu} style="position: fixed; inset: 0; z-index: 999;"></div>
+            }
+            <header class="global-header" style="position: sticky; top: 0; z-index: 1000; background: rgba(255, 255, 255, 0.1); backdrop-filter: blur(10px); -webkit-backdrop-filter: blur(10px); border-bottom: 1px solid rgba(255, 255, 255, 0.2); padding: calc(12px + env(safe-area-inset-top, 0px)) 16px 12px; display: flex; align-items: center; justify-content: space-between; gap: 12px; min-height: calc(60px + env(safe-area-inset-top, 0px)); box-sizing: border-box;">
+                <div class="header-left" style="display: flex; flex-direction: column; align-items: flex-start; gap: 4px; min-width: 0;">
+                    {
+                        if let Some(identity) = &identity {
+                            html! {
+                                <>
+                                    // Full identity row (wide screens)
+                                    <div class="header-identity-full user-info" style="align-items: center; gap: 12px;">
+                                        {render_avatar(identity.pfp_url.as_deref())}
+                                        <div class="user-details" style="display: flex; flex-direction: column; align-items: flex-start; gap: 4px;">
                                             {
-                                                if let Some(username) = &user.username {
+                                                if let Some(username) = &identity.username {
                                                     html! {
                                                         <span style="font-size: 14px; font-weight: 500; color: white;">
                                                             {format!("@{}", username)}
                                                         </span>
                                                     }
-                                                } else {
-                                                    html! {}
-                                                }
-                                            }
-                                            {
-                                                if let Some(fid) = user.fid {
+                                                } else if let Some(address) = &identity.address {
                                                     html! {
-                                                        <span style="font-size: 12px; color: white;">
-                                                            {format!("FID: {}", fid)}
+                                                        <span style="font-size: 14px; font-weight: 500; color: white; font-family: 'SF Mono', Monaco, monospace;">
+                                                            {short_address(address)}
                                                         </span>
                                                     }
                                                 } else {
                                                     html! {}
                                                 }
                                             }
-                                        </div>
-                                    </div>
-                                }
-                            } else {
-                                html! {}
-                            }
-                        } else {
-                            html! {}
-                        }
-                    } else if !props.wallet_initialized {
-                        html! {}
-                    } else if let Some(account) = &props.wallet_account {
-                        if account.is_connected {
-                            // Show user info if we have profile data
-                            if let Some(profile) = (*user_profile).as_ref() {
-                                html! {
-                                    <div class="user-info" style="display: flex; align-items: center; gap: 12px;">
-                                        // Avatar with circular border
-                                        <div class="avatar-container" style="width: 40px; height: 40px; border-radius: 50%; border: 2px solid #007AFF; padding: 2px; display: flex; align-items: center; justify-content: center; background: white;">
                                             {
-                                                if let Some(pfp_url) = &profile.pfp_url {
+                                                if identity.is_loading {
                                                     html! {
-                                                        <img
-                                                            src={pfp_url.clone()}
-                                                            alt="Avatar"
-                                                            style="width: 100%; height: 100%; border-radius: 50%; object-fit: cover;"
-                                                        />
-                                                    }
-                                                } else {
-                                                    html! {
-                                                        <div style="width: 100%; height: 100%; border-radius: 50%; background: #f0f0f0; display: flex; align-items: center; justify-content: center; font-size: 20px;">
-                                                            {"👤"}
+                                                        <div style="display: flex; align-items: center; gap: 6px;">
+                                                            <div style="width: 12px; height: 12px; border: 2px solid #f3f3f3; border-top: 2px solid #007AFF; border-radius: 50%; animation: spin 1s linear infinite;"></div>
+                                                            <span style="font-size: 12px; color: white;">{"Loading profile..."}</span>
                                                         </div>
                                                     }
-                                                }
-                                            }
-                                        </div>
-                                        // Username and FID
-                                        <div class="user-details" style="display: flex; flex-direction: column; align-items: flex-start;">
-                                            {
-                                                if let Some(username) = &profile.username {
+                                                } else if let Some(fid) = identity.fid {
                                                     html! {
-                                                        <span style="font-size: 14px; font-weight: 500; color: white;">
-                                                            {format!("@{}", username)}
+                                                        <span style="font-size: 12px; color: white;">
+                                                            {format!("FID: {}", fid)}
                                                         </span>
                                                     }
                                                 } else {
                                                     html! {}
                                                 }
                                             }
-                                            <span style="font-size: 12px; color: white;">
-                                                {format!("FID: {}", profile.fid)}
-                                            </span>
                                         </div>
-                                        // Disconnect button
-                                        <button
-                                            class="disconnect-btn"
-                                            style="background: none; border: none; font-size: 18px; cursor: pointer; padding: 4px 8px; color: white;"
-                                            onclick={props.on_disconnect.clone().reform(|_| ())}
-                                        >
-                                            {icons::close()}
-                                        </button>
-                                    </div>
-                                }
-                            } else if *is_loading_profile {
-                                // Loading state - show address with loading indicator
-                                html! {
-                                    <div class="user-info" style="display: flex; align-items: center; gap: 12px;">
-                                        <div class="avatar-container" style="width: 40px; height: 40px; border-radius: 50%; border: 2px solid #007AFF; padding: 2px; display: flex; align-items: center; justify-content: center; background: white;">
-                                            <div style="width: 100%; height: 100%; border-radius: 50%; background: #f0f0f0; display: flex; align-items: center; justify-content: center; font-size: 20px;">
-                                                {"👤"}
-                                            </div>
-                                        </div>
-                                        <div class="user-details" style="display: flex; flex-direction: column; align-items: flex-start; gap: 4px;">
-                                            {
-                                                if let Some(address) = &account.address {
-                                                    html! {
-                                                        <span style="font-size: 14px; font-weight: 500; color: white; font-family: 'SF Mono', Monaco, monospace;">
-                                                            {format!("{}...{}", &address[..4.min(address.len())], &address[address.len().saturating_sub(4)..])}
-                                                        </span>
-                                                    }
-                                                } else {
-                                                    html! {}
+                                        {
+                                            if identity.can_disconnect {
+                                                html! {
+                                                    <button
+                                                        class="disconnect-btn"
+                                                        style="background: none; border: none; font-size: 18px; cursor: pointer; padding: 4px 8px; color: white;"
+                                                        onclick={props.on_disconnect.clone().reform(|_| ())}
+                                                    >
+                                                        {icons::close()}
+                                                    </button>
                                                 }
+                                            } else {
+                                                html! {}
                                             }
-                                            <div style="display: flex; align-items: center; gap: 6px;">
-                                                <div style="width: 12px; height: 12px; border: 2px solid #f3f3f3; border-top: 2px solid #007AFF; border-radius: 50%; animation: spin 1s linear infinite;"></div>
-                                                <span style="font-size: 12px; color: white;">{"Loading profile..."}</span>
-                                            </div>
-                                        </div>
+                                        }
+                                    </div>
+                                    // Compact identity (narrow screens): avatar button with a popover
+                                    <div class="header-identity-compact" style="position: relative;">
                                         <button
-                                            class="disconnect-btn"
-                                            style="background: none; border: none; font-size: 18px; cursor: pointer; padding: 4px 8px; color: white;"
-                                            onclick={props.on_disconnect.clone().reform(|_| ())}
+                                            onclick={on_toggle_menu}
+                                            aria-label="Account details"
+                                            aria-expanded={(*is_menu_open).to_string()}
+                                            style="background: none; border: none; padding: 0; cursor: pointer;"
                                         >
-                                            {icons::close()}
+                                            {render_avatar(identity.pfp_url.as_deref())}
                                         </button>
-                                    </div>
-                                }
-                            } else {
-                                // Connected but no profile yet (fallback to address)
-                                html! {
-                                    <div class="user-info" style="display: flex; align-items: center; gap: 12px;">
-                                        <div class="avatar-container" style="width: 40px; height: 40px; border-radius: 50%; border: 2px solid #007AFF; padding: 2px; display: flex; align-items: center; justify-content: center; background: white;">
-                                            <div style="width: 100%; height: 100%; border-radius: 50%; background: #f0f0f0; display: flex; align-items: center; justify-content: center; font-size: 20px;">
-                                                {"👤"}
-                                            </div>
-                                        </div>
-                                        <div class="user-details" style="display: flex; flex-direction: column; align-items: flex-start;">
-                                            {
-                                                if let Some(address) = &account.address {
-                                                    html! {
-                                                        <span style="font-size: 14px; font-weight: 500; color: white; font-family: 'SF Mono', Monaco, monospace;">
-                                                            {format!("{}...{}", &address[..4.min(address.len())], &address[address.len().saturating_sub(4)..])}
-                                                        </span>
-                                                    }
-                                                } else {
-                                                    html! {}
+                                        if *is_menu_open {
+                                            <div class="header-identity-popover" style="position: absolute; top: calc(100% + 8px); left: 0; width: min(280px, calc(100vw - 32px)); padding: 12px; border-radius: 12px; background: rgba(30, 30, 60, 0.95); color: white; box-shadow: 0 8px 24px rgba(0, 0, 0, 0.3); z-index: 1100; font-size: 13px; box-sizing: border-box; display: flex; flex-direction: column; gap: 6px;">
+                                                if let Some(username) = &identity.username {
+                                                    <span style="font-size: 14px; font-weight: 600;">{format!("@{}", username)}</span>
                                                 }
-                                            }
-                                            {
-                                                if let Some(fid) = account.fid {
-                                                    html! {
-                                                        <span style="font-size: 12px; color: white;">
-                                                            {format!("FID: {}", fid)}
-                                                        </span>
-                                                    }
-                                                } else {
-                                                    html! {}
+                                                if let Some(address) = &identity.address {
+                                                    <span style="font-family: 'SF Mono', Monaco, monospace; font-size: 12px; word-break: break-all; opacity: 0.9;">
+                                                        {address.clone()}
+                                                    </span>
                                                 }
-                                            }
-                                        </div>
-                                        <button
-                                            class="disconnect-btn"
-                                            style="background: none; border: none; font-size: 18px; cursor: pointer; padding: 4px 8px; color: white;"
-                                            onclick={props.on_disconnect.clone().reform(|_| ())}
-                                        >
-                                            {icons::close()}
-                                        </button>
+                                                if let Some(fid) = identity.fid {
+                                                    <span style="font-size: 12px; opacity: 0.9;">{format!("FID: {}", fid)}</span>
+                                                }
+                                                if identity.is_loading {
+                                                    <span style="font-size: 12px; opacity: 0.8;">{"Loading profile..."}</span>
+                                                }
+                                                if identity.can_disconnect {
+                                                    <button
+                                                        onclick={on_disconnect}
+                                                        style="margin-top: 6px; background: none; border: 1px solid rgba(255, 255, 255, 0.4); border-radius: 8px; color: white; padding: 6px 10px; font-size: 13px; cursor: pointer;"
+                                                    >
+                                                        {"Disconnect"}
+                                                    </button>
+                                                }
+                                            </div>
+                                        }
                                     </div>
-                                }
+                                </>
                             }
-                        } else {
-                            // Not connected - show connect button
+                        } else if show_connect {
                             html! {
                                 <button
                                     class="wallet-button"
@@ -376,30 +332,92 @@ pub fn Header(props: &HeaderProps) -> Html {
                                     {"Connect"}
                                 </button>
                             }
+                        } else {
+                            html! {}
                         }
-                    } else {
-                        // No account - show connect button
-                        html! {
-                            <button
-                                class="wallet-button"
-                                onclick={props.on_connect.clone().reform(|_| ())}
-                                style="background: rgba(102, 126, 234, 0.8); border: 1px solid rgba(102, 126, 234, 0.9); border-radius: 12px; color: #ffffff; font-size: 13px; font-weight: 600; padding: 8px 16px; cursor: pointer; backdrop-filter: blur(20px); -webkit-backdrop-filter: blur(20px); box-shadow: 0 4px 15px rgba(102, 126, 234, 0.3), inset 0 1px 0 rgba(255,255,255,0.2); text-shadow: 0 1px 2px rgba(0,0,0,0.2); white-space: nowrap;"
-                            >
-                                {"Connect"}
-                            </button>
+                    }
+                </div>
+                <div class="header-right" style="display: flex; align-items: center; gap: 12px; flex-shrink: 0;">
+                    {
+                        if props.wallet_account.as_ref().is_some_and(|a| a.is_connected) {
+                            html! { <PaymentSpendControl /> }
+                        } else {
+                            html! {}
                         }
                     }
-                }
-            </div>
-            <div class="header-right" style="display: flex; align-items: center; gap: 12px;">
-                {
-                    if let Some(action) = &props.left_action {
-                        action.clone()
-                    } else {
-                        html! {}
+                    {
+                        if let Some(on_open_settings) = &props.on_open_settings {
+                            html! {
+                                <button
+                                    class="settings-btn"
+                                    onclick={on_open_settings.reform(|_| ())}
+                                    aria-label="Settings"
+                                    title="API server settings"
+                                    style="background: none; border: none; font-size: 18px; cursor: pointer; padding: 4px 8px; color: white;"
+                                >
+                                    {icons::settings()}
+                                </button>
+                            }
+                        } else {
+                            html! {}
+                        }
+                    }
+                    {
+                        if let Some(action) = &props.left_action {
+                            action.clone()
+                        } else {
+                            html! {}
+                        }
+                    }
+                </div>
+            </header>
+        </>
+    }
+}
+
+/// What the header shows about the current user
+struct HeaderIdentity {
+    pfp_url: Option<String>,
+    username: Option<String>,
+    fid: Option<i64>,
+    address: Option<String>,
+    is_loading: bool,
+    can_disconnect: bool,
+}
+
+/// "0x12...cdef" form of a wallet address
+fn short_address(address: &str) -> String {
+    // Addresses are ASCII hex; fall back to the full string if slicing would split a char
+    match (
+        address.get(..4),
+        address.get(address.len().saturating_sub(4)..),
+    ) {
+        (Some(start), Some(end)) if address.len() > 8 => format!("{}...{}", start, end),
+        _ => address.to_string(),
+    }
+}
+
+/// Circular avatar with a placeholder when there's no picture
+fn render_avatar(pfp_url: Option<&str>) -> Html {
+    html! {
+        <div class="avatar-container" style="width: 40px; height: 40px; border-radius: 50%; border: 2px solid #007AFF; padding: 2px; display: flex; align-items: center; justify-content: center; background: white;">
+            {
+                if let Some(pfp_url) = pfp_url {
+                    html! {
+                        <img
+                            src={pfp_url.to_string()}
+                            alt="Avatar"
+                            style="width: 100%; height: 100%; border-radius: 50%; object-fit: cover;"
+                        />
+                    }
+                } else {
+                    html! {
+                        <div style="width: 100%; height: 100%; border-radius: 50%; background: #f0f0f0; display: flex; align-items: center; justify-content: center; font-size: 20px;">
+                            {"👤"}
+                        </div>
                     }
                 }
-            </div>
-        </header>
+            }
+        </div>
     }
 }
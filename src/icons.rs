@@ -51,6 +51,14 @@ pub fn info() -> Html {
     html! { <Icon name="info-circle" /> }
 }
 
+pub fn trophy() -> Html {
+    html! { <Icon name="trophy" /> }
+}
+
+pub fn refresh() -> Html {
+    html! { <Icon name="rotate-right" /> }
+}
+
 pub fn chat() -> Html {
     html! { <Icon name="comment" /> }
 }
@@ -59,6 +67,14 @@ pub fn send() -> Html {
     html! { <Icon name="paper-plane" /> }
 }
 
+pub fn stop() -> Html {
+    html! { <Icon name="stop" /> }
+}
+
 pub fn close() -> Html {
     html! { <Icon name="times" /> }
 }
+
+pub fn settings() -> Html {
+    html! { <Icon name="gear" /> }
+}
@@ -10,11 +10,13 @@ mod farcaster;
 mod handlers;
 mod headers;
 mod icons;
+mod markdown;
 mod models;
 mod pages;
 mod payment;
 mod services;
 mod share;
+mod toast;
 mod views;
 mod wallet;
 
@@ -32,16 +34,21 @@ fn App() -> Html {
     // Wallet state
     let wallet_account = use_state(|| None::<wallet::WalletAccount>);
     let wallet_initialized = use_state(|| false);
-    let wallet_error = use_state(|| None::<String>);
     let show_wallet_list = use_state(|| false);
     let discovered_wallets = use_state(Vec::<wallet::DiscoveredWallet>::new);
 
+    // App-wide toast notifications (share, wallet, endpoint feedback)
+    let toasts = use_reducer(toast::ToastState::default);
+    let toaster = toast::Toaster::new(toasts.dispatcher());
+
     // Track if we're in Farcaster Mini App environment
     let is_farcaster_env = use_state(|| false);
     let farcaster_context = use_state(|| None::<farcaster::MiniAppContext>);
+    let farcaster_initialized = use_state(|| false); // Mini App detection/context fetch finished
 
     // Tab navigation state
-    let active_tab = use_state(|| "search".to_string()); // "profile", "search", or "about"
+    let active_tab = use_state(|| "search".to_string()); // "profile", "search", "leaderboard", or "about"
+    let leaderboard_available = use_state(|| false); // Hide the leaderboard tab until the endpoint answers
 
     // State management
     let search_input = use_state(String::new);
@@ -51,15 +58,15 @@ fn App() -> Html {
     let loading_tasks = use_state(std::collections::HashSet::<String>::new); // Multiple loading tasks
     let error_message = use_state(|| None::<String>);
     let api_url = use_state(|| {
-        // Get API URL from build-time environment variable, fallback to default
-        let url = option_env!("SNAPRAG_API_URL")
-            .unwrap_or("https://snaprag.0xbase.ai")
-            .trim_end_matches('/')
-            .to_string();
+        // Server saved in settings, else the build-time environment variable or default
+        let url =
+            crate::services::load_saved_api_url().unwrap_or_else(crate::services::default_api_url);
 
         web_sys::console::log_1(&format!("🌐 Using API Server: {}", url).into());
         url
     });
+    // Set for a single search when the selected server failed its health check
+    let search_api_url = use_state(|| None::<String>);
 
     // Chat state management
     let chat_session = use_state(|| None::<ChatSession>);
@@ -67,10 +74,27 @@ fn App() -> Html {
     let chat_messages = use_state(Vec::<ChatMessage>::new);
     let is_chat_loading = use_state(|| false);
     let chat_error = use_state(|| None::<String>);
+    let chat_abort = use_mut_ref(|| None::<web_sys::AbortController>); // Controller for the in-flight chat request
+    {
+        // Cancel the in-flight chat request when the session changes or the app unmounts
+        let chat_abort = chat_abort.clone();
+        let session_id = (*chat_session)
+            .as_ref()
+            .map(|session| session.session_id.clone());
+        use_effect_with(session_id, move |_| {
+            move || {
+                if let Some(controller) = chat_abort.borrow_mut().take() {
+                    controller.abort();
+                }
+            }
+        });
+    }
     let current_view = use_state(|| "profile".to_string()); // "profile" or "chat"
     let show_annual_report = use_state(|| false); // Whether to show annual report
     let annual_report_fid = use_state(|| None::<i64>); // FID for annual report
     let show_annual_report_modal = use_state(|| false); // Whether to show annual report modal
+    let ready_report_fid = use_state(|| None::<i64>); // Saved pending report that has since finished
+    let compare_fids = use_state(|| None::<(i64, i64)>); // FIDs for the /compare/{fid1}/{fid2} view
 
     // Endpoint state management
     let endpoint_data = use_state(|| None::<EndpointData>);
@@ -78,17 +102,20 @@ fn App() -> Html {
     let endpoint_error = use_state(|| None::<String>);
     let show_endpoint = use_state(|| false);
     let ping_results = use_state(Vec::<(String, Option<f64>)>::new);
-    let selected_endpoint = use_state(|| None::<String>); // Currently selected endpoint
+    let selected_endpoint = use_state(crate::services::load_saved_api_url); // Currently selected endpoint
     let custom_endpoints = use_state(Vec::<String>::new); // Custom endpoints added by user
     let custom_url_input = use_state(String::new); // Input for custom URL
     let custom_endpoint_error = use_state(|| None::<String>); // Error message for custom endpoint
     let is_adding_endpoint = use_state(|| false); // Whether we're currently adding an endpoint
+    let debug_mode = use_state(crate::services::is_debug_mode); // ?debug=1 shows support tools
+    let show_api_settings = use_state(|| false); // Whether the API server settings panel is open
 
     // Initialize Farcaster Mini App SDK on mount
     // According to Farcaster docs: call sdk.actions.ready() when app is fully loaded
     {
         let is_farcaster_env = is_farcaster_env.clone();
         let farcaster_context = farcaster_context.clone();
+        let farcaster_initialized = farcaster_initialized.clone();
         use_effect_with((), move |_| {
             spawn_local(async move {
                 // Wait a bit for app to fully render
@@ -101,10 +128,17 @@ fn App() -> Html {
                         is_farcaster_env.set(true);
                         // Call sdk.actions.ready() to hide loading screen and show content
                         // This must be called when app is fully loaded
-                        if let Err(e) = farcaster::initialize().await {
-                            web_sys::console::warn_1(
-                                &format!("⚠️ Failed to call sdk.actions.ready(): {}", e).into(),
+                        if let Err(e) = farcaster::initialize_with_retry(500).await {
+                            // Some hosts never hide the splash when ready() fails; run as a
+                            // regular browser so wallet discovery keeps the app usable
+                            web_sys::console::error_1(
+                                &format!(
+                                    "❌ sdk.actions.ready() failed twice, falling back to regular browser mode: {}",
+                                    e
+                                )
+                                .into(),
                             );
+                            is_farcaster_env.set(false);
                         } else {
                             web_sys::console::log_1(
                                 &"✅ sdk.actions.ready() called successfully".into(),
@@ -112,6 +146,24 @@ fn App() -> Html {
                             // Get context after ready() and store it
                             match farcaster::get_context().await {
                                 Ok(context) => {
+                                    // Remember the host client so sharing only uses the
+                                    // composeCast options it supports
+                                    let capabilities = farcaster::get_capabilities().await;
+                                    let client_info =
+                                        farcaster::ClientInfo::from_context(&context, capabilities);
+                                    web_sys::console::log_1(
+                                        &format!(
+                                            "📱 Farcaster client: {} ({}), capabilities: {:?}",
+                                            client_info.label(),
+                                            client_info
+                                                .platform_type
+                                                .as_deref()
+                                                .unwrap_or("unknown platform"),
+                                            client_info.capabilities
+                                        )
+                                        .into(),
+                                    );
+                                    farcaster::set_client_info(client_info);
                                     // Validate: In Farcaster environment, user.fid must exist
                                     if let Some(user) = &context.user {
                                         if user.fid.is_none() {
@@ -164,6 +216,7 @@ fn App() -> Html {
                         // If SDK is not available, assume regular browser
                     }
                 }
+                farcaster_initialized.set(true);
             });
             || ()
         });
@@ -173,7 +226,7 @@ fn App() -> Html {
     {
         let wallet_initialized = wallet_initialized.clone();
         let wallet_account = wallet_account.clone();
-        let wallet_error = wallet_error.clone();
+        let toaster = toaster.clone();
         let is_farcaster_env = is_farcaster_env.clone();
         let api_url = api_url.clone();
 
@@ -209,58 +262,69 @@ fn App() -> Html {
                                 .into(),
                             );
 
-                            // Try to reconnect to the saved wallet
-                            match wallet::connect_to_wallet(&saved_uuid).await {
-                                Ok(_) => {
-                                    // Wait a bit for connection to establish
-                                    gloo_timers::future::TimeoutFuture::new(500).await;
-
-                                    if let Ok(account) = wallet::get_account().await {
-                                        if account.is_connected {
-                                            // Verify the address matches
-                                            if account.address.as_ref() == Some(&saved_address) {
-                                                web_sys::console::log_1(&"✅ Wallet connection restored from localStorage".into());
-
-                                                // Get FID for the connected address
-                                                let api_url_for_fid = api_url_clone.clone();
-                                                let wallet_account_for_fid =
-                                                    wallet_account_clone.clone();
-                                                let account_for_fid = account.clone();
-                                                spawn_local(async move {
-                                                    match wallet::get_fid_for_address(
-                                                        &api_url_for_fid,
-                                                        &saved_address,
-                                                    )
-                                                    .await
-                                                    {
-                                                        Ok(fid) => {
-                                                            let mut updated_account =
-                                                                account_for_fid;
-                                                            updated_account.fid = fid;
-                                                            wallet_account_for_fid
-                                                                .set(Some(updated_account));
-                                                        }
-                                                        Err(_) => {
-                                                            wallet_account_for_fid
-                                                                .set(Some(account_for_fid));
-                                                        }
-                                                    }
-                                                });
-                                            } else {
-                                                web_sys::console::log_1(&"⚠️ Wallet address mismatch, clearing saved connection".into());
-                                                let _ = wallet::clear_wallet_from_storage();
+                            // Try to reconnect to the saved wallet, without prompting
+                            match wallet::restore_wallet_connection(&saved_uuid, &saved_address)
+                                .await
+                            {
+                                wallet::RestoreOutcome::Restored(account) => {
+                                    web_sys::console::log_1(
+                                        &"✅ Wallet connection restored from localStorage".into(),
+                                    );
+
+                                    // Get FID for the connected address
+                                    let api_url_for_fid = api_url_clone.clone();
+                                    let wallet_account_for_fid = wallet_account_clone.clone();
+                                    let account_for_fid = account.clone();
+                                    spawn_local(async move {
+                                        match wallet::get_fid_for_address(
+                                            &api_url_for_fid,
+                                            &saved_address,
+                                        )
+                                        .await
+                                        {
+                                            Ok(fid) => {
+                                                let mut updated_account = account_for_fid;
+                                                updated_account.fid = fid;
+                                                wallet_account_for_fid.set(Some(updated_account));
+                                            }
+                                            Err(_) => {
+                                                wallet_account_for_fid.set(Some(account_for_fid));
                                             }
                                         }
-                                    }
+                                    });
                                 }
-                                Err(e) => {
+                                wallet::RestoreOutcome::AddressMismatch(address) => {
+                                    web_sys::console::log_1(
+                                        &format!(
+                                            "⚠️ Wallet address mismatch ({:?}), clearing saved connection",
+                                            address
+                                        )
+                                        .into(),
+                                    );
+                                    let _ = wallet::clear_wallet_from_storage();
+                                }
+                                wallet::RestoreOutcome::Rejected => {
+                                    // The user declined; forget the wallet rather than ask again
+                                    web_sys::console::log_1(
+                                        &"⚠️ Wallet restore rejected, clearing saved connection"
+                                            .into(),
+                                    );
+                                    let _ = wallet::clear_wallet_from_storage();
+                                }
+                                wallet::RestoreOutcome::ConnectFailed(e) => {
                                     web_sys::console::log_1(
                                         &format!("⚠️ Failed to restore wallet connection: {}", e)
                                             .into(),
                                     );
-                                    // Clear invalid saved connection
+                                    // Every restore attempt failed; the saved wallet is gone
                                     let _ = wallet::clear_wallet_from_storage();
                                 }
+                                wallet::RestoreOutcome::NotConnected => {
+                                    // Likely transient (locked or still initializing); try again next load
+                                    web_sys::console::log_1(
+                                        &"⚠️ Wallet not restored, keeping saved connection".into(),
+                                    );
+                                }
                             }
                         } else {
                             // No saved wallet, check if there's already a connected account
@@ -299,7 +363,7 @@ fn App() -> Html {
                     }
                     Err(e) => {
                         wallet_initialized.set(true); // Set initialized even on error
-                        wallet_error.set(Some(e));
+                        toaster.error(e);
                     }
                 }
             });
@@ -327,6 +391,8 @@ fn App() -> Html {
         let current_view = current_view.clone();
         let annual_report_fid_for_restore = annual_report_fid_for_effect.clone();
         let show_annual_report_for_restore = show_annual_report_for_effect.clone();
+        let compare_fids_for_restore = compare_fids.clone();
+        let active_tab_for_restore = active_tab.clone();
 
         // Function to restore state from URL path
         let restore_from_path = {
@@ -336,6 +402,8 @@ fn App() -> Html {
             let loading_tasks = loading_tasks.clone();
             let error_message = error_message.clone();
             let api_url = api_url.clone();
+            let search_api_url = search_api_url.clone();
+            let toaster = toaster.clone();
             let chat_session = chat_session.clone();
             let chat_messages = chat_messages.clone();
             let is_chat_loading = is_chat_loading.clone();
@@ -364,6 +432,8 @@ fn App() -> Html {
                 let loading_tasks_clone = loading_tasks.clone();
                 let error_message_clone = error_message.clone();
                 let api_url_clone = (*api_url).clone();
+                let search_api_url_clone = search_api_url.clone();
+                let toaster_clone = toaster.clone();
                 let _chat_session_clone = chat_session.clone();
                 let _chat_messages_clone = chat_messages.clone();
                 let _is_chat_loading_clone = is_chat_loading.clone();
@@ -380,6 +450,8 @@ fn App() -> Html {
                         loading_tasks_clone,
                         error_message_clone,
                         api_url_clone,
+                        search_api_url_clone,
+                        toaster_clone,
                         current_view_clone,
                     )
                     .await;
@@ -387,15 +459,64 @@ fn App() -> Html {
             }
         };
 
+        // Open the report for an /annual-report/{fid or username} query; usernames are
+        // resolved to a FID first and the friendly URL is kept
+        let open_annual_report = {
+            let api_url = api_url.clone();
+            let annual_report_fid = annual_report_fid_for_restore.clone();
+            let show_annual_report = show_annual_report_for_restore.clone();
+            let error_message = error_message.clone();
+            move |query: &str| match crate::services::parse_report_target(query) {
+                Some(crate::services::ReportTarget::Fid(fid)) => {
+                    annual_report_fid.set(Some(fid));
+                    show_annual_report.set(true);
+                }
+                Some(crate::services::ReportTarget::Username(username)) => {
+                    let api_url = (*api_url).clone();
+                    let annual_report_fid = annual_report_fid.clone();
+                    let show_annual_report = show_annual_report.clone();
+                    let error_message = error_message.clone();
+                    spawn_local(async move {
+                        match crate::services::resolve_username_fid(&api_url, &username).await {
+                            Ok(fid) => {
+                                web_sys::console::log_1(
+                                    &format!("✅ Resolved @{} to FID {}", username, fid).into(),
+                                );
+                                annual_report_fid.set(Some(fid));
+                                show_annual_report.set(true);
+                            }
+                            Err(e) => {
+                                web_sys::console::log_1(
+                                    &format!("⚠️ Couldn't resolve @{}: {}", username, e).into(),
+                                );
+                                error_message.set(Some(format!(
+                                    "No Farcaster user found for @{}",
+                                    username
+                                )));
+                            }
+                        }
+                    });
+                }
+                None => {
+                    web_sys::console::log_1(
+                        &format!("⚠️ Invalid annual report path: {}", query).into(),
+                    );
+                }
+            }
+        };
+
         use_effect_with((), move |_| {
             // Check if there's a URL path to restore from on initial load
             if let Some((query, view)) = crate::services::get_url_path() {
                 // Handle annual-report URL separately
                 if view == "annual-report" {
-                    if let Ok(fid) = query.parse::<i64>() {
-                        annual_report_fid_for_restore.set(Some(fid));
-                        show_annual_report_for_restore.set(true);
+                    open_annual_report(&query);
+                } else if view == "compare" {
+                    if let Some(fids) = crate::services::parse_compare_fids(&query) {
+                        compare_fids_for_restore.set(Some(fids));
                     }
+                } else if view == "leaderboard" {
+                    active_tab_for_restore.set("leaderboard".to_string());
                 } else {
                     restore_from_path(query, view);
                 }
@@ -406,14 +527,21 @@ fn App() -> Html {
             let search_query_state = search_query_state.clone();
             let annual_report_fid_for_popstate = annual_report_fid_for_restore.clone();
             let show_annual_report_for_popstate = show_annual_report_for_restore.clone();
+            let compare_fids_for_popstate = compare_fids_for_restore.clone();
+            let active_tab_for_popstate = active_tab_for_restore.clone();
             crate::services::setup_popstate_listener(move |path| {
                 if let Some((query, view)) = path {
-                    // Handle annual-report URL separately
+                    // Leaving (or switching) the compare view
+                    compare_fids_for_popstate.set(None);
+                    // Handle annual-report and compare URLs separately
                     if view == "annual-report" {
-                        if let Ok(fid) = query.parse::<i64>() {
-                            annual_report_fid_for_popstate.set(Some(fid));
-                            show_annual_report_for_popstate.set(true);
+                        open_annual_report(&query);
+                    } else if view == "compare" {
+                        if let Some(fids) = crate::services::parse_compare_fids(&query) {
+                            compare_fids_for_popstate.set(Some(fids));
                         }
+                    } else if view == "leaderboard" {
+                        active_tab_for_popstate.set("leaderboard".to_string());
                     } else {
                         restore_from_path(query, view);
                     }
@@ -428,6 +556,8 @@ fn App() -> Html {
                     current_view.set("profile".to_string());
                     show_annual_report_for_popstate.set(false);
                     annual_report_fid_for_popstate.set(None);
+                    compare_fids_for_popstate.set(None);
+                    active_tab_for_popstate.set("search".to_string());
                 }
             });
 
@@ -477,7 +607,7 @@ fn App() -> Html {
 
     // Handler for selecting a wallet
     let on_select_wallet = create_wallet_select_handler(
-        wallet_error.clone(),
+        toaster.clone(),
         wallet_account.clone(),
         api_url.clone(),
     );
@@ -489,6 +619,8 @@ fn App() -> Html {
         loading_tasks.clone(),
         error_message.clone(),
         api_url.clone(),
+        search_api_url.clone(),
+        toaster.clone(),
         current_view.clone(),
     );
 
@@ -524,7 +656,9 @@ fn App() -> Html {
         chat_error.clone(),
         api_url.clone(),
         wallet_account.clone(),
+        chat_abort.clone(),
     );
+    let on_stop_chat = create_chat_stop_handler(chat_abort.clone(), is_chat_loading.clone());
 
     let on_chat_keypress = create_chat_keypress_handler(on_send_chat_message.clone());
     let on_search_input_change = create_input_change_handler(search_input.clone());
@@ -551,13 +685,59 @@ fn App() -> Html {
         let selected_endpoint = selected_endpoint.clone();
         Callback::from(move |endpoint: String| {
             let endpoint_clone = endpoint.clone();
-            api_url.set(endpoint_clone.clone().trim_end_matches('/').to_string());
+            let normalized = endpoint_clone.trim_end_matches('/').to_string();
+            if let Err(e) = crate::services::save_api_url(Some(&normalized)) {
+                web_sys::console::warn_1(&format!("⚠️ {}", e).into());
+            }
+            api_url.set(normalized);
             selected_endpoint.set(Some(endpoint_clone.clone()));
             show_endpoint.set(false);
             web_sys::console::log_1(&format!("✅ Selected endpoint: {}", &endpoint).into());
         })
     };
 
+    // API server settings panel
+    let on_open_api_settings = {
+        let show_api_settings = show_api_settings.clone();
+        Callback::from(move |_| show_api_settings.set(true))
+    };
+    let on_close_api_settings = {
+        let show_api_settings = show_api_settings.clone();
+        Callback::from(move |_| show_api_settings.set(false))
+    };
+    let on_apply_api_url = {
+        let api_url = api_url.clone();
+        let selected_endpoint = selected_endpoint.clone();
+        let show_api_settings = show_api_settings.clone();
+        let toaster = toaster.clone();
+        Callback::from(move |url: String| {
+            if let Err(e) = crate::services::save_api_url(Some(&url)) {
+                web_sys::console::warn_1(&format!("⚠️ {}", e).into());
+            }
+            web_sys::console::log_1(&format!("🌐 Using API Server: {}", url).into());
+            toaster.success(format!("Using API server {}", url));
+            api_url.set(url.clone());
+            selected_endpoint.set(Some(url));
+            show_api_settings.set(false);
+        })
+    };
+    let on_reset_api_url = {
+        let api_url = api_url.clone();
+        let selected_endpoint = selected_endpoint.clone();
+        let show_api_settings = show_api_settings.clone();
+        let toaster = toaster.clone();
+        Callback::from(move |_| {
+            if let Err(e) = crate::services::save_api_url(None) {
+                web_sys::console::warn_1(&format!("⚠️ {}", e).into());
+            }
+            let url = crate::services::default_api_url();
+            toaster.info(format!("Using default API server {}", url));
+            api_url.set(url);
+            selected_endpoint.set(None);
+            show_api_settings.set(false);
+        })
+    };
+
     // Handler for adding custom endpoint
     let on_add_custom_endpoint = {
         let custom_endpoints = custom_endpoints.clone();
@@ -565,21 +745,20 @@ fn App() -> Html {
         let ping_results = ping_results.clone();
         let custom_endpoint_error = custom_endpoint_error.clone();
         let is_adding_endpoint = is_adding_endpoint.clone();
+        let toaster = toaster.clone();
         Callback::from(move |_| {
-            let url = (*custom_url_input).clone().trim().to_string();
-            if url.is_empty() {
-                return;
-            }
-
-            // Validate URL format
-            if !url.starts_with("http://") && !url.starts_with("https://") {
-                custom_endpoint_error.set(Some(
-                    "Invalid URL format. Must start with http:// or https://".to_string(),
-                ));
+            let url = (*custom_url_input).clone();
+            if url.trim().is_empty() {
                 return;
             }
 
-            let normalized_url = url.trim_end_matches('/').to_string();
+            let normalized_url = match crate::services::normalize_api_url(&url) {
+                Ok(url) => url,
+                Err(e) => {
+                    custom_endpoint_error.set(Some(e));
+                    return;
+                }
+            };
 
             // Check if endpoint already exists
             let endpoints = (*custom_endpoints).clone();
@@ -600,6 +779,7 @@ fn App() -> Html {
             let is_adding_endpoint_clone = is_adding_endpoint.clone();
             let normalized_url_for_ping = normalized_url.clone();
             let normalized_url_for_log = normalized_url.clone();
+            let toaster = toaster.clone();
 
             wasm_bindgen_futures::spawn_local(async move {
                 // Try to ping the endpoint
@@ -617,6 +797,7 @@ fn App() -> Html {
                         ping_results_clone.set(current_results);
 
                         custom_endpoint_error_clone.set(None);
+                        toaster.success(format!("Added endpoint {}", &normalized_url_for_log));
                         web_sys::console::log_1(
                             &format!("✅ Added custom endpoint: {}", &normalized_url_for_log)
                                 .into(),
@@ -644,25 +825,129 @@ fn App() -> Html {
     // Handler for custom URL input change
     let on_custom_url_input_change = create_input_change_handler(custom_url_input.clone());
 
+    // Probe the leaderboard endpoint once; the tab stays hidden if the API doesn't serve it
+    {
+        let leaderboard_available = leaderboard_available.clone();
+        let api_url = (*api_url).clone();
+        use_effect_with(api_url, move |api_url| {
+            let api_url = api_url.clone();
+            spawn_local(async move {
+                match pages::leaderboard::load_leaderboard(&api_url, None, "casts", 1).await {
+                    Ok(_) => leaderboard_available.set(true),
+                    Err(e) => {
+                        web_sys::console::log_1(
+                            &format!("ℹ️ Leaderboard unavailable, hiding tab: {}", e).into(),
+                        );
+                        leaderboard_available.set(false);
+                    }
+                }
+            });
+            || ()
+        });
+    }
+
     // Handler for tab change
     let on_tab_change = {
         let active_tab = active_tab.clone();
         Callback::from(move |tab: String| {
+            // The leaderboard is the only tab with its own URL
+            if tab == "leaderboard" {
+                crate::services::update_url_path("", "leaderboard");
+            } else if (*active_tab).as_str() == "leaderboard" {
+                crate::services::clear_url_path();
+            }
             active_tab.set(tab);
         })
     };
 
+    // Leaderboard rows open the profile search or the annual report for that FID
+    let on_leaderboard_view_profile = on_popular_fid.reform(|fid: i64| fid.to_string());
+    let on_leaderboard_view_report = {
+        let show_annual_report = show_annual_report.clone();
+        let annual_report_fid = annual_report_fid.clone();
+        Callback::from(move |fid: i64| {
+            annual_report_fid.set(Some(fid));
+            show_annual_report.set(true);
+            crate::services::update_annual_report_url(fid);
+        })
+    };
+
+    // "See an example" on the search page opens the DEMO_FID showcase report
+    let on_view_demo_report = {
+        let on_leaderboard_view_report = on_leaderboard_view_report.clone();
+        Callback::from(move |_: MouseEvent| {
+            if let Some(demo_fid) = crate::services::demo_fid() {
+                web_sys::console::log_1(
+                    &format!("👀 Opening example report for FID {}", demo_fid).into(),
+                );
+                on_leaderboard_view_report.emit(demo_fid);
+            }
+        })
+    };
+
+    // Check reports saved via "Notify me" on a previous visit; surface the first one that's ready
+    {
+        let ready_report_fid = ready_report_fid.clone();
+        let api_url = (*api_url).clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                use pages::annual_report::notify;
+                for saved in notify::load_pending_reports() {
+                    match notify::check_pending_report(&api_url, saved.fid, saved.year).await {
+                        Ok(Some(true)) => {
+                            web_sys::console::log_1(
+                                &format!("🎉 Saved annual report for FID {} is ready", saved.fid)
+                                    .into(),
+                            );
+                            if let Err(e) = notify::forget_pending_report(saved.fid, saved.year) {
+                                web_sys::console::warn_1(&format!("⚠️ {}", e).into());
+                            }
+                            ready_report_fid.set(Some(saved.fid));
+                            break;
+                        }
+                        Ok(_) => {}
+                        Err(e) => {
+                            web_sys::console::warn_1(
+                                &format!(
+                                    "⚠️ Failed to check saved report for FID {}: {}",
+                                    saved.fid, e
+                                )
+                                .into(),
+                            );
+                        }
+                    }
+                }
+            });
+            || ()
+        });
+    }
+    let on_open_ready_report = {
+        let ready_report_fid = ready_report_fid.clone();
+        let on_leaderboard_view_report = on_leaderboard_view_report.clone();
+        Callback::from(move |fid: i64| {
+            ready_report_fid.set(None);
+            on_leaderboard_view_report.emit(fid);
+        })
+    };
+    let on_dismiss_ready_report = {
+        let ready_report_fid = ready_report_fid.clone();
+        Callback::from(move |_| ready_report_fid.set(None))
+    };
+
     // Show annual report modal when FID is available and user is on home page (search tab)
     // Only show on home page, close when user navigates away
+    // The show timer only starts once FID resolution has settled and is cancelled if the deps change
     {
         let show_annual_report_modal = show_annual_report_modal.clone();
         let farcaster_context = farcaster_context.clone();
         let wallet_account = wallet_account.clone();
         let is_farcaster_env = is_farcaster_env.clone();
+        let farcaster_initialized = farcaster_initialized.clone();
         let active_tab = active_tab.clone();
         let search_query = search_query.clone();
         let show_annual_report = show_annual_report.clone();
         let show_endpoint = show_endpoint.clone();
+        let compare_fids = compare_fids.clone();
         
         use_effect_with(
             (
@@ -673,13 +958,18 @@ fn App() -> Html {
                 (*search_query).clone(),
                 *show_annual_report,
                 *show_endpoint,
+                *farcaster_initialized,
+                compare_fids.is_some(),
             ),
-            move |(farcaster_context, wallet_account, is_farcaster_env, active_tab, search_query, show_annual_report, show_endpoint)| {
+            move |(farcaster_context, wallet_account, is_farcaster_env, active_tab, search_query, show_annual_report, show_endpoint, farcaster_initialized, show_compare)| {
+                let mut pending_show = None;
+
                 // Check if we're on the home page (search tab, no search query, no annual report, no endpoint)
                 let is_home_page = active_tab.as_str() == "search" 
                     && search_query.is_none() 
                     && !show_annual_report 
-                    && !show_endpoint;
+                    && !show_endpoint
+                    && !show_compare;
                 
                 if is_home_page {
                     // Check if we have a FID
@@ -694,16 +984,17 @@ fn App() -> Html {
                             .and_then(|acc| acc.fid)
                     };
 
-                    if fid.is_some() {
-                        {
-                            let show_modal = show_annual_report_modal.clone();
-                            if !*show_modal {
-                                // Show modal after a short delay to ensure page is loaded
-                                spawn_local(async move {
-                                    gloo_timers::future::TimeoutFuture::new(500).await;
-                                    show_modal.set(true);
-                                });
-                            }
+                    // Until Mini App detection finishes we don't know whether the FID should come
+                    // from the Farcaster context or the wallet, so don't trust either yet
+                    let fid_settled = *farcaster_initialized && fid.is_some();
+
+                    if fid_settled {
+                        let show_modal = show_annual_report_modal.clone();
+                        if !*show_modal {
+                            // Show modal after a short delay to ensure page is loaded
+                            pending_show = Some(gloo_timers::callback::Timeout::new(500, move || {
+                                show_modal.set(true);
+                            }));
                         }
                     }
                 } else {
@@ -712,6 +1003,9 @@ fn App() -> Html {
                         show_annual_report_modal.set(false);
                     }
                 }
+
+                // Dropping the timeout cancels a pending show (e.g. user navigated away)
+                move || drop(pending_show)
             },
         );
     }
@@ -757,7 +1051,19 @@ fn App() -> Html {
     };
 
     // Determine left action button based on current page state
-    let left_action = if *show_annual_report {
+    let left_action = if compare_fids.is_some() {
+        // Compare page - share the comparison link
+        let current_url = web_sys::window()
+            .and_then(|w| w.location().href().ok())
+            .unwrap_or_default();
+        Some(html! {
+            <share::ShareButton
+                url={Some(current_url)}
+                text={Some("See how our Farcaster years compare on Polyjuice!".to_string())}
+                is_farcaster_env={*is_farcaster_env}
+            />
+        })
+    } else if *show_annual_report {
         // Annual report page - show share button
         let current_url = web_sys::window()
             .and_then(|w| w.location().href().ok())
@@ -820,282 +1126,425 @@ fn App() -> Html {
         })
     };
 
-    html! {
-        <div class="app-container">
-            <div class="content">
-                // Global Header (inside content, inherits background)
-                <Header
-                    wallet_account={(*wallet_account).clone()}
-                    wallet_initialized={*wallet_initialized}
-                    wallet_error={(*wallet_error).clone()}
-                    on_disconnect={on_disconnect_wallet.clone()}
-                    on_connect={on_connect_wallet.clone()}
-                    api_url={(*api_url).clone()}
-                    left_action={left_action}
-                    is_farcaster_env={*is_farcaster_env}
-                    farcaster_context={(*farcaster_context).clone()}
-                />
-                // Main content
-                <div>
-                    // Endpoint View (show when show_endpoint is true, hides tabs)
-                    if *show_endpoint {
-                        <div class="endpoint-page">
-                            <div class="back-to-search">
-                                <button class="back-button" onclick={on_back_from_endpoint}>
-                                    {icons::back_arrow()}
-                                </button>
-                            </div>
-                            <EndpointView
-                                endpoint_data={(*endpoint_data).clone()}
-                                is_loading={*is_endpoint_loading}
-                                error={(*endpoint_error).clone()}
-                                ping_results={(*ping_results).clone()}
-                                selected_endpoint={(*selected_endpoint).clone()}
-                                on_select_endpoint={on_select_endpoint.clone()}
-                                custom_endpoints={(*custom_endpoints).clone()}
-                                custom_url_input={(*custom_url_input).clone()}
-                                on_custom_url_input_change={on_custom_url_input_change.clone()}
-                                on_add_custom_endpoint={on_add_custom_endpoint.clone()}
-                                custom_endpoint_error={(*custom_endpoint_error).clone()}
-                                is_adding_endpoint={*is_adding_endpoint}
-                            />
-                        </div>
-                    } else {
-                        // Main content area with tabs
-                        <div class="main-content">
-                            // Results Page (Profile + Chat cards) - shown when search_query exists
-                            if let Some(query) = (*search_query).as_ref() {
-                                <div class="results-page">
-
-                                    // Profile Card (only show if current_view is "profile")
-                                    if (*current_view).as_str() == "profile" {
-                                        <ProfileLoader
-                                            search_query={query.clone()}
-                                            is_fid={*is_fid_search}
-                                            api_url={(*api_url).clone()}
-                                            wallet_account={(*wallet_account).clone()}
-                                            on_profile_loaded={Callback::from({
-                                                let search_result = search_result.clone();
-                                                move |profile: ProfileData| {
-                                                    // Update search_result when profile loads (for ChatView compatibility)
-                                                    search_result.set(Some(SearchResult {
-                                                        profile,
-                                                        social: None,
-                                                        mbti: None,
-                                                        pending_jobs: None,
-                                                    }));
-                                                }
-                                            })}
-                                        />
-                                    }
+    // ?debug=1: copy a JSON snapshot of app state for bug reports
+    let on_copy_debug_info = {
+        let toaster = toaster.clone();
+        let api_url = api_url.clone();
+        let selected_endpoint = selected_endpoint.clone();
+        let ping_results = ping_results.clone();
+        let is_farcaster_env = is_farcaster_env.clone();
+        let farcaster_context = farcaster_context.clone();
+        let wallet_account = wallet_account.clone();
+        let error_message = error_message.clone();
+        let chat_error = chat_error.clone();
+        let endpoint_error = endpoint_error.clone();
+        let custom_endpoint_error = custom_endpoint_error.clone();
+        Callback::from(move |_: MouseEvent| {
+            let window = web_sys::window();
+            let resolved_fid = if *is_farcaster_env {
+                (*farcaster_context)
+                    .as_ref()
+                    .and_then(|ctx| ctx.user.as_ref())
+                    .and_then(|user| user.fid)
+            } else {
+                (*wallet_account).as_ref().and_then(|acc| acc.fid)
+            };
+            let bundle = crate::services::DebugBundle {
+                app_version: env!("CARGO_PKG_VERSION"),
+                page_url: window.as_ref().and_then(|w| w.location().href().ok()),
+                user_agent: window
+                    .as_ref()
+                    .and_then(|w| js_sys::Reflect::get(w, &"navigator".into()).ok())
+                    .and_then(|nav| js_sys::Reflect::get(&nav, &"userAgent".into()).ok())
+                    .and_then(|ua| ua.as_string()),
+                api_url: (*api_url).clone(),
+                selected_endpoint: (*selected_endpoint).clone(),
+                ping_results: (*ping_results).clone(),
+                is_farcaster_env: *is_farcaster_env,
+                resolved_fid,
+                last_error: (*error_message)
+                    .clone()
+                    .or_else(|| (*chat_error).clone())
+                    .or_else(|| (*endpoint_error).clone())
+                    .or_else(|| (*custom_endpoint_error).clone()),
+            };
+            let json = bundle.to_json();
+            let toaster = toaster.clone();
+            spawn_local(async move {
+                if crate::services::copy_to_clipboard_async(&json).await {
+                    toaster.success("Debug info copied to clipboard");
+                } else {
+                    web_sys::console::log_1(&format!("🐞 Debug info:\n{}", json).into());
+                    toaster.error("Couldn't copy debug info (logged to console instead)");
+                }
+            });
+        })
+    };
 
-                                    // Chat Card (only show if current_view is "chat")
-                                    if (*current_view).as_str() == "chat" {
-                                        <ChatView
-                                            chat_session={(*chat_session).clone()}
-                                            chat_messages={(*chat_messages).clone()}
-                                            chat_message={(*chat_message).clone()}
-                                            is_chat_loading={*is_chat_loading}
-                                            chat_error={(*chat_error).clone()}
-                                            search_result={(*search_result).clone()}
-                                            on_input_change={on_chat_input_change}
-                                            on_keypress={on_chat_keypress}
-                                            on_send_message={on_send_chat_message}
-                                        />
-                                    }
+    let on_dismiss_toast = {
+        let dispatcher = toasts.dispatcher();
+        Callback::from(move |id: u32| dispatcher.dispatch(toast::ToastAction::Dismiss(id)))
+    };
 
-                                    // Floating Chat Button (only show on results page when profile is visible)
-                                    if (*current_view).as_str() == "profile" {
-                                        <FloatingChatButton on_switch_to_chat={on_switch_to_chat} />
-                                    }
+    html! {
+        <ContextProvider<toast::Toaster> context={toaster.clone()}>
+            <div class="app-container">
+                <div class="content">
+                    // Global Header (inside content, inherits background)
+                    <Header
+                        wallet_account={(*wallet_account).clone()}
+                        wallet_initialized={*wallet_initialized}
+                        on_disconnect={on_disconnect_wallet.clone()}
+                        on_connect={on_connect_wallet.clone()}
+                        api_url={(*api_url).clone()}
+                        left_action={left_action}
+                        is_farcaster_env={*is_farcaster_env}
+                        farcaster_context={(*farcaster_context).clone()}
+                        on_open_settings={Some(on_open_api_settings)}
+                    />
+                    if *show_api_settings {
+                        <ApiSettingsPanel
+                            api_url={(*api_url).clone()}
+                            on_apply={on_apply_api_url}
+                            on_reset={on_reset_api_url}
+                            on_close={on_close_api_settings}
+                        />
+                    }
+                    // Main content
+                    <div>
+                        // Endpoint View (show when show_endpoint is true, hides tabs)
+                        if *show_endpoint {
+                            <div class="endpoint-page">
+                                <div class="back-to-search">
+                                    <button class="back-button" onclick={on_back_from_endpoint}>
+                                        {icons::back_arrow()}
+                                    </button>
                                 </div>
-                            } else {
-                                // Tab-based pages (only show when no search results)
-                                {
-                                    if *show_annual_report {
-                                        if let Some(fid) = *annual_report_fid {
-                                            // Generate share URL for annual report
-                                            let share_url = web_sys::window()
-                                                .and_then(|w| w.location().origin().ok())
-                                                .map(|origin| format!("{}/annual-report/{}", origin, fid));
-
-                                            // Get current user FID from farcaster context or wallet account
-                                            let current_user_fid = if *is_farcaster_env {
-                                                (*farcaster_context).as_ref()
-                                                    .and_then(|ctx| ctx.user.as_ref())
-                                                    .and_then(|user| user.fid)
-                                            } else {
-                                                (*wallet_account).as_ref()
-                                                    .and_then(|acc| acc.fid)
-                                            };
+                                <EndpointView
+                                    endpoint_data={(*endpoint_data).clone()}
+                                    is_loading={*is_endpoint_loading}
+                                    error={(*endpoint_error).clone()}
+                                    ping_results={(*ping_results).clone()}
+                                    selected_endpoint={(*selected_endpoint).clone()}
+                                    on_select_endpoint={on_select_endpoint.clone()}
+                                    custom_endpoints={(*custom_endpoints).clone()}
+                                    custom_url_input={(*custom_url_input).clone()}
+                                    on_custom_url_input_change={on_custom_url_input_change.clone()}
+                                    on_add_custom_endpoint={on_add_custom_endpoint.clone()}
+                                    custom_endpoint_error={(*custom_endpoint_error).clone()}
+                                    is_adding_endpoint={*is_adding_endpoint}
+                                />
+                            </div>
+                        } else {
+                            // Main content area with tabs
+                            <div class="main-content">
+                                // Results Page (Profile + Chat cards) - shown when search_query exists
+                                if let Some(query) = (*search_query).as_ref() {
+                                    <div class="results-page">
+
+                                        // Profile Card (only show if current_view is "profile")
+                                        if (*current_view).as_str() == "profile" {
+                                            <ProfileLoader
+                                                search_query={query.clone()}
+                                                is_fid={*is_fid_search}
+                                                api_url={(*search_api_url).clone().unwrap_or_else(|| (*api_url).clone())}
+                                                wallet_account={(*wallet_account).clone()}
+                                                on_profile_loaded={Callback::from({
+                                                    let search_result = search_result.clone();
+                                                    move |profile: ProfileData| {
+                                                        // Update search_result when profile loads (for ChatView compatibility)
+                                                        search_result.set(Some(SearchResult {
+                                                            profile,
+                                                            social: None,
+                                                            mbti: None,
+                                                            pending_jobs: None,
+                                                        }));
+                                                    }
+                                                })}
+                                            />
+                                        }
 
+                                        // Chat Card (only show if current_view is "chat")
+                                        if (*current_view).as_str() == "chat" {
+                                            <ChatView
+                                                chat_session={(*chat_session).clone()}
+                                                chat_messages={(*chat_messages).clone()}
+                                                chat_message={(*chat_message).clone()}
+                                                is_chat_loading={*is_chat_loading}
+                                                chat_error={(*chat_error).clone()}
+                                                search_result={(*search_result).clone()}
+                                                on_input_change={on_chat_input_change}
+                                                on_keypress={on_chat_keypress}
+                                                on_send_message={on_send_chat_message}
+                                                on_stop={on_stop_chat}
+                                            />
+                                        }
+
+                                        // Floating Chat Button (only show on results page when profile is visible)
+                                        if (*current_view).as_str() == "profile" {
+                                            <FloatingChatButton on_switch_to_chat={on_switch_to_chat} />
+                                        }
+                                    </div>
+                                } else {
+                                    // Tab-based pages (only show when no search results)
+                                    {
+                                        if let Some((fid_a, fid_b)) = *compare_fids {
                                             html! {
                                                 <div class="annual-report-container">
-                                                    <AnnualReportPage
-                                                        fid={fid}
+                                                    <CompareReport
+                                                        fid_a={fid_a}
+                                                        fid_b={fid_b}
                                                         api_url={(*api_url).clone()}
                                                         wallet_account={(*wallet_account).clone()}
-                                                        is_farcaster_env={*is_farcaster_env}
-                                                        share_url={share_url}
-                                                        current_user_fid={current_user_fid}
-                                                        farcaster_context={(*farcaster_context).clone()}
                                                     />
                                                 </div>
                                             }
-                                        } else {
-                                            html! {
-                                                <div class="error-container">
-                                                    <p>{"No FID available for annual report"}</p>
-                                                </div>
+                                        } else if *show_annual_report {
+                                            if let Some(fid) = *annual_report_fid {
+                                                // Generate share URL for annual report
+                                                let share_url = web_sys::window()
+                                                    .and_then(|w| w.location().origin().ok())
+                                                    .map(|origin| format!("{}/annual-report/{}", origin, fid));
+
+                                                // Get current user FID from farcaster context or wallet account
+                                                let current_user_fid = if *is_farcaster_env {
+                                                    (*farcaster_context).as_ref()
+                                                        .and_then(|ctx| ctx.user.as_ref())
+                                                        .and_then(|user| user.fid)
+                                                } else {
+                                                    (*wallet_account).as_ref()
+                                                        .and_then(|acc| acc.fid)
+                                                };
+
+                                                html! {
+                                                    <div class="annual-report-container">
+                                                        <AnnualReportPage
+                                                            fid={fid}
+                                                            api_url={(*api_url).clone()}
+                                                            wallet_account={(*wallet_account).clone()}
+                                                            is_farcaster_env={*is_farcaster_env}
+                                                            share_url={share_url}
+                                                            current_user_fid={current_user_fid}
+                                                            farcaster_context={(*farcaster_context).clone()}
+                                                        />
+                                                    </div>
+                                                }
+                                            } else {
+                                                html! {
+                                                    <div class="error-container">
+                                                        <p>{"No FID available for annual report"}</p>
+                                                    </div>
+                                                }
                                             }
-                                        }
-                                    } else if (*active_tab).as_str() == "profile" {
-                                        {
-                                            // Get current user FID from Farcaster context or wallet
-                                            let current_fid = (*farcaster_context).as_ref()
+                                        } else if (*active_tab).as_str() == "profile" {
+                                            {
+                                                // Get current user FID from Farcaster context or wallet
+                                                let current_fid = (*farcaster_context).as_ref()
+                                                    .and_then(|ctx| ctx.user.as_ref())
+                                                    .and_then(|u| u.fid)
+                                                    .or_else(|| {
+                                                        (*wallet_account).as_ref()
+                                                            .and_then(|acc| acc.fid)
+                                                    });
+                                                
+                                                html! {
+                                                    <div class="results-page">
+                                                        if let Some(fid) = current_fid {
+                                                            <>
+                                                                // Annual Report Button
+                                                                <div class="annual-report-button-container">
+                                                                    <button
+                                                                        class="annual-report-button"
+                                                                        onclick={Callback::from({
+                                                                            let show_annual_report_clone = show_annual_report.clone();
+                                                                            let annual_report_fid_clone = annual_report_fid.clone();
+                                                                            move |_| {
+                                                                                annual_report_fid_clone.set(Some(fid));
+                                                                                show_annual_report_clone.set(true);
+                                                                                // Update URL to /annual-report/{fid}
+                                                                                crate::services::update_annual_report_url(fid);
+                                                                            }
+                                                                        })}
+                                                                    >
+                                                                        {"🎉 View 2025 Annual Report"}
+                                                                    </button>
+                                                                </div>
+                                                                
+                                                                // Profile Loader (same as MBTI page)
+                                                                <ProfileLoader
+                                                                    search_query={fid.to_string()}
+                                                                    is_fid={true}
+                                                                    api_url={(*api_url).clone()}
+                                                                    wallet_account={(*wallet_account).clone()}
+                                                                    on_profile_loaded={Callback::from(|_| {})}
+                                                                />
+                                                            </>
+                                                        } else {
+                                                            <div class="profile-empty">
+                                                                <p>{"No user profile available"}</p>
+                                                                <p class="profile-hint">{"Please open with Farcaster or connect a Farcaster-linked wallet"}</p>
+                                                            </div>
+                                                        }
+                                                    </div>
+                                                }
+                                            }
+                                        } else if (*active_tab).as_str() == "search" {
+                                            // First-time visitors (no Farcaster or wallet FID) can open a showcase report
+                                            let has_user_fid = (*farcaster_context).as_ref()
                                                 .and_then(|ctx| ctx.user.as_ref())
                                                 .and_then(|u| u.fid)
-                                                .or_else(|| {
-                                                    (*wallet_account).as_ref()
-                                                        .and_then(|acc| acc.fid)
-                                                });
-                                            
+                                                .or_else(|| (*wallet_account).as_ref().and_then(|acc| acc.fid))
+                                                .is_some();
+                                            let show_demo_cta = !has_user_fid && crate::services::demo_fid().is_some();
                                             html! {
-                                                <div class="results-page">
-                                                    if let Some(fid) = current_fid {
-                                                        <>
-                                                            // Annual Report Button
-                                                            <div class="annual-report-button-container">
-                                                                <button
-                                                                    class="annual-report-button"
-                                                                    onclick={Callback::from({
-                                                                        let show_annual_report_clone = show_annual_report.clone();
-                                                                        let annual_report_fid_clone = annual_report_fid.clone();
-                                                                        move |_| {
-                                                                            annual_report_fid_clone.set(Some(fid));
-                                                                            show_annual_report_clone.set(true);
-                                                                            // Update URL to /annual-report/{fid}
-                                                                            crate::services::update_annual_report_url(fid);
-                                                                        }
-                                                                    })}
-                                                                >
-                                                                    {"🎉 View 2025 Annual Report"}
-                                                                </button>
+                                                <div class="search-page">
+
+                                                    <div class="search-header">
+                                                        <div class="logo">
+                                                            // Logo Image
+                                                            <div class="logo-image">
+                                                                <img src="/logo.png" alt="Polyjuice Logo" />
                                                             </div>
-                                                            
-                                                            // Profile Loader (same as MBTI page)
-                                                            <ProfileLoader
-                                                                search_query={fid.to_string()}
-                                                                is_fid={true}
-                                                                api_url={(*api_url).clone()}
-                                                                wallet_account={(*wallet_account).clone()}
-                                                                on_profile_loaded={Callback::from(|_| {})}
-                                                            />
-                                                        </>
-                                                    } else {
-                                                        <div class="profile-empty">
-                                                            <p>{"No user profile available"}</p>
-                                                            <p class="profile-hint">{"Please open with Farcaster or connect a Farcaster-linked wallet"}</p>
-                                                        </div>
-                                                    }
-                                                </div>
-                                            }
-                                        }
-                                    } else if (*active_tab).as_str() == "search" {
-                                        html! {
-                                            <div class="search-page">
-
-                                                <div class="search-header">
-                                                    <div class="logo">
-                                                        // Logo Image
-                                                        <div class="logo-image">
-                                                            <img src="/logo.png" alt="Polyjuice Logo" />
+                                                            <h1>{"polyjuice"}</h1>
+                                                            <p class="tagline">{"Discover & Chat with Farcaster Users"}</p>
                                                         </div>
-                                                        <h1>{"polyjuice"}</h1>
-                                                        <p class="tagline">{"Discover & Chat with Farcaster Users"}</p>
+
                                                     </div>
 
-                                                </div>
+                                                    <div class="search-content">
+                                                        <SearchBox
+                                                            search_input={(*search_input).clone()}
+                                                            is_loading={!(*loading_tasks).is_empty()}
+                                                            on_input_change={on_search_input_change}
+                                                            on_keypress={on_keypress}
+                                                            on_search={on_search.clone()}
+                                                        />
 
-                                                <div class="search-content">
-                                                    <SearchBox
-                                                        search_input={(*search_input).clone()}
-                                                        is_loading={!(*loading_tasks).is_empty()}
-                                                        on_input_change={on_search_input_change}
-                                                        on_keypress={on_keypress}
-                                                        on_search={on_search.clone()}
-                                                    />
+                                                        <MobileSearchButton
+                                                            is_loading={!(*loading_tasks).is_empty()}
+                                                            on_search={on_search.clone()}
+                                                        />
 
-                                                    <MobileSearchButton
-                                                        is_loading={!(*loading_tasks).is_empty()}
-                                                        on_search={on_search.clone()}
-                                                    />
+                                                        <SearchSuggestions on_popular_fid={on_popular_fid} />
 
-                                                    <SearchSuggestions on_popular_fid={on_popular_fid} />
+                                                        if show_demo_cta {
+                                                            <div class="annual-report-button-container">
+                                                                <button
+                                                                    class="annual-report-button demo-report-button"
+                                                                    onclick={on_view_demo_report.clone()}
+                                                                >
+                                                                    {"👀 See an example"}
+                                                                </button>
+                                                            </div>
+                                                        }
 
-                                                    <ErrorMessage error={(*error_message).clone()} />
+                                                        <ErrorMessage error={(*error_message).clone()} />
+                                                    </div>
                                                 </div>
-                                            </div>
-                                        }
-                                    } else if (*active_tab).as_str() == "about" {
-                                        html! {
-                                            <AboutPage
-                                                endpoint_data={(*endpoint_data).clone()}
-                                                is_loading={*is_endpoint_loading}
-                                                error={(*endpoint_error).clone()}
-                                                ping_results={(*ping_results).clone()}
-                                                selected_endpoint={(*selected_endpoint).clone()}
-                                                on_select_endpoint={on_select_endpoint.clone()}
-                                                custom_endpoints={(*custom_endpoints).clone()}
-                                                custom_url_input={(*custom_url_input).clone()}
-                                                on_custom_url_input_change={on_custom_url_input_change.clone()}
-                                                on_add_custom_endpoint={on_add_custom_endpoint.clone()}
-                                                custom_endpoint_error={(*custom_endpoint_error).clone()}
-                                                is_adding_endpoint={*is_adding_endpoint}
-                                                on_fetch_endpoints={on_fetch_endpoints.clone()}
-                                            />
-                                        }
-                                    } else {
-                                        html! {
-                                            <div class="search-page">
-                                                <p>{"Unknown tab"}</p>
-                                            </div>
+                                            }
+                                        } else if (*active_tab).as_str() == "leaderboard" {
+                                            html! {
+                                                <LeaderboardPage
+                                                    api_url={(*api_url).clone()}
+                                                    wallet_account={(*wallet_account).clone()}
+                                                    on_view_profile={on_leaderboard_view_profile.clone()}
+                                                    on_view_report={on_leaderboard_view_report.clone()}
+                                                />
+                                            }
+                                        } else if (*active_tab).as_str() == "about" {
+                                            html! {
+                                                <AboutPage
+                                                    endpoint_data={(*endpoint_data).clone()}
+                                                    is_loading={*is_endpoint_loading}
+                                                    error={(*endpoint_error).clone()}
+                                                    ping_results={(*ping_results).clone()}
+                                                    selected_endpoint={(*selected_endpoint).clone()}
+                                                    on_select_endpoint={on_select_endpoint.clone()}
+                                                    custom_endpoints={(*custom_endpoints).clone()}
+                                                    custom_url_input={(*custom_url_input).clone()}
+                                                    on_custom_url_input_change={on_custom_url_input_change.clone()}
+                                                    on_add_custom_endpoint={on_add_custom_endpoint.clone()}
+                                                    custom_endpoint_error={(*custom_endpoint_error).clone()}
+                                                    is_adding_endpoint={*is_adding_endpoint}
+                                                    on_fetch_endpoints={on_fetch_endpoints.clone()}
+                                                />
+                                            }
+                                        } else {
+                                            html! {
+                                                <div class="search-page">
+                                                    <p>{"Unknown tab"}</p>
+                                                </div>
+                                            }
                                         }
                                     }
                                 }
-                            }
-                        </div>
+                            </div>
 
-                        // Bottom Tab Navigation (only show when not in endpoint view, no search query, and not showing annual report)
-                        if (*search_query).is_none() && !*show_annual_report {
-                            <BottomTab active_tab={(*active_tab).clone()} on_tab_change={on_tab_change} />
+                            // Bottom Tab Navigation (only show when not in endpoint view, no search query, and not showing annual report)
+                            if (*search_query).is_none() && !*show_annual_report && compare_fids.is_none() {
+                                <BottomTab
+                                    active_tab={(*active_tab).clone()}
+                                    on_tab_change={on_tab_change}
+                                    show_leaderboard={*leaderboard_available}
+                                />
+                            }
                         }
-                    }
+                    </div>
                 </div>
+                // Wallet List Modal
+                if *show_wallet_list {
+                    <WalletList
+                        wallets={discovered_wallets.to_vec()}
+                        on_select_wallet={Callback::from({
+                            let on_select_wallet = on_select_wallet.clone();
+                            let on_close_wallet_list = on_close_wallet_list.clone();
+                            move |uuid: String| {
+                                on_close_wallet_list.emit(());
+                                on_select_wallet.emit(uuid);
+                            }
+                        })}
+                        on_close={on_close_wallet_list.clone()}
+                    />
+                }
+                // Annual Report Modal
+                if *show_annual_report_modal {
+                    <AnnualReportModal
+                        on_claim={on_claim_annual_report.clone()}
+                        on_close={on_close_annual_report_modal.clone()}
+                    />
+                }
+                if let Some(fid) = *ready_report_fid {
+                    <pages::annual_report::notify::ReportReadyBanner
+                        fid={fid}
+                        on_open={on_open_ready_report.clone()}
+                        on_dismiss={on_dismiss_ready_report.clone()}
+                    />
+                }
             </div>
-            // Wallet List Modal
-            if *show_wallet_list {
-                <WalletList
-                    wallets={discovered_wallets.to_vec()}
-                    on_select_wallet={Callback::from({
-                        let on_select_wallet = on_select_wallet.clone();
-                        let on_close_wallet_list = on_close_wallet_list.clone();
-                        move |uuid: String| {
-                            on_close_wallet_list.emit(());
-                            on_select_wallet.emit(uuid);
-                        }
-                    })}
-                    on_close={on_close_wallet_list.clone()}
-                />
-            }
-            // Annual Report Modal
-            if *show_annual_report_modal {
-                <AnnualReportModal
-                    on_claim={on_claim_annual_report.clone()}
-                    on_close={on_close_annual_report_modal.clone()}
-                />
+            if *debug_mode {
+                <button
+                    class="debug-copy-button"
+                    onclick={on_copy_debug_info}
+                    style="
+                        position: fixed;
+                        left: calc(12px + env(safe-area-inset-left, 0px));
+                        bottom: calc(88px + env(safe-area-inset-bottom, 0px));
+                        padding: 6px 10px;
+                        font-size: 12px;
+                        color: white;
+                        background: rgba(0, 0, 0, 0.6);
+                        border: 1px solid rgba(255, 255, 255, 0.3);
+                        border-radius: 8px;
+                        cursor: pointer;
+                        z-index: 19000;
+                    "
+                >
+                    {"🐞 Copy debug info"}
+                </button>
             }
-        </div>
+            <toast::ToastContainer toasts={toasts.toasts.clone()} on_dismiss={on_dismiss_toast} />
+            <ConfirmHost />
+        </ContextProvider<toast::Toaster>>
     }
 }
 
@@ -0,0 +1,170 @@
+use pulldown_cmark::Event;
+use pulldown_cmark::Options;
+use pulldown_cmark::Parser;
+use pulldown_cmark::Tag;
+use yew::prelude::*;
+
+// Markdown is turned into Yew nodes directly rather than an HTML string, so text is always
+// escaped by the renderer. Raw HTML in the source is shown as text and link targets are
+// limited to `safe_link_href` schemes.
+
+/// Element being built while walking the parser events
+enum Node {
+    Root,
+    Paragraph,
+    Heading,
+    BlockQuote,
+    CodeBlock,
+    List(Option<u64>),
+    Item,
+    Emphasis,
+    Strong,
+    Strikethrough,
+    Link(Option<String>),
+    Table,
+    TableHead,
+    TableRow,
+    TableCell,
+    /// Tags we don't render specially (footnotes, metadata, ...): children only
+    Passthrough,
+}
+
+impl Node {
+    fn from_tag(tag: Tag) -> Self {
+        match tag {
+            Tag::Paragraph => Node::Paragraph,
+            Tag::Heading { .. } => Node::Heading,
+            Tag::BlockQuote(_) => Node::BlockQuote,
+            Tag::CodeBlock(_) => Node::CodeBlock,
+            Tag::List(start) => Node::List(start),
+            Tag::Item => Node::Item,
+            Tag::Emphasis => Node::Emphasis,
+            Tag::Strong => Node::Strong,
+            Tag::Strikethrough => Node::Strikethrough,
+            // Images become links to the image; chat bubbles don't load remote media
+            Tag::Link { dest_url, .. } | Tag::Image { dest_url, .. } => {
+                Node::Link(safe_link_href(&dest_url))
+            }
+            Tag::Table(_) => Node::Table,
+            Tag::TableHead => Node::TableHead,
+            Tag::TableRow => Node::TableRow,
+            Tag::TableCell => Node::TableCell,
+            _ => Node::Passthrough,
+        }
+    }
+
+    fn into_html(self, children: Vec<Html>) -> Html {
+        let children: Html = children.into_iter().collect();
+        match self {
+            Node::Root | Node::Passthrough => children,
+            // Headings in a chat bubble read better as bold lines than page-sized titles
+            Node::Heading => html! { <p class="md-heading"><strong>{children}</strong></p> },
+            Node::Paragraph => html! { <p>{children}</p> },
+            Node::BlockQuote => html! { <blockquote>{children}</blockquote> },
+            Node::CodeBlock => html! { <pre><code>{children}</code></pre> },
+            Node::List(Some(start)) => {
+                html! { <ol start={start.to_string()}>{children}</ol> }
+            }
+            Node::List(None) => html! { <ul>{children}</ul> },
+            Node::Item => html! { <li>{children}</li> },
+            Node::Emphasis => html! { <em>{children}</em> },
+            Node::Strong => html! { <strong>{children}</strong> },
+            Node::Strikethrough => html! { <del>{children}</del> },
+            Node::Link(Some(href)) => html! {
+                <a href={href} target="_blank" rel="noopener noreferrer">{children}</a>
+            },
+            Node::Link(None) => html! { <span>{children}</span> },
+            Node::Table => html! { <table>{children}</table> },
+            Node::TableHead => html! { <thead><tr>{children}</tr></thead> },
+            Node::TableRow => html! { <tr>{children}</tr> },
+            Node::TableCell => html! { <td>{children}</td> },
+        }
+    }
+}
+
+/// Allow only http(s) and mailto links; anything else (javascript:, data:, relative paths)
+/// is dropped and the link text is rendered plain
+pub fn safe_link_href(url: &str) -> Option<String> {
+    let url = url.trim();
+    if url.chars().any(|c| c.is_control() || c.is_whitespace()) {
+        return None;
+    }
+    let lower = url.to_ascii_lowercase();
+    let allowed = ["https://", "http://", "mailto:"]
+        .iter()
+        .any(|scheme| lower.starts_with(scheme) && lower.len() > scheme.len());
+    allowed.then(|| url.to_string())
+}
+
+/// Render markdown (CommonMark plus tables, strikethrough, task lists) as Yew nodes
+pub fn render_markdown(source: &str) -> Html {
+    let options =
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+    let mut stack: Vec<(Node, Vec<Html>)> = vec![(Node::Root, Vec::new())];
+
+    for event in Parser::new_ext(source, options) {
+        match event {
+            Event::Start(tag) => stack.push((Node::from_tag(tag), Vec::new())),
+            Event::End(_) => {
+                // The root frame is never popped; the parser always balances Start/End
+                if stack.len() > 1 {
+                    let (kind, children) = stack.pop().unwrap_or((Node::Root, Vec::new()));
+                    let node = kind.into_html(children);
+                    if let Some((_, parent)) = stack.last_mut() {
+                        parent.push(node);
+                    }
+                }
+            }
+            event => {
+                let node = match event {
+                    // Raw HTML is shown as text, never parsed
+                    Event::Text(text) | Event::Html(text) | Event::InlineHtml(text) => {
+                        html! { {text.to_string()} }
+                    }
+                    Event::Code(code) => html! { <code>{code.to_string()}</code> },
+                    Event::SoftBreak => html! { {" "} },
+                    Event::HardBreak => html! { <br /> },
+                    Event::Rule => html! { <hr /> },
+                    Event::TaskListMarker(checked) => html! {
+                        <input type="checkbox" checked={checked} disabled=true />
+                    },
+                    _ => html! {},
+                };
+                if let Some((_, children)) = stack.last_mut() {
+                    children.push(node);
+                }
+            }
+        }
+    }
+
+    // Fold anything left open back into the root
+    while stack.len() > 1 {
+        let (kind, children) = stack.pop().unwrap_or((Node::Root, Vec::new()));
+        let node = kind.into_html(children);
+        if let Some((_, parent)) = stack.last_mut() {
+            parent.push(node);
+        }
+    }
+    let (root, children) = stack.pop().unwrap_or((Node::Root, Vec::new()));
+    root.into_html(children)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_safe_link_href() {
+        assert_eq!(
+            safe_link_href(" https://warpcast.com/~/channel "),
+            Some("https://warpcast.com/~/channel".to_string())
+        );
+        assert!(safe_link_href("mailto:hi@example.com").is_some());
+        assert_eq!(safe_link_href("javascript:alert(1)"), None);
+        assert_eq!(safe_link_href("JaVaScRiPt:alert(1)"), None);
+        assert_eq!(safe_link_href("data:text/html;base64,PHNjcmlwdD4="), None);
+        assert_eq!(safe_link_href("/relative/path"), None);
+        assert_eq!(safe_link_href("https://"), None);
+        assert_eq!(safe_link_href("https://a.com/\u{0}x"), None);
+    }
+}
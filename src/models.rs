@@ -338,12 +338,89 @@ pub struct EngagementResponse {
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct PopularCast {
+    #[serde(default)]
     pub message_hash: String,
     pub text: String,
     pub reactions: usize,
     pub recasts: usize,
     pub replies: usize,
     pub timestamp: i64,
+    /// Embedded URLs/casts; missing or null in older responses
+    #[serde(default, deserialize_with = "deserialize_embeds")]
+    pub embeds: Vec<CastEmbed>,
+}
+
+/// Embeds that don't parse are skipped instead of failing the whole report
+fn deserialize_embeds<'de, D>(deserializer: D) -> Result<Vec<CastEmbed>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let values = Option::<Vec<serde_json::Value>>::deserialize(deserializer)?;
+    Ok(values
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|value| serde_json::from_value(value).ok())
+        .collect())
+}
+
+/// A cast embed: either a bare URL string or an object (`{"url": ...}` or a quoted cast)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum CastEmbed {
+    Url(String),
+    Object {
+        #[serde(default)]
+        url: Option<String>,
+    },
+}
+
+impl CastEmbed {
+    pub fn url(&self) -> Option<&str> {
+        match self {
+            CastEmbed::Url(url) => Some(url),
+            CastEmbed::Object { url } => url.as_deref(),
+        }
+    }
+}
+
+impl EngagementResponse {
+    /// Whether anyone reacted, recast or replied, whether or not `top_reactors` was aggregated
+    pub fn has_interactions(&self) -> bool {
+        self.reactions_received > 0 || self.recasts_received > 0 || self.replies_received > 0
+    }
+}
+
+impl PopularCast {
+    /// First embed that looks like an image (by extension or a known image host)
+    pub fn first_image_embed(&self) -> Option<&str> {
+        const IMAGE_EXTENSIONS: [&str; 5] = [".jpg", ".jpeg", ".png", ".gif", ".webp"];
+        const IMAGE_HOSTS: [&str; 2] = ["imagedelivery.net/", "i.imgur.com/"];
+
+        self.embeds.iter().filter_map(CastEmbed::url).find(|url| {
+            let path = url.split(['?', '#']).next().unwrap_or(url).to_ascii_lowercase();
+            path.starts_with("https://")
+                && (IMAGE_EXTENSIONS.iter().any(|ext| path.ends_with(ext))
+                    || IMAGE_HOSTS.iter().any(|host| path.contains(host)))
+        })
+    }
+}
+
+/// Batch profile lookup response: a bare array, or the array under `profiles`
+/// FIDs that don't exist are left out rather than returned as errors
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum ProfilesBatchResponse {
+    List(Vec<ProfileData>),
+    Wrapped { profiles: Vec<ProfileData> },
+}
+
+impl ProfilesBatchResponse {
+    pub fn into_profiles(self) -> Vec<ProfileData> {
+        match self {
+            ProfilesBatchResponse::List(profiles) => profiles,
+            ProfilesBatchResponse::Wrapped { profiles } => profiles,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -384,6 +461,7 @@ pub struct MonthlyDistribution {
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct CastInfo {
+    #[serde(default)]
     pub message_hash: String,
     pub text: String,
     pub timestamp: i64,
@@ -471,4 +549,107 @@ pub struct ProfileWithRegistration {
     pub total_casts: Option<usize>,
     pub total_reactions: Option<usize>,
     pub total_links: Option<usize>,
+    /// Power badge / verified flag, when the profile API exposes it
+    #[serde(default, alias = "powerBadge")]
+    pub power_badge: Option<bool>,
+}
+
+impl ProfileWithRegistration {
+    /// Stand-in when the profile couldn't be loaded: just the FID, every detail missing
+    pub fn fid_only(fid: i64) -> Self {
+        Self {
+            fid,
+            username: None,
+            display_name: None,
+            bio: None,
+            pfp_url: None,
+            location: None,
+            twitter_username: None,
+            github_username: None,
+            registered_at: None,
+            total_casts: None,
+            total_reactions: None,
+            total_links: None,
+            power_badge: None,
+        }
+    }
+
+    pub fn has_power_badge(&self) -> bool {
+        self.power_badge.unwrap_or(false)
+    }
+}
+
+/// One ranked user on the public leaderboard
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LeaderboardEntry {
+    pub fid: i64,
+    #[serde(default)]
+    pub username: Option<String>,
+    #[serde(default)]
+    pub display_name: Option<String>,
+    #[serde(default)]
+    pub pfp_url: Option<String>,
+    #[serde(default)]
+    pub total_casts: usize,
+    #[serde(default)]
+    pub followers: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LeaderboardResponse {
+    #[serde(default, alias = "users")]
+    pub entries: Vec<LeaderboardEntry>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_popular_cast_first_image_embed() {
+        let cast: PopularCast = serde_json::from_str(
+            r#"{"text":"gm","reactions":1,"recasts":0,"replies":0,"timestamp":0,
+                "embeds":[{"cast_id":{"fid":3}},"https://example.com/post","https://example.com/a.PNG?w=600",{"url":"https://example.com/b.jpg"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            cast.first_image_embed(),
+            Some("https://example.com/a.PNG?w=600")
+        );
+
+        // Older responses without embeds still parse
+        let cast: PopularCast = serde_json::from_str(
+            r#"{"text":"gm","reactions":1,"recasts":0,"replies":0,"timestamp":0}"#,
+        )
+        .unwrap();
+        assert_eq!(cast.first_image_embed(), None);
+
+        // A null list or malformed entries don't fail the report
+        let cast: PopularCast = serde_json::from_str(
+            r#"{"text":"gm","reactions":1,"recasts":0,"replies":0,"timestamp":0,"embeds":null}"#,
+        )
+        .unwrap();
+        assert!(cast.embeds.is_empty());
+        let cast: PopularCast = serde_json::from_str(
+            r#"{"text":"gm","reactions":1,"recasts":0,"replies":0,"timestamp":0,
+                "embeds":[42,null,["x"],"https://example.com/c.gif"]}"#,
+        )
+        .unwrap();
+        assert_eq!(cast.first_image_embed(), Some("https://example.com/c.gif"));
+    }
+
+    #[test]
+    fn test_has_interactions() {
+        let mut engagement = EngagementResponse {
+            reactions_received: 0,
+            recasts_received: 0,
+            replies_received: 0,
+            most_popular_cast: None,
+            top_reactors: Vec::new(),
+        };
+        assert!(!engagement.has_interactions());
+
+        engagement.replies_received = 2;
+        assert!(engagement.has_interactions());
+    }
 }
@@ -9,6 +9,9 @@ pub struct ReportCardProps {
     pub with_padding_top: bool,
     #[prop_or(true)]
     pub is_own_report: bool,
+    /// Small note shown at the top of the card, e.g. when part of its data failed to load
+    #[prop_or_default]
+    pub notice: Option<AttrValue>,
 }
 
 #[function_component]
@@ -20,6 +23,7 @@ pub fn ReportCard(props: &ReportCardProps) -> Html {
         format!(
             "
             flex: 0 0 100%;
+            position: relative;
             width: 100%;
             height: 100%;
             scroll-snap-align: start;
@@ -33,6 +37,7 @@ pub fn ReportCard(props: &ReportCardProps) -> Html {
         format!(
             "
             flex: 0 0 100%;
+            position: relative;
             width: 100%;
             height: 100%;
             scroll-snap-align: start;
@@ -69,10 +74,35 @@ pub fn ReportCard(props: &ReportCardProps) -> Html {
         html! {}
     };
 
+    let notice = match &props.notice {
+        Some(text) => html! {
+            <div class="report-card-notice" role="status" style="
+                position: absolute;
+                top: 64px;
+                left: 50%;
+                transform: translateX(-50%);
+                max-width: calc(100% - 32px);
+                padding: 6px 12px;
+                border-radius: 999px;
+                background: rgba(0, 0, 0, 0.45);
+                color: rgba(255, 255, 255, 0.9);
+                font-size: 12px;
+                text-align: center;
+                z-index: 5;
+                pointer-events: none;
+                box-sizing: border-box;
+            ">
+                {format!("⚠️ {}", text)}
+            </div>
+        },
+        None => html! {},
+    };
+
     html! {
         <>
             {child_override_style}
             <div class="annual-report-card" style={card_style}>
+                {notice}
                 {props.children.clone()}
             </div>
         </>
@@ -1,6 +1,8 @@
 pub mod components;
+pub mod notify;
 pub mod page;
 pub mod sections;
+pub mod share_text;
 pub mod utils;
 
 use yew::prelude::*;
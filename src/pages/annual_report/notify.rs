@@ -0,0 +1,251 @@
+use serde::Deserialize;
+use serde::Serialize;
+use yew::prelude::*;
+
+use crate::models::ApiResponse;
+use crate::services::create_annual_report_endpoint;
+use crate::services::create_report_notify_endpoint;
+use crate::services::make_request_with_payment;
+use crate::wallet::WalletAccount;
+
+/// localStorage key for reports that were still generating when the user left
+const PENDING_REPORTS_STORAGE_KEY: &str = "polyjuice_pending_reports";
+
+/// How long a pending report is remembered; a job that hasn't finished by then never will
+const PENDING_REPORT_TTL_MS: u64 = 7 * 24 * 60 * 60 * 1000;
+
+/// A report the user asked to be told about once it finishes generating
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SavedPendingReport {
+    pub fid: i64,
+    pub year: i32,
+    pub saved_at: u64,
+}
+
+impl SavedPendingReport {
+    /// Whether this entry is still worth checking at `now_ms`
+    fn is_fresh(&self, now_ms: u64) -> bool {
+        now_ms.saturating_sub(self.saved_at) < PENDING_REPORT_TTL_MS
+    }
+}
+
+fn get_storage() -> Result<web_sys::Storage, String> {
+    web_sys::window()
+        .ok_or("No window available".to_string())?
+        .local_storage()
+        .map_err(|_| "Failed to get localStorage".to_string())?
+        .ok_or("localStorage not available".to_string())
+}
+
+/// Load reports saved with `remember_pending_report` (empty if none or unreadable)
+/// Expired entries are dropped here and pruned from storage on the next save
+pub fn load_pending_reports() -> Vec<SavedPendingReport> {
+    let now_ms = js_sys::Date::now() as u64;
+    get_storage()
+        .ok()
+        .and_then(|storage| storage.get_item(PENDING_REPORTS_STORAGE_KEY).ok().flatten())
+        .and_then(|json| serde_json::from_str::<Vec<SavedPendingReport>>(&json).ok())
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|report| report.is_fresh(now_ms))
+        .collect()
+}
+
+fn save_pending_reports(reports: &[SavedPendingReport]) -> Result<(), String> {
+    let storage = get_storage()?;
+    if reports.is_empty() {
+        return storage
+            .remove_item(PENDING_REPORTS_STORAGE_KEY)
+            .map_err(|_| "Failed to clear pending reports".to_string());
+    }
+    let json = serde_json::to_string(reports)
+        .map_err(|e| format!("Failed to serialize pending reports: {}", e))?;
+    storage
+        .set_item(PENDING_REPORTS_STORAGE_KEY, &json)
+        .map_err(|_| "Failed to save pending reports to localStorage".to_string())
+}
+
+/// Persist a pending report so the next app open can check whether it's ready
+pub fn remember_pending_report(fid: i64, year: i32) -> Result<(), String> {
+    let mut reports = load_pending_reports();
+    if reports.iter().any(|r| r.fid == fid && r.year == year) {
+        return Ok(());
+    }
+    reports.push(SavedPendingReport {
+        fid,
+        year,
+        saved_at: js_sys::Date::now() as u64,
+    });
+    save_pending_reports(&reports)
+}
+
+/// Stop tracking a report (it loaded, or the user has been told it's ready)
+pub fn forget_pending_report(fid: i64, year: i32) -> Result<(), String> {
+    let mut reports = load_pending_reports();
+    let before = reports.len();
+    reports.retain(|r| !(r.fid == fid && r.year == year));
+    if reports.len() == before {
+        return Ok(());
+    }
+    save_pending_reports(&reports)
+}
+
+/// Interpret an annual report response: `Some(true)` when the report is ready,
+/// `Some(false)` while its job is still pending/processing, `None` if we can't tell
+/// (payment required, server error, unparseable body)
+pub fn report_ready_from_response(status: u16, body: &str) -> Option<bool> {
+    if status != 200 {
+        return None;
+    }
+    let response = serde_json::from_str::<ApiResponse<serde_json::Value>>(body).ok()?;
+    if !response.success {
+        return None;
+    }
+    let job_status = response
+        .data
+        .as_ref()
+        .and_then(|data| data.get("status"))
+        .and_then(|status| status.as_str());
+    match job_status {
+        Some("pending") | Some("processing") => Some(false),
+        Some("failed") => None,
+        _ => Some(response.data.is_some()),
+    }
+}
+
+/// Check once whether a saved report has finished generating
+/// Uses a plain request so a still-pending job doesn't start background polling
+pub async fn check_pending_report(
+    api_url: &str,
+    fid: i64,
+    year: i32,
+) -> Result<Option<bool>, String> {
+    let endpoint = create_annual_report_endpoint(fid, year, false);
+    let resp = crate::api::make_request(api_url, &endpoint, None, None).await?;
+    Ok(report_ready_from_response(resp.status, &resp.body))
+}
+
+/// Ask the backend to ping `notify_fid` when the report for `fid` is ready
+pub async fn register_report_notify(
+    api_url: &str,
+    wallet_account: Option<&WalletAccount>,
+    fid: i64,
+    year: i32,
+    notify_fid: Option<i64>,
+) -> Result<(), String> {
+    let endpoint = create_report_notify_endpoint(fid, year);
+    let body = serde_json::json!({
+        "fid": fid,
+        "year": year,
+        "notify_fid": notify_fid,
+    })
+    .to_string();
+    make_request_with_payment::<serde_json::Value>(
+        api_url,
+        &endpoint,
+        Some(body),
+        wallet_account,
+        None,
+        None,
+    )
+    .await
+    .map(|_| ())
+    .map_err(String::from)
+}
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct ReportReadyBannerProps {
+    pub fid: i64,
+    pub on_open: Callback<i64>,
+    pub on_dismiss: Callback<()>,
+}
+
+/// "Your report is ready!" banner shown when a saved pending report has finished
+#[function_component]
+pub fn ReportReadyBanner(props: &ReportReadyBannerProps) -> Html {
+    let fid = props.fid;
+    let on_open = props.on_open.reform(move |_: MouseEvent| fid);
+    let on_dismiss = props.on_dismiss.reform(|_: MouseEvent| ());
+
+    html! {
+        <div class="report-ready-banner" role="status" style="
+            position: fixed;
+            top: 72px;
+            left: 50%;
+            transform: translateX(-50%);
+            width: min(420px, calc(100% - 32px));
+            display: flex;
+            align-items: center;
+            gap: 12px;
+            padding: 12px 16px;
+            border-radius: 12px;
+            background: rgba(90, 79, 207, 0.95);
+            color: white;
+            box-shadow: 0 8px 24px rgba(0, 0, 0, 0.3);
+            z-index: 15000;
+            box-sizing: border-box;
+        ">
+            <span style="font-size: 20px;">{"🎉"}</span>
+            <span style="flex: 1; font-size: 14px; font-weight: 600;">
+                {"Your annual report is ready!"}
+            </span>
+            <button
+                onclick={on_open}
+                style="
+                    background: white;
+                    border: none;
+                    border-radius: 8px;
+                    color: #5a4fcf;
+                    padding: 6px 10px;
+                    font-size: 13px;
+                    font-weight: 600;
+                    cursor: pointer;
+                "
+            >{"View"}</button>
+            <button
+                onclick={on_dismiss}
+                aria-label="Dismiss"
+                style="
+                    background: none;
+                    border: none;
+                    color: white;
+                    font-size: 18px;
+                    cursor: pointer;
+                    padding: 0 4px;
+                "
+            >{"×"}</button>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_ready_from_response() {
+        let ready = r#"{"success":true,"data":{"fid":3,"year":2025}}"#;
+        let pending = r#"{"success":true,"data":{"status":"processing","job_key":"k"}}"#;
+        let failed = r#"{"success":false,"error":"boom"}"#;
+
+        assert_eq!(report_ready_from_response(200, ready), Some(true));
+        assert_eq!(report_ready_from_response(200, pending), Some(false));
+        assert_eq!(report_ready_from_response(200, failed), None);
+        assert_eq!(report_ready_from_response(402, ready), None);
+        assert_eq!(report_ready_from_response(200, "not json"), None);
+    }
+
+    #[test]
+    fn test_pending_report_expiry() {
+        let report = SavedPendingReport {
+            fid: 3,
+            year: 2025,
+            saved_at: 1_000,
+        };
+        assert!(report.is_fresh(1_000));
+        assert!(report.is_fresh(1_000 + PENDING_REPORT_TTL_MS - 1));
+        assert!(!report.is_fresh(1_000 + PENDING_REPORT_TTL_MS));
+        // A clock that moved backwards doesn't expire it
+        assert!(report.is_fresh(0));
+    }
+}
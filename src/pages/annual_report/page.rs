@@ -6,21 +6,105 @@ use wasm_bindgen_futures::spawn_local;
 use web_sys;
 use yew::prelude::*;
 
+use super::notify::forget_pending_report;
+use super::notify::register_report_notify;
+use super::notify::remember_pending_report;
 use super::sections::*;
+use super::utils::avatar_label;
 use super::utils::convert_annual_report_response;
+use super::utils::neighbor_pages;
+use super::utils::viewing_other_label;
 use super::AnnualReportPageProps;
 use super::ReportCard;
+use crate::components::confirm;
+use crate::components::ConfirmRequest;
 use crate::models::AnnualReportResponse;
 use crate::models::CastsStatsResponse;
 use crate::models::EngagementResponse;
 use crate::models::PendingJob;
 use crate::models::ProfileWithRegistration;
+use crate::services::blob_download;
 use crate::services::create_annual_report_endpoint;
 use crate::services::create_casts_stats_endpoint;
 use crate::services::create_profile_endpoint;
 use crate::services::get_2025_timestamps;
 use crate::services::make_request_with_payment;
 use crate::services::StatusCallback;
+use crate::toast::use_toaster;
+use crate::wallet::WalletAccount;
+
+/// Which of the sequential annual report sub-requests have resolved
+/// Profile and casts stats are optional: a failure is recorded and the report renders
+/// without them
+#[derive(Clone, Copy, PartialEq, Default)]
+struct LoadProgress {
+    report: bool,
+    profile: bool,
+    casts: bool,
+    profile_failed: bool,
+    casts_failed: bool,
+}
+
+impl LoadProgress {
+    /// Render as "Report ✓ · Profile ✗ · Stats …"
+    fn summary(&self) -> String {
+        let mark = |done: bool, failed: bool| match (done, failed) {
+            (true, true) => "✗",
+            (true, false) => "✓",
+            _ => "…",
+        };
+        format!(
+            "Report {} · Profile {} · Stats {}",
+            mark(self.report, false),
+            mark(self.profile, self.profile_failed),
+            mark(self.casts, self.casts_failed)
+        )
+    }
+}
+
+/// Load the report owner's profile (with registration date)
+async fn load_report_profile(
+    api_url: &str,
+    wallet_account: Option<&WalletAccount>,
+    fid: i64,
+) -> Result<ProfileWithRegistration, String> {
+    let profile_endpoint = create_profile_endpoint(&fid.to_string(), true);
+    make_request_with_payment::<ProfileWithRegistration>(
+        api_url,
+        &profile_endpoint,
+        None,
+        wallet_account,
+        None,
+        None,
+    )
+    .await
+    .map_err(String::from)
+}
+
+/// Load 2025 cast statistics; the payload sits under `data`, sometimes nested twice
+async fn load_report_casts_stats(
+    api_url: &str,
+    wallet_account: Option<&WalletAccount>,
+    fid: i64,
+) -> Result<CastsStatsResponse, String> {
+    let (start_2025, end_2025) = get_2025_timestamps();
+    let casts_endpoint = create_casts_stats_endpoint(fid, Some(start_2025), Some(end_2025));
+    let json_data = make_request_with_payment::<serde_json::Value>(
+        api_url,
+        &casts_endpoint,
+        None,
+        wallet_account,
+        None,
+        None,
+    )
+    .await?;
+    let outer_data = json_data
+        .get("data")
+        .ok_or("Cast statistics response has no data")?;
+    let actual_data = outer_data.get("data").unwrap_or(outer_data);
+    serde_json::from_value::<CastsStatsResponse>(actual_data.clone())
+        .map_err(|e| format!("Failed to parse cast statistics: {}", e))
+}
 
 /// Annual Report page component
 #[function_component]
@@ -49,14 +133,28 @@ pub fn AnnualReportPage(props: &AnnualReportPageProps) -> Html {
 
     // When viewing own report, show intro screen first, then content after clicking "lets begin"
     // When viewing someone else's report, skip intro screen and show personality tag page directly, then content after clicking button
+    // The DEMO_FID showcase report is labeled as an example for everyone but its owner
+    let is_demo_report = !is_own_report && crate::services::demo_fid() == Some(fid);
+    // Visitors arriving through a share link whose owner hid their FID don't see it;
+    // the owner always does
+    let hide_fid = !is_own_report
+        && web_sys::window()
+            .and_then(|w| w.location().href().ok())
+            .is_some_and(|href| share_url_hides_fid(&href));
+
     let show_intro = use_state(|| is_own_report); // Only show intro for own report
     let has_clicked_begin = use_state(|| false); // Track if user clicked begin (for own report)
     let show_content = use_state(|| false); // Always start with false, show content only after clicking button or for own report after intro
     let data_loading_complete = use_state(|| false); // Track if data loading is complete
     let _error = use_state(|| None::<String>);
     let loading_status = use_state(|| "Loading annual report...".to_string());
+    let not_found = use_state(|| false); // FID doesn't exist (404), no point retrying
+    let load_progress = use_state(LoadProgress::default); // Which sub-requests have resolved
     let current_page = use_state(|| 0);
     let scroll_container_ref = use_node_ref();
+    let notify_requested = use_state(|| false); // "Notify me" pressed while the report is pending
+    let recompute_nonce = use_state(|| 0u32); // Bumped by "Recompute" to reload with refresh=true
+    let toaster = use_toaster();
 
     // Helper function to parse JOB_STATUS error format
     fn parse_job_status_error(
@@ -98,6 +196,121 @@ pub fn AnnualReportPage(props: &AnnualReportPageProps) -> Html {
         Some((status, job_key, message))
     }
 
+    // Once the report loads, stop tracking it as a pending "notify me" report
+    use_effect_with(annual_report.is_some(), move |loaded| {
+        if *loaded {
+            if let Err(e) = forget_pending_report(fid, 2025) {
+                web_sys::console::warn_1(&format!("⚠️ {}", e).into());
+            }
+        }
+        || ()
+    });
+
+    // "Notify me": remember the job locally (checked on next app open) and register
+    // a completion ping with the backend when it supports it
+    let on_notify_me = {
+        let notify_requested = notify_requested.clone();
+        let toaster = toaster.clone();
+        let api_url = api_url.clone();
+        let wallet_account = wallet_account.clone();
+        Callback::from(move |_: MouseEvent| {
+            notify_requested.set(true);
+            if let Err(e) = remember_pending_report(fid, 2025) {
+                web_sys::console::warn_1(&format!("⚠️ {}", e).into());
+            }
+            let toaster = toaster.clone();
+            let api_url = api_url.clone();
+            let wallet_account = wallet_account.clone();
+            spawn_local(async move {
+                match register_report_notify(
+                    &api_url,
+                    wallet_account.as_ref(),
+                    fid,
+                    2025,
+                    current_user_fid,
+                )
+                .await
+                {
+                    Ok(()) => toaster.success("We'll let you know when your report is ready"),
+                    Err(e) => {
+                        web_sys::console::log_1(
+                            &format!("ℹ️ Report notify unavailable, using local check: {}", e)
+                                .into(),
+                        );
+                        toaster.info("We'll check again next time you open Polyjuice");
+                    }
+                }
+            });
+        })
+    };
+
+    // "Recompute": after confirming (a new job may need a payment), clear the loaded report
+    // and rerun the load with refresh=true so the backend starts a fresh computation
+    let on_recompute = {
+        let recompute_nonce = recompute_nonce.clone();
+        let annual_report = annual_report.clone();
+        let casts_stats = casts_stats.clone();
+        let pending_job = pending_job.clone();
+        let data_loading_complete = data_loading_complete.clone();
+        let load_progress = load_progress.clone();
+        let notify_requested = notify_requested.clone();
+        let current_page = current_page.clone();
+        Callback::from(move |_: MouseEvent| {
+            let recompute_nonce = recompute_nonce.clone();
+            let annual_report = annual_report.clone();
+            let casts_stats = casts_stats.clone();
+            let pending_job = pending_job.clone();
+            let data_loading_complete = data_loading_complete.clone();
+            let load_progress = load_progress.clone();
+            let notify_requested = notify_requested.clone();
+            let current_page = current_page.clone();
+            spawn_local(async move {
+                let confirmed = confirm(ConfirmRequest {
+                    title: "Recompute your report?".to_string(),
+                    message: "This rebuilds your report from your latest activity. It starts a new report job and may require a payment.".to_string(),
+                    confirm_label: "Recompute".to_string(),
+                })
+                .await;
+                if !confirmed {
+                    return;
+                }
+                web_sys::console::log_1(
+                    &format!("🔄 Recomputing annual report for FID {}", fid).into(),
+                );
+                annual_report.set(None);
+                casts_stats.set(None);
+                pending_job.set(None);
+                data_loading_complete.set(false);
+                load_progress.set(LoadProgress::default());
+                notify_requested.set(false);
+                current_page.set(0);
+                recompute_nonce.set(*recompute_nonce + 1);
+            });
+        })
+    };
+
+    // "Export": save the loaded report as a JSON file
+    let on_export = {
+        let annual_report = annual_report.clone();
+        let toaster = toaster.clone();
+        Callback::from(move |_: MouseEvent| {
+            let Some(report) = (*annual_report).clone() else {
+                return;
+            };
+            let filename = format!("polyjuice-report-{}-{}.json", report.fid, report.year);
+            let saved = serde_json::to_vec_pretty(&report)
+                .map_err(|e| e.to_string())
+                .and_then(|bytes| {
+                    blob_download(&filename, &bytes, "application/json")
+                        .map_err(|e| format!("{:?}", e))
+                });
+            if let Err(e) = saved {
+                web_sys::console::error_1(&format!("❌ Failed to export report: {}", e).into());
+                toaster.error("Couldn't export the report");
+            }
+        })
+    };
+
     // Load annual report data in background
     {
         let annual_report = annual_report.clone();
@@ -107,13 +320,18 @@ pub fn AnnualReportPage(props: &AnnualReportPageProps) -> Html {
         let is_loading = is_loading.clone();
         let data_loading_complete = data_loading_complete.clone();
         let loading_status = loading_status.clone();
+        let not_found = not_found.clone();
+        let load_progress = load_progress.clone();
         let pending_job = pending_job.clone();
         let api_url_clone = api_url.clone();
         let wallet_account_clone = wallet_account.clone();
         let scroll_container_ref_for_loading = scroll_container_ref.clone();
         let current_page_for_loading = current_page.clone();
 
-        use_effect_with((), move |_| {
+        // Reruns when "Recompute" bumps the nonce; only that first request asks for a
+        // refresh, polling and the reload after completion fetch the report normally
+        use_effect_with(*recompute_nonce, move |nonce| {
+            let refresh = *nonce > 0;
             let annual_report = annual_report.clone();
             let profile = profile.clone();
             let casts_stats = casts_stats.clone();
@@ -121,6 +339,8 @@ pub fn AnnualReportPage(props: &AnnualReportPageProps) -> Html {
             let is_loading = is_loading.clone();
             let data_loading_complete = data_loading_complete.clone();
             let loading_status = loading_status.clone();
+            let not_found = not_found.clone();
+            let load_progress = load_progress.clone();
             let pending_job = pending_job.clone();
             let api_url_clone = api_url_clone.clone();
             let wallet_account_clone = wallet_account_clone.clone();
@@ -136,11 +356,12 @@ pub fn AnnualReportPage(props: &AnnualReportPageProps) -> Html {
             spawn_local(async move {
                 // Load annual report using unified endpoint
                 loading_status.set("Loading annual report...".to_string());
+                load_progress.set(LoadProgress::default());
                 web_sys::console::log_1(
                     &"🚀 Loading annual report from unified endpoint...".into(),
                 );
 
-                let annual_report_endpoint = create_annual_report_endpoint(fid, 2025);
+                let annual_report_endpoint = create_annual_report_endpoint(fid, 2025, refresh);
                 web_sys::console::log_1(
                     &format!(
                         "🌐 Requesting annual report from: {}",
@@ -157,6 +378,7 @@ pub fn AnnualReportPage(props: &AnnualReportPageProps) -> Html {
                 let is_loading_for_callback = is_loading.clone();
                 let data_loading_complete_for_callback = data_loading_complete.clone();
                 let loading_status_for_callback = loading_status.clone();
+                let load_progress_for_callback = load_progress.clone();
                 let api_url_for_reload = api_url_clone.clone();
                 let wallet_account_for_reload = wallet_account_clone.clone();
                 let fid_for_reload = fid;
@@ -178,6 +400,7 @@ pub fn AnnualReportPage(props: &AnnualReportPageProps) -> Html {
                             pending_job_for_callback.set(None);
                             is_loading_for_callback.set(true);
                             loading_status_for_callback.set("Loading annual report...".to_string());
+                            load_progress_for_callback.set(LoadProgress::default());
 
                             // Reload the annual report data
                             let annual_report_clone = annual_report_for_callback.clone();
@@ -187,6 +410,7 @@ pub fn AnnualReportPage(props: &AnnualReportPageProps) -> Html {
                             let data_loading_complete_clone =
                                 data_loading_complete_for_callback.clone();
                             let loading_status_clone = loading_status_for_callback.clone();
+                            let load_progress_clone = load_progress_for_callback.clone();
                             let api_url_reload = api_url_for_reload.clone();
                             let wallet_account_reload = wallet_account_for_reload.clone();
                             let fid_reload = fid_for_reload;
@@ -194,7 +418,7 @@ pub fn AnnualReportPage(props: &AnnualReportPageProps) -> Html {
 
                             wasm_bindgen_futures::spawn_local(async move {
                                 let annual_report_endpoint =
-                                    create_annual_report_endpoint(fid_reload, 2025);
+                                    create_annual_report_endpoint(fid_reload, 2025, false);
 
                                 // Create a new status callback for reload (in case it's still pending)
                                 let pending_job_reload = pending_job_reload_clone.clone();
@@ -246,59 +470,53 @@ pub fn AnnualReportPage(props: &AnnualReportPageProps) -> Html {
                                                 &"✅ Successfully loaded annual report after polling".into(),
                                             );
                                                 annual_report_clone.set(Some(report));
+                                                let mut progress = LoadProgress {
+                                                    report: true,
+                                                    ..LoadProgress::default()
+                                                };
+                                                load_progress_clone.set(progress);
 
-                                                // Load profile
-                                                let profile_endpoint = create_profile_endpoint(
-                                                    &fid_reload.to_string(),
-                                                    true,
-                                                );
-                                                if let Ok(p) = make_request_with_payment::<
-                                                    ProfileWithRegistration,
-                                                >(
+                                                // Profile and casts stats load independently;
+                                                // a failure leaves that part of the report empty
+                                                match load_report_profile(
                                                     &api_url_reload,
-                                                    &profile_endpoint,
-                                                    None,
                                                     wallet_account_reload.as_ref(),
-                                                    None,
-                                                    None,
+                                                    fid_reload,
                                                 )
                                                 .await
                                                 {
-                                                    profile_clone.set(Some(p));
+                                                    Ok(p) => profile_clone.set(Some(p)),
+                                                    Err(e) => {
+                                                        web_sys::console::warn_1(
+                                                            &format!(
+                                                                "⚠️ Failed to load profile: {}",
+                                                                e
+                                                            )
+                                                            .into(),
+                                                        );
+                                                        progress.profile_failed = true;
+                                                    }
                                                 }
+                                                progress.profile = true;
+                                                load_progress_clone.set(progress);
 
-                                                // Load casts stats
-                                                let (start_2025, end_2025) = get_2025_timestamps();
-                                                let casts_endpoint = create_casts_stats_endpoint(
+                                                match load_report_casts_stats(
+                                                    &api_url_reload,
+                                                    wallet_account_reload.as_ref(),
                                                     fid_reload,
-                                                    Some(start_2025),
-                                                    Some(end_2025),
-                                                );
-                                                if let Ok(json_data) =
-                                                    make_request_with_payment::<serde_json::Value>(
-                                                        &api_url_reload,
-                                                        &casts_endpoint,
-                                                        None,
-                                                        wallet_account_reload.as_ref(),
-                                                        None,
-                                                        None,
-                                                    )
-                                                    .await
+                                                )
+                                                .await
                                                 {
-                                                    if let Some(outer_data) = json_data.get("data")
-                                                    {
-                                                        let actual_data = outer_data
-                                                            .get("data")
-                                                            .unwrap_or(outer_data);
-                                                        if let Ok(stats) = serde_json::from_value::<
-                                                            CastsStatsResponse,
-                                                        >(
-                                                            actual_data.clone()
-                                                        ) {
-                                                            casts_stats_clone.set(Some(stats));
-                                                        }
+                                                    Ok(stats) => casts_stats_clone.set(Some(stats)),
+                                                    Err(e) => {
+                                                        web_sys::console::warn_1(
+                                                            &format!("⚠️ Failed to load cast statistics: {}", e).into(),
+                                                        );
+                                                        progress.casts_failed = true;
                                                     }
                                                 }
+                                                progress.casts = true;
+                                                load_progress_clone.set(progress);
 
                                                 is_loading_clone.set(false);
                                                 data_loading_complete_clone.set(true);
@@ -413,28 +631,35 @@ pub fn AnnualReportPage(props: &AnnualReportPageProps) -> Html {
                                     .into(),
                                 );
                                 annual_report.set(Some(report));
+                                let mut progress = LoadProgress {
+                                    report: true,
+                                    ..LoadProgress::default()
+                                };
+                                load_progress.set(progress);
 
                                 // Load profile for display purposes
                                 loading_status.set("Loading profile...".to_string());
-                                let profile_endpoint =
-                                    create_profile_endpoint(&fid.to_string(), true);
                                 let farcaster_context_for_profile = farcaster_context.clone();
                                 let is_own_report_for_profile = current_user_fid == Some(fid);
                                 web_sys::console::log_1(
                                     &format!("🔍 Profile loading check: fid={}, current_user_fid={:?}, is_own_report={}", 
                                         fid, current_user_fid, is_own_report_for_profile).into()
                                 );
-                                if let Ok(mut p) =
-                                    make_request_with_payment::<ProfileWithRegistration>(
-                                        &api_url_clone,
-                                        &profile_endpoint,
-                                        None,
-                                        wallet_account_clone.as_ref(),
-                                        None,
-                                        None,
-                                    )
-                                    .await
-                                {
+                                // Profile and casts stats load independently; a failure
+                                // leaves that part of the report empty instead of aborting
+                                let profile_result = load_report_profile(
+                                    &api_url_clone,
+                                    wallet_account_clone.as_ref(),
+                                    fid,
+                                )
+                                .await;
+                                if let Err(e) = &profile_result {
+                                    web_sys::console::warn_1(
+                                        &format!("⚠️ Failed to load profile: {}", e).into(),
+                                    );
+                                    progress.profile_failed = true;
+                                }
+                                if let Ok(mut p) = profile_result {
                                     web_sys::console::log_1(
                                         &format!("📥 Profile loaded from API: FID={}, pfp_url={:?}, is_own_report={}, current_user_fid={:?}", 
                                             p.fid, p.pfp_url, is_own_report_for_profile, current_user_fid).into()
@@ -520,38 +745,29 @@ pub fn AnnualReportPage(props: &AnnualReportPageProps) -> Html {
                                     );
                                     profile.set(Some(p));
                                 }
+                                progress.profile = true;
+                                load_progress.set(progress);
 
                                 // Load casts stats for additional data
                                 loading_status.set("Loading cast statistics...".to_string());
-                                let (start_2025, end_2025) = get_2025_timestamps();
-                                let casts_endpoint = create_casts_stats_endpoint(
+                                match load_report_casts_stats(
+                                    &api_url_clone,
+                                    wallet_account_clone.as_ref(),
                                     fid,
-                                    Some(start_2025),
-                                    Some(end_2025),
-                                );
-                                if let Ok(json_data) =
-                                    make_request_with_payment::<serde_json::Value>(
-                                        &api_url_clone,
-                                        &casts_endpoint,
-                                        None,
-                                        wallet_account_clone.as_ref(),
-                                        None,
-                                        None,
-                                    )
-                                    .await
+                                )
+                                .await
                                 {
-                                    if let Some(outer_data) = json_data.get("data") {
-                                        let actual_data =
-                                            outer_data.get("data").unwrap_or(outer_data);
-                                        if let Ok(stats) =
-                                            serde_json::from_value::<CastsStatsResponse>(
-                                                actual_data.clone(),
-                                            )
-                                        {
-                                            casts_stats.set(Some(stats));
-                                        }
+                                    Ok(stats) => casts_stats.set(Some(stats)),
+                                    Err(e) => {
+                                        web_sys::console::warn_1(
+                                            &format!("⚠️ Failed to load cast statistics: {}", e)
+                                                .into(),
+                                        );
+                                        progress.casts_failed = true;
                                     }
                                 }
+                                progress.casts = true;
+                                load_progress.set(progress);
 
                                 web_sys::console::log_1(&"✅ All data loading completed".into());
                                 is_loading.set(false);
@@ -633,7 +849,7 @@ pub fn AnnualReportPage(props: &AnnualReportPageProps) -> Html {
                     Err(e) => {
                         // Check for JOB_STATUS error format (pending job)
                         if let Some((status, job_key, message)) =
-                            parse_job_status_error(&e, format!("annual_report:{}", fid))
+                            parse_job_status_error(&e.to_string(), format!("annual_report:{}", fid))
                         {
                             web_sys::console::log_1(
                                 &format!(
@@ -657,6 +873,7 @@ pub fn AnnualReportPage(props: &AnnualReportPageProps) -> Html {
                             web_sys::console::error_1(
                                 &format!("❌ Failed to load annual report: {}", e).into(),
                             );
+                            not_found.set(e.is_not_found());
                             is_loading.set(false);
                             data_loading_complete.set(true); // Mark as complete even on error
                             loading_status.set(format!("Failed to load annual report: {}", e));
@@ -668,13 +885,57 @@ pub fn AnnualReportPage(props: &AnnualReportPageProps) -> Html {
         });
     }
 
+    // Sections render from whatever loaded: a failed profile falls back to a bare FID
+    // profile, and the affected cards say which part is missing
+    let report_profile = (*profile).clone().or_else(|| {
+        load_progress
+            .profile_failed
+            .then(|| ProfileWithRegistration::fid_only(fid))
+    });
+    let profile_notice = load_progress
+        .profile_failed
+        .then(|| AttrValue::from("Couldn't load profile details for this part"));
+    let casts_notice = load_progress
+        .casts_failed
+        .then(|| AttrValue::from("Couldn't load cast statistics for this part"));
+
     // Calculate total number of cards
-    let total_cards = if annual_report.is_some() && profile.is_some() {
+    let total_cards = if annual_report.is_some() && report_profile.is_some() {
         7 // Cover + 6 sections (Identity, Follower Growth, Top Interactive Users, Style, Personality Tag)
     } else {
         0
     };
 
+    // Preload the images of the pages either side of the current one, so scrolling to
+    // them doesn't wait on the zodiac/social/tarot art; only immediate neighbors are loaded
+    let preloaded_images = use_mut_ref(Vec::<web_sys::HtmlImageElement>::new);
+    {
+        let preloaded_images = preloaded_images.clone();
+        let report = (*annual_report).clone();
+        let profile = report_profile.clone();
+        use_effect_with((*current_page, total_cards), move |(page, total)| {
+            if let Some(report) = report {
+                let mut images = preloaded_images.borrow_mut();
+                images.clear();
+                for neighbor in neighbor_pages(*page, *total) {
+                    for url in report_page_image_urls(neighbor, profile.as_ref(), &report, 10) {
+                        match web_sys::HtmlImageElement::new() {
+                            Ok(image) => {
+                                image.set_src(&url);
+                                images.push(image);
+                            }
+                            Err(e) => {
+                                web_sys::console::warn_1(
+                                    &format!("⚠️ Failed to preload {}: {:?}", url, e).into(),
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        });
+    }
+
     // Generate background image URL with cache busting
     let bg_image_url = get_image_url("/imgs/report-bg.png");
     
@@ -723,6 +984,25 @@ pub fn AnnualReportPage(props: &AnnualReportPageProps) -> Html {
             e.prevent_default();
         })}
         >
+                if is_demo_report {
+                    // Signed-in viewers also get the viewing-other banner at the top, so sit below it
+                    <div class="demo-report-label" style={format!("
+                        position: fixed;
+                        top: calc({}px + env(safe-area-inset-top, 0px));
+                        left: 50%;
+                        transform: translateX(-50%);
+                        padding: 6px 14px;
+                        border-radius: 999px;
+                        background: rgba(0, 0, 0, 0.45);
+                        border: 1px solid rgba(255, 255, 255, 0.3);
+                        color: white;
+                        font-size: 13px;
+                        font-weight: 600;
+                        pointer-events: none;
+                        white-space: nowrap;
+                        z-index: 2000;
+                    ", if current_user_fid.is_some() { 52 } else { 12 })}>{"Example report"}</div>
+                }
                 // Show intro screen first (only for own report and when not showing content yet)
                 if is_own_report && !*show_content && !*has_clicked_begin {
                     <>
@@ -817,6 +1097,10 @@ pub fn AnnualReportPage(props: &AnnualReportPageProps) -> Html {
                     } else {
                         None
                     };
+                    let display_pfp_alt = avatar_label(
+                        profile.as_ref().and_then(|p| p.username.as_deref()),
+                        fid,
+                    );
 
                     html! {
                 <div style="
@@ -851,7 +1135,8 @@ pub fn AnnualReportPage(props: &AnnualReportPageProps) -> Html {
                                         ">
                                             <img
                                                 src={display_pfp.unwrap()}
-                                                alt="Profile"
+                                                alt={display_pfp_alt.clone()}
+                                                title={display_pfp_alt.clone()}
                                                 style="
                                                     width: 100%;
                                                     height: 100%;
@@ -926,6 +1211,44 @@ pub fn AnnualReportPage(props: &AnnualReportPageProps) -> Html {
                                     (*loading_status).as_str()
                                 }
                             }</p>
+                            {if pending_job.is_none() {
+                                html! {
+                                    <p style="
+                                        font-size: 14px;
+                                        font-weight: 500;
+                                        color: rgba(255, 255, 255, 0.75);
+                                        margin: 0;
+                                        letter-spacing: 0.5px;
+                                    ">{load_progress.summary()}</p>
+                                }
+                            } else if *notify_requested {
+                                html! {
+                                    <p style="
+                                        font-size: 14px;
+                                        color: rgba(255, 255, 255, 0.75);
+                                        margin: 0;
+                                    ">{"🔔 We'll let you know when it's ready"}</p>
+                                }
+                            } else {
+                                html! {
+                                    <button
+                                        onclick={on_notify_me.clone()}
+                                        style="
+                                            align-self: center;
+                                            padding: 10px 20px;
+                                            font-size: 15px;
+                                            font-weight: 600;
+                                            color: white;
+                                            background: rgba(255, 255, 255, 0.15);
+                                            border: 1px solid rgba(255, 255, 255, 0.4);
+                                            border-radius: 999px;
+                                            cursor: pointer;
+                                        "
+                                    >
+                                        {"🔔 Notify me when it's ready"}
+                                    </button>
+                                }
+                            }}
                         </div>
 
                         // Progress dots animation
@@ -984,7 +1307,14 @@ pub fn AnnualReportPage(props: &AnnualReportPageProps) -> Html {
                 } else {
                 <>
                     // Show error if annual report failed to load
-                    {if annual_report.is_none() {
+                    {if annual_report.is_none() && *not_found {
+                        html! {
+                            <div class="error-container" style="padding: 40px; text-align: center;">
+                                <h2>{format!("No Farcaster user found for FID {}", fid)}</h2>
+                                <p>{"Double-check the FID, or search by username from the home page."}</p>
+                            </div>
+                        }
+                    } else if annual_report.is_none() {
                         html! {
                             <div class="error-container" style="padding: 40px; text-align: center;">
                                 <h2>{"Failed to load annual report"}</h2>
@@ -1018,6 +1348,7 @@ pub fn AnnualReportPage(props: &AnnualReportPageProps) -> Html {
                                 followers,
                                 &casts,
                                 fid,
+                                0,
                             );
 
                             // Get username for button text (this is the owner of the report being viewed)
@@ -1029,6 +1360,10 @@ pub fn AnnualReportPage(props: &AnnualReportPageProps) -> Html {
                             let display_pfp = profile.as_ref()
                                 .and_then(|p| p.pfp_url.as_ref())
                                 .cloned();
+                            let display_pfp_alt = avatar_label(
+                                profile.as_ref().and_then(|p| p.username.as_deref()),
+                                fid,
+                            );
 
                             let show_content_clone = show_content.clone();
                             html! {
@@ -1066,7 +1401,8 @@ pub fn AnnualReportPage(props: &AnnualReportPageProps) -> Html {
                                                     ">
                                                         <img
                                                             src={display_pfp.unwrap()}
-                                                            alt="Profile"
+                                                            alt={display_pfp_alt.clone()}
+                                                            title={display_pfp_alt.clone()}
                                                             style="
                                                                 width: 100%;
                                                                 height: 100%;
@@ -1136,6 +1472,35 @@ pub fn AnnualReportPage(props: &AnnualReportPageProps) -> Html {
                 } else {
                         html! {
                             <>
+                                // Make it obvious whose report this is when it isn't the viewer's own
+                                {if let Some(label) = viewing_other_label(
+                                    fid,
+                                    current_user_fid,
+                                    profile.as_ref().and_then(|p| p.username.as_deref()),
+                                ) {
+                                    html! {
+                                        <div class="viewing-other-banner" style="
+                                            position: fixed;
+                                            top: calc(12px + env(safe-area-inset-top, 0px));
+                                            left: 50%;
+                                            transform: translateX(-50%);
+                                            z-index: 10;
+                                            padding: 6px 14px;
+                                            border-radius: 999px;
+                                            background: rgba(0, 0, 0, 0.45);
+                                            backdrop-filter: blur(10px);
+                                            -webkit-backdrop-filter: blur(10px);
+                                            border: 1px solid rgba(255, 255, 255, 0.2);
+                                            color: white;
+                                            font-size: 13px;
+                                            font-weight: 600;
+                                            pointer-events: none;
+                                            white-space: nowrap;
+                                        ">{label}</div>
+                                    }
+                                } else {
+                                    html! {}
+                                }}
                                 // Horizontal scrolling container
                                 <div
                                     ref={scroll_container_ref.clone()}
@@ -1192,10 +1557,10 @@ pub fn AnnualReportPage(props: &AnnualReportPageProps) -> Html {
                                     })}
                                 >
                                     // Cover Page Card (only shown in scroll container after clicking button)
-                                    {if let Some(p) = &*profile {
+                                    {if let Some(p) = &report_profile {
                                         html! {
-                                            <ReportCard is_own_report={is_own_report}>
-                                                <AnnualReportCover profile={p.clone()} />
+                                            <ReportCard is_own_report={is_own_report} notice={profile_notice.clone()}>
+                                                <AnnualReportCover profile={p.clone()} hide_fid={hide_fid} />
                                             </ReportCard>
                                         }
                                     } else {
@@ -1207,17 +1572,20 @@ pub fn AnnualReportPage(props: &AnnualReportPageProps) -> Html {
                                         html! {
                                             <>
                                                 // Section 1: Your Farcaster Identity Card
-                                                {if let (Some(p), Some(temporal), Some(followers)) = (
-                                                    &*profile,
+                                                {if let (Some(p), Some(temporal), Some(followers), Some(content_style)) = (
+                                                    &report_profile,
                                                     annual_report.as_ref().map(|r| &r.temporal_activity),
                                                     annual_report.as_ref().map(|r| &r.follower_growth),
+                                                    annual_report.as_ref().map(|r| &r.content_style),
                                                 ) {
                                                     html! {
-                                                        <ReportCard is_own_report={is_own_report}>
+                                                        <ReportCard is_own_report={is_own_report} notice={profile_notice.clone()}>
                                                             <IdentitySection
                                                                 profile={p.clone()}
                                                                 temporal={temporal.clone()}
                                                                 followers={followers.clone()}
+                                                                content_style={content_style.clone()}
+                                                                hide_fid={hide_fid}
                                                             />
                                                         </ReportCard>
                                                     }
@@ -1226,19 +1594,22 @@ pub fn AnnualReportPage(props: &AnnualReportPageProps) -> Html {
                                                 }}
 
                                                 // Section 2: Follower Growth Card
-                                                {if let (Some(followers), Some(temporal), Some(engagement), Some(p)) = (
+                                                {if let (Some(followers), Some(temporal), Some(engagement), Some(p), Some(year)) = (
                                                     annual_report.as_ref().map(|r| &r.follower_growth),
                                                     annual_report.as_ref().map(|r| &r.temporal_activity),
                                                     annual_report.as_ref().map(|r| &r.engagement),
-                                                    &*profile,
+                                                    &report_profile,
+                                                    annual_report.as_ref().map(|r| r.year),
                                                 ) {
                                                     html! {
-                                                        <ReportCard is_own_report={is_own_report}>
+                                                        <ReportCard is_own_report={is_own_report} notice={profile_notice.clone()}>
                                                             <FollowerGrowthSection
                                                                 followers={followers.clone()}
                                                                 temporal={temporal.clone()}
                                                                 engagement={engagement.clone()}
                                                                 profile={p.clone()}
+                                                                year={year}
+                                                                hide_fid={hide_fid}
                                                             />
                                                         </ReportCard>
                                                     }
@@ -1253,6 +1624,7 @@ pub fn AnnualReportPage(props: &AnnualReportPageProps) -> Html {
                                                 <TopInteractiveUsersSection
                                                     engagement={engagement.clone()}
                                                     current_user_fid={current_user_fid}
+                                                    api_url={api_url.clone()}
                                                 />
                                             </ReportCard>
                                         }
@@ -1262,7 +1634,7 @@ pub fn AnnualReportPage(props: &AnnualReportPageProps) -> Html {
 
                                     // Section 4: Your Unique Style Card (renumbered from Section 6)
                         {if let Some(style) = annual_report.as_ref().map(|r| &r.content_style) {
-                            if let Some(profile_data) = profile.as_ref() {
+                            if let Some(profile_data) = report_profile.as_ref() {
                                 let casts = casts_stats.as_ref().cloned().unwrap_or_else(|| CastsStatsResponse {
                                     total_casts: 0,
                                     date_distribution: Vec::new(),
@@ -1272,7 +1644,7 @@ pub fn AnnualReportPage(props: &AnnualReportPageProps) -> Html {
                                     top_verbs: Vec::new(),
                                 });
                                 html! {
-                                    <ReportCard is_own_report={is_own_report}>
+                                    <ReportCard is_own_report={is_own_report} notice={casts_notice.clone()}>
                                         <StyleSection
                                             style={style.clone()}
                                             casts_stats={casts}
@@ -1303,7 +1675,7 @@ pub fn AnnualReportPage(props: &AnnualReportPageProps) -> Html {
                                             top_verbs: Vec::new(),
                                         });
                                         html! {
-                                            <ReportCard is_own_report={is_own_report}>
+                                            <ReportCard is_own_report={is_own_report} notice={casts_notice.clone()}>
                                                 <PersonalityTagSection
                                                     temporal={temporal.clone()}
                                                     engagement={engagement.clone()}
@@ -1330,12 +1702,56 @@ pub fn AnnualReportPage(props: &AnnualReportPageProps) -> Html {
                                     }}
                                 </div>
 
+                                // Owners can export their report, or force a fresh computation if their
+                                // data changed after it was computed
+                                {if *show_content && is_own_report && total_cards > 0 {
+                                    let button_style = "
+                                        padding: 6px 14px;
+                                        border-radius: 999px;
+                                        background: rgba(0, 0, 0, 0.45);
+                                        border: 1px solid rgba(255, 255, 255, 0.3);
+                                        color: white;
+                                        font-size: 13px;
+                                        font-weight: 600;
+                                        cursor: pointer;
+                                    ";
+                                    html! {
+                                        <div style="
+                                            position: fixed;
+                                            top: calc(12px + env(safe-area-inset-top, 0px));
+                                            right: calc(12px + env(safe-area-inset-right, 0px));
+                                            display: flex;
+                                            gap: 8px;
+                                            z-index: 2000;
+                                        ">
+                                            <button
+                                                class="export-report-button"
+                                                onclick={on_export.clone()}
+                                                title="Download this report as a JSON file"
+                                                style={button_style}
+                                            >
+                                                {"⬇️ Export"}
+                                            </button>
+                                            <button
+                                                class="recompute-report-button"
+                                                onclick={on_recompute.clone()}
+                                                title="Recompute this report from your latest activity"
+                                                style={button_style}
+                                            >
+                                                {"🔄 Recompute"}
+                                            </button>
+                                        </div>
+                                    }
+                                } else {
+                                    html! {}
+                                }}
+
                                 // Pagination indicators (glassmorphism dots) - only show when content is visible
                                 {if *show_content && total_cards > 0 {
                                     html! {
                                         <div class="pagination-indicators" style="
                                             position: fixed;
-                                            bottom: 20px;
+                                            bottom: calc(20px + env(safe-area-inset-bottom, 0px));
                                             left: 50%;
                                             transform: translateX(-50%);
                                             display: flex;
@@ -1,18 +1,41 @@
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::rc::Rc;
+
 use wasm_bindgen::closure::Closure;
 use wasm_bindgen::JsCast;
 use wasm_bindgen_futures::spawn_local;
 use wasm_bindgen_futures::JsFuture;
 use yew::prelude::*;
 
+use super::share_text::render_share_template;
+use super::share_text::share_template;
+use super::share_text::ShareTextValues;
+use super::utils::avatar_label;
+use super::utils::cast_permalink;
+use super::utils::format_count;
+use super::utils::image_label;
+use super::utils::month_name;
 use super::utils::normalize_registration_timestamp;
+use super::utils::parse_month_key;
+use crate::components::Modal;
 use crate::farcaster;
 use crate::models::AnnualReportResponse;
 use crate::models::CastsStatsResponse;
 use crate::models::ContentStyleResponse;
 use crate::models::EngagementResponse;
 use crate::models::FollowerGrowthResponse;
+use crate::models::MonthlyDistribution;
 use crate::models::ProfileWithRegistration;
 use crate::models::TemporalActivityResponse;
+use crate::models::TopWord;
+use crate::services::cache_profile;
+use crate::services::copy_to_clipboard_async;
+use crate::services::resolve_profiles;
+use crate::services::CachedProfile;
+use crate::share::use_twitter_share_domain;
+use crate::share::TwitterDomainToggle;
+use crate::toast::use_toaster;
 
 // Unified styles for annual report sections
 const REPORT_CARD_CONTENT_STYLE: &str = "
@@ -54,10 +77,14 @@ const REPORT_INFO_CARD_STYLE: &str = "
 #[derive(Properties, PartialEq, Clone)]
 pub struct AnnualReportCoverProps {
     pub profile: ProfileWithRegistration,
+    /// Leave the FID out (a shared report whose owner chose to hide it)
+    #[prop_or_default]
+    pub hide_fid: bool,
 }
 
 #[function_component]
 pub fn AnnualReportCover(props: &AnnualReportCoverProps) -> Html {
+    let profile_avatar_alt = avatar_label(props.profile.username.as_deref(), props.profile.fid);
     html! {
         <div class="report-card-content cover-page-content" style="
             width: 100%;
@@ -78,7 +105,8 @@ pub fn AnnualReportCover(props: &AnnualReportCoverProps) -> Html {
                         html! {
                     <img
                         src={pfp_url.clone()}
-                        alt="Profile"
+                        alt={profile_avatar_alt.clone()}
+                        title={profile_avatar_alt.clone()}
                         style="
                             width: 100px;
                             height: 100px;
@@ -141,13 +169,33 @@ pub fn AnnualReportCover(props: &AnnualReportCoverProps) -> Html {
                             font-weight: 600;
                             color: white;
                             margin: 0 0 6px 0;
-                        ">{format!("@{}", username)}</p>
+                        ">
+                            {format!("@{}", username)}
+                            if props.profile.has_power_badge() {
+                                <span
+                                    title="Power badge"
+                                    style="
+                                        display: inline-block;
+                                        margin-left: 8px;
+                                        padding: 2px 8px;
+                                        border-radius: 999px;
+                                        background: rgba(255, 215, 0, 0.9);
+                                        color: #4a3b00;
+                                        font-size: 13px;
+                                        font-weight: 700;
+                                        vertical-align: middle;
+                                    "
+                                >{"⚡"}</span>
+                            }
+                        </p>
+                    }
+                    if !props.hide_fid {
+                        <p style="
+                            font-size: 14px;
+                            color: rgba(255, 255, 255, 0.7);
+                            margin: 0;
+                        ">{format!("FID: {}", props.profile.fid)}</p>
                     }
-                    <p style="
-                        font-size: 14px;
-                        color: rgba(255, 255, 255, 0.7);
-                        margin: 0;
-                    ">{format!("FID: {}", props.profile.fid)}</p>
                 </div>
             </div>
         </div>
@@ -159,28 +207,113 @@ pub struct IdentitySectionProps {
     pub profile: ProfileWithRegistration,
     pub temporal: TemporalActivityResponse,
     pub followers: FollowerGrowthResponse,
+    pub content_style: ContentStyleResponse,
+    /// Leave the FID out (a shared report whose owner chose to hide it)
+    #[prop_or_default]
+    pub hide_fid: bool,
+    /// Characters of first-cast text shown before "show more"
+    #[prop_or(DEFAULT_CAST_TEXT_LIMIT)]
+    pub cast_text_limit: usize,
 }
 
-// Helper function to truncate text to specified characters with ellipsis
-fn truncate_text(text: &str, max_len: usize) -> String {
-    if text.chars().count() <= max_len {
-        text.to_string()
-    } else {
-        let truncated: String = text.chars().take(max_len).collect();
-        format!("{}...", truncated)
-    }
-}
+/// Default number of characters of cast text shown before the "show more" expander
+pub const DEFAULT_CAST_TEXT_LIMIT: usize = 280;
 
-// Helper function to normalize cast text: replace newlines with spaces and truncate
-fn normalize_and_truncate_cast(text: &str, max_len: usize) -> String {
+// Helper function to normalize cast text: replace newlines with spaces
+fn normalize_cast_text(text: &str) -> String {
     // Replace all newline characters (both \n and \r\n) with spaces
-    let normalized: String = text
-        .replace("\r\n", " ")
+    text.replace("\r\n", " ")
         .replace("\n", " ")
-        .replace("\r", " ");
-    
-    // Truncate the normalized text
-    truncate_text(&normalized, max_len)
+        .replace("\r", " ")
+}
+
+/// Shorten text to at most `max_chars` characters, cutting at the last word break when one
+/// falls in the second half of the limit (otherwise mid-word); counts chars, not bytes
+/// Returns the shortened text and whether anything was cut
+pub fn clamp_cast_text(text: &str, max_chars: usize) -> (String, bool) {
+    let Some((cut, _)) = text.char_indices().nth(max_chars) else {
+        return (text.to_string(), false);
+    };
+    let head = &text[..cut];
+    let at_word_break = text[cut..].starts_with(char::is_whitespace);
+    let head = match head.rfind(char::is_whitespace) {
+        Some(space) if !at_word_break && head[..space].chars().count() >= max_chars / 2 => {
+            &head[..space]
+        }
+        _ => head,
+    };
+    (head.trim_end().to_string(), true)
+}
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct CastTextProps {
+    pub text: String,
+    /// Warpcast link for the cast; the text links to it when present
+    pub permalink: Option<String>,
+    #[prop_or(DEFAULT_CAST_TEXT_LIMIT)]
+    pub max_chars: usize,
+}
+
+/// Cast text clamped to `max_chars`, with a "show more" toggle for the rest
+#[function_component]
+pub fn CastText(props: &CastTextProps) -> Html {
+    let expanded = use_state(|| false);
+    let full_text = normalize_cast_text(&props.text);
+    let (clamped_text, was_clamped) = clamp_cast_text(&full_text, props.max_chars);
+    let shown_text = if *expanded { full_text } else { clamped_text };
+
+    let text = match &props.permalink {
+        Some(url) => html! {
+            <a
+                href={url.clone()}
+                target="_blank"
+                rel="noopener noreferrer"
+                title="View cast on Farcaster"
+                style="color: inherit; text-decoration: none;"
+            >
+                {shown_text}
+                <span style="margin-left: 6px; font-size: 12px; color: rgba(255, 255, 255, 0.6);">{"↗"}</span>
+            </a>
+        },
+        None => html! { {shown_text} },
+    };
+
+    let toggle = if was_clamped {
+        let on_toggle = {
+            let expanded = expanded.clone();
+            Callback::from(move |e: MouseEvent| {
+                e.stop_propagation();
+                expanded.set(!*expanded);
+            })
+        };
+        html! {
+            <button
+                onclick={on_toggle}
+                aria-expanded={expanded.to_string()}
+                style="
+                    background: none;
+                    border: none;
+                    padding: 0;
+                    margin-left: 6px;
+                    color: rgba(255, 255, 255, 0.7);
+                    font-size: 13px;
+                    text-decoration: underline;
+                    cursor: pointer;
+                "
+            >
+                {if *expanded { "show less" } else { "… show more" }}
+            </button>
+        }
+    } else {
+        html! {}
+    };
+
+    html! {
+        <>
+            {text}
+            {toggle}
+        </>
+    }
 }
 
 // Helper function to get zodiac sign from date (month and day)
@@ -222,11 +355,78 @@ fn get_far_zodiac_sign(fid: i64) -> &'static str {
     zodiacs[index]
 }
 
+/// Zodiac image for a registration timestamp (Farcaster or Unix), Capricorn when missing or
+/// implausible
+pub(crate) fn registration_zodiac_image_url(registered_at: Option<i64>) -> String {
+    registered_at
+        .and_then(normalize_registration_timestamp)
+        .map(|unix_timestamp| {
+            // Convert Unix timestamp (in seconds) to JavaScript Date (expects milliseconds)
+            let date = js_sys::Date::new(&wasm_bindgen::JsValue::from_f64(
+                unix_timestamp as f64 * 1000.0,
+            ));
+            let zodiac = get_zodiac_sign(date.get_month() + 1, date.get_date());
+            get_image_url(&format!("/imgs/zodiac/{}.png", zodiac.to_lowercase()))
+        })
+        .unwrap_or_else(|| get_image_url("/imgs/zodiac/capricorn.png"))
+}
+
+/// Images shown on report page `page` (cover, identity, follower growth, top interactive
+/// users, style, personality tag), for preloading before the page is scrolled to
+/// Pages without images of their own (or past the end) return nothing
+pub(crate) fn report_page_image_urls(
+    page: usize,
+    profile: Option<&ProfileWithRegistration>,
+    report: &AnnualReportResponse,
+    max_bubbles: usize,
+) -> Vec<String> {
+    let avatar = profile.and_then(|p| p.pfp_url.clone());
+    match page {
+        0 | 4 => avatar.into_iter().collect(),
+        1 => std::iter::once(registration_zodiac_image_url(
+            profile.and_then(|p| p.registered_at),
+        ))
+        .chain(avatar)
+        .collect(),
+        2 => {
+            let total_casts = report
+                .temporal_activity
+                .total_casts_in_year
+                .unwrap_or(report.temporal_activity.total_casts);
+            std::iter::once(get_image_url(classify_social(total_casts).image_path))
+                .chain(avatar)
+                .collect()
+        }
+        3 => {
+            // Same selection as TopInteractiveUsersSection: the most frequent reactors first
+            let mut reactors: Vec<_> = report
+                .engagement
+                .top_reactors
+                .iter()
+                .filter(|r| profile.is_none_or(|p| r.fid != p.fid))
+                .collect();
+            reactors.sort_by_key(|r| std::cmp::Reverse(r.interaction_count));
+            reactors
+                .into_iter()
+                .filter_map(|r| r.pfp_url.clone())
+                .take(max_bubbles)
+                .collect()
+        }
+        5 => {
+            let fid = profile.map(|p| p.fid).unwrap_or(report.fid);
+            let (_name, filename, _description) = TAROT_CARDS[tarot_card_index(fid, 0)];
+            vec![get_image_url(&format!("/imgs/tarot/{}", filename))]
+        }
+        _ => Vec::new(),
+    }
+}
+
 #[function_component]
 pub fn IdentitySection(props: &IdentitySectionProps) -> Html {
+    let profile_avatar_alt = avatar_label(props.profile.username.as_deref(), props.profile.fid);
     // Get registration date and calculate zodiac signs
     // Normalize timestamp (may be Farcaster or Unix format) and validate range
-    let (birthday_date, zodiac_image_url, zodiac_info) = props
+    let (birthday_date, zodiac_image_url, zodiac_info, zodiac_alt) = props
         .profile
         .registered_at
         .and_then(|timestamp| {
@@ -248,13 +448,17 @@ pub fn IdentitySection(props: &IdentitySectionProps) -> Html {
             // Build image URL from zodiac name (convert to lowercase)
             let zodiac_lower = zodiac.to_lowercase();
             let zodiac_image_url = get_image_url(&format!("/imgs/zodiac/{}.png", zodiac_lower));
-            (birthday_date, zodiac_image_url, zodiac_info)
+            let zodiac_alt = image_label("Zodiac", zodiac);
+            (birthday_date, zodiac_image_url, zodiac_info, zodiac_alt)
         })
         .unwrap_or_else(|| {
+            // Missing or implausible registration: no birthday, but the FID still gives
+            // the far zodiac
             (
                 "N/A".to_string(),
                 get_image_url("/imgs/zodiac/capricorn.png"),
-                "N/A".to_string(),
+                get_far_zodiac_sign(props.profile.fid).to_string(),
+                "Zodiac".to_string(),
             )
         });
 
@@ -276,6 +480,16 @@ pub fn IdentitySection(props: &IdentitySectionProps) -> Html {
         })
         .unwrap_or_else(|| "N/A".to_string());
 
+    // "Your signature": #1 emoji and top 3 words
+    let top_emoji = props.content_style.top_emojis.first().map(|e| e.emoji.clone());
+    let top_words: Vec<String> = props
+        .content_style
+        .top_words
+        .iter()
+        .take(3)
+        .map(|w| w.word.clone())
+        .collect();
+
     html! {
         <div class="report-card-content" style={REPORT_CARD_CONTENT_STYLE}
         oncopy={Callback::from(|e: web_sys::Event| {
@@ -310,7 +524,8 @@ pub fn IdentitySection(props: &IdentitySectionProps) -> Html {
                     ">
                         <img
                             src={zodiac_image_url.clone()}
-                            alt="Zodiac"
+                            alt={zodiac_alt.clone()}
+                            title={zodiac_alt.clone()}
                             style="
                                 width: 100px;
                                 height: 100px;
@@ -347,7 +562,8 @@ pub fn IdentitySection(props: &IdentitySectionProps) -> Html {
                                             html! {
                                                 <img
                                                     src={pfp_url.clone()}
-                                                    alt="Avatar"
+                                                    alt={profile_avatar_alt.clone()}
+                                                    title={profile_avatar_alt.clone()}
                                                     style="
                                                         width: 100%;
                                                         height: 100%;
@@ -402,6 +618,10 @@ pub fn IdentitySection(props: &IdentitySectionProps) -> Html {
                                                 html! {
                                                     <span>{format!("@{}", username)}</span>
                                                 }
+                                            } else if props.hide_fid {
+                                                html! {
+                                                    <span>{"A Farcaster user"}</span>
+                                                }
                                             } else {
                                                 html! {
                                                     <span>{format!("FID: {}", props.profile.fid)}</span>
@@ -418,7 +638,11 @@ pub fn IdentitySection(props: &IdentitySectionProps) -> Html {
                                             line-height: 1.6;
                                             word-wrap: break-word;
                                         ">
-                                            {normalize_and_truncate_cast(&first_cast.text, 48)}
+                                            <CastText
+                                                text={first_cast.text.clone()}
+                                                permalink={cast_permalink(&first_cast.message_hash)}
+                                                max_chars={props.cast_text_limit}
+                                            />
                                         </div>
                                     </div>
                                 </div>
@@ -447,12 +671,147 @@ pub fn IdentitySection(props: &IdentitySectionProps) -> Html {
                         <span style="font-weight: 700; font-size: 18px; color: white;">{zodiac_info.clone()}</span>
                         {", a unique combination that reflects both your birth date and your Farcaster identity."}
                     </div>
+
+                    // Your signature: most used emoji and words
+                    {if top_emoji.is_some() || !top_words.is_empty() {
+                        html! {
+                            <div style="
+                                display: flex;
+                                align-items: center;
+                                gap: 16px;
+                                padding: 12px 16px;
+                                border-radius: 12px;
+                                background: rgba(255, 255, 255, 0.08);
+                            ">
+                                {if let Some(emoji) = &top_emoji {
+                                    html! {
+                                        <span style="font-size: 48px; line-height: 1;">{emoji.clone()}</span>
+                                    }
+                                } else {
+                                    html! {}
+                                }}
+                                <div style="display: flex; flex-direction: column; gap: 6px;">
+                                    <span style="font-size: 14px; color: rgba(255, 255, 255, 0.7);">{"Your signature"}</span>
+                                    {if !top_words.is_empty() {
+                                        html! {
+                                            <div style="display: flex; flex-wrap: wrap; gap: 6px;">
+                                                {for top_words.iter().map(|word| html! {
+                                                    <span style="
+                                                        padding: 2px 10px;
+                                                        border-radius: 10px;
+                                                        background: rgba(255, 255, 255, 0.15);
+                                                        font-weight: 600;
+                                                        color: white;
+                                                    ">{word.clone()}</span>
+                                                })}
+                                            </div>
+                                        }
+                                    } else {
+                                        html! {}
+                                    }}
+                                </div>
+                            </div>
+                        }
+                    } else {
+                        html! {}
+                    }}
                 </div>
             </div>
         </div>
     }
 }
 
+/// How long a stat takes to count up to its final value
+const COUNT_UP_DURATION_MS: f64 = 1000.0;
+
+/// Value shown `progress` (0.0-1.0) of the way through a count-up from `from` to `to`,
+/// eased out so it slows near the end. Always exactly `to` once progress reaches 1
+pub fn count_up_value(from: usize, to: usize, progress: f64) -> usize {
+    if progress >= 1.0 {
+        return to;
+    }
+    let eased = 1.0 - (1.0 - progress.max(0.0)).powi(3);
+    let value = from as f64 + (to as f64 - from as f64) * eased;
+    value.round().max(0.0) as usize
+}
+
+/// Whether the user asked the OS/browser for reduced motion
+fn prefers_reduced_motion() -> bool {
+    web_sys::window()
+        .and_then(|w| {
+            w.match_media("(prefers-reduced-motion: reduce)")
+                .ok()
+                .flatten()
+        })
+        .map(|query| query.matches())
+        .unwrap_or(false)
+}
+
+/// requestAnimationFrame callback that re-schedules itself until the animation finishes
+type AnimationFrameCallback = Rc<RefCell<Option<Closure<dyn FnMut(f64)>>>>;
+
+/// Animate a displayed number from its current value (0 on first render) to `target` over
+/// COUNT_UP_DURATION_MS using requestAnimationFrame. Jumps straight to `target` when reduced
+/// motion is preferred
+#[hook]
+pub fn use_count_up(target: usize) -> usize {
+    let reduced_motion = use_memo((), |_| prefers_reduced_motion());
+    let value = use_state(|| if *reduced_motion { target } else { 0 });
+
+    {
+        let value = value.clone();
+        let reduced_motion = *reduced_motion;
+        use_effect_with(target, move |&target| {
+            let from = *value;
+            let frame: AnimationFrameCallback = Rc::new(RefCell::new(None));
+            let request_id = Rc::new(Cell::new(None::<i32>));
+            let window = web_sys::window();
+
+            match window.as_ref() {
+                Some(window) if !reduced_motion && from != target => {
+                    let start = Rc::new(Cell::new(None::<f64>));
+                    let next_frame = frame.clone();
+                    let next_request_id = request_id.clone();
+                    let frame_window = window.clone();
+                    *frame.borrow_mut() = Some(Closure::new(move |now: f64| {
+                        let started = start.get().unwrap_or(now);
+                        start.set(Some(started));
+                        let progress = (now - started) / COUNT_UP_DURATION_MS;
+                        value.set(count_up_value(from, target, progress));
+                        if progress < 1.0 {
+                            if let Some(callback) = next_frame.borrow().as_ref() {
+                                next_request_id.set(
+                                    frame_window
+                                        .request_animation_frame(callback.as_ref().unchecked_ref())
+                                        .ok(),
+                                );
+                            }
+                        }
+                    }));
+                    if let Some(callback) = frame.borrow().as_ref() {
+                        request_id.set(
+                            window
+                                .request_animation_frame(callback.as_ref().unchecked_ref())
+                                .ok(),
+                        );
+                    }
+                }
+                _ => value.set(target),
+            }
+
+            move || {
+                if let (Some(window), Some(id)) = (window, request_id.get()) {
+                    let _ = window.cancel_animation_frame(id);
+                }
+                // Dropping the closure breaks its reference cycle with `frame`
+                frame.borrow_mut().take();
+            }
+        });
+    }
+
+    *value
+}
+
 // Follower Growth Section Component
 #[derive(Properties, PartialEq, Clone)]
 pub struct FollowerGrowthSectionProps {
@@ -460,10 +819,33 @@ pub struct FollowerGrowthSectionProps {
     pub temporal: TemporalActivityResponse,
     pub engagement: EngagementResponse,
     pub profile: ProfileWithRegistration,
+    /// Year the report covers; the monthly chart only shows this year's months
+    pub year: i32,
+    /// Leave the FID out (a shared report whose owner chose to hide it)
+    #[prop_or_default]
+    pub hide_fid: bool,
+    /// Characters of popular-cast text shown before "show more"
+    #[prop_or(DEFAULT_CAST_TEXT_LIMIT)]
+    pub cast_text_limit: usize,
 }
 
 #[function_component]
 pub fn FollowerGrowthSection(props: &FollowerGrowthSectionProps) -> Html {
+    let profile_avatar_alt = avatar_label(props.profile.username.as_deref(), props.profile.fid);
+    let popular_image_failed = use_state(|| false);
+    {
+        // A new popular cast gets a fresh chance to show its image
+        let popular_image_failed = popular_image_failed.clone();
+        let popular_image = props
+            .engagement
+            .most_popular_cast
+            .as_ref()
+            .and_then(|cast| cast.first_image_embed().map(str::to_string));
+        use_effect_with(popular_image, move |_| {
+            popular_image_failed.set(false);
+            || ()
+        });
+    }
     let follower_change =
         props.followers.current_followers as i64 - props.followers.followers_at_start as i64;
 
@@ -482,37 +864,24 @@ pub fn FollowerGrowthSection(props: &FollowerGrowthSectionProps) -> Html {
         "reserved"
     };
 
-    // Get most active month
-    let most_active_month = props
-        .temporal
-        .monthly_distribution
+    // Most active month of the report year, the same one the chart highlights
+    let month_bars = monthly_activity_bars(&props.temporal.monthly_distribution, props.year);
+    let most_active_month = month_bars
         .iter()
-        .max_by_key(|m| m.count)
-        .map(|m| {
-            let parts: Vec<&str> = m.month.split('-').collect();
-            if parts.len() >= 2 {
-                let month_num: u32 = parts[1].parse().unwrap_or(1);
-                match month_num {
-                    1 => "January",
-                    2 => "February",
-                    3 => "March",
-                    4 => "April",
-                    5 => "May",
-                    6 => "June",
-                    7 => "July",
-                    8 => "August",
-                    9 => "September",
-                    10 => "October",
-                    11 => "November",
-                    12 => "December",
-                    _ => "Unknown",
-                }
-            } else {
-                "N/A"
-            }
-        })
+        .find(|bar| bar.is_peak)
+        .and_then(|bar| month_name(bar.month))
         .unwrap_or("N/A");
 
+    // Big numbers count up from 0 when the section first renders
+    let popular_cast = props.engagement.most_popular_cast.as_ref();
+    let shown_total_casts = use_count_up(total_casts);
+    let shown_avg_per_week = use_count_up(avg_per_week);
+    let shown_popular_reactions = use_count_up(popular_cast.map_or(0, |c| c.reactions));
+    let shown_popular_recasts = use_count_up(popular_cast.map_or(0, |c| c.recasts));
+    let shown_popular_replies = use_count_up(popular_cast.map_or(0, |c| c.replies));
+    let shown_followers = use_count_up(props.followers.current_followers);
+    let shown_follower_change = use_count_up(follower_change.max(0) as usize);
+
     // Get most active hour
     let most_active_hour = props
         .temporal
@@ -521,11 +890,10 @@ pub fn FollowerGrowthSection(props: &FollowerGrowthSectionProps) -> Html {
         .unwrap_or_else(|| "N/A".to_string());
 
     // Determine social type image and title based on total casts
-    let (social_type_image, section_title) = if total_casts >= 200 {
-        (get_image_url("/imgs/social_type/social.png"), "Social Butterfly")
-    } else {
-        (get_image_url("/imgs/social_type/slient.png"), "Man of Few Words")
-    };
+    let social_type = classify_social(total_casts);
+    let social_type_image = get_image_url(social_type.image_path);
+    let section_title = social_type.label;
+    let social_type_alt = image_label("Social type", social_type.label);
 
     html! {
         <div class="report-card-content" style={REPORT_CARD_CONTENT_STYLE}
@@ -561,7 +929,8 @@ pub fn FollowerGrowthSection(props: &FollowerGrowthSectionProps) -> Html {
                     ">
                         <img
                             src={social_type_image}
-                            alt="Social Type"
+                            alt={social_type_alt.clone()}
+                            title={social_type_alt.clone()}
                             style="
                                 width: 100px;
                                 height: 100px;
@@ -569,6 +938,11 @@ pub fn FollowerGrowthSection(props: &FollowerGrowthSectionProps) -> Html {
                             "
                         />
                 </div>
+                    <p style="
+                        margin: 0;
+                        text-align: center;
+                        color: rgba(255, 255, 255, 0.8);
+                    ">{social_type.narrative}</p>
 
                     // Popular cast quote - chat bubble style
                     {if let Some(popular_cast) = &props.engagement.most_popular_cast {
@@ -598,7 +972,8 @@ pub fn FollowerGrowthSection(props: &FollowerGrowthSectionProps) -> Html {
                             html! {
                                                 <img
                                                     src={pfp_url.clone()}
-                                                    alt="Avatar"
+                                                    alt={profile_avatar_alt.clone()}
+                                                    title={profile_avatar_alt.clone()}
                                                     style="
                                                         width: 100%;
                                                         height: 100%;
@@ -653,6 +1028,10 @@ pub fn FollowerGrowthSection(props: &FollowerGrowthSectionProps) -> Html {
                                                 html! {
                                                     <span>{format!("@{}", username)}</span>
                                                 }
+                                            } else if props.hide_fid {
+                                                html! {
+                                                    <span>{"A Farcaster user"}</span>
+                                                }
                                             } else {
                                                 html! {
                                                     <span>{format!("FID: {}", props.profile.fid)}</span>
@@ -669,8 +1048,38 @@ pub fn FollowerGrowthSection(props: &FollowerGrowthSectionProps) -> Html {
                                             line-height: 1.6;
                                             word-wrap: break-word;
                                         ">
-                                            {normalize_and_truncate_cast(&popular_cast.text, 48)}
+                                            <CastText
+                                                text={popular_cast.text.clone()}
+                                                permalink={cast_permalink(&popular_cast.message_hash)}
+                                                max_chars={props.cast_text_limit}
+                                            />
                                         </div>
+                                        // First image embed, hidden if it fails to load
+                                        {match popular_cast.first_image_embed() {
+                                            Some(image_url) if !*popular_image_failed => {
+                                                let on_error = {
+                                                    let popular_image_failed = popular_image_failed.clone();
+                                                    Callback::from(move |_: Event| popular_image_failed.set(true))
+                                                };
+                                                html! {
+                                                    <img
+                                                        src={image_url.to_string()}
+                                                        alt="Image from your most popular cast"
+                                                        loading="lazy"
+                                                        onerror={on_error}
+                                                        style="
+                                                            display: block;
+                                                            max-width: 100%;
+                                                            max-height: 180px;
+                                                            margin-top: 6px;
+                                                            border-radius: 12px;
+                                                            object-fit: cover;
+                                                        "
+                                                    />
+                                                }
+                                            }
+                                            _ => html! {},
+                                        }}
                                     </div>
                                 </div>
                             }
@@ -683,9 +1092,9 @@ pub fn FollowerGrowthSection(props: &FollowerGrowthSectionProps) -> Html {
 
                     <div>
                         {"This year, you published "}
-                        <span style="font-weight: 700; font-size: 18px; color: white;">{total_casts.to_string()}</span>
+                        <span title={total_casts.to_string()} style="font-weight: 700; font-size: 18px; color: white;">{format_count(shown_total_casts)}</span>
                         {" messages in total, averaging "}
-                        <span style="font-weight: 700; font-size: 18px; color: white;">{avg_per_week.to_string()}</span>
+                        <span title={avg_per_week.to_string()} style="font-weight: 700; font-size: 18px; color: white;">{format_count(shown_avg_per_week)}</span>
                         {" per week. It shows you are "}
                         <span style="font-weight: 700; font-size: 18px; color: white;">{personality_trait}</span>
                         {"."}
@@ -699,15 +1108,17 @@ pub fn FollowerGrowthSection(props: &FollowerGrowthSectionProps) -> Html {
                         {"."}
                 </div>
 
+                    {render_monthly_bar_chart(&month_bars)}
+
                     {if let Some(popular_cast) = &props.engagement.most_popular_cast {
                         html! {
                             <div>
                                 {"This year, your voice was heard. The most popular one received "}
-                                <span style="font-weight: 700; font-size: 18px; color: white;">{popular_cast.reactions.to_string()}</span>
+                                <span title={popular_cast.reactions.to_string()} style="font-weight: 700; font-size: 18px; color: white;">{format_count(shown_popular_reactions)}</span>
                                 {" likes, "}
-                                <span style="font-weight: 700; font-size: 18px; color: white;">{popular_cast.recasts.to_string()}</span>
+                                <span title={popular_cast.recasts.to_string()} style="font-weight: 700; font-size: 18px; color: white;">{format_count(shown_popular_recasts)}</span>
                                 {" recasts, and "}
-                                <span style="font-weight: 700; font-size: 18px; color: white;">{popular_cast.replies.to_string()}</span>
+                                <span title={popular_cast.replies.to_string()} style="font-weight: 700; font-size: 18px; color: white;">{format_count(shown_popular_replies)}</span>
                                 {" replies."}
                             </div>
                         }
@@ -717,13 +1128,13 @@ pub fn FollowerGrowthSection(props: &FollowerGrowthSectionProps) -> Html {
 
                     <div>
                         {"You have "}
-                        <span style="font-weight: 700; font-size: 18px; color: white;">{props.followers.current_followers.to_string()}</span>
+                        <span title={props.followers.current_followers.to_string()} style="font-weight: 700; font-size: 18px; color: white;">{format_count(shown_followers)}</span>
                         {" followers"}
                         {if follower_change > 0 {
                             html! {
                                 <>
                                     {", "}
-                                    <span style="font-weight: 700; font-size: 18px; color: white;">{follower_change.to_string()}</span>
+                                    <span title={follower_change.to_string()} style="font-weight: 700; font-size: 18px; color: white;">{format_count(shown_follower_change)}</span>
                                     {" of which were gained this year."}
                                 </>
                             }
@@ -737,15 +1148,169 @@ pub fn FollowerGrowthSection(props: &FollowerGrowthSectionProps) -> Html {
     }
 }
 
+/// One bar of the monthly activity chart
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonthBar {
+    /// 1-12
+    pub month: u32,
+    pub count: usize,
+    pub is_peak: bool,
+}
+
+const MONTH_INITIALS: [&str; 12] = ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"];
+
+/// Twelve bars (January to December) for `year`, empty if no month key of that year can be
+/// read (see `parse_month_key`). Months missing from the distribution count as zero; entries
+/// from other years or with unreadable keys are ignored. The busiest month is the peak,
+/// the earliest one on a tie
+pub fn monthly_activity_bars(distribution: &[MonthlyDistribution], year: i32) -> Vec<MonthBar> {
+    let entries: Vec<(u32, usize)> = distribution
+        .iter()
+        .filter_map(|m| match parse_month_key(&m.month) {
+            Some((entry_year, month)) if entry_year == year => Some((month, m.count)),
+            _ => None,
+        })
+        .collect();
+    if entries.is_empty() {
+        return Vec::new();
+    }
+
+    let mut bars: Vec<MonthBar> = (1..=12)
+        .map(|month| MonthBar {
+            month,
+            count: entries
+                .iter()
+                .filter(|(entry_month, _)| *entry_month == month)
+                .map(|(_, count)| count)
+                .sum(),
+            is_peak: false,
+        })
+        .collect();
+    let peak = bars
+        .iter()
+        .enumerate()
+        .filter(|(_, bar)| bar.count > 0)
+        .max_by_key(|(index, bar)| (bar.count, std::cmp::Reverse(*index)))
+        .map(|(index, _)| index);
+    if let Some(index) = peak {
+        bars[index].is_peak = true;
+    }
+    bars
+}
+
+/// Small 12-bar chart of casts per month; renders nothing without monthly data
+fn render_monthly_bar_chart(bars: &[MonthBar]) -> Html {
+    let max_count = bars.iter().map(|b| b.count).max().unwrap_or(0);
+    if max_count == 0 {
+        return html! {};
+    }
+
+    html! {
+        <div class="monthly-activity-chart" style="margin-top: 4px;">
+            <div style="
+                display: flex;
+                align-items: flex-end;
+                gap: 4px;
+                height: 80px;
+            ">
+                {for bars.iter().map(|bar| {
+                    // Keep non-empty months visible even next to a much larger peak
+                    let height = if bar.count == 0 {
+                        0.0
+                    } else {
+                        (bar.count as f32 / max_count as f32 * 100.0).max(4.0)
+                    };
+                    let color = if bar.is_peak { "white" } else { "rgba(255, 255, 255, 0.4)" };
+                    let name = month_name(bar.month).unwrap_or_default();
+                    html! {
+                        <div
+                            title={format!("{}: {} casts", name, bar.count)}
+                            aria-label={format!("{}: {} casts", name, bar.count)}
+                            style={format!("
+                                flex: 1;
+                                height: {:.1}%;
+                                min-height: 2px;
+                                background: {};
+                                border-radius: 4px 4px 0 0;
+                            ", height, color)}
+                        ></div>
+                    }
+                })}
+            </div>
+            <div style="display: flex; gap: 4px; margin-top: 4px;">
+                {for bars.iter().map(|bar| html! {
+                    <span style={format!("
+                        flex: 1;
+                        text-align: center;
+                        font-size: 11px;
+                        color: {};
+                        font-weight: {};
+                    ",
+                        if bar.is_peak { "white" } else { "rgba(255, 255, 255, 0.7)" },
+                        if bar.is_peak { 700 } else { 400 }
+                    )}>
+                        {MONTH_INITIALS[(bar.month - 1) as usize]}
+                    </span>
+                })}
+            </div>
+        </div>
+    }
+}
+
 // Top Interactive Users Section Component
 #[derive(Properties, PartialEq, Clone)]
 pub struct TopInteractiveUsersSectionProps {
     pub engagement: EngagementResponse,
     pub current_user_fid: Option<i64>,
+    /// Maximum number of reactor bubbles; the rest collapse into a "+N more" bubble
+    #[prop_or(10)]
+    pub max_bubbles: usize,
+    /// API used to fill in reactors the report returned without a username or avatar
+    #[prop_or_default]
+    pub api_url: Option<String>,
+}
+
+/// Placeholder for an empty reactor list: honest about missing aggregation when the report
+/// clearly had interactions. Worded neutrally since it also shows on other people's reports
+pub(crate) fn empty_reactors_message(engagement: &EngagementResponse) -> &'static str {
+    if engagement.has_interactions() {
+        "Top supporters are still being tallied"
+    } else {
+        "No interactive users data available"
+    }
 }
 
 #[function_component]
 pub fn TopInteractiveUsersSection(props: &TopInteractiveUsersSectionProps) -> Html {
+    // Usernames/avatars for reactors the report left blank, via the shared username cache
+    let resolved_profiles = use_state(std::collections::HashMap::<i64, CachedProfile>::new);
+    {
+        let resolved_profiles = resolved_profiles.clone();
+        use_effect_with(
+            (props.engagement.top_reactors.clone(), props.api_url.clone()),
+            move |(reactors, api_url)| {
+                let mut incomplete = Vec::new();
+                for reactor in reactors {
+                    if reactor.username.is_some() && reactor.pfp_url.is_some() {
+                        cache_profile(CachedProfile {
+                            fid: reactor.fid,
+                            username: reactor.username.clone(),
+                            display_name: reactor.display_name.clone(),
+                            pfp_url: reactor.pfp_url.clone(),
+                        });
+                    } else {
+                        incomplete.push(reactor.fid);
+                    }
+                }
+                if let (Some(api_url), false) = (api_url.clone(), incomplete.is_empty()) {
+                    spawn_local(async move {
+                        resolved_profiles.set(resolve_profiles(&api_url, &incomplete).await);
+                    });
+                }
+            },
+        );
+    }
+
     html! {
         <div class="report-card-content" style="
             width: 100%;
@@ -801,8 +1366,8 @@ pub fn TopInteractiveUsersSection(props: &TopInteractiveUsersSectionProps) -> Ht
                         <>
                             {{
                                 // Sort reactors by interaction count and calculate sizes
-                                // Display all top reactors (up to 10), excluding current user
-                                let filtered_reactors: Vec<_> = props.engagement.top_reactors.iter()
+                                // Display up to max_bubbles reactors, excluding current user
+                                let mut filtered_reactors: Vec<_> = props.engagement.top_reactors.iter()
                                     .filter(|reactor| {
                                         // Exclude current user if FID matches
                                         if let Some(current_fid) = props.current_user_fid {
@@ -813,12 +1378,17 @@ pub fn TopInteractiveUsersSection(props: &TopInteractiveUsersSectionProps) -> Ht
                                     })
                                     .collect();
 
+                                filtered_reactors.sort_by_key(|r| std::cmp::Reverse(r.interaction_count));
+                                let hidden_count = filtered_reactors.len().saturating_sub(props.max_bubbles);
+                                filtered_reactors.truncate(props.max_bubbles);
+
+                                // Size relative to the displayed set only
                                 let max_count = filtered_reactors.iter()
                                             .map(|r| r.interaction_count)
                                             .max()
                                             .unwrap_or(1);
 
-                                let mut reactors_with_sizes: Vec<_> = filtered_reactors.iter()
+                                let reactors_with_sizes: Vec<_> = filtered_reactors.iter()
                                     .map(|reactor| {
                                         // Calculate bubble size based on interaction count
                                         // Use a base size and scale based on count
@@ -840,19 +1410,23 @@ pub fn TopInteractiveUsersSection(props: &TopInteractiveUsersSectionProps) -> Ht
                                     })
                                     .collect();
 
-                                reactors_with_sizes.sort_by(|a, b| b.0.interaction_count.cmp(&a.0.interaction_count));
-
                                 html! {
                                     <>
                                         {for reactors_with_sizes.iter().enumerate().map(|(idx, (reactor, size, offset_x, offset_y))| {
                                             let bubble_size = format!("{}px", *size as i32);
-                                            let avatar_url = reactor.pfp_url.as_ref().cloned();
-                                            let username = reactor.username.as_ref()
-                                                .or(reactor.display_name.as_ref()).cloned()
+                                            let resolved = resolved_profiles.get(&reactor.fid);
+                                            let avatar_url = reactor.pfp_url.clone()
+                                                .or_else(|| resolved.and_then(|p| p.pfp_url.clone()));
+                                            let handle = reactor.username.clone()
+                                                .or_else(|| resolved.and_then(|p| p.username.clone()));
+                                            let username = handle.clone()
+                                                .or_else(|| reactor.display_name.clone())
+                                                .or_else(|| resolved.and_then(|p| p.display_name.clone()))
                                                 .unwrap_or_else(|| format!("FID {}", reactor.fid));
+                                            let reactor_alt = avatar_label(handle.as_deref(), reactor.fid);
 
                                             html! {
-                                                <div style={format!("
+                                                <div class="floating-bubble" style={format!("
                                                     position: relative;
                                                     width: {};
                                                     height: {};
@@ -886,7 +1460,8 @@ pub fn TopInteractiveUsersSection(props: &TopInteractiveUsersSectionProps) -> Ht
                                                             html! {
                                                                 <img
                                                                     src={url}
-                                                                    alt=""
+                                                                    alt={reactor_alt.clone()}
+                                                                    title={reactor_alt.clone()}
                                                                     style="
                                                                         width: 100%;
                                                                         height: 100%;
@@ -924,6 +1499,37 @@ pub fn TopInteractiveUsersSection(props: &TopInteractiveUsersSectionProps) -> Ht
                                                 </div>
                                             }
                                         })}
+                                        {if hidden_count > 0 {
+                                            html! {
+                                                <div
+                                                    class="floating-bubble"
+                                                    title={format!("{} more people interacted with you", hidden_count)}
+                                                    style={format!("
+                                                        width: 80px;
+                                                        height: 80px;
+                                                        display: flex;
+                                                        align-items: center;
+                                                        justify-content: center;
+                                                        background: rgba(255, 255, 255, 0.08);
+                                                        backdrop-filter: blur(15px);
+                                                        -webkit-backdrop-filter: blur(15px);
+                                                        border-radius: 50%;
+                                                        border: 2px dashed rgba(255, 255, 255, 0.3);
+                                                        box-shadow: 0 8px 32px rgba(0, 0, 0, 0.2);
+                                                        font-size: 15px;
+                                                        font-weight: 700;
+                                                        color: white;
+                                                        text-align: center;
+                                                        animation: float 3s ease-in-out infinite;
+                                                        animation-delay: {}s;
+                                                    ", reactors_with_sizes.len() as f32 * 0.3)}
+                                                >
+                                                    {format!("+{} more", hidden_count)}
+                                                </div>
+                                            }
+                                        } else {
+                                            html! {}
+                                        }}
                                     </>
                                 }
                             }}
@@ -936,6 +1542,12 @@ pub fn TopInteractiveUsersSection(props: &TopInteractiveUsersSectionProps) -> Ht
                                         transform: translateY(-10px);
                                     }
                                 }
+                                /* Inline animation styles need !important to be overridden */
+                                @media (prefers-reduced-motion: reduce) {
+                                    .floating-bubble {
+                                        animation: none !important;
+                                    }
+                                }
                             "}</style>
                         </>
                     }
@@ -951,7 +1563,7 @@ pub fn TopInteractiveUsersSection(props: &TopInteractiveUsersSectionProps) -> Ht
                             text-align: center;
                             color: rgba(255, 255, 255, 0.7);
                         ">
-                            {"No interactive users data available"}
+                            {empty_reactors_message(&props.engagement)}
                         </div>
                     }
                 }}
@@ -970,12 +1582,23 @@ pub struct StyleSectionProps {
 
 #[function_component]
 pub fn StyleSection(props: &StyleSectionProps) -> Html {
+    let profile_avatar_alt = avatar_label(props.profile.username.as_deref(), props.profile.fid);
     // Use top_words from content_style, not from casts_stats
     let top_words = props.style.top_words.clone();
 
     // Find max count for font size calculation
     let max_count = top_words.iter().map(|w| w.count).max().unwrap_or(1);
 
+    let top_nouns = top_words_by_count(&props.casts_stats.top_nouns, MAX_WORD_CHIPS);
+    let top_verbs = top_words_by_count(&props.casts_stats.top_verbs, MAX_WORD_CHIPS);
+    let languages = top_languages(&props.casts_stats.language_distribution, MAX_LANGUAGES_SHOWN);
+    let language_count = props
+        .casts_stats
+        .language_distribution
+        .values()
+        .filter(|count| **count > 0)
+        .count();
+
     html! {
         <div class="report-card-content" style="
             width: 100%;
@@ -1056,7 +1679,8 @@ pub fn StyleSection(props: &StyleSectionProps) -> Html {
                                     html! {
                                         <img
                                             src={pfp_url.clone()}
-                                            alt="Avatar"
+                                            alt={profile_avatar_alt.clone()}
+                                            title={profile_avatar_alt.clone()}
                                             style="
                                                 width: 100%;
                                                 height: 100%;
@@ -1099,7 +1723,7 @@ pub fn StyleSection(props: &StyleSectionProps) -> Html {
                             </div>
                         }
                     }}
-                    <div style="
+                    <div class="word-sphere" style="
                         width: 100%;
                         height: 100%;
                         position: relative;
@@ -1116,6 +1740,13 @@ pub fn StyleSection(props: &StyleSectionProps) -> Html {
                                     transform: rotateY(360deg) rotateX(15deg);
                                 }
                             }
+                            /* Keep the sphere still, at the starting tilt */
+                            @media (prefers-reduced-motion: reduce) {
+                                .word-sphere {
+                                    animation: none !important;
+                                    transform: rotateY(0deg) rotateX(15deg);
+                                }
+                            }
                             "#}
                         </style>
                         {{
@@ -1237,83 +1868,251 @@ pub fn StyleSection(props: &StyleSectionProps) -> Html {
                     }}
                 </div>
                 </div>
+
+                // Nouns and verbs the user uses most (each row hidden when empty)
+                {if !top_nouns.is_empty() || !top_verbs.is_empty() {
+                    html! {
+                        <div style={format!("{} width: 100%; max-width: 500px; margin: 0 auto; box-sizing: border-box; display: flex; flex-direction: column; gap: 16px;", REPORT_INFO_CARD_STYLE)}>
+                            {render_word_chips("You talk about", &top_nouns, "rgba(0, 206, 209, 0.25)")}
+                            {render_word_chips("And you", &top_verbs, "rgba(255, 215, 0, 0.25)")}
+                        </div>
+                    }
+                } else {
+                    html! {}
+                }}
+
+                // Languages the user cast in (omitted when the API has no data)
+                {if !languages.is_empty() {
+                    html! {
+                        <div style={format!("{} width: 100%; max-width: 500px; margin: 0 auto; box-sizing: border-box;", REPORT_INFO_CARD_STYLE)}>
+                            <div style="
+                                font-size: 16px;
+                                font-weight: 600;
+                                color: white;
+                                margin-bottom: 16px;
+                            ">
+                                {if language_count == 1 {
+                                    "You cast in 1 language".to_string()
+                                } else {
+                                    format!("You cast in {} languages", language_count)
+                                }}
+                            </div>
+                            <div style="display: flex; flex-direction: column; gap: 10px;">
+                                {for languages.iter().map(|(name, percent)| html! {
+                                    <div style="display: flex; align-items: center; gap: 12px;">
+                                        <span style="
+                                            width: 90px;
+                                            flex-shrink: 0;
+                                            font-size: 14px;
+                                            color: rgba(255, 255, 255, 0.9);
+                                            white-space: nowrap;
+                                            overflow: hidden;
+                                            text-overflow: ellipsis;
+                                        ">{name.clone()}</span>
+                                        <div style="
+                                            flex: 1;
+                                            height: 10px;
+                                            background: rgba(255, 255, 255, 0.15);
+                                            border-radius: 5px;
+                                            overflow: hidden;
+                                        ">
+                                            <div style={format!("
+                                                width: {:.1}%;
+                                                height: 100%;
+                                                background: linear-gradient(90deg, #00CED1, #FFD700);
+                                                border-radius: 5px;
+                                            ", percent)}></div>
+                                        </div>
+                                        <span style="
+                                            width: 44px;
+                                            flex-shrink: 0;
+                                            text-align: right;
+                                            font-size: 14px;
+                                            font-weight: 600;
+                                            color: white;
+                                        ">{format!("{:.0}%", percent)}</span>
+                                    </div>
+                                })}
+                            </div>
+                        </div>
+                    }
+                } else {
+                    html! {}
+                }}
             </div>
         </div>
     }
 }
 
-// Helper function to copy text to clipboard (async version for modern Clipboard API)
-async fn copy_to_clipboard_async(text: &str) -> bool {
-    let window = web_sys::window().unwrap();
-
-    // Try modern Clipboard API first using js_sys::Reflect
-    if let Ok(navigator_val) = js_sys::Reflect::get(&window, &"navigator".into()) {
-        if !navigator_val.is_null() && !navigator_val.is_undefined() {
-            if let Ok(clipboard_val) = js_sys::Reflect::get(&navigator_val, &"clipboard".into()) {
-                if !clipboard_val.is_null() && !clipboard_val.is_undefined() {
-                    if let Ok(write_text_fn) =
-                        js_sys::Reflect::get(&clipboard_val, &"writeText".into())
-                    {
-                        if let Some(write_fn) = write_text_fn.dyn_ref::<js_sys::Function>() {
-                            if let Ok(promise_val) = write_fn.call1(&clipboard_val, &text.into()) {
-                                if let Ok(promise) = promise_val.dyn_into::<js_sys::Promise>() {
-                                    match JsFuture::from(promise).await {
-                                        Ok(_) => {
-                                            web_sys::console::log_1(
-                                                &"✅ Text copied using Clipboard API".into(),
-                                            );
-                                            return true;
-                                        }
-                                        Err(e) => {
-                                            web_sys::console::warn_1(
-                                                &format!("⚠️ Clipboard API failed: {:?}", e).into(),
-                                            );
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-        }
-    }
+// Maximum number of noun/verb chips shown per row in the Style section
+const MAX_WORD_CHIPS: usize = 10;
 
-    // Fallback: use document.execCommand
-    let document = window.document().unwrap();
-    let textarea = document.create_element("textarea").unwrap();
-    let textarea_js: &wasm_bindgen::JsValue = textarea.as_ref();
+// Highest-count words first, capped at `limit`
+fn top_words_by_count(words: &[TopWord], limit: usize) -> Vec<String> {
+    let mut sorted: Vec<&TopWord> = words
+        .iter()
+        .filter(|w| w.count > 0 && !w.word.trim().is_empty())
+        .collect();
+    sorted.sort_by_key(|w| std::cmp::Reverse(w.count));
+    sorted.into_iter().take(limit).map(|w| w.word.clone()).collect()
+}
 
-    if js_sys::Reflect::set(textarea_js, &"value".into(), &text.into()).is_err() {
-        return false;
+// Labeled row of word chips; renders nothing when there are no words
+fn render_word_chips(label: &str, words: &[String], chip_background: &str) -> Html {
+    if words.is_empty() {
+        return html! {};
+    }
+    html! {
+        <div style="display: flex; flex-direction: column; gap: 8px;">
+            <span style="font-size: 14px; color: rgba(255, 255, 255, 0.7);">{label.to_string()}</span>
+            <div style="display: flex; flex-wrap: wrap; gap: 6px;">
+                {for words.iter().map(|word| html! {
+                    <span style={format!("
+                        padding: 4px 12px;
+                        border-radius: 12px;
+                        background: {};
+                        font-size: 14px;
+                        font-weight: 600;
+                        color: white;
+                    ", chip_background)}>{word.clone()}</span>
+                })}
+            </div>
+        </div>
     }
+}
 
-    let style = match js_sys::Reflect::get(textarea_js, &"style".into()) {
-        Ok(s) => s,
-        Err(_) => return false,
+// Maximum number of languages shown in the Style section
+const MAX_LANGUAGES_SHOWN: usize = 5;
+
+// Map an ISO 639-1 language code to its native display name
+fn language_display_name(code: &str) -> String {
+    let name = match code.to_lowercase().as_str() {
+        "en" => "English",
+        "zh" => "中文",
+        "ja" => "日本語",
+        "ko" => "한국어",
+        "es" => "Español",
+        "fr" => "Français",
+        "de" => "Deutsch",
+        "pt" => "Português",
+        "it" => "Italiano",
+        "ru" => "Русский",
+        "tr" => "Türkçe",
+        "ar" => "العربية",
+        "hi" => "हिन्दी",
+        "id" => "Bahasa Indonesia",
+        "vi" => "Tiếng Việt",
+        "th" => "ไทย",
+        "nl" => "Nederlands",
+        "pl" => "Polski",
+        "uk" => "Українська",
+        "fa" => "فارسی",
+        _ => return code.to_uppercase(),
     };
+    name.to_string()
+}
 
-    js_sys::Reflect::set(&style, &"position".into(), &"fixed".into()).ok();
-    js_sys::Reflect::set(&style, &"left".into(), &"-9999px".into()).ok();
-
-    if document.body().unwrap().append_child(&textarea).is_err() {
-        return false;
+// Top languages by cast count as (display name, percent of all casts), highest first
+fn top_languages(
+    distribution: &std::collections::HashMap<String, usize>,
+    limit: usize,
+) -> Vec<(String, f32)> {
+    let total: usize = distribution.values().sum();
+    if total == 0 {
+        return Vec::new();
     }
 
-    js_sys::Reflect::get(textarea_js, &"select".into())
-        .and_then(|f| js_sys::Function::from(f).call0(textarea_js))
-        .ok();
-
-    let success = js_sys::Reflect::get(&document, &"execCommand".into())
-        .and_then(|f| {
-            js_sys::Function::from(f)
-                .call2(&document, &"copy".into(), &wasm_bindgen::JsValue::FALSE)
-                .map(|_| true)
+    let mut entries: Vec<_> = distribution.iter().filter(|(_, count)| **count > 0).collect();
+    // Sort by count, then code, so ties render in a stable order
+    entries.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+    entries
+        .into_iter()
+        .take(limit)
+        .map(|(code, count)| {
+            (
+                language_display_name(code),
+                *count as f32 / total as f32 * 100.0,
+            )
         })
+        .collect()
+}
+
+// Whether the browser can write images to the clipboard (ClipboardItem + navigator.clipboard.write)
+fn clipboard_image_supported() -> bool {
+    let Some(window) = web_sys::window() else {
+        return false;
+    };
+    let has_clipboard_item = js_sys::Reflect::get(&window, &"ClipboardItem".into())
+        .map(|ctor| ctor.is_function())
         .unwrap_or(false);
+    let has_write = js_sys::Reflect::get(&window, &"navigator".into())
+        .and_then(|navigator| js_sys::Reflect::get(&navigator, &"clipboard".into()))
+        .and_then(|clipboard| js_sys::Reflect::get(&clipboard, &"write".into()))
+        .map(|write| write.is_function())
+        .unwrap_or(false);
+    has_clipboard_item && has_write
+}
+
+// Fetch an image and read it as a Blob
+async fn fetch_image_blob(image_url: &str) -> Result<wasm_bindgen::JsValue, String> {
+    let window = web_sys::window().ok_or("No window available")?;
+
+    let response_val = JsFuture::from(window.fetch_with_str(image_url))
+        .await
+        .map_err(|e| format!("Fetch failed: {:?}", e))?;
+    let response: web_sys::Response = response_val
+        .dyn_into()
+        .map_err(|_| "Invalid fetch response".to_string())?;
+    if !response.ok() {
+        return Err(format!("Image request failed with status {}", response.status()));
+    }
+    let blob_promise = response
+        .blob()
+        .map_err(|e| format!("Failed to read image: {:?}", e))?;
+    JsFuture::from(blob_promise)
+        .await
+        .map_err(|e| format!("Failed to read image: {:?}", e))
+}
+
+// Start copying the PNG at `image_url` to the clipboard; the returned promise settles once
+// the write is done. Call it straight from the click handler: Safari/iOS only allow clipboard
+// writes during the user gesture, so the ClipboardItem gets a pending Promise<Blob> and the
+// write is issued before the image has been fetched
+fn write_image_to_clipboard(image_url: &str) -> Result<js_sys::Promise, String> {
+    let window = web_sys::window().ok_or("No window available")?;
+
+    let image_url = image_url.to_string();
+    let blob = wasm_bindgen_futures::future_to_promise(async move {
+        fetch_image_blob(&image_url)
+            .await
+            .map_err(|e| wasm_bindgen::JsValue::from_str(&e))
+    });
 
-    document.body().unwrap().remove_child(&textarea).ok();
-    success
+    // new ClipboardItem({ "image/png": blobPromise })
+    let item_data = js_sys::Object::new();
+    js_sys::Reflect::set(&item_data, &"image/png".into(), &blob)
+        .map_err(|e| format!("Failed to build clipboard item: {:?}", e))?;
+    let clipboard_item_ctor: js_sys::Function =
+        js_sys::Reflect::get(&window, &"ClipboardItem".into())
+            .and_then(|ctor| ctor.dyn_into())
+            .map_err(|_| "ClipboardItem not supported".to_string())?;
+    let clipboard_item = js_sys::Reflect::construct(
+        &clipboard_item_ctor,
+        &js_sys::Array::of1(&item_data),
+    )
+    .map_err(|e| format!("Failed to build clipboard item: {:?}", e))?;
+
+    // navigator.clipboard.write([item])
+    let clipboard = js_sys::Reflect::get(&window, &"navigator".into())
+        .and_then(|navigator| js_sys::Reflect::get(&navigator, &"clipboard".into()))
+        .map_err(|_| "Clipboard API not available".to_string())?;
+    let write_fn: js_sys::Function = js_sys::Reflect::get(&clipboard, &"write".into())
+        .and_then(|write| write.dyn_into())
+        .map_err(|_| "Clipboard write not supported".to_string())?;
+    write_fn
+        .call1(&clipboard, &js_sys::Array::of1(&clipboard_item))
+        .and_then(|p| p.dyn_into())
+        .map_err(|e| format!("Clipboard write failed: {:?}", e))
 }
 
 // Tarot card mapping: index 0-21 corresponds to 22 tarot cards
@@ -1431,7 +2230,37 @@ const TAROT_CARDS: &[(&str, &str, &str)] = &[
     ),
 ];
 
-// Helper function to calculate personality tag based on FID hash mod 22
+// Number of salts the reroll easter egg cycles through before returning to the real card
+const TAROT_REROLL_CYCLE: u64 = 8;
+
+// How long the tarot card must be held to trigger a reroll
+const TAROT_LONG_PRESS_MS: u32 = 600;
+
+// FNV-1a (64-bit). Used instead of DefaultHasher because its output is stable
+// across Rust releases, so the frontend and worker always agree on the card.
+// Must stay identical to the copy in worker/src/lib.rs
+fn fnv1a_64(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+// Tarot card index (0-21) for a FID: FNV-1a over the little-endian FID bytes
+// (followed by the salt bytes when salt is non-zero), mod 22
+fn tarot_card_index(fid: i64, salt: u64) -> usize {
+    let mut bytes = fid.to_le_bytes().to_vec();
+    if salt != 0 {
+        bytes.extend_from_slice(&salt.to_le_bytes());
+    }
+    (fnv1a_64(&bytes) % TAROT_CARDS.len() as u64) as usize
+}
+
+// Helper function to calculate personality tag based on FID hash mod 22
+// `salt` is 0 for the canonical card; any other value gives a "reroll" that is
+// only shown locally and never used for sharing (the worker always uses 0)
 pub(crate) fn calculate_personality_tag(
     _temporal: &crate::models::TemporalActivityResponse,
     _engagement: &crate::models::EngagementResponse,
@@ -1439,18 +2268,9 @@ pub(crate) fn calculate_personality_tag(
     _follower_growth: &crate::models::FollowerGrowthResponse,
     _casts_stats: &crate::models::CastsStatsResponse,
     fid: i64,
+    salt: u64,
 ) -> (String, String, String) {
-    use std::collections::hash_map::DefaultHasher;
-    use std::hash::Hash;
-    use std::hash::Hasher;
-
-    // Calculate hash of FID
-    let mut hasher = DefaultHasher::new();
-    fid.hash(&mut hasher);
-    let hash = hasher.finish();
-
-    // Get index by mod 22 (0-21)
-    let index = (hash % 22) as usize;
+    let index = tarot_card_index(fid, salt);
 
     // Get tarot card name, image path, and description
     let (name, filename, description) = TAROT_CARDS[index];
@@ -1459,29 +2279,120 @@ pub(crate) fn calculate_personality_tag(
     (name.to_string(), image_path, description.to_string())
 }
 
+/// Zodiac signs in share params index order (0-11)
+const ZODIAC_NAMES: [&str; 12] = [
+    "Capricorn",
+    "Aquarius",
+    "Pisces",
+    "Aries",
+    "Taurus",
+    "Gemini",
+    "Cancer",
+    "Leo",
+    "Virgo",
+    "Libra",
+    "Scorpio",
+    "Sagittarius",
+];
+
 // Helper function to get zodiac index (0-11) from zodiac name
 fn get_zodiac_index(zodiac_name: &str) -> u8 {
-    match zodiac_name {
-        "Capricorn" => 0,
-        "Aquarius" => 1,
-        "Pisces" => 2,
-        "Aries" => 3,
-        "Taurus" => 4,
-        "Gemini" => 5,
-        "Cancer" => 6,
-        "Leo" => 7,
-        "Virgo" => 8,
-        "Libra" => 9,
-        "Scorpio" => 10,
-        "Sagittarius" => 11,
-        _ => 0, // Default to Capricorn
+    ZODIAC_NAMES
+        .iter()
+        .position(|name| *name == zodiac_name)
+        .unwrap_or(0) as u8 // Default to Capricorn
+}
+
+// Saturate a stat to u32 so oversized counts show as u32::MAX instead of wrapping
+fn saturate_to_u32(value: usize) -> u32 {
+    value.min(u32::MAX as usize) as u32
+}
+
+// Clamp a stat for the share params, logging when it doesn't fit in u32
+fn clamp_stat_to_u32(name: &str, value: usize) -> u32 {
+    let clamped = saturate_to_u32(value);
+    if clamped as usize != value {
+        web_sys::console::warn_1(
+            &format!("⚠️ {} ({}) exceeds u32::MAX, clamping for share params", name, value).into(),
+        );
     }
+    clamped
+}
+
+/// Social archetype shown in the follower growth section and encoded into share params
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct SocialType {
+    /// Wire index in the share params; 0 and 1 keep their original silent/social meaning
+    pub index: u8,
+    pub label: &'static str,
+    pub image_path: &'static str,
+    pub narrative: &'static str,
+    /// Minimum casts in the year for this tier
+    pub min_casts: usize,
+}
+
+/// Casts in the year needed to reach each tier above Lurker
+const CASUAL_MIN_CASTS: usize = 50;
+const CHATTY_MIN_CASTS: usize = 200;
+const SOCIAL_BUTTERFLY_MIN_CASTS: usize = 500;
+
+/// Social tiers, lowest threshold first
+/// The worker's get_social_type_url_from_index must map the same indices to the same images
+pub(crate) const SOCIAL_TIERS: [SocialType; 4] = [
+    SocialType {
+        index: 0,
+        label: "Lurker",
+        image_path: "/imgs/social_type/slient.png",
+        narrative: "You mostly listened this year, and made every cast count.",
+        min_casts: 0,
+    },
+    SocialType {
+        index: 2,
+        label: "Casual",
+        image_path: "/imgs/social_type/casual.png",
+        narrative: "You dropped in whenever you had something to say.",
+        min_casts: CASUAL_MIN_CASTS,
+    },
+    SocialType {
+        index: 3,
+        label: "Chatty",
+        image_path: "/imgs/social_type/chatty.png",
+        narrative: "You always had something to add to the conversation.",
+        min_casts: CHATTY_MIN_CASTS,
+    },
+    SocialType {
+        index: 1,
+        label: "Social Butterfly",
+        image_path: "/imgs/social_type/social.png",
+        narrative: "You were everywhere this year, keeping the timeline buzzing.",
+        min_casts: SOCIAL_BUTTERFLY_MIN_CASTS,
+    },
+];
+
+/// Pick the highest tier whose threshold `total_casts` reaches
+pub(crate) fn classify_social(total_casts: usize) -> SocialType {
+    SOCIAL_TIERS
+        .iter()
+        .rev()
+        .find(|tier| total_casts >= tier.min_casts)
+        .copied()
+        .unwrap_or(SOCIAL_TIERS[0])
 }
 
+/// Share params flag: render the card without the stats lines (counts are encoded as zero)
+/// Must match SHARE_FLAG_HIDE_STATS in worker/src/lib.rs
+pub(crate) const SHARE_FLAG_HIDE_STATS: u8 = 0x01;
+
+/// Share params flag: leave the FID off the card and the shared report
+/// Must match SHARE_FLAG_HIDE_FID in worker/src/lib.rs
+pub(crate) const SHARE_FLAG_HIDE_FID: u8 = 0x02;
+
 // Helper function to encode user stats as compact binary format for sharing
-// Format: [0-7]: FID (i64, little-endian), [8]: Zodiac (u8, 0-11), [9]: Social type (u8, 0=silent, 1=social),
+// Format: [0-7]: FID (i64, little-endian), [8]: Zodiac (u8, 0-11), [9]: Social tier (u8, SocialType::index),
 //         [10-13]: Total casts (u32), [14-17]: Total reactions (u32), [18-21]: Total followers (u32)
-// Total: 22 bytes -> ~30 chars in base64url
+//         [22]: Optional flags byte (SHARE_FLAG_*), written when non-zero or when a year follows
+//         [23-24]: Optional report year (u16, little-endian), read by the worker for the banner title
+// Total: 22 bytes -> ~30 chars in base64url (23 bytes with flags, 25 bytes with the year)
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn encode_image_params_for_share(
     fid: i64,
@@ -1492,12 +2403,19 @@ pub(crate) fn encode_image_params_for_share(
     total_casts: usize,
     total_reactions: usize,
     total_followers: usize,
+    hide_stats: bool,
+    hide_fid: bool,
+    report_year: Option<i32>,
 ) -> String {
     use base64::engine::general_purpose::STANDARD_NO_PAD;
     use base64::Engine;
 
-    // Extract zodiac name from URL (e.g., "/imgs/zodiac/capricorn.png" -> "capricorn")
+    // Extract zodiac name from URL (e.g., "/imgs/zodiac/capricorn.png?v=..." -> "capricorn")
+    // The cache-busting query string from get_image_url must be dropped first
     let zodiac_name = zodiac_url
+        .split('?')
+        .next()
+        .unwrap_or(zodiac_url)
         .split('/')
         .next_back()
         .and_then(|s| s.strip_suffix(".png"))
@@ -1516,15 +2434,29 @@ pub(crate) fn encode_image_params_for_share(
 
     let zodiac_index = get_zodiac_index(&zodiac_capitalized);
 
-    // Extract social type from URL (0 = silent, 1 = social)
-    let social_type_index = if social_type_url.contains("social.png") {
-        1u8
+    // Extract social tier from the image file name, defaulting to the lowest tier
+    let social_file = social_type_url
+        .split('?')
+        .next()
+        .unwrap_or(social_type_url)
+        .rsplit('/')
+        .next()
+        .unwrap_or_default();
+    let social_type_index = SOCIAL_TIERS
+        .iter()
+        .find(|tier| tier.image_path.rsplit('/').next() == Some(social_file))
+        .map(|tier| tier.index)
+        .unwrap_or(0);
+
+    // Private shares never embed the real counts
+    let (total_casts, total_reactions, total_followers) = if hide_stats {
+        (0, 0, 0)
     } else {
-        0u8 // silent
+        (total_casts, total_reactions, total_followers)
     };
 
     // Pack into binary format
-    let mut bytes = Vec::with_capacity(22);
+    let mut bytes = Vec::with_capacity(25);
 
     // FID as i64 (8 bytes, little-endian)
     bytes.extend_from_slice(&fid.to_le_bytes());
@@ -1536,13 +2468,26 @@ pub(crate) fn encode_image_params_for_share(
     bytes.push(social_type_index);
 
     // Total casts as u32 (4 bytes, little-endian)
-    bytes.extend_from_slice(&(total_casts as u32).to_le_bytes());
+    bytes.extend_from_slice(&clamp_stat_to_u32("total_casts", total_casts).to_le_bytes());
 
     // Total reactions as u32 (4 bytes, little-endian)
-    bytes.extend_from_slice(&(total_reactions as u32).to_le_bytes());
+    bytes.extend_from_slice(&clamp_stat_to_u32("total_reactions", total_reactions).to_le_bytes());
 
     // Total followers as u32 (4 bytes, little-endian)
-    bytes.extend_from_slice(&(total_followers as u32).to_le_bytes());
+    bytes.extend_from_slice(&clamp_stat_to_u32("total_followers", total_followers).to_le_bytes());
+
+    // Flags (1 byte), omitted when zero and there is no year so links without one stay unchanged
+    let flags = if hide_stats { SHARE_FLAG_HIDE_STATS } else { 0 }
+        | if hide_fid { SHARE_FLAG_HIDE_FID } else { 0 };
+    let report_year = report_year.and_then(|year| u16::try_from(year).ok());
+    if flags != 0 || report_year.is_some() {
+        bytes.push(flags);
+    }
+
+    // Report year as u16 (2 bytes, little-endian), always after the flags byte
+    if let Some(year) = report_year {
+        bytes.extend_from_slice(&year.to_le_bytes());
+    }
 
     // Encode to base64url (URL-safe, no padding)
     STANDARD_NO_PAD
@@ -1551,92 +2496,205 @@ pub(crate) fn encode_image_params_for_share(
         .replace('/', "_")
 }
 
+/// Share params decoded back into their fields, for the `?debug=1` share params panel
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct DecodedShareParams {
+    pub fid: i64,
+    pub zodiac_index: u8,
+    pub social_type_index: u8,
+    pub total_casts: u32,
+    pub total_reactions: u32,
+    pub total_followers: u32,
+    pub flags: u8,
+    pub year: Option<u16>,
+}
+
+impl DecodedShareParams {
+    /// Human-readable `(field, value)` rows
+    pub fn describe(&self) -> Vec<(&'static str, String)> {
+        let zodiac = ZODIAC_NAMES
+            .get(self.zodiac_index as usize)
+            .copied()
+            .unwrap_or("unknown");
+        let social = SOCIAL_TIERS
+            .iter()
+            .find(|tier| tier.index == self.social_type_index)
+            .map(|tier| tier.label)
+            .unwrap_or("unknown");
+        vec![
+            ("fid", self.fid.to_string()),
+            ("zodiac", format!("{} ({})", zodiac, self.zodiac_index)),
+            ("social", format!("{} ({})", social, self.social_type_index)),
+            ("casts", self.total_casts.to_string()),
+            ("reactions", self.total_reactions.to_string()),
+            ("followers", self.total_followers.to_string()),
+            (
+                "hide stats",
+                (self.flags & SHARE_FLAG_HIDE_STATS != 0).to_string(),
+            ),
+            ("hide fid", self.hides_fid().to_string()),
+            (
+                "year",
+                self.year
+                    .map(|year| year.to_string())
+                    .unwrap_or_else(|| "default".to_string()),
+            ),
+        ]
+    }
+
+    /// Whether the sharer asked for their FID to be left off
+    pub fn hides_fid(&self) -> bool {
+        self.flags & SHARE_FLAG_HIDE_FID != 0
+    }
+}
+
+/// Decode params produced by `encode_image_params_for_share` (the worker's decode_image_params
+/// is the authoritative reader; this mirrors it for debugging)
+pub(crate) fn decode_image_params_for_share(params: &str) -> Result<DecodedShareParams, String> {
+    use base64::engine::general_purpose::STANDARD_NO_PAD;
+    use base64::Engine;
+
+    let bytes = STANDARD_NO_PAD
+        .decode(params.trim().replace('-', "+").replace('_', "/"))
+        .map_err(|e| format!("Invalid base64url params: {}", e))?;
+    if bytes.len() < 22 {
+        return Err(format!(
+            "Invalid params length: {} bytes (expected 22)",
+            bytes.len()
+        ));
+    }
+    let u32_at =
+        |i: usize| u32::from_le_bytes([bytes[i], bytes[i + 1], bytes[i + 2], bytes[i + 3]]);
+    let mut fid_bytes = [0u8; 8];
+    fid_bytes.copy_from_slice(&bytes[0..8]);
+    Ok(DecodedShareParams {
+        fid: i64::from_le_bytes(fid_bytes),
+        zodiac_index: bytes[8],
+        social_type_index: bytes[9],
+        total_casts: u32_at(10),
+        total_reactions: u32_at(14),
+        total_followers: u32_at(18),
+        flags: bytes.get(22).copied().unwrap_or(0),
+        year: bytes
+            .get(23..25)
+            .map(|year| u16::from_le_bytes([year[0], year[1]])),
+    })
+}
+
+/// Whether a share URL's params ask for the FID to be hidden; false without valid params
+pub(crate) fn share_url_hides_fid(url: &str) -> bool {
+    share_params_from_url(url)
+        .and_then(|params| decode_image_params_for_share(params).ok())
+        .is_some_and(|params| params.hides_fid())
+}
+
+/// The `params` value of a share URL, if it has one
+pub(crate) fn share_params_from_url(url: &str) -> Option<&str> {
+    let query = url.split_once('?')?.1;
+    let query = query.split('#').next().unwrap_or(query);
+    query
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("params="))
+        .filter(|params| !params.is_empty())
+}
+
 // Build version for cache busting (generated at compile time by build.rs)
 // The version file is generated in OUT_DIR and included at compile time
 const BUILD_VERSION: &str = include_str!(concat!(env!("OUT_DIR"), "/build_version.txt"));
 
-// Helper function to convert relative image path to absolute URL with cache busting
-pub fn get_image_url(image_path: &str) -> String {
+// Origin used for image URLs when the page has none (crawlers, sandboxed iframes)
+// Set POLYJUICE_BASE_URL at build time to match the worker's BASE_URL
+const DEFAULT_IMAGE_ORIGIN: &str = "https://miniapp.polyjuice.io";
+
+fn fallback_image_origin() -> &'static str {
+    option_env!("POLYJUICE_BASE_URL")
+        .map(|url| url.trim_end_matches('/'))
+        .filter(|url| !url.is_empty())
+        .unwrap_or(DEFAULT_IMAGE_ORIGIN)
+}
+
+// Resolve `image_path` against `origin` (the runtime origin, if usable) or the build-time base URL
+fn absolute_image_url(image_path: &str, origin: Option<&str>) -> String {
     if image_path.starts_with("http://") || image_path.starts_with("https://") {
-        // For external URLs, add version parameter if not already present
-        if image_path.contains('?') {
-            return format!("{}&v={}", image_path, BUILD_VERSION);
-        } else {
-            return format!("{}?v={}", image_path, BUILD_VERSION);
-        }
+        return image_path.to_string();
     }
+    // Opaque origins (file://, sandboxed iframes) serialize as "null"
+    let origin = origin
+        .filter(|o| !o.is_empty() && *o != "null")
+        .unwrap_or(fallback_image_origin());
+    format!("{}{}", origin, image_path)
+}
 
-    // Get current origin
-    if let Some(window) = web_sys::window() {
-        if let Ok(origin) = window.location().origin() {
-            let full_url = format!("{}{}", origin, image_path);
-            // Add version parameter for cache busting
-            if full_url.contains('?') {
-                return format!("{}&v={}", full_url, BUILD_VERSION);
-            } else {
-                return format!("{}?v={}", full_url, BUILD_VERSION);
-            }
-        }
-    }
+// Helper function to convert relative image path to absolute URL with cache busting
+pub fn get_image_url(image_path: &str) -> String {
+    // Runtime origin first, then the build-time base URL so shared URLs are always absolute
+    let origin = web_sys::window().and_then(|window| window.location().origin().ok());
+    let full_url = absolute_image_url(image_path, origin.as_deref());
 
-    // Fallback to relative path with version parameter
-    if image_path.contains('?') {
-        format!("{}&v={}", image_path, BUILD_VERSION)
+    // Add version parameter for cache busting
+    if full_url.contains('?') {
+        format!("{}&v={}", full_url, BUILD_VERSION)
     } else {
-        format!("{}?v={}", image_path, BUILD_VERSION)
+        format!("{}?v={}", full_url, BUILD_VERSION)
     }
 }
 
 // Fetch image data from URL and return as Vec<u8>
 
-// Helper function to build share text
+// Helper function to build share text from the locale's template (see share_text.rs)
 fn build_share_text(
     _profile: &Option<ProfileWithRegistration>,
     report: &Option<AnnualReportResponse>,
     tarot_card_name: Option<&str>,
     share_url: Option<&str>,
+    hide_stats: bool,
 ) -> String {
-    let mut text = String::from("My Annual Report: This year I ");
+    let template = share_template();
+    let values = share_text_values(report.as_ref(), tarot_card_name, share_url, hide_stats);
+    // A private share only differs when there is a report to hide the counts of
+    let text = if hide_stats && report.is_some() {
+        &template.private
+    } else {
+        &template.public
+    };
+    render_share_template(text, &template.separator, &values)
+}
 
+// Values for the share template; counts are left out of private shares
+fn share_text_values(
+    report: Option<&AnnualReportResponse>,
+    tarot_card_name: Option<&str>,
+    share_url: Option<&str>,
+    hide_stats: bool,
+) -> ShareTextValues {
+    let mut values = ShareTextValues {
+        tarot: tarot_card_name.map(str::to_string),
+        url: share_url.map(str::to_string),
+        ..ShareTextValues::default()
+    };
     if let Some(r) = report {
-        // Use total_casts_in_year if available, otherwise fallback to total_casts
-        // This matches what's displayed in the report
-        let total_casts = r
-            .temporal_activity
-            .total_casts_in_year
-            .unwrap_or(r.temporal_activity.total_casts);
-        text.push_str(&format!("Published {} Casts this year, ", total_casts));
-        text.push_str(&format!(
-            "Received {} likes, ",
-            r.engagement.reactions_received
-        ));
-        text.push_str(&format!(
-            "Received {} recasts, ",
-            r.engagement.recasts_received
-        ));
-
-        if let Some(most_active) = &r.temporal_activity.most_active_month {
-            text.push_str(&format!("Most active month: {}, ", most_active));
-        }
-
-        if !r.content_style.top_emojis.is_empty() {
-            let top_emoji = &r.content_style.top_emojis[0];
-            text.push_str(&format!("Most used emoji: {}", top_emoji.emoji));
+        values.year = Some(r.year.to_string());
+        // "2024-03" reads as "March"; keys we can't parse are shown as sent
+        values.month = r.temporal_activity.most_active_month.as_ref().map(|key| {
+            parse_month_key(key)
+                .and_then(|(_, month)| month_name(month))
+                .map(str::to_string)
+                .unwrap_or_else(|| key.to_string())
+        });
+        values.emoji = r.content_style.top_emojis.first().map(|e| e.emoji.clone());
+        if !hide_stats {
+            // Use total_casts_in_year if available, otherwise fallback to total_casts
+            // This matches what's displayed in the report
+            let total_casts = r
+                .temporal_activity
+                .total_casts_in_year
+                .unwrap_or(r.temporal_activity.total_casts);
+            values.casts = Some(format_count(total_casts));
+            values.likes = Some(format_count(r.engagement.reactions_received));
+            values.recasts = Some(format_count(r.engagement.recasts_received));
         }
     }
-
-    text.push_str("\n\n");
-
-    if let Some(tarot_name) = tarot_card_name {
-        text.push_str(&format!("My Annual Tarot Card is {}\n\n", tarot_name));
-    }
-
-    if let Some(url) = share_url {
-        text.push_str(&format!("url: {}\n\n", url));
-    }
-
-    text.push_str("#MyFarcaster2025 #polyjuice");
-    text
+    values
 }
 
 // Personality Tag Section Component - Classifies user into one tag
@@ -1667,7 +2725,11 @@ struct PersonalityTag {
 pub fn PersonalityTagSection(props: &PersonalityTagSectionProps) -> Html {
     let share_text = use_state(String::new);
     let is_sharing = use_state(|| false);
-    let share_status = use_state(|| None::<String>);
+    let toaster = use_toaster();
+    // Privacy toggle: leave the counts out of the share image, link and caption
+    let hide_stats = use_state(|| false);
+    // Privacy toggle: leave the FID off the share image and the shared report
+    let hide_fid = use_state(|| false);
     let is_farcaster_env = props.is_farcaster_env;
     let base_share_url = props.share_url.clone();
     let is_own_report = props.is_own_report;
@@ -1676,6 +2738,14 @@ pub fn PersonalityTagSection(props: &PersonalityTagSectionProps) -> Html {
     // State for share URL with encoded params
     let share_url_with_params = use_state(|| base_share_url.clone());
 
+    // ?debug=1 shows the encoded share params and a button to copy them
+    let debug_mode = use_state(crate::services::is_debug_mode);
+
+    // Preview of the worker-generated share image (/api/generate)
+    let share_preview_url = use_state(|| None::<String>);
+    let share_preview_loaded = use_state(|| false);
+    let share_preview_failed = use_state(|| false);
+
     // Calculate personality tag and get image URL
     let (tarot_card_name, personality_tag_image_url) = if let Some(report) = &props.annual_report {
         let temp_casts_stats = crate::models::CastsStatsResponse {
@@ -1701,6 +2771,7 @@ pub fn PersonalityTagSection(props: &PersonalityTagSectionProps) -> Html {
             &report.follower_growth,
             &temp_casts_stats,
             fid,
+            0,
         );
         (Some(tag_name), Some(get_image_url(&image_path)))
     } else {
@@ -1713,8 +2784,12 @@ pub fn PersonalityTagSection(props: &PersonalityTagSectionProps) -> Html {
         let temporal = props.temporal.clone();
         let engagement = props.engagement.clone();
         let follower_growth = props.follower_growth.clone();
+        let report_year = props.annual_report.as_ref().map(|report| report.year);
         let share_url_with_params_for_effect = share_url_with_params.clone();
         let base_share_url_for_effect = base_share_url.clone();
+        let share_preview_url = share_preview_url.clone();
+        let share_preview_loaded = share_preview_loaded.clone();
+        let share_preview_failed = share_preview_failed.clone();
 
         use_effect_with(
             (
@@ -1722,29 +2797,23 @@ pub fn PersonalityTagSection(props: &PersonalityTagSectionProps) -> Html {
                 temporal.clone(),
                 engagement.clone(),
                 follower_growth.clone(),
+                *hide_stats,
+                *hide_fid,
+                is_own_report,
+                report_year,
             ),
-            move |_| {
+            move |(_, _, _, _, hide_stats, hide_fid, is_own_report, report_year)| {
+                // Share params are only ever built for the owner's own report
+                if !*is_own_report {
+                    share_preview_url.set(None);
+                    share_url_with_params_for_effect.set(None);
+                    return;
+                }
+
                 // Get zodiac image URL
                 // Normalize timestamp (may be Farcaster or Unix format) and validate range
-                let zodiac_url = profile
-                    .as_ref()
-                    .and_then(|p| p.registered_at)
-                    .and_then(|timestamp| {
-                        // Normalize timestamp: convert from Farcaster to Unix if needed, and validate range
-                        normalize_registration_timestamp(timestamp)
-                    })
-                    .map(|unix_timestamp| {
-                        // Convert Unix timestamp (in seconds) to JavaScript Date (expects milliseconds)
-                        let date = js_sys::Date::new(&wasm_bindgen::JsValue::from_f64(
-                            unix_timestamp as f64 * 1000.0,
-                        ));
-                        let month = date.get_month() + 1;
-                        let day = date.get_date();
-                        let zodiac = get_zodiac_sign(month, day);
-                        let zodiac_lower = zodiac.to_lowercase();
-                        get_image_url(&format!("/imgs/zodiac/{}.png", zodiac_lower))
-                    })
-                    .unwrap_or_else(|| get_image_url("/imgs/zodiac/capricorn.png"));
+                let zodiac_url =
+                    registration_zodiac_image_url(profile.as_ref().and_then(|p| p.registered_at));
 
                 // Get user info and stats - use the same fields as displayed in the report
                 let fid = profile.as_ref().map(|p| p.fid).unwrap_or(0);
@@ -1760,11 +2829,7 @@ pub fn PersonalityTagSection(props: &PersonalityTagSectionProps) -> Html {
                 let total_followers = follower_growth.current_followers;
 
                 // Get social type image URL based on total casts (same logic as FollowerGrowthSection)
-                let social_type_url = if total_casts >= 200 {
-                    get_image_url("/imgs/social_type/social.png")
-                } else {
-                    get_image_url("/imgs/social_type/slient.png")
-                };
+                let social_type_url = get_image_url(classify_social(total_casts).image_path);
 
                 // Encode params (only fid, zodiac index, social_type index, and stats)
                 // Username and avatar will be fetched by worker from API
@@ -1777,8 +2842,21 @@ pub fn PersonalityTagSection(props: &PersonalityTagSectionProps) -> Html {
                     total_casts,
                     total_reactions,
                     total_followers,
+                    *hide_stats,
+                    *hide_fid,
+                    *report_year,
                 );
 
+                // Point the preview at the same image the worker will embed
+                if let Some(origin) = web_sys::window().and_then(|w| w.location().origin().ok()) {
+                    share_preview_loaded.set(false);
+                    share_preview_failed.set(false);
+                    share_preview_url.set(Some(format!(
+                        "{}/api/generate?params={}",
+                        origin, params_base64
+                    )));
+                }
+
                 // Append params to share URL
                 if let Some(base_url) = base_share_url_for_effect {
                     let url_with_params = format!("{}?params={}", base_url, params_base64);
@@ -1799,38 +2877,40 @@ pub fn PersonalityTagSection(props: &PersonalityTagSectionProps) -> Html {
         &props.annual_report,
         tarot_card_name.as_deref(),
         share_url_for_text,
+        *hide_stats,
     );
 
     // Handler for Farcaster share (composeCast)
     let on_farcaster_share = {
         let is_sharing = is_sharing.clone();
-        let share_status = share_status.clone();
+        let toaster = toaster.clone();
         let text_for_share = share_text_content.clone();
         let url_for_share = share_url_with_params.clone();
+        let image_for_share = share_preview_url.clone();
 
         Callback::from(move |_| {
+            if !is_own_report {
+                return;
+            }
             is_sharing.set(true);
-            share_status.set(None);
 
             let text_clone = text_for_share.clone();
-            let share_status_clone = share_status.clone();
+            let toaster = toaster.clone();
             let is_sharing_clone = is_sharing.clone();
 
-            // Build embeds: include only share URL
-            let embeds_option = if let Some(url_str) = url_for_share.as_ref() {
-                Some(vec![url_str.clone()])
-            } else {
-                None
-            };
+            // Build embeds: the share URL, plus the card image on clients that take image embeds
+            let embeds_option =
+                farcaster::share_embeds((*url_for_share).clone(), (*image_for_share).clone());
 
             spawn_local(async move {
-                match farcaster::compose_cast(&text_clone, embeds_option).await {
+                // No channel: the user picks one in the composer
+                match farcaster::compose_cast(&text_clone, embeds_option, None).await {
                     Ok(_) => {
-                        share_status_clone.set(Some("Share dialog opened!".to_string()));
+                        toaster.success("Share dialog opened!");
                         web_sys::console::log_1(&"✅ Compose cast opened successfully".into());
                     }
                     Err(e) => {
-                        share_status_clone.set(Some(format!("Failed to open share: {}", e)));
+                        toaster.error(format!("Failed to open share: {}", e));
                         web_sys::console::error_1(
                             &format!("❌ Failed to compose cast: {}", e).into(),
                         );
@@ -1841,13 +2921,46 @@ pub fn PersonalityTagSection(props: &PersonalityTagSectionProps) -> Html {
         })
     };
 
+    // Handler for sharing to Farcaster from a regular browser: Warpcast's compose page
+    let on_warpcast_share = {
+        let text = share_text_content.clone();
+        let url_for_share = share_url_with_params.clone();
+        let image_for_share = share_preview_url.clone();
+        Callback::from(move |_| {
+            if !is_own_report {
+                return;
+            }
+            let embeds: Vec<String> = (*url_for_share)
+                .clone()
+                .into_iter()
+                .chain((*image_for_share).clone())
+                .collect();
+            let compose_url = farcaster::warpcast_compose_url(&text, &embeds, |value| {
+                js_sys::encode_uri_component(value).into()
+            });
+
+            if let Some(window) = web_sys::window() {
+                if let Ok(Some(_)) = window.open_with_url_and_target(&compose_url, "_blank") {
+                    web_sys::console::log_1(&"✅ Warpcast compose opened".into());
+                } else {
+                    web_sys::console::error_1(&"⚠️ Failed to open Warpcast compose window".into());
+                }
+            }
+        })
+    };
+
     // Handler for Twitter share
+    let twitter_domain = use_twitter_share_domain();
     let on_twitter_share = {
         let text = share_text_content.clone();
+        let twitter_domain = *twitter_domain;
         // Text already includes URL, so we can use it directly
         Callback::from(move |_| {
+            if !is_own_report {
+                return;
+            }
             let encoded_text = js_sys::encode_uri_component(&text);
-            let twitter_url = format!("https://twitter.com/intent/tweet?text={}", encoded_text);
+            let twitter_url = twitter_domain.intent_url(&format!("text={}", encoded_text));
 
             if let Some(window) = web_sys::window() {
                 if let Ok(Some(_)) = window.open_with_url_and_target(&twitter_url, "_blank") {
@@ -1863,11 +2976,14 @@ pub fn PersonalityTagSection(props: &PersonalityTagSectionProps) -> Html {
     let on_copy = {
         let text = share_text_content.clone();
         let share_text = share_text.clone();
-        let share_status = share_status.clone();
+        let toaster = toaster.clone();
         let is_sharing = is_sharing.clone();
         let image_url = personality_tag_image_url.clone();
 
         Callback::from(move |_| {
+            if !is_own_report {
+                return;
+            }
             let mut text_with_image = text.clone();
 
             if let Some(img_url) = &image_url {
@@ -1875,19 +2991,18 @@ pub fn PersonalityTagSection(props: &PersonalityTagSectionProps) -> Html {
             }
 
             share_text.set(text_with_image.clone());
-            share_status.set(None);
             is_sharing.set(true);
 
             let text_clone = text_with_image.clone();
-            let share_status_clone = share_status.clone();
+            let toaster = toaster.clone();
             let is_sharing_clone = is_sharing.clone();
 
             spawn_local(async move {
                 if copy_to_clipboard_async(&text_clone).await {
-                    share_status_clone.set(Some("Copied to clipboard!".to_string()));
+                    toaster.success("Copied to clipboard!");
                     web_sys::console::log_1(&"✅ Text copied to clipboard".into());
                 } else {
-                    share_status_clone.set(Some("Failed to copy to clipboard".to_string()));
+                    toaster.error("Failed to copy to clipboard");
                     web_sys::console::warn_1(&"⚠️ Failed to copy to clipboard".into());
                 }
                 is_sharing_clone.set(false);
@@ -1895,6 +3010,93 @@ pub fn PersonalityTagSection(props: &PersonalityTagSectionProps) -> Html {
         })
     };
 
+    // Handler for copying the generated share image; falls back to copying text
+    // when the browser can't put images on the clipboard
+    let on_copy_image = {
+        let on_copy = on_copy.clone();
+        let toaster = toaster.clone();
+        let is_sharing = is_sharing.clone();
+        let image_url = (*share_preview_url).clone();
+
+        Callback::from(move |e: MouseEvent| {
+            let Some(image_url) = image_url.clone() else {
+                return;
+            };
+            if !clipboard_image_supported() {
+                web_sys::console::log_1(&"ℹ️ ClipboardItem not available, copying share text instead".into());
+                on_copy.emit(e);
+                return;
+            }
+
+            // Issued synchronously so the write still counts as part of the click
+            let write = match write_image_to_clipboard(&image_url) {
+                Ok(write) => write,
+                Err(e) => {
+                    toaster.error(format!("Failed to copy image: {}", e));
+                    web_sys::console::warn_1(&format!("⚠️ Failed to copy image: {}", e).into());
+                    return;
+                }
+            };
+
+            is_sharing.set(true);
+
+            let toaster = toaster.clone();
+            let is_sharing_clone = is_sharing.clone();
+
+            spawn_local(async move {
+                let written = JsFuture::from(write)
+                    .await
+                    .map(|_| ())
+                    .map_err(|e| e.as_string().unwrap_or_else(|| format!("{:?}", e)));
+                match written {
+                    Ok(()) => {
+                        toaster.success("Image copied to clipboard!");
+                        web_sys::console::log_1(&"✅ Image copied to clipboard".into());
+                    }
+                    Err(e) => {
+                        toaster.error(format!("Failed to copy image: {}", e));
+                        web_sys::console::warn_1(&format!("⚠️ Failed to copy image: {}", e).into());
+                    }
+                }
+                is_sharing_clone.set(false);
+            });
+        })
+    };
+
+    // ?debug=1: the exact params string in the share link, and what it decodes to
+    // Falls back to the page URL so received share links can be inspected too
+    let debug_share_params = if *debug_mode {
+        share_url_with_params
+            .as_deref()
+            .and_then(share_params_from_url)
+            .map(str::to_string)
+            .or_else(|| {
+                web_sys::window()
+                    .and_then(|w| w.location().href().ok())
+                    .and_then(|href| share_params_from_url(&href).map(str::to_string))
+            })
+    } else {
+        None
+    };
+    let on_copy_share_params = {
+        let toaster = toaster.clone();
+        let params = debug_share_params.clone();
+        Callback::from(move |_: MouseEvent| {
+            let Some(params) = params.clone() else {
+                return;
+            };
+            let toaster = toaster.clone();
+            spawn_local(async move {
+                if copy_to_clipboard_async(&params).await {
+                    toaster.success("Share params copied to clipboard");
+                } else {
+                    toaster.error("Couldn't copy share params (logged to console instead)");
+                    web_sys::console::log_1(&format!("🐞 Share params: {}", params).into());
+                }
+            });
+        })
+    };
+
     // Calculate tarot card based on FID hash mod 22
     let fid = props
         .profile
@@ -1902,6 +3104,11 @@ pub fn PersonalityTagSection(props: &PersonalityTagSectionProps) -> Html {
         .map(|p| p.fid)
         .unwrap_or_else(|| props.annual_report.as_ref().map(|r| r.fid).unwrap_or(0));
 
+    // Easter egg: long-pressing the revealed card rerolls it locally with a
+    // different salt. Sharing always uses the canonical card (salt 0).
+    let reroll_salt = use_state(|| 0u64);
+    let long_press = use_mut_ref(|| None::<(gloo_timers::callback::Timeout, i32, i32)>);
+
     let (name, image_path, description) = calculate_personality_tag(
         &props.temporal,
         &props.engagement,
@@ -1909,6 +3116,7 @@ pub fn PersonalityTagSection(props: &PersonalityTagSectionProps) -> Html {
         &props.follower_growth,
         &props.casts_stats,
         fid,
+        *reroll_salt,
     );
 
     let matched_tag = PersonalityTag {
@@ -2119,6 +3327,77 @@ pub fn PersonalityTagSection(props: &PersonalityTagSectionProps) -> Html {
         })
     };
 
+    // Long-press handlers for the reroll easter egg (only once the card is revealed)
+    let on_card_pointer_down = {
+        let is_flipped = is_flipped.clone();
+        let reroll_salt = reroll_salt.clone();
+        let long_press = long_press.clone();
+        Callback::from(move |e: PointerEvent| {
+            if !*is_flipped {
+                return;
+            }
+            let next_salt = (*reroll_salt + 1) % TAROT_REROLL_CYCLE;
+            let reroll_salt = reroll_salt.clone();
+            let timeout = gloo_timers::callback::Timeout::new(TAROT_LONG_PRESS_MS, move || {
+                web_sys::console::log_1(&format!("🔮 Rerolling tarot card (salt {})", next_salt).into());
+                reroll_salt.set(next_salt);
+            });
+            *long_press.borrow_mut() = Some((timeout, e.client_x(), e.client_y()));
+        })
+    };
+
+    let on_card_pointer_move = {
+        let long_press = long_press.clone();
+        Callback::from(move |e: PointerEvent| {
+            // Dragging to rotate the card should not count as a long press
+            let moved = long_press
+                .borrow()
+                .as_ref()
+                .map(|(_, x, y)| (e.client_x() - x).abs() > 10 || (e.client_y() - y).abs() > 10)
+                .unwrap_or(false);
+            if moved {
+                long_press.borrow_mut().take();
+            }
+        })
+    };
+
+    let on_card_pointer_end = {
+        let long_press = long_press.clone();
+        Callback::from(move |_: PointerEvent| {
+            long_press.borrow_mut().take();
+        })
+    };
+
+    let on_show_real_card = {
+        let reroll_salt = reroll_salt.clone();
+        Callback::from(move |_| reroll_salt.set(0))
+    };
+
+    // Use original tarot card URL directly, unless rerolled
+    let tarot_image_src = if *reroll_salt != 0 {
+        get_image_url(&matched_tag.image_path)
+    } else {
+        personality_tag_image_url
+            .clone()
+            .unwrap_or_else(|| "".to_string())
+    };
+    let tarot_alt = image_label("Tarot", &matched_tag.name);
+
+    // Full-screen view of the card art; a separate button so it never competes with the
+    // flip click or the long-press reroll on the card itself
+    let show_tarot_lightbox = use_state(|| false);
+    let on_open_lightbox = {
+        let show_tarot_lightbox = show_tarot_lightbox.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.stop_propagation();
+            show_tarot_lightbox.set(true);
+        })
+    };
+    let on_close_lightbox = {
+        let show_tarot_lightbox = show_tarot_lightbox.clone();
+        Callback::from(move |_: ()| show_tarot_lightbox.set(false))
+    };
+
     html! {
         <div class="report-card-content" style="
             width: 100%;
@@ -2141,11 +3420,13 @@ pub fn PersonalityTagSection(props: &PersonalityTagSectionProps) -> Html {
                     margin-bottom: 12px;
                     min-height: 40px;
                     display: flex;
+                    flex-direction: column;
                     align-items: center;
                     justify-content: center;
                 ">
                     {if *is_flipped {
                         html! {
+                            <>
                             <p style="
                                 font-size: 14px;
                                 font-weight: 500;
@@ -2158,6 +3439,27 @@ pub fn PersonalityTagSection(props: &PersonalityTagSectionProps) -> Html {
                             ">
                                 {format!("{}: {}", matched_tag.name.clone(), matched_tag.description.clone())}
                             </p>
+                            {if *reroll_salt != 0 {
+                                html! {
+                                    <p style="
+                                        font-size: 12px;
+                                        color: rgba(255, 255, 255, 0.7);
+                                        margin: 4px 0 0 0;
+                                        padding: 0 20px;
+                                    ">
+                                        {"✨ Just for fun, not your real card · "}
+                                        <span
+                                            onclick={on_show_real_card.clone()}
+                                            style="text-decoration: underline; cursor: pointer;"
+                                        >
+                                            {"Show my real card"}
+                                        </span>
+                                    </p>
+                                }
+                            } else {
+                                html! {}
+                            }}
+                            </>
                         }
                     } else {
                         html! {
@@ -2178,6 +3480,11 @@ pub fn PersonalityTagSection(props: &PersonalityTagSectionProps) -> Html {
                 <div
                     class="tarot-card"
                     onclick={on_card_click.clone()}
+                    onpointerdown={on_card_pointer_down}
+                    onpointermove={on_card_pointer_move}
+                    onpointerup={on_card_pointer_end.clone()}
+                    onpointerleave={on_card_pointer_end.clone()}
+                    onpointercancel={on_card_pointer_end}
                     style="
                         width: 320px;
                         height: 448px;
@@ -2210,29 +3517,22 @@ pub fn PersonalityTagSection(props: &PersonalityTagSectionProps) -> Html {
                                 box-sizing: border-box;
                             "
                         >
-                            {{
-                                // Use original tarot card URL directly
-                                let image_src = personality_tag_image_url.clone()
-                                    .unwrap_or_else(|| "".to_string());
-
-                                html! {
-                                    <img
-                                        src={image_src.clone()}
-                                        alt={matched_tag.name.clone()}
-                                        style="
-                                            width: 100%;
-                                            height: 100%;
-                                            object-fit: contain;
-                                            border-radius: 0;
-                                            box-shadow: none;
-                                            border: none;
-                                            padding: 0;
-                                            margin: 0;
-                                            display: block;
-                                        "
-                                    />
-                                }
-                            }}
+                            <img
+                                src={tarot_image_src.clone()}
+                                alt={tarot_alt.clone()}
+                                title={tarot_alt.clone()}
+                                style="
+                                    width: 100%;
+                                    height: 100%;
+                                    object-fit: contain;
+                                    border-radius: 0;
+                                    box-shadow: none;
+                                    border: none;
+                                    padding: 0;
+                                    margin: 0;
+                                    display: block;
+                                "
+                            />
                         </div>
                         <div
                             class="tarot-card-back"
@@ -2293,6 +3593,193 @@ pub fn PersonalityTagSection(props: &PersonalityTagSectionProps) -> Html {
                     </div>
                 </div>
 
+                {if *is_flipped && !tarot_image_src.is_empty() {
+                    html! {
+                        <button
+                            onclick={on_open_lightbox}
+                            style="
+                                background: none;
+                                border: 1px solid rgba(255, 255, 255, 0.4);
+                                border-radius: 999px;
+                                color: white;
+                                padding: 6px 14px;
+                                margin: 0 auto 16px;
+                                font-size: 13px;
+                                cursor: pointer;
+                            "
+                        >
+                            {"🔍 View full card"}
+                        </button>
+                    }
+                } else {
+                    html! {}
+                }}
+
+                {if *show_tarot_lightbox {
+                    html! {
+                        <Modal
+                            on_close={on_close_lightbox.clone()}
+                            label={matched_tag.name.clone()}
+                            overlay_class="tarot-lightbox"
+                            overlay_style="background: rgba(0, 0, 0, 0.92); overflow: auto; touch-action: pan-x pan-y pinch-zoom;"
+                            panel_style="display: flex; align-items: center; justify-content: center; max-width: 100vw; max-height: 100vh; outline: none;"
+                            z_index={20000}
+                        >
+                            <img
+                                src={tarot_image_src.clone()}
+                                alt={tarot_alt.clone()}
+                                title={tarot_alt.clone()}
+                                style="
+                                    max-width: 100vw;
+                                    max-height: 100vh;
+                                    object-fit: contain;
+                                    touch-action: pinch-zoom;
+                                "
+                            />
+                            <button
+                                onclick={on_close_lightbox.reform(|_: MouseEvent| ())}
+                                aria-label="Close"
+                                style="
+                                    position: fixed;
+                                    top: 16px;
+                                    right: 16px;
+                                    width: 40px;
+                                    height: 40px;
+                                    border-radius: 50%;
+                                    border: none;
+                                    background: rgba(255, 255, 255, 0.2);
+                                    color: white;
+                                    font-size: 20px;
+                                    cursor: pointer;
+                                "
+                            >
+                                {"✕"}
+                            </button>
+                        </Modal>
+                    }
+                } else {
+                    html! {}
+                }}
+
+                // Share preview: the exact image the worker generates for the embed
+                {if let (true, Some(preview_url)) = (is_own_report, (*share_preview_url).clone()) {
+                    let on_preview_load = {
+                        let share_preview_loaded = share_preview_loaded.clone();
+                        Callback::from(move |_: Event| share_preview_loaded.set(true))
+                    };
+                    let on_preview_error = {
+                        let share_preview_failed = share_preview_failed.clone();
+                        Callback::from(move |_: Event| share_preview_failed.set(true))
+                    };
+                    html! {
+                        <div style="
+                            width: 100%;
+                            max-width: 300px;
+                            margin: 0 auto 16px;
+                        ">
+                            <p style="
+                                font-size: 12px;
+                                color: rgba(255, 255, 255, 0.7);
+                                margin: 0 0 6px 0;
+                            ">{"Share preview"}</p>
+                            {if *share_preview_failed {
+                                html! {
+                                    <p style="
+                                        font-size: 13px;
+                                        color: rgba(255, 255, 255, 0.8);
+                                        margin: 0;
+                                        padding: 16px;
+                                        border-radius: 10px;
+                                        background: rgba(255, 255, 255, 0.1);
+                                    ">{"Preview unavailable"}</p>
+                                }
+                            } else {
+                                html! {
+                                    <div style="
+                                        position: relative;
+                                        min-height: 80px;
+                                        border-radius: 10px;
+                                        overflow: hidden;
+                                        background: rgba(255, 255, 255, 0.1);
+                                    ">
+                                        {if !*share_preview_loaded {
+                                            html! {
+                                                <div style="
+                                                    position: absolute;
+                                                    top: 50%;
+                                                    left: 50%;
+                                                    width: 24px;
+                                                    height: 24px;
+                                                    margin: -12px 0 0 -12px;
+                                                    border: 3px solid rgba(255, 255, 255, 0.3);
+                                                    border-top-color: white;
+                                                    border-radius: 50%;
+                                                    animation: share-preview-spin 1s linear infinite;
+                                                "></div>
+                                            }
+                                        } else {
+                                            html! {}
+                                        }}
+                                        <img
+                                            src={preview_url}
+                                            alt="Share preview"
+                                            loading="lazy"
+                                            onload={on_preview_load}
+                                            onerror={on_preview_error}
+                                            style={format!("
+                                                width: 100%;
+                                                display: block;
+                                                opacity: {};
+                                                transition: opacity 0.3s ease;
+                                            ", if *share_preview_loaded { 1 } else { 0 })}
+                                        />
+                                    </div>
+                                }
+                            }}
+                            <label style="
+                                display: flex;
+                                align-items: center;
+                                gap: 8px;
+                                margin-top: 8px;
+                                font-size: 13px;
+                                color: rgba(255, 255, 255, 0.85);
+                                cursor: pointer;
+                            ">
+                                <input
+                                    type="checkbox"
+                                    checked={*hide_stats}
+                                    onchange={{
+                                        let hide_stats = hide_stats.clone();
+                                        Callback::from(move |_: Event| hide_stats.set(!*hide_stats))
+                                    }}
+                                />
+                                {"Hide my numbers when sharing"}
+                            </label>
+                            <label title="Your FID is still part of the share link" style="
+                                display: flex;
+                                align-items: center;
+                                gap: 8px;
+                                margin-top: 8px;
+                                font-size: 13px;
+                                color: rgba(255, 255, 255, 0.85);
+                                cursor: pointer;
+                            ">
+                                <input
+                                    type="checkbox"
+                                    checked={*hide_fid}
+                                    onchange={{
+                                        let hide_fid = hide_fid.clone();
+                                        Callback::from(move |_: Event| hide_fid.set(!*hide_fid))
+                                    }}
+                                />
+                                {"Hide my FID on the image"}
+                            </label>
+                        </div>
+                    }
+                } else {
+                    html! {}
+                }}
+
                 // Share buttons
                 <div style="
                     display: flex;
@@ -2303,18 +3790,13 @@ pub fn PersonalityTagSection(props: &PersonalityTagSectionProps) -> Html {
                     max-width: 300px;
                     margin: 0 auto;
                 ">
-                    {if !is_own_report {
+                    {if let (false, Some(user_fid)) = (is_own_report, current_user_fid) {
                         html! {
                             <button
-                                onclick={Callback::from({
-                                    let current_user_fid_clone = current_user_fid;
-                                    move |_| {
-                                        if let Some(user_fid) = current_user_fid_clone {
-                                            crate::services::update_annual_report_url(user_fid);
-                                            if let Some(window) = web_sys::window() {
-                                                window.location().reload().ok();
-                                            }
-                                        }
+                                onclick={Callback::from(move |_| {
+                                    crate::services::update_annual_report_url(user_fid);
+                                    if let Some(window) = web_sys::window() {
+                                        window.location().reload().ok();
                                     }
                                 })}
                                 style="
@@ -2336,6 +3818,9 @@ pub fn PersonalityTagSection(props: &PersonalityTagSectionProps) -> Html {
                                 {"View Your Annual Report"}
                             </button>
                         }
+                    } else if !is_own_report {
+                        // Visitors without a known FID get no owner actions at all
+                        html! {}
                     } else if is_farcaster_env {
                         html! {
                             <button
@@ -2367,6 +3852,26 @@ pub fn PersonalityTagSection(props: &PersonalityTagSectionProps) -> Html {
                     } else {
                         html! {
                             <>
+                                <button
+                                    onclick={on_warpcast_share.clone()}
+                                    style="
+                                        background: rgba(138, 99, 210, 0.85);
+                                        color: white;
+                                        border: none;
+                                        border-radius: 10px;
+                                        padding: 12px 24px;
+                                        font-size: 16px;
+                                        font-weight: 600;
+                                        cursor: pointer;
+                                        transition: all 0.3s ease;
+                                        backdrop-filter: blur(10px);
+                                        -webkit-backdrop-filter: blur(10px);
+                                        border: 1px solid rgba(255, 255, 255, 0.2);
+                                        width: 100%;
+                                    "
+                                >
+                                    {"Share on Farcaster"}
+                                </button>
                                 <button
                                     onclick={on_twitter_share.clone()}
                                     style="
@@ -2387,6 +3892,10 @@ pub fn PersonalityTagSection(props: &PersonalityTagSectionProps) -> Html {
                                 >
                                     {"Share on Twitter"}
                                 </button>
+                                <TwitterDomainToggle
+                                    domain={twitter_domain.clone()}
+                                    style="color: rgba(255, 255, 255, 0.7);"
+                                />
                                 <button
                                     onclick={on_copy.clone()}
                                     disabled={*is_sharing}
@@ -2411,22 +3920,92 @@ pub fn PersonalityTagSection(props: &PersonalityTagSectionProps) -> Html {
                                         "Copy Share Text"
                                     }}
                                 </button>
+                                {if share_preview_url.is_some() {
+                                    html! {
+                                        <button
+                                            onclick={on_copy_image.clone()}
+                                            disabled={*is_sharing}
+                                            style="
+                                                background: rgba(255, 255, 255, 0.1);
+                                                color: white;
+                                                border: 1px solid rgba(255, 255, 255, 0.2);
+                                                border-radius: 10px;
+                                                padding: 12px 24px;
+                                                font-size: 16px;
+                                                font-weight: 600;
+                                                cursor: pointer;
+                                                transition: all 0.3s ease;
+                                                backdrop-filter: blur(10px);
+                                                -webkit-backdrop-filter: blur(10px);
+                                                width: 100%;
+                                            "
+                                        >
+                                            {if *is_sharing {
+                                                "Copying..."
+                                            } else {
+                                                "Copy Image"
+                                            }}
+                                        </button>
+                                    }
+                                } else {
+                                    html! {}
+                                }}
                             </>
                         }
                     }}
-                    {if let Some(status) = (*share_status).as_ref() {
-                        html! {
-                    <p style="
-                        font-size: 14px;
-                                color: rgba(255, 255, 255, 0.8);
-                                margin: 8px 0 0 0;
-                                text-align: center;
-                            ">{status.clone()}</p>
-                        }
-                    } else {
-                        html! {}
-                    }}
                 </div>
+                {if let Some(params) = &debug_share_params {
+                    let decoded = decode_image_params_for_share(params);
+                    html! {
+                        <div class="debug-share-params" style="
+                            width: 100%;
+                            max-width: 300px;
+                            margin: 12px auto 0;
+                            padding: 8px 10px;
+                            font-size: 12px;
+                            font-family: monospace;
+                            color: rgba(255, 255, 255, 0.85);
+                            background: rgba(0, 0, 0, 0.4);
+                            border: 1px solid rgba(255, 255, 255, 0.2);
+                            border-radius: 8px;
+                            box-sizing: border-box;
+                            text-align: left;
+                        ">
+                            <div style="word-break: break-all; margin-bottom: 6px;">
+                                {format!("params={}", params)}
+                            </div>
+                            {match decoded {
+                                Ok(decoded) => decoded
+                                    .describe()
+                                    .into_iter()
+                                    .map(|(field, value)| html! {
+                                        <div>{format!("{}: {}", field, value)}</div>
+                                    })
+                                    .collect::<Html>(),
+                                Err(e) => html! {
+                                    <div style="color: #ff9b9b;">{e}</div>
+                                },
+                            }}
+                            <button
+                                onclick={on_copy_share_params}
+                                style="
+                                    margin-top: 6px;
+                                    padding: 4px 8px;
+                                    font-size: 12px;
+                                    color: white;
+                                    background: rgba(0, 0, 0, 0.6);
+                                    border: 1px solid rgba(255, 255, 255, 0.3);
+                                    border-radius: 6px;
+                                    cursor: pointer;
+                                "
+                            >
+                                {"🐞 Copy share params"}
+                            </button>
+                        </div>
+                    }
+                } else {
+                    html! {}
+                }}
             </div>
             <style>{r#"
                 .tarot-card {
@@ -2442,6 +4021,11 @@ pub fn PersonalityTagSection(props: &PersonalityTagSectionProps) -> Html {
                         transform: rotateY(5deg) rotateX(5deg);
                     }
                 }
+
+                @keyframes share-preview-spin {
+                    0% { transform: rotate(0deg); }
+                    100% { transform: rotate(360deg); }
+                }
                 
             "#}</style>
             <script>{r#"
@@ -2534,3 +4118,420 @@ pub fn PersonalityTagSection(props: &PersonalityTagSectionProps) -> Html {
         </div>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tarot_card_index_is_pinned() {
+        // These values must never change, and must match the worker
+        assert_eq!(tarot_card_index(1, 0), 0);
+        assert_eq!(tarot_card_index(2, 0), 1);
+        assert_eq!(tarot_card_index(3, 0), 8);
+        assert_eq!(tarot_card_index(194, 0), 11);
+        assert_eq!(tarot_card_index(3621, 0), 20);
+        assert_eq!(tarot_card_index(12345, 0), 20);
+    }
+
+    #[test]
+    fn test_tarot_card_index_salt() {
+        assert_eq!(tarot_card_index(3621, 1), 21);
+    }
+
+    // Inputs to encode_image_params_for_share for the share params golden vectors. The
+    // worker's decode_image_params tests decode these exact strings, so both sides must
+    // change together. The defaults encode to "JQ4AAAAAAAAHAdIEAADV3QAA4RAAAA"
+    struct ShareParamsFixture {
+        fid: i64,
+        zodiac_url: String,
+        social_url: String,
+        // (casts, reactions, followers)
+        stats: (usize, usize, usize),
+        hide_stats: bool,
+        hide_fid: bool,
+        year: Option<i32>,
+    }
+
+    impl Default for ShareParamsFixture {
+        fn default() -> Self {
+            Self {
+                fid: 3621,
+                zodiac_url: "/imgs/zodiac/leo.png".to_string(),
+                social_url: "/imgs/social_type/social.png".to_string(),
+                stats: (1234, 56789, 4321),
+                hide_stats: false,
+                hide_fid: false,
+                year: None,
+            }
+        }
+    }
+
+    impl ShareParamsFixture {
+        fn encode(&self) -> String {
+            let (casts, reactions, followers) = self.stats;
+            encode_image_params_for_share(
+                self.fid,
+                None,
+                None,
+                &self.zodiac_url,
+                &self.social_url,
+                casts,
+                reactions,
+                followers,
+                self.hide_stats,
+                self.hide_fid,
+                self.year,
+            )
+        }
+    }
+
+    #[test]
+    fn test_encode_image_params_golden_vectors() {
+        let encode = |fid, zodiac: &str, social: &str, stats| {
+            ShareParamsFixture {
+                fid,
+                zodiac_url: format!("/imgs/zodiac/{}.png", zodiac),
+                social_url: format!("/imgs/social_type/{}.png", social),
+                stats,
+                ..ShareParamsFixture::default()
+            }
+            .encode()
+        };
+        assert_eq!(
+            ShareParamsFixture::default().encode(),
+            "JQ4AAAAAAAAHAdIEAADV3QAA4RAAAA"
+        );
+        // FID near i64::MAX and zero stats
+        assert_eq!(
+            encode(i64::MAX - 1, "capricorn", "slient", (0, 0, 0)),
+            "_v_______38AAAAAAAAAAAAAAAAAAA"
+        );
+        // Stats at u32::MAX
+        let max = u32::MAX as usize;
+        assert_eq!(
+            encode(1, "sagittarius", "social", (max, max, max)),
+            "AQAAAAAAAAALAf_______________w"
+        );
+        // Bytes that map to '+' and '/' in standard base64 must use '-' and '_'
+        assert_eq!(
+            encode(
+                12345,
+                "sagittarius",
+                "social",
+                (0xfbffbf, 0xfefefe, 0x3f3f3f)
+            ),
+            "OTAAAAAAAAALAb__-wD-_v4APz8_AA"
+        );
+    }
+
+    #[test]
+    fn test_decode_image_params_for_share() {
+        let decoded = decode_image_params_for_share("JQ4AAAAAAAAHAdIEAADV3QAA4RAAAA").unwrap();
+        assert_eq!(decoded.fid, 3621);
+        assert_eq!(
+            (
+                decoded.total_casts,
+                decoded.total_reactions,
+                decoded.total_followers
+            ),
+            (1234, 56789, 4321)
+        );
+        let rows = decoded.describe();
+        assert!(rows.contains(&("zodiac", "Leo (7)".to_string())));
+        assert!(rows.contains(&("social", "Social Butterfly (1)".to_string())));
+        assert!(rows.contains(&("hide stats", "false".to_string())));
+
+        // Round-trips the URL-safe alphabet and the flags byte
+        let decoded = decode_image_params_for_share("OTAAAAAAAAALAb__-wD-_v4APz8_AA").unwrap();
+        assert_eq!(decoded.total_casts, 0xfbffbf);
+        let hidden = ShareParamsFixture {
+            hide_stats: true,
+            ..ShareParamsFixture::default()
+        }
+        .encode();
+        let decoded = decode_image_params_for_share(&hidden).unwrap();
+        assert_eq!(decoded.flags, SHARE_FLAG_HIDE_STATS);
+        assert_eq!(decoded.total_casts, 0);
+        assert!(decode_image_params_for_share("JQ4AAAAAAAAHAdIE").is_err());
+        assert!(decode_image_params_for_share("not base64!").is_err());
+
+        // Report year after the flags byte; same vector as the worker's test_decode_image_params_year
+        let with_year = ShareParamsFixture {
+            year: Some(2026),
+            ..ShareParamsFixture::default()
+        }
+        .encode();
+        assert_eq!(with_year, "JQ4AAAAAAAAHAdIEAADV3QAA4RAAAADqBw");
+        let decoded = decode_image_params_for_share(&with_year).unwrap();
+        assert_eq!((decoded.flags, decoded.year), (0, Some(2026)));
+        assert!(decoded.describe().contains(&("year", "2026".to_string())));
+
+        assert_eq!(
+            share_params_from_url("https://x.io/annual-report/3?params=abc#top"),
+            Some("abc")
+        );
+        assert_eq!(
+            share_params_from_url("https://x.io/annual-report/3?params="),
+            None
+        );
+        assert_eq!(share_params_from_url("https://x.io/annual-report/3"), None);
+    }
+
+    #[test]
+    fn test_encode_image_params_ignores_cache_busting_query() {
+        let encoded = ShareParamsFixture {
+            zodiac_url: "https://example.com/imgs/zodiac/leo.png?v=123".to_string(),
+            social_url: "https://example.com/imgs/social_type/social.png?v=123".to_string(),
+            ..ShareParamsFixture::default()
+        }
+        .encode();
+        assert_eq!(encoded, "JQ4AAAAAAAAHAdIEAADV3QAA4RAAAA");
+    }
+
+    #[test]
+    fn test_classify_social_tiers() {
+        assert_eq!(classify_social(0).label, "Lurker");
+        assert_eq!(classify_social(CASUAL_MIN_CASTS - 1).label, "Lurker");
+        assert_eq!(classify_social(CASUAL_MIN_CASTS).label, "Casual");
+        assert_eq!(classify_social(CHATTY_MIN_CASTS).label, "Chatty");
+        assert_eq!(
+            classify_social(SOCIAL_BUTTERFLY_MIN_CASTS - 1).label,
+            "Chatty"
+        );
+        assert_eq!(
+            classify_social(SOCIAL_BUTTERFLY_MIN_CASTS).label,
+            "Social Butterfly"
+        );
+        // The original silent/social indices keep their meaning
+        assert_eq!(classify_social(0).index, 0);
+        assert_eq!(classify_social(10_000).index, 1);
+    }
+
+    #[test]
+    fn test_encode_image_params_social_tier() {
+        // Byte 9 carries the tier index; the worker maps index 3 back to chatty.png
+        let encoded = ShareParamsFixture {
+            social_url: "https://example.com/imgs/social_type/chatty.png?v=123".to_string(),
+            ..ShareParamsFixture::default()
+        }
+        .encode();
+        assert_eq!(encoded, "JQ4AAAAAAAAHA9IEAADV3QAA4RAAAA");
+    }
+
+    #[test]
+    fn test_encode_image_params_hide_stats() {
+        // Counts are zeroed and the flags byte is appended; the worker decodes this vector too
+        let encoded = ShareParamsFixture {
+            hide_stats: true,
+            ..ShareParamsFixture::default()
+        }
+        .encode();
+        assert_eq!(encoded, "JQ4AAAAAAAAHAQAAAAAAAAAAAAAAAAE");
+    }
+
+    #[test]
+    fn test_encode_image_params_hide_fid() {
+        // Counts are kept and only the flag is set; the worker decodes these vectors too
+        let hide_fid = |hide_stats| {
+            ShareParamsFixture {
+                hide_stats,
+                hide_fid: true,
+                ..ShareParamsFixture::default()
+            }
+            .encode()
+        };
+        assert_eq!(hide_fid(false), "JQ4AAAAAAAAHAdIEAADV3QAA4RAAAAI");
+        assert_eq!(hide_fid(true), "JQ4AAAAAAAAHAQAAAAAAAAAAAAAAAAM");
+
+        let url = format!(
+            "https://example.com/annual-report/3621?params={}",
+            hide_fid(false)
+        );
+        assert!(share_url_hides_fid(&url));
+        assert!(!share_url_hides_fid(
+            "https://example.com/annual-report/3621?params=JQ4AAAAAAAAHAQAAAAAAAAAAAAAAAAE"
+        ));
+        assert!(!share_url_hides_fid(
+            "https://example.com/annual-report/3621"
+        ));
+    }
+
+    #[test]
+    fn test_top_words_by_count() {
+        let word = |word: &str, count| TopWord {
+            count,
+            language: "en".to_string(),
+            word: word.to_string(),
+        };
+        let words = vec![word("build", 3), word("ship", 9), word(" ", 50), word("rest", 0), word("cast", 5)];
+
+        assert_eq!(top_words_by_count(&words, 10), vec!["ship", "cast", "build"]);
+        assert_eq!(top_words_by_count(&words, 1), vec!["ship"]);
+        assert!(top_words_by_count(&[], 10).is_empty());
+    }
+
+    #[test]
+    fn test_top_languages() {
+        let mut distribution = std::collections::HashMap::new();
+        distribution.insert("en".to_string(), 70);
+        distribution.insert("ja".to_string(), 20);
+        distribution.insert("xx".to_string(), 10);
+        distribution.insert("fr".to_string(), 0);
+
+        let languages = top_languages(&distribution, 2);
+        assert_eq!(languages.len(), 2);
+        assert_eq!(languages[0].0, "English");
+        assert!((languages[0].1 - 70.0).abs() < 0.01);
+        assert_eq!(languages[1].0, "日本語");
+
+        assert_eq!(top_languages(&distribution, 5)[2].0, "XX");
+        assert!(top_languages(&std::collections::HashMap::new(), 5).is_empty());
+    }
+
+    // Stats above u32::MAX don't fit the 4-byte fields and saturate to u32::MAX
+    #[test]
+    fn test_saturate_to_u32() {
+        assert_eq!(saturate_to_u32(0), 0);
+        assert_eq!(saturate_to_u32(1234), 1234);
+        assert_eq!(saturate_to_u32(u32::MAX as usize), u32::MAX);
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(saturate_to_u32(u32::MAX as usize + 5), u32::MAX);
+    }
+
+    #[test]
+    fn test_absolute_image_url() {
+        assert_eq!(
+            absolute_image_url("/imgs/zodiac/leo.png", Some("https://app.example")),
+            "https://app.example/imgs/zodiac/leo.png"
+        );
+        // No usable runtime origin: fall back to the build-time base URL, never a relative path
+        for origin in [None, Some("null"), Some("")] {
+            let url = absolute_image_url("/imgs/social_type/social.png", origin);
+            assert!(url.starts_with("https://"), "{}", url);
+            assert!(url.ends_with("/imgs/social_type/social.png"));
+        }
+        assert_eq!(
+            absolute_image_url("https://cdn.example/a.png", Some("https://app.example")),
+            "https://cdn.example/a.png"
+        );
+    }
+
+    #[test]
+    fn test_count_up_value() {
+        assert_eq!(count_up_value(0, 1234, 0.0), 0);
+        assert_eq!(count_up_value(0, 1234, 1.0), 1234);
+        assert_eq!(count_up_value(0, 1234, 1.7), 1234);
+        assert_eq!(count_up_value(0, 1234, -0.5), 0);
+        // Eased out: more than halfway at the halfway point, never past the target
+        let mid = count_up_value(0, 1000, 0.5);
+        assert!(mid > 500 && mid < 1000);
+        // Counting down from a previous target works too
+        assert_eq!(count_up_value(500, 100, 1.0), 100);
+        assert!(count_up_value(500, 100, 0.5) < 500);
+    }
+
+    #[test]
+    fn test_clamp_cast_text() {
+        assert_eq!(
+            clamp_cast_text("short cast", 280),
+            ("short cast".to_string(), false)
+        );
+        assert_eq!(
+            clamp_cast_text("exactly", 7),
+            ("exactly".to_string(), false)
+        );
+        // Cuts back to the last word break
+        assert_eq!(
+            clamp_cast_text("hello wonderful world", 10),
+            ("hello".to_string(), true)
+        );
+        // A cut that lands right before a space keeps the whole word
+        assert_eq!(
+            clamp_cast_text("hello world", 5),
+            ("hello".to_string(), true)
+        );
+        // No usable word break: cut mid-word on a char boundary
+        assert_eq!(clamp_cast_text("abcdefghij", 4), ("abcd".to_string(), true));
+        assert_eq!(
+            clamp_cast_text("a bcdefghij", 6),
+            ("a bcde".to_string(), true)
+        );
+        // Multi-byte text is counted in chars
+        assert_eq!(
+            clamp_cast_text("日本語のテキスト", 3),
+            ("日本語".to_string(), true)
+        );
+        assert_eq!(clamp_cast_text("🎉🎉 🎉🎉", 4), ("🎉🎉".to_string(), true));
+        assert_eq!(clamp_cast_text("", 0), (String::new(), false));
+    }
+
+    #[test]
+    fn test_monthly_activity_bars() {
+        let month = |key: &str, count: usize| MonthlyDistribution {
+            month: key.to_string(),
+            count,
+        };
+        // Unsorted, with gaps, an unreadable key and a busier stray entry from the next year
+        let distribution = vec![
+            month("2024/03", 40),
+            month("01-2024", 10),
+            month("2025-01", 99),
+            month("bogus", 7),
+            month("2024-12", 5),
+        ];
+
+        let bars = monthly_activity_bars(&distribution, 2024);
+        assert_eq!(bars.len(), 12);
+        assert_eq!(
+            bars.iter().map(|b| b.count).collect::<Vec<_>>(),
+            vec![10, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 5]
+        );
+        assert_eq!(
+            bars.iter().map(|b| b.month).collect::<Vec<_>>(),
+            (1..=12).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            bars.iter()
+                .filter(|b| b.is_peak)
+                .map(|b| b.month)
+                .collect::<Vec<_>>(),
+            vec![3]
+        );
+
+        // Ties go to the earlier month
+        let bars = monthly_activity_bars(&[month("2024-05", 8), month("2024-02", 8)], 2024);
+        assert_eq!(
+            bars.iter()
+                .filter(|b| b.is_peak)
+                .map(|b| b.month)
+                .collect::<Vec<_>>(),
+            vec![2]
+        );
+
+        assert!(monthly_activity_bars(&[], 2024).is_empty());
+        assert!(monthly_activity_bars(&[month("bogus", 3)], 2024).is_empty());
+        assert!(monthly_activity_bars(&[month("2025-01", 3)], 2024).is_empty());
+    }
+
+    #[test]
+    fn test_empty_reactors_message() {
+        let mut engagement = EngagementResponse {
+            reactions_received: 0,
+            recasts_received: 0,
+            replies_received: 0,
+            most_popular_cast: None,
+            top_reactors: Vec::new(),
+        };
+        assert_eq!(
+            empty_reactors_message(&engagement),
+            "No interactive users data available"
+        );
+
+        engagement.replies_received = 2;
+        assert_eq!(
+            empty_reactors_message(&engagement),
+            "Top supporters are still being tallied"
+        );
+    }
+}
@@ -0,0 +1,100 @@
+// Templates and the renderer live in shared/share_text.rs, which the worker includes too;
+// this module adds the browser locale and the build-time overrides
+
+#[path = "../../../shared/share_text.rs"]
+mod template;
+
+pub use template::*;
+
+/// Template for `locale`, with the build-time SHARE_TEXT_TEMPLATE /
+/// SHARE_TEXT_TEMPLATE_PRIVATE overriding the built-in ones when set
+pub fn share_template_with_overrides(
+    locale: &str,
+    public_override: Option<&str>,
+    private_override: Option<&str>,
+) -> ShareTextTemplate {
+    let mut template = builtin_share_template(locale);
+    let unescape = |raw: &str| raw.replace("\\n", "\n");
+    if let Some(public) = public_override.filter(|t| !t.trim().is_empty()) {
+        template.public = unescape(public);
+    }
+    if let Some(private) = private_override.filter(|t| !t.trim().is_empty()) {
+        template.private = unescape(private);
+    }
+    template
+}
+
+/// Template for the browser's language and the build-time overrides
+pub fn share_template() -> ShareTextTemplate {
+    let locale = web_sys::window()
+        .and_then(|w| js_sys::Reflect::get(&w, &"navigator".into()).ok())
+        .and_then(|navigator| js_sys::Reflect::get(&navigator, &"language".into()).ok())
+        .and_then(|language| language.as_string())
+        .unwrap_or_else(|| "en".to_string());
+    share_template_with_overrides(
+        &locale,
+        option_env!("SHARE_TEXT_TEMPLATE"),
+        option_env!("SHARE_TEXT_TEMPLATE_PRIVATE"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_share_template() {
+        let values = ShareTextValues {
+            casts: Some("1.2K".to_string()),
+            likes: Some("56".to_string()),
+            recasts: Some("7".to_string()),
+            month: Some("March".to_string()),
+            emoji: Some("🔥".to_string()),
+            tarot: Some("The Star".to_string()),
+            url: Some("https://example.com/r".to_string()),
+            year: Some("2026".to_string()),
+        };
+        let en = builtin_share_template("en-US");
+        assert_eq!(
+            render_share_template(&en.public, &en.separator, &values),
+            "My Annual Report: This year I Published 1.2K Casts this year, Received 56 likes, \
+             Received 7 recasts, Most active month: March, Most used emoji: 🔥\n\n\
+             My Annual Tarot Card is The Star\n\nurl: https://example.com/r\n\n\
+             #MyFarcaster2026 #polyjuice"
+        );
+
+        // Parts whose placeholders have no value are dropped
+        let sparse = ShareTextValues {
+            month: Some("March".to_string()),
+            ..ShareTextValues::default()
+        };
+        // ...along with their list separator
+        assert_eq!(
+            render_share_template(&en.private, &en.separator, &sparse),
+            "My Annual Report: Most active month: March\n\n#polyjuice"
+        );
+        let emoji_only = ShareTextValues {
+            emoji: Some("🔥".to_string()),
+            ..ShareTextValues::default()
+        };
+        assert_eq!(
+            render_share_template("[{month}|{emoji}|{tarot}]", ", ", &emoji_only),
+            "🔥"
+        );
+        assert_eq!(
+            render_share_template("{casts} [{nope}]{url} [a{", ", ", &sparse),
+            "  [a{"
+        );
+
+        assert_eq!(
+            builtin_share_template("zh-CN"),
+            builtin_share_template("zh")
+        );
+        assert_ne!(builtin_share_template("zh").public, en.public);
+        assert_eq!(builtin_share_template("fr"), en);
+
+        let custom = share_template_with_overrides("en", Some("{tarot}\\n{url}"), Some("  "));
+        assert_eq!(custom.public, "{tarot}\n{url}");
+        assert_eq!(custom.private, en.private);
+    }
+}
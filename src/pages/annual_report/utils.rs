@@ -25,13 +25,170 @@ pub fn unix_to_farcaster(unix_timestamp: i64) -> i64 {
     unix_timestamp - FARCASTER_EPOCH
 }
 
-/// Safely normalize a timestamp that may be in either Farcaster or Unix format
-/// Returns a Unix timestamp that is guaranteed to be:
-/// - Between 2020-01-01 and current time
-/// - If out of range, returns None
-pub fn normalize_registration_timestamp(maybe_timestamp: i64) -> Option<i64> {
-    // Get current time in seconds
-    let current_time = (js_sys::Date::now() / 1000.0) as i64;
+/// Abbreviate large counts for display: 999 -> "999", 12847 -> "12.8K", 3_400_000 -> "3.4M"
+/// Must stay identical to the copy in worker/src/lib.rs
+pub fn format_count(n: usize) -> String {
+    const UNITS: [(usize, &str); 3] = [(1_000, "K"), (1_000_000, "M"), (1_000_000_000, "B")];
+
+    if n < 1_000 {
+        return n.to_string();
+    }
+
+    for (idx, (unit, suffix)) in UNITS.iter().enumerate() {
+        // Round to one decimal place using integer math
+        let tenths = (n as u128 * 10 + *unit as u128 / 2) / *unit as u128;
+        // 999_999 rounds to 1000.0K, so promote to the next unit when there is one
+        if tenths >= 10_000 && idx + 1 < UNITS.len() {
+            continue;
+        }
+        let (whole, decimal) = (tenths / 10, tenths % 10);
+        return if decimal == 0 {
+            format!("{}{}", whole, suffix)
+        } else {
+            format!("{}.{}{}", whole, decimal, suffix)
+        };
+    }
+    unreachable!("last unit always returns")
+}
+
+/// Label shown when the signed-in user is looking at someone else's report
+/// Returns None for the owner, or when the viewer's FID is unknown
+pub fn viewing_other_label(
+    report_fid: i64,
+    current_user_fid: Option<i64>,
+    username: Option<&str>,
+) -> Option<String> {
+    let viewer_fid = current_user_fid?;
+    if viewer_fid == report_fid {
+        return None;
+    }
+    Some(match username.filter(|u| !u.is_empty()) {
+        Some(username) => format!("Viewing @{}'s report", username),
+        None => format!("Viewing FID {}'s report", report_fid),
+    })
+}
+
+/// Build the canonical Warpcast URL for a cast hash
+/// Returns None when the hash is missing (older API responses)
+pub fn cast_permalink(message_hash: &str) -> Option<String> {
+    let hash = message_hash.trim();
+    if hash.is_empty() {
+        return None;
+    }
+    let hash = if hash.starts_with("0x") {
+        hash.to_string()
+    } else {
+        format!("0x{}", hash)
+    };
+    Some(format!("https://warpcast.com/~/conversations/{}", hash))
+}
+
+/// Longest alt/title text we put on an image, in characters
+const IMAGE_LABEL_MAX_CHARS: usize = 80;
+
+/// Clean user-provided text for an `alt`/`title` attribute: drop control characters,
+/// collapse whitespace runs and cap the length
+pub fn sanitize_attr_text(text: &str) -> String {
+    let cleaned = text
+        .chars()
+        .map(|c| if c.is_control() { ' ' } else { c })
+        .collect::<String>();
+    let collapsed = cleaned.split_whitespace().collect::<Vec<_>>().join(" ");
+    if collapsed.chars().count() <= IMAGE_LABEL_MAX_CHARS {
+        return collapsed;
+    }
+    let truncated: String = collapsed.chars().take(IMAGE_LABEL_MAX_CHARS - 1).collect();
+    format!("{}…", truncated.trim_end())
+}
+
+/// Alt text for a labelled report image, e.g. "Zodiac: Leo"
+pub fn image_label(label: &str, value: &str) -> String {
+    let value = sanitize_attr_text(value);
+    if value.is_empty() {
+        return label.to_string();
+    }
+    sanitize_attr_text(&format!("{}: {}", label, value))
+}
+
+/// Alt text for a user's avatar: "@username's avatar", falling back to the FID
+pub fn avatar_label(username: Option<&str>, fid: i64) -> String {
+    let username = username.map(sanitize_attr_text).unwrap_or_default();
+    let username = username.trim_start_matches('@');
+    if username.is_empty() {
+        format!("FID {}'s avatar", fid)
+    } else {
+        sanitize_attr_text(&format!("@{}'s avatar", username))
+    }
+}
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// Report pages whose images are preloaded while `current` is shown: its immediate
+/// neighbors within `0..total`
+pub fn neighbor_pages(current: usize, total: usize) -> Vec<usize> {
+    [current.checked_sub(1), current.checked_add(1)]
+        .into_iter()
+        .flatten()
+        .filter(|page| *page < total)
+        .collect()
+}
+
+/// English name for a month number (1-12)
+pub fn month_name(month: u32) -> Option<&'static str> {
+    MONTH_NAMES.get((month as usize).checked_sub(1)?).copied()
+}
+
+/// Parse a `monthly_distribution` month key into (year, month)
+/// Accepts YYYY-MM (also with `/` or `.`), YYYY-MM-DD, MM-YYYY, MM/YYYY and YYYYMM;
+/// returns None for anything else or a month outside 1..=12
+pub fn parse_month_key(key: &str) -> Option<(i32, u32)> {
+    let is_digits = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit());
+    let key = key.trim();
+    let parts: Vec<&str> = key.split(['-', '/', '.']).collect();
+    let (year, month) = match parts.as_slice() {
+        [compact] if compact.len() == 6 && is_digits(compact) => (&compact[..4], &compact[4..]),
+        [year, month] | [year, month, _] if year.len() == 4 => (*year, *month),
+        [month, year] if year.len() == 4 => (*year, *month),
+        _ => return None,
+    };
+    if let [_, _, day] = parts.as_slice() {
+        if day.len() > 2 || !is_digits(day) {
+            return None;
+        }
+    }
+    if !is_digits(year) || !is_digits(month) || month.len() > 2 {
+        return None;
+    }
+    let month: u32 = month.parse().ok()?;
+    (1..=12)
+        .contains(&month)
+        .then_some((year.parse().ok()?, month))
+}
+
+/// How far past "now" a registration timestamp may be, to tolerate client clock skew
+const REGISTRATION_CLOCK_SKEW_SECS: i64 = 24 * 60 * 60;
+
+/// Interpret a registration timestamp that may be in either Farcaster or Unix format
+/// Returns the Unix timestamp, or why it was rejected: zero/negative values and anything
+/// before 2020-01-01 or after `now` (plus a day of clock skew) can't be a real registration
+pub fn check_registration_timestamp(maybe_timestamp: i64, now: i64) -> Result<i64, String> {
+    // Zero or negative values are placeholders/corruption, not "the Farcaster epoch"
+    if maybe_timestamp <= 0 {
+        return Err(format!("non-positive timestamp {}", maybe_timestamp));
+    }
 
     // If timestamp is less than 1 billion, it's likely a Farcaster timestamp
     // (Farcaster timestamps are typically small numbers since epoch is 2021-01-01)
@@ -44,14 +201,31 @@ pub fn normalize_registration_timestamp(maybe_timestamp: i64) -> Option<i64> {
     };
 
     // Validate: Farcaster registration cannot be before 2020 or after current time
-    if unix_timestamp < FARCASTER_MIN_TIMESTAMP || unix_timestamp > current_time {
-        // Invalid timestamp, return None
-        None
+    if unix_timestamp < FARCASTER_MIN_TIMESTAMP {
+        Err(format!("{} is before Farcaster existed", unix_timestamp))
+    } else if unix_timestamp > now.saturating_add(REGISTRATION_CLOCK_SKEW_SECS) {
+        Err(format!("{} is in the future", unix_timestamp))
     } else {
-        Some(unix_timestamp)
+        Ok(unix_timestamp)
     }
 }
 
+/// Safely normalize a timestamp that may be in either Farcaster or Unix format
+/// Returns a Unix timestamp between 2020-01-01 and the current time, or None (logging
+/// why) when it's out of range so callers fall back to their N/A displays
+pub fn normalize_registration_timestamp(maybe_timestamp: i64) -> Option<i64> {
+    // Get current time in seconds
+    let current_time = (js_sys::Date::now() / 1000.0) as i64;
+
+    check_registration_timestamp(maybe_timestamp, current_time)
+        .map_err(|reason| {
+            web_sys::console::warn_1(
+                &format!("⚠️ Ignoring implausible registered_at: {}", reason).into(),
+            );
+        })
+        .ok()
+}
+
 /// Helper function to extract data from nested API response structure
 #[allow(dead_code)]
 pub fn extract_nested_data<T>(json_data: serde_json::Value) -> Result<T, String>
@@ -305,3 +479,124 @@ pub fn convert_annual_report_response(
         network_comparison: None, // API doesn't return this yet
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cast_permalink() {
+        assert_eq!(
+            cast_permalink("0xabc123").as_deref(),
+            Some("https://warpcast.com/~/conversations/0xabc123")
+        );
+        assert_eq!(
+            cast_permalink("abc123").as_deref(),
+            Some("https://warpcast.com/~/conversations/0xabc123")
+        );
+        assert_eq!(cast_permalink("  "), None);
+    }
+
+    #[test]
+    fn test_viewing_other_label() {
+        // Own report and unknown viewer get no label
+        assert_eq!(viewing_other_label(3, Some(3), Some("dwr")), None);
+        assert_eq!(viewing_other_label(3, None, Some("dwr")), None);
+        assert_eq!(
+            viewing_other_label(3, Some(194), Some("dwr")),
+            Some("Viewing @dwr's report".to_string())
+        );
+        assert_eq!(
+            viewing_other_label(3, Some(194), Some("")),
+            Some("Viewing FID 3's report".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_month_key() {
+        assert_eq!(parse_month_key("2024-03"), Some((2024, 3)));
+        assert_eq!(parse_month_key("2024/3"), Some((2024, 3)));
+        assert_eq!(parse_month_key("2024.11"), Some((2024, 11)));
+        assert_eq!(parse_month_key(" 2024-12-01 "), Some((2024, 12)));
+        assert_eq!(parse_month_key("03-2024"), Some((2024, 3)));
+        assert_eq!(parse_month_key("7/2024"), Some((2024, 7)));
+        assert_eq!(parse_month_key("202405"), Some((2024, 5)));
+
+        assert_eq!(parse_month_key("2024-13"), None);
+        assert_eq!(parse_month_key("2024-00"), None);
+        assert_eq!(parse_month_key("2024"), None);
+        assert_eq!(parse_month_key("24-03"), None);
+        assert_eq!(parse_month_key("2024-March"), None);
+        assert_eq!(parse_month_key("2024-03-01T00:00:00Z"), None);
+        assert_eq!(parse_month_key(""), None);
+
+        assert_eq!(month_name(1), Some("January"));
+        assert_eq!(month_name(12), Some("December"));
+        assert_eq!(month_name(0), None);
+        assert_eq!(month_name(13), None);
+    }
+
+    #[test]
+    fn test_image_alt_labels() {
+        assert_eq!(image_label("Zodiac", "Leo"), "Zodiac: Leo");
+        assert_eq!(image_label("Tarot", "The Star"), "Tarot: The Star");
+        assert_eq!(image_label("Tarot", "  \n "), "Tarot");
+        assert_eq!(avatar_label(Some("alice"), 1), "@alice's avatar");
+        assert_eq!(avatar_label(Some("@alice"), 1), "@alice's avatar");
+        assert_eq!(avatar_label(Some(""), 42), "FID 42's avatar");
+        assert_eq!(avatar_label(None, 42), "FID 42's avatar");
+
+        assert_eq!(sanitize_attr_text("a\u{0}b\t\n  c"), "a b c");
+        let long = sanitize_attr_text(&"x".repeat(200));
+        assert_eq!(long.chars().count(), 80);
+        assert!(long.ends_with('…'));
+    }
+
+    #[test]
+    fn test_check_registration_timestamp() {
+        // 2025-01-01
+        let now = 1_735_689_600;
+
+        // Unix timestamps pass through; small values are Farcaster time
+        assert_eq!(
+            check_registration_timestamp(1_700_000_000, now),
+            Ok(1_700_000_000)
+        );
+        assert_eq!(
+            check_registration_timestamp(100_000_000, now),
+            Ok(1_709_459_200)
+        );
+        // A little clock skew is tolerated
+        assert!(check_registration_timestamp(now + 3_600, now).is_ok());
+
+        assert!(check_registration_timestamp(0, now).is_err());
+        assert!(check_registration_timestamp(-42, now).is_err());
+        assert!(check_registration_timestamp(1_500_000_000, now).is_err());
+        assert!(check_registration_timestamp(4_070_908_800, now).is_err());
+        assert!(check_registration_timestamp(i64::MAX, now).is_err());
+    }
+
+    #[test]
+    fn test_format_count_boundaries() {
+        assert_eq!(format_count(0), "0");
+        assert_eq!(format_count(999), "999");
+        assert_eq!(format_count(1_000), "1K");
+        assert_eq!(format_count(1_050), "1.1K");
+        assert_eq!(format_count(12_847), "12.8K");
+        assert_eq!(format_count(999_949), "999.9K");
+        assert_eq!(format_count(999_999), "1M");
+        assert_eq!(format_count(1_000_000), "1M");
+        assert_eq!(format_count(3_400_000), "3.4M");
+        assert_eq!(format_count(1_000_000_000), "1B");
+        assert!(format_count(usize::MAX).ends_with('B'));
+    }
+
+    #[test]
+    fn test_neighbor_pages() {
+        assert_eq!(neighbor_pages(0, 7), vec![1]);
+        assert_eq!(neighbor_pages(3, 7), vec![2, 4]);
+        assert_eq!(neighbor_pages(6, 7), vec![5]);
+        assert_eq!(neighbor_pages(0, 1), Vec::<usize>::new());
+        assert_eq!(neighbor_pages(0, 0), Vec::<usize>::new());
+    }
+}
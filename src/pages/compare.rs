@@ -0,0 +1,341 @@
+use std::cmp::Ordering;
+
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+
+use super::annual_report::sections::calculate_personality_tag;
+use super::annual_report::sections::get_image_url;
+use super::annual_report::utils::convert_annual_report_response;
+use crate::models::AnnualReportResponse;
+use crate::models::CastsStatsResponse;
+use crate::services::create_annual_report_endpoint;
+use crate::services::make_request_with_payment;
+use crate::services::RequestError;
+use crate::wallet::WalletAccount;
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct CompareReportProps {
+    pub fid_a: i64,
+    pub fid_b: i64,
+    pub api_url: String,
+    pub wallet_account: Option<WalletAccount>,
+}
+
+/// Key annual report stats for one side of the comparison
+#[derive(Clone, PartialEq)]
+struct CompareStats {
+    fid: i64,
+    name: String,
+    casts: usize,
+    reactions: usize,
+    followers: usize,
+    tarot_name: String,
+    tarot_image_url: String,
+}
+
+impl CompareStats {
+    fn from_report(report: &AnnualReportResponse) -> Self {
+        let empty_casts = CastsStatsResponse {
+            total_casts: 0,
+            date_distribution: Vec::new(),
+            date_range: None,
+            language_distribution: std::collections::HashMap::new(),
+            top_nouns: Vec::new(),
+            top_verbs: Vec::new(),
+        };
+        let (tarot_name, tarot_path, _description) = calculate_personality_tag(
+            &report.temporal_activity,
+            &report.engagement,
+            &report.content_style,
+            &report.follower_growth,
+            &empty_casts,
+            report.fid,
+            0,
+        );
+
+        let name = report
+            .username
+            .as_ref()
+            .map(|u| format!("@{}", u))
+            .or_else(|| report.display_name.clone())
+            .unwrap_or_else(|| format!("FID {}", report.fid));
+
+        Self {
+            fid: report.fid,
+            name,
+            // Same fields the report itself displays
+            casts: report
+                .temporal_activity
+                .total_casts_in_year
+                .unwrap_or(report.temporal_activity.total_casts),
+            reactions: report.engagement.reactions_received,
+            followers: report.follower_growth.current_followers,
+            tarot_name,
+            tarot_image_url: get_image_url(&tarot_path),
+        }
+    }
+}
+
+/// Load state for one side, so a failure on one side doesn't blank the other
+#[derive(Clone, PartialEq)]
+enum CompareSide {
+    Loading,
+    Loaded(Box<CompareStats>),
+    Failed(String),
+}
+
+/// Fetch and summarize one user's annual report
+async fn load_compare_stats(
+    api_url: &str,
+    wallet_account: Option<&WalletAccount>,
+    fid: i64,
+) -> Result<CompareStats, String> {
+    let endpoint = create_annual_report_endpoint(fid, 2025, false);
+    let json = make_request_with_payment::<serde_json::Value>(
+        api_url,
+        &endpoint,
+        None,
+        wallet_account,
+        None,
+        None,
+    )
+    .await
+    .map_err(|e| match e {
+        RequestError::NotFound => format!("No Farcaster user found for FID {}", fid),
+        RequestError::Failed(e) if e.starts_with("JOB_STATUS:") => {
+            "Report is still being generated, check back soon".to_string()
+        }
+        RequestError::Failed(e) => e,
+    })?;
+
+    let api_data = json.get("data").cloned().unwrap_or(json);
+    let report = convert_annual_report_response(api_data)?;
+    Ok(CompareStats::from_report(&report))
+}
+
+/// Side-by-side comparison of two users' annual reports
+#[function_component]
+pub fn CompareReport(props: &CompareReportProps) -> Html {
+    let side_a = use_state(|| CompareSide::Loading);
+    let side_b = use_state(|| CompareSide::Loading);
+    // Bumped on every load so responses for a previous pair of FIDs are dropped
+    let load_generation = use_mut_ref(|| 0u32);
+
+    {
+        let side_a = side_a.clone();
+        let side_b = side_b.clone();
+        let wallet_account = props.wallet_account.clone();
+
+        use_effect_with(
+            (props.fid_a, props.fid_b, props.api_url.clone()),
+            move |(fid_a, fid_b, api_url)| {
+                let generation = {
+                    let mut current = load_generation.borrow_mut();
+                    *current = current.wrapping_add(1);
+                    *current
+                };
+                // Load each side independently so one failure doesn't block the other
+                for (fid, side) in [(*fid_a, side_a), (*fid_b, side_b)] {
+                    side.set(CompareSide::Loading);
+                    let api_url = api_url.clone();
+                    let wallet_account = wallet_account.clone();
+                    let load_generation = load_generation.clone();
+                    spawn_local(async move {
+                        let result =
+                            load_compare_stats(&api_url, wallet_account.as_ref(), fid).await;
+                        if *load_generation.borrow() != generation {
+                            // The FIDs changed while this was loading
+                            return;
+                        }
+                        match result {
+                            Ok(stats) => side.set(CompareSide::Loaded(Box::new(stats))),
+                            Err(e) => {
+                                web_sys::console::warn_1(
+                                    &format!("⚠️ Failed to load report for FID {}: {}", fid, e)
+                                        .into(),
+                                );
+                                side.set(CompareSide::Failed(e));
+                            }
+                        }
+                    });
+                }
+                || ()
+            },
+        );
+    }
+
+    let stats_a = match &*side_a {
+        CompareSide::Loaded(stats) => Some(stats.as_ref()),
+        _ => None,
+    };
+    let stats_b = match &*side_b {
+        CompareSide::Loaded(stats) => Some(stats.as_ref()),
+        _ => None,
+    };
+
+    // (label, value A, value B)
+    let rows: Vec<(&str, Option<usize>, Option<usize>)> = vec![
+        ("Casts", stats_a.map(|s| s.casts), stats_b.map(|s| s.casts)),
+        ("Reactions", stats_a.map(|s| s.reactions), stats_b.map(|s| s.reactions)),
+        ("Followers", stats_a.map(|s| s.followers), stats_b.map(|s| s.followers)),
+    ];
+
+    // Count wins per side (only when both sides loaded)
+    let (wins_a, wins_b) = rows.iter().fold((0, 0), |(a, b), (_, va, vb)| {
+        match (va, vb) {
+            (Some(va), Some(vb)) => match va.cmp(vb) {
+                Ordering::Greater => (a + 1, b),
+                Ordering::Less => (a, b + 1),
+                Ordering::Equal => (a, b),
+            },
+            _ => (a, b),
+        }
+    });
+
+    let render_side = |side: &CompareSide, fid: i64| -> Html {
+        match side {
+            CompareSide::Loading => html! {
+                <div class="loading-container" style="padding: 24px; text-align: center;">
+                    <div class="skeleton-spinner"></div>
+                    <p>{format!("Loading FID {}...", fid)}</p>
+                </div>
+            },
+            CompareSide::Failed(e) => html! {
+                <div class="error-message" style="padding: 24px; text-align: center;">
+                    <p>{format!("Couldn't load FID {}", fid)}</p>
+                    <p style="font-size: 13px; opacity: 0.8;">{e.clone()}</p>
+                </div>
+            },
+            CompareSide::Loaded(stats) => html! {
+                <div style="
+                    display: flex;
+                    flex-direction: column;
+                    align-items: center;
+                    gap: 12px;
+                    text-align: center;
+                ">
+                    <h3 style="margin: 0; font-size: 20px; color: white; word-break: break-word;">
+                        {stats.name.clone()}
+                    </h3>
+                    <span style="font-size: 13px; color: rgba(255, 255, 255, 0.7);">
+                        {format!("FID {}", stats.fid)}
+                    </span>
+                    <img
+                        src={stats.tarot_image_url.clone()}
+                        alt={stats.tarot_name.clone()}
+                        title={stats.tarot_name.clone()}
+                        style="
+                            width: 100%;
+                            max-width: 160px;
+                            border-radius: 12px;
+                            box-shadow: 0 8px 24px rgba(0, 0, 0, 0.3);
+                        "
+                    />
+                    <span style="font-size: 15px; font-weight: 600; color: white;">
+                        {stats.tarot_name.clone()}
+                    </span>
+                </div>
+            },
+        }
+    };
+
+    let render_value = |value: Option<usize>, other: Option<usize>| -> Html {
+        let ahead = matches!((value, other), (Some(v), Some(o)) if v > o);
+        match value {
+            Some(v) => html! {
+                <span style={format!(
+                    "font-size: 20px; font-weight: 700; color: {};",
+                    if ahead { "#FFD700" } else { "white" }
+                )}>
+                    {if ahead { format!("👑 {}", v) } else { v.to_string() }}
+                </span>
+            },
+            None => html! {
+                <span style="font-size: 20px; color: rgba(255, 255, 255, 0.4);">{"—"}</span>
+            },
+        }
+    };
+
+    let name_or_fid = |stats: Option<&CompareStats>, fid: i64| {
+        stats
+            .map(|s| s.name.clone())
+            .unwrap_or_else(|| format!("FID {}", fid))
+    };
+
+    html! {
+        <div class="compare-report" style="
+            max-width: 800px;
+            margin: 0 auto;
+            padding: 24px 16px 40px 16px;
+            box-sizing: border-box;
+            color: white;
+        ">
+            <h2 style="
+                font-size: 28px;
+                font-weight: 700;
+                margin: 0 0 24px 0;
+                text-align: center;
+            ">{"2025 Head to Head"}</h2>
+
+            <div style="
+                display: grid;
+                grid-template-columns: 1fr 1fr;
+                gap: 16px;
+                margin-bottom: 24px;
+            ">
+                <div class="card" style="padding: 16px;">{render_side(&side_a, props.fid_a)}</div>
+                <div class="card" style="padding: 16px;">{render_side(&side_b, props.fid_b)}</div>
+            </div>
+
+            <div class="card" style="padding: 16px;">
+                {for rows.iter().map(|(label, value_a, value_b)| html! {
+                    <div style="
+                        display: grid;
+                        grid-template-columns: 1fr auto 1fr;
+                        align-items: center;
+                        gap: 12px;
+                        padding: 10px 0;
+                        border-bottom: 1px solid rgba(255, 255, 255, 0.1);
+                    ">
+                        <div style="text-align: center;">{render_value(*value_a, *value_b)}</div>
+                        <div style="
+                            font-size: 13px;
+                            text-transform: uppercase;
+                            letter-spacing: 1px;
+                            color: rgba(255, 255, 255, 0.7);
+                        ">{*label}</div>
+                        <div style="text-align: center;">{render_value(*value_b, *value_a)}</div>
+                    </div>
+                })}
+
+                {if stats_a.is_some() && stats_b.is_some() {
+                    let verdict = match wins_a.cmp(&wins_b) {
+                        Ordering::Greater => format!(
+                            "{} leads {}–{}",
+                            name_or_fid(stats_a, props.fid_a),
+                            wins_a,
+                            wins_b
+                        ),
+                        Ordering::Less => format!(
+                            "{} leads {}–{}",
+                            name_or_fid(stats_b, props.fid_b),
+                            wins_b,
+                            wins_a
+                        ),
+                        Ordering::Equal => "It's a tie!".to_string(),
+                    };
+                    html! {
+                        <p style="
+                            margin: 16px 0 0 0;
+                            text-align: center;
+                            font-size: 16px;
+                            font-weight: 600;
+                        ">{verdict}</p>
+                    }
+                } else {
+                    html! {}
+                }}
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,258 @@
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+
+use super::annual_report::utils::format_count;
+use crate::models::LeaderboardEntry;
+use crate::models::LeaderboardResponse;
+use crate::services::create_leaderboard_endpoint;
+use crate::services::make_request_with_payment;
+use crate::wallet::WalletAccount;
+
+/// Number of users shown on the leaderboard
+const LEADERBOARD_LIMIT: usize = 50;
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct LeaderboardPageProps {
+    pub api_url: String,
+    pub wallet_account: Option<WalletAccount>,
+    /// Open the user's profile (search by FID)
+    pub on_view_profile: Callback<i64>,
+    /// Open the user's annual report
+    pub on_view_report: Callback<i64>,
+}
+
+#[derive(Clone, PartialEq)]
+enum LeaderboardState {
+    Loading,
+    Loaded(Vec<LeaderboardEntry>),
+    Failed(String),
+}
+
+/// Fetch the top users ranked by `metric` ("casts" or "followers")
+pub async fn load_leaderboard(
+    api_url: &str,
+    wallet_account: Option<&WalletAccount>,
+    metric: &str,
+    limit: usize,
+) -> Result<Vec<LeaderboardEntry>, String> {
+    let endpoint = create_leaderboard_endpoint(metric, limit);
+    make_request_with_payment::<LeaderboardResponse>(
+        api_url,
+        &endpoint,
+        None,
+        wallet_account,
+        None,
+        None,
+    )
+    .await
+    .map(|resp| resp.entries)
+    .map_err(String::from)
+}
+
+/// Public leaderboard of the most active FIDs
+#[function_component]
+pub fn LeaderboardPage(props: &LeaderboardPageProps) -> Html {
+    let metric = use_state(|| "casts".to_string());
+    let state = use_state(|| LeaderboardState::Loading);
+
+    {
+        let state = state.clone();
+        let wallet_account = props.wallet_account.clone();
+        use_effect_with(
+            ((*metric).clone(), props.api_url.clone()),
+            move |(metric, api_url)| {
+                state.set(LeaderboardState::Loading);
+                let metric = metric.clone();
+                let api_url = api_url.clone();
+                spawn_local(async move {
+                    match load_leaderboard(&api_url, wallet_account.as_ref(), &metric, LEADERBOARD_LIMIT)
+                        .await
+                    {
+                        Ok(entries) => state.set(LeaderboardState::Loaded(entries)),
+                        Err(e) => {
+                            web_sys::console::warn_1(
+                                &format!("⚠️ Failed to load leaderboard: {}", e).into(),
+                            );
+                            state.set(LeaderboardState::Failed(e));
+                        }
+                    }
+                });
+                || ()
+            },
+        );
+    }
+
+    let render_metric_button = |value: &'static str, label: &'static str| {
+        let is_active = *metric == value;
+        let metric = metric.clone();
+        html! {
+            <button
+                onclick={Callback::from(move |_| metric.set(value.to_string()))}
+                style={format!("
+                    padding: 8px 16px;
+                    border-radius: 999px;
+                    border: 1px solid rgba(255, 255, 255, 0.4);
+                    background: {};
+                    color: {};
+                    font-size: 14px;
+                    font-weight: 600;
+                    cursor: pointer;
+                ",
+                    if is_active { "white" } else { "transparent" },
+                    if is_active { "#5a4fcf" } else { "white" }
+                )}
+            >
+                {label}
+            </button>
+        }
+    };
+
+    let show_followers = *metric == "followers";
+
+    html! {
+        <div class="leaderboard-page" style="
+            max-width: 640px;
+            margin: 0 auto;
+            padding: 24px 16px 100px 16px;
+            box-sizing: border-box;
+            color: white;
+        ">
+            <h2 style="
+                font-size: 28px;
+                font-weight: 700;
+                margin: 0 0 16px 0;
+                text-align: center;
+            ">{"Leaderboard"}</h2>
+
+            <div style="
+                display: flex;
+                justify-content: center;
+                gap: 8px;
+                margin-bottom: 20px;
+            ">
+                {render_metric_button("casts", "Most Casts")}
+                {render_metric_button("followers", "Most Followers")}
+            </div>
+
+            {match &*state {
+                LeaderboardState::Loading => html! {
+                    <div class="loading-container" style="padding: 24px; text-align: center;">
+                        <div class="skeleton-spinner"></div>
+                        <p>{"Loading leaderboard..."}</p>
+                    </div>
+                },
+                LeaderboardState::Failed(e) => html! {
+                    <div class="error-message" style="padding: 24px; text-align: center;">
+                        <p>{"Couldn't load the leaderboard"}</p>
+                        <p style="font-size: 13px; opacity: 0.8;">{e.clone()}</p>
+                    </div>
+                },
+                LeaderboardState::Loaded(entries) if entries.is_empty() => html! {
+                    <p style="text-align: center; opacity: 0.8;">{"No users to show yet"}</p>
+                },
+                LeaderboardState::Loaded(entries) => html! {
+                    <ol style="list-style: none; margin: 0; padding: 0;">
+                        {for entries.iter().enumerate().map(|(idx, entry)| {
+                            let fid = entry.fid;
+                            let name = entry
+                                .username
+                                .as_ref()
+                                .map(|u| format!("@{}", u))
+                                .or_else(|| entry.display_name.clone())
+                                .unwrap_or_else(|| format!("FID {}", fid));
+                            let value = if show_followers {
+                                format!("{} followers", format_count(entry.followers))
+                            } else {
+                                format!("{} casts", format_count(entry.total_casts))
+                            };
+                            let on_view_profile = props.on_view_profile.reform(move |_: MouseEvent| fid);
+                            let on_view_report = props.on_view_report.reform(move |_: MouseEvent| fid);
+
+                            html! {
+                                <li class="card" style="
+                                    display: flex;
+                                    align-items: center;
+                                    gap: 12px;
+                                    padding: 12px 16px;
+                                    margin-bottom: 8px;
+                                ">
+                                    <span style="
+                                        width: 28px;
+                                        font-size: 16px;
+                                        font-weight: 700;
+                                        text-align: center;
+                                        color: rgba(255, 255, 255, 0.8);
+                                    ">{idx + 1}</span>
+                                    {match entry.pfp_url.as_ref().filter(|url| !url.is_empty()) {
+                                        Some(url) => html! {
+                                            <img
+                                                src={url.clone()}
+                                                alt={name.clone()}
+                                                loading="lazy"
+                                                style="
+                                                    width: 40px;
+                                                    height: 40px;
+                                                    border-radius: 50%;
+                                                    object-fit: cover;
+                                                    flex-shrink: 0;
+                                                "
+                                            />
+                                        },
+                                        None => html! {
+                                            <div style="
+                                                width: 40px;
+                                                height: 40px;
+                                                border-radius: 50%;
+                                                background: rgba(255, 255, 255, 0.15);
+                                                display: flex;
+                                                align-items: center;
+                                                justify-content: center;
+                                                flex-shrink: 0;
+                                            ">{"👤"}</div>
+                                        },
+                                    }}
+                                    <div style="flex: 1; min-width: 0;">
+                                        <div style="
+                                            font-weight: 600;
+                                            overflow: hidden;
+                                            text-overflow: ellipsis;
+                                            white-space: nowrap;
+                                        ">{name}</div>
+                                        <div style="font-size: 13px; color: rgba(255, 255, 255, 0.7);">
+                                            {value}
+                                        </div>
+                                    </div>
+                                    <button
+                                        onclick={on_view_profile}
+                                        style="
+                                            background: none;
+                                            border: 1px solid rgba(255, 255, 255, 0.4);
+                                            border-radius: 8px;
+                                            color: white;
+                                            padding: 6px 10px;
+                                            font-size: 13px;
+                                            cursor: pointer;
+                                        "
+                                    >{"Profile"}</button>
+                                    <button
+                                        onclick={on_view_report}
+                                        style="
+                                            background: white;
+                                            border: none;
+                                            border-radius: 8px;
+                                            color: #5a4fcf;
+                                            padding: 6px 10px;
+                                            font-size: 13px;
+                                            font-weight: 600;
+                                            cursor: pointer;
+                                        "
+                                    >{"Report"}</button>
+                                </li>
+                            }
+                        })}
+                    </ol>
+                },
+            }}
+        </div>
+    }
+}
@@ -1,7 +1,11 @@
 pub mod about;
 pub mod annual_report;
+pub mod compare;
+pub mod leaderboard;
 // profile module removed - Profile tab now uses ProfileLoader
 
 // Re-export pages
 pub use about::AboutPage;
 pub use annual_report::AnnualReportPage;
+pub use compare::CompareReport;
+pub use leaderboard::LeaderboardPage;
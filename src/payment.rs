@@ -1,5 +1,30 @@
+use std::cell::Cell;
+use std::cell::RefCell;
+
 use serde::Deserialize;
 use serde::Serialize;
+use yew::prelude::*;
+
+/// x402 payments are settled in USDC, which has 6 decimals
+const USDC_DECIMALS: usize = 6;
+
+/// USDC contracts x402 servers ask for, by network; amounts in any other asset have
+/// unknown decimals and are shown raw
+const KNOWN_USDC_ASSETS: [(&str, &str); 2] = [
+    ("base", "0x833589fcd6edb6e08f4c7c32d4f71b54bda02913"),
+    ("base-sepolia", "0x036cbd53842c5426634e7929541ec2318f3dcf7e"),
+];
+
+/// localStorage key for the opt-in auto-pay threshold (atomic units)
+const AUTO_PAY_LIMIT_STORAGE_KEY: &str = "polyjuice_auto_pay_limit";
+
+thread_local! {
+    // Atomic units paid since the page was opened
+    static SESSION_PAID_TOTAL: Cell<u128> = const { Cell::new(0) };
+    // Components showing the session total, keyed by subscription id
+    static SESSION_TOTAL_LISTENERS: RefCell<Vec<(u32, Callback<u128>)>> = const { RefCell::new(Vec::new()) };
+    static NEXT_LISTENER_ID: Cell<u32> = const { Cell::new(0) };
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -16,6 +41,38 @@ pub struct PaymentRequirements {
     pub extra: Option<serde_json::Value>,
 }
 
+impl PaymentRequirements {
+    /// Whether `asset` is the USDC contract on `network`
+    pub fn is_usdc(&self) -> bool {
+        let asset = normalize_address(&self.asset);
+        KNOWN_USDC_ASSETS.iter().any(|(network, address)| {
+            self.network.eq_ignore_ascii_case(network) && asset == *address
+        })
+    }
+
+    /// Amount requested in atomic USDC units; None for other assets or an invalid amount
+    pub fn amount_atomic(&self) -> Option<u128> {
+        if !self.is_usdc() {
+            return None;
+        }
+        self.max_amount_required.trim().parse().ok()
+    }
+
+    /// Human-readable summary shown before paying, e.g. "0.01 USDC on base"
+    pub fn describe(&self) -> String {
+        let amount = self
+            .amount_atomic()
+            .map(format_usdc_amount)
+            .unwrap_or_else(|| {
+                format!(
+                    "{} atomic units of {}",
+                    self.max_amount_required, self.asset
+                )
+            });
+        format!("{} on {}", amount, self.network)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PaymentRequirementsResponse {
@@ -238,6 +295,177 @@ pub fn create_payment_payload(
     }
 }
 
+/// Format an atomic USDC amount, e.g. 10000 -> "0.01 USDC"
+pub fn format_usdc_amount(atomic: u128) -> String {
+    let scale = 10u128.pow(USDC_DECIMALS as u32);
+    let whole = atomic / scale;
+    let fraction = format!("{:0width$}", atomic % scale, width = USDC_DECIMALS);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        format!("{} USDC", whole)
+    } else {
+        format!("{}.{} USDC", whole, fraction)
+    }
+}
+
+/// Parse a user-entered USDC amount ("0.05", "$1", "2.5 USDC") into atomic units
+pub fn parse_usdc_amount(input: &str) -> Option<u128> {
+    let input = input.trim().trim_start_matches('$');
+    let input = input
+        .strip_suffix("USDC")
+        .or_else(|| input.strip_suffix("usdc"))
+        .unwrap_or(input)
+        .trim();
+    let (whole, fraction) = input.split_once('.').unwrap_or((input, ""));
+    if (whole.is_empty() && fraction.is_empty())
+        || fraction.len() > USDC_DECIMALS
+        || !whole
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+    let whole: u128 = if whole.is_empty() {
+        0
+    } else {
+        whole.parse().ok()?
+    };
+    let fraction: u128 = format!("{:0<width$}", fraction, width = USDC_DECIMALS)
+        .parse()
+        .ok()?;
+    whole
+        .checked_mul(10u128.pow(USDC_DECIMALS as u32))?
+        .checked_add(fraction)
+}
+
+fn get_storage() -> Result<web_sys::Storage, String> {
+    web_sys::window()
+        .ok_or("No window available".to_string())?
+        .local_storage()
+        .map_err(|_| "Failed to get localStorage".to_string())?
+        .ok_or("localStorage not available".to_string())
+}
+
+/// Auto-pay threshold in atomic units; `None` means every payment is confirmed
+pub fn load_auto_pay_limit() -> Option<u128> {
+    get_storage()
+        .ok()?
+        .get_item(AUTO_PAY_LIMIT_STORAGE_KEY)
+        .ok()
+        .flatten()?
+        .parse()
+        .ok()
+}
+
+/// Save (or clear with `None`) the auto-pay threshold
+pub fn save_auto_pay_limit(limit: Option<u128>) -> Result<(), String> {
+    let storage = get_storage()?;
+    match limit {
+        Some(limit) => storage
+            .set_item(AUTO_PAY_LIMIT_STORAGE_KEY, &limit.to_string())
+            .map_err(|_| "Failed to save auto-pay limit to localStorage".to_string()),
+        None => storage
+            .remove_item(AUTO_PAY_LIMIT_STORAGE_KEY)
+            .map_err(|_| "Failed to clear auto-pay limit".to_string()),
+    }
+}
+
+/// Decide whether a 402 may be paid: USDC amounts within the auto-pay limit go through,
+/// anything else needs the user to confirm the amount and asset in the in-app dialog
+pub async fn approve_payment(requirements: &PaymentRequirements) -> Result<(), String> {
+    let summary = requirements.describe();
+    let within_limit = match (requirements.amount_atomic(), load_auto_pay_limit()) {
+        (Some(amount), Some(limit)) => amount <= limit,
+        _ => false,
+    };
+    if within_limit {
+        web_sys::console::log_1(&format!("💳 Auto-paying {}", summary).into());
+        return Ok(());
+    }
+
+    let message = format!(
+        "This request costs {}.\n\nAsset: {}\nPay to: {}\n{}\nPaid this session: {}",
+        summary,
+        requirements.asset,
+        requirements.pay_to,
+        requirements.description,
+        format_usdc_amount(session_paid_total()),
+    );
+    let confirmed = crate::components::confirm(crate::components::ConfirmRequest {
+        title: "Sign the payment?".to_string(),
+        message,
+        confirm_label: "Sign payment".to_string(),
+    })
+    .await;
+    if confirmed {
+        Ok(())
+    } else {
+        web_sys::console::log_1(&format!("🚫 Payment of {} declined", summary).into());
+        Err("Payment cancelled".to_string())
+    }
+}
+
+/// Total paid during this page session, in atomic units
+pub fn session_paid_total() -> u128 {
+    SESSION_PAID_TOTAL.with(|total| total.get())
+}
+
+/// Add a settled USDC payment to the session total and notify listeners
+pub fn record_payment(requirements: &PaymentRequirements) {
+    let Some(amount) = requirements.amount_atomic() else {
+        return;
+    };
+    let total = SESSION_PAID_TOTAL.with(|total| {
+        total.set(total.get().saturating_add(amount));
+        total.get()
+    });
+    web_sys::console::log_1(
+        &format!(
+            "💳 Paid {} (session total: {})",
+            format_usdc_amount(amount),
+            format_usdc_amount(total)
+        )
+        .into(),
+    );
+    let listeners: Vec<Callback<u128>> = SESSION_TOTAL_LISTENERS.with(|listeners| {
+        listeners
+            .borrow()
+            .iter()
+            .map(|(_, cb)| cb.clone())
+            .collect()
+    });
+    for listener in listeners {
+        listener.emit(total);
+    }
+}
+
+/// Session payment total that re-renders the component whenever a payment settles
+#[hook]
+pub fn use_session_paid_total() -> u128 {
+    let total = use_state(session_paid_total);
+    {
+        let total = total.clone();
+        use_effect_with((), move |_| {
+            let id = NEXT_LISTENER_ID.with(|next| {
+                let id = next.get();
+                next.set(id.wrapping_add(1));
+                id
+            });
+            SESSION_TOTAL_LISTENERS.with(|listeners| {
+                listeners
+                    .borrow_mut()
+                    .push((id, Callback::from(move |value| total.set(value))))
+            });
+            move || {
+                SESSION_TOTAL_LISTENERS
+                    .with(|listeners| listeners.borrow_mut().retain(|(other, _)| *other != id));
+            }
+        });
+    }
+    *total
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -248,4 +476,52 @@ mod tests {
         assert_eq!(normalize_address("ABCD1234"), "0xabcd1234");
         assert_eq!(normalize_address("0X1234"), "0x1234");
     }
+
+    #[test]
+    fn test_format_usdc_amount() {
+        assert_eq!(format_usdc_amount(0), "0 USDC");
+        assert_eq!(format_usdc_amount(10_000), "0.01 USDC");
+        assert_eq!(format_usdc_amount(1_500_000), "1.5 USDC");
+        assert_eq!(format_usdc_amount(2_000_000), "2 USDC");
+        assert_eq!(format_usdc_amount(1), "0.000001 USDC");
+    }
+
+    #[test]
+    fn test_describe_payment_asset() {
+        let mut requirements = PaymentRequirements {
+            scheme: "exact".to_string(),
+            network: "base".to_string(),
+            max_amount_required: "10000".to_string(),
+            asset: "0x833589fCD6eDb6E08f4c7C32D4f71b54bdA02913".to_string(),
+            pay_to: "0xabc".to_string(),
+            resource: String::new(),
+            description: String::new(),
+            mime_type: None,
+            max_timeout_seconds: None,
+            extra: None,
+        };
+        assert_eq!(requirements.amount_atomic(), Some(10_000));
+        assert_eq!(requirements.describe(), "0.01 USDC on base");
+
+        // USDC's Base address on another network, or another token: decimals unknown
+        requirements.network = "polygon".to_string();
+        assert_eq!(requirements.amount_atomic(), None);
+        assert_eq!(
+            requirements.describe(),
+            "10000 atomic units of 0x833589fCD6eDb6E08f4c7C32D4f71b54bdA02913 on polygon"
+        );
+    }
+
+    #[test]
+    fn test_parse_usdc_amount() {
+        assert_eq!(parse_usdc_amount("0.05"), Some(50_000));
+        assert_eq!(parse_usdc_amount("$1"), Some(1_000_000));
+        assert_eq!(parse_usdc_amount(" 2.5 USDC "), Some(2_500_000));
+        assert_eq!(parse_usdc_amount(".1"), Some(100_000));
+        assert_eq!(parse_usdc_amount("0.0000001"), None);
+        assert_eq!(parse_usdc_amount("abc"), None);
+        assert_eq!(parse_usdc_amount("-1"), None);
+        assert_eq!(parse_usdc_amount(""), None);
+        assert_eq!(parse_usdc_amount("."), None);
+    }
 }
@@ -1,3 +1,5 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::rc::Rc;
 
 use wasm_bindgen::JsCast;
@@ -8,7 +10,77 @@ use crate::models::*;
 use crate::payment::PaymentRequirements;
 use crate::wallet::WalletAccount;
 
+/// Seconds before `validBefore` after which a stored payment proof is no longer reused
+const PAYMENT_RECEIPT_EXPIRY_MARGIN_SECS: u64 = 5;
+
+/// A signed payment header whose request never got a response
+#[derive(Clone)]
+struct PaymentReceipt {
+    header: String,
+    valid_before: u64,
+}
+
+thread_local! {
+    // In-flight payment proofs keyed by `payment_receipt_key`
+    static PAYMENT_RECEIPTS: RefCell<HashMap<String, PaymentReceipt>> = RefCell::new(HashMap::new());
+}
+
+/// Key identifying a paid request: the same method, URL and body reuse the same proof
+pub fn payment_receipt_key(api_url: &str, endpoint: &EndpointInfo, body: Option<&str>) -> String {
+    format!(
+        "{} {}{}\n{}",
+        endpoint.method,
+        api_url,
+        endpoint.path,
+        body.unwrap_or("")
+    )
+}
+
+/// Whether a proof valid until `valid_before` (unix seconds) can still be presented at `now`
+pub fn payment_receipt_is_reusable(valid_before: u64, now: u64) -> bool {
+    now + PAYMENT_RECEIPT_EXPIRY_MARGIN_SECS < valid_before
+}
+
+fn reusable_payment_receipt(key: &str) -> Option<String> {
+    let now = crate::payment::get_timestamp();
+    PAYMENT_RECEIPTS.with(|receipts| {
+        let mut receipts = receipts.borrow_mut();
+        receipts.retain(|_, receipt| payment_receipt_is_reusable(receipt.valid_before, now));
+        receipts.get(key).map(|receipt| receipt.header.clone())
+    })
+}
+
+fn store_payment_receipt(key: String, receipt: PaymentReceipt) {
+    PAYMENT_RECEIPTS.with(|receipts| receipts.borrow_mut().insert(key, receipt));
+}
+
+fn forget_payment_receipt(key: &str) {
+    PAYMENT_RECEIPTS.with(|receipts| receipts.borrow_mut().remove(key));
+}
+
+/// Send the request with a payment header and settle the stored receipt:
+/// a response (even a rejection) consumes it, a network failure keeps it for the retry
+async fn send_with_payment_receipt(
+    api_url: &str,
+    endpoint: &EndpointInfo,
+    body: Option<String>,
+    receipt_key: &str,
+    payment_header: String,
+) -> Result<RawApiResponse, String> {
+    let result = crate::api::make_request(api_url, endpoint, body, Some(payment_header))
+        .await
+        .map_err(|e| format!("Request with payment failed: {}", e));
+    if result.is_ok() {
+        forget_payment_receipt(receipt_key);
+    }
+    result
+}
+
 /// Handle payment flow: sign and retry request with payment header
+///
+/// If an earlier attempt signed a payment but never got a response, the stored proof
+/// is presented again instead of signing (and paying) a second time. Only a fresh
+/// signature asks for approval, and only its successful response counts as a payment
 pub async fn handle_payment(
     requirements: &PaymentRequirements,
     account: &WalletAccount,
@@ -16,6 +88,27 @@ pub async fn handle_payment(
     endpoint: &EndpointInfo,
     body: Option<String>,
 ) -> Result<RawApiResponse, String> {
+    let receipt_key = payment_receipt_key(api_url, endpoint, body.as_deref());
+    if let Some(payment_header) = reusable_payment_receipt(&receipt_key) {
+        web_sys::console::log_1(&"♻️ Retrying with the existing payment proof...".into());
+        let resp = send_with_payment_receipt(
+            api_url,
+            endpoint,
+            body.clone(),
+            &receipt_key,
+            payment_header,
+        )
+        .await?;
+        if resp.status != 402 {
+            return Ok(resp);
+        }
+        // The server no longer accepts that proof (already settled or expired): pay afresh
+        web_sys::console::log_1(&"⚠️ Existing payment proof rejected, signing a new one".into());
+    }
+
+    // Show the amount and ask unless it's within the auto-pay limit
+    crate::payment::approve_payment(requirements).await?;
+
     // Generate nonce and timestamp
     let nonce = crate::payment::generate_nonce();
     let timestamp = crate::payment::get_timestamp();
@@ -80,17 +173,34 @@ pub async fn handle_payment(
         .into(),
     );
 
+    // Keep the proof until the server answers so a retry after a network failure reuses it
+    let valid_before = payment_payload
+        .payload
+        .authorization
+        .valid_before
+        .parse()
+        .unwrap_or(timestamp);
+    store_payment_receipt(
+        receipt_key.clone(),
+        PaymentReceipt {
+            header: payment_header.clone(),
+            valid_before,
+        },
+    );
+
     // Retry request with payment
     web_sys::console::log_1(&"🔄 Retrying request with payment header...".into());
-    let result = crate::api::make_request(api_url, endpoint, body, Some(payment_header))
-        .await
-        .map_err(|e| format!("Request with payment failed: {}", e));
+    let result =
+        send_with_payment_receipt(api_url, endpoint, body, &receipt_key, payment_header).await;
 
     match &result {
         Ok(resp) => {
             web_sys::console::log_1(
                 &format!("✅ Request with payment succeeded: status {}", resp.status).into(),
             );
+            if (200..300).contains(&resp.status) {
+                crate::payment::record_payment(requirements);
+            }
         }
         Err(e) => {
             web_sys::console::log_1(&format!("❌ Request with payment failed: {}", e).into());
@@ -169,7 +279,15 @@ where
             .into(),
         );
 
-        match crate::api::make_request(api_url, endpoint, body.clone(), None).await {
+        match crate::api::make_request_with_timeout(
+            api_url,
+            endpoint,
+            body.clone(),
+            None,
+            crate::api::POLL_REQUEST_TIMEOUT_MS,
+        )
+        .await
+        {
             Ok(resp) => {
                 if resp.status == 200 {
                     // Parse response - could be pending status or actual data
@@ -381,6 +499,36 @@ where
 /// Callback function type for notifying about job status
 pub type StatusCallback = Rc<Box<dyn Fn(String, String, String)>>; // (status, job_key, message) - Fn allows multiple calls, Rc allows cloning
 
+/// Failure from `make_request_with_payment`
+#[derive(Debug, Clone, PartialEq)]
+pub enum RequestError {
+    /// HTTP 404: the profile/FID doesn't exist, so there's no point retrying
+    NotFound,
+    /// Any other failure, as a message for the UI
+    Failed(String),
+}
+
+impl RequestError {
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, RequestError::NotFound)
+    }
+}
+
+impl std::fmt::Display for RequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RequestError::NotFound => write!(f, "Request failed with status: 404"),
+            RequestError::Failed(message) => f.write_str(message),
+        }
+    }
+}
+
+impl From<RequestError> for String {
+    fn from(error: RequestError) -> Self {
+        error.to_string()
+    }
+}
+
 pub async fn make_request_with_payment<T>(
     api_url: &str,
     endpoint: &EndpointInfo,
@@ -388,12 +536,43 @@ pub async fn make_request_with_payment<T>(
     wallet_account: Option<&WalletAccount>,
     on_polling_start: Option<Box<dyn FnOnce()>>,
     on_status_detected: Option<StatusCallback>,
-) -> Result<T, String>
+) -> Result<T, RequestError>
 where
     T: serde::de::DeserializeOwned,
 {
     // First attempt without payment
-    match crate::api::make_request(api_url, endpoint, body.clone(), None).await {
+    let first = crate::api::make_request(api_url, endpoint, body.clone(), None).await;
+    if matches!(&first, Ok(resp) if resp.status == 404) {
+        // Distinguish "no such user" from other failures so callers don't offer retries
+        return Err(RequestError::NotFound);
+    }
+    handle_first_response(
+        first,
+        api_url,
+        endpoint,
+        body,
+        wallet_account,
+        on_polling_start,
+        on_status_detected,
+    )
+    .await
+    .map_err(RequestError::Failed)
+}
+
+/// Pay, poll or parse depending on the unpaid first response
+async fn handle_first_response<T>(
+    first: Result<crate::api::ApiResponse, String>,
+    api_url: &str,
+    endpoint: &EndpointInfo,
+    body: Option<String>,
+    wallet_account: Option<&WalletAccount>,
+    on_polling_start: Option<Box<dyn FnOnce()>>,
+    on_status_detected: Option<StatusCallback>,
+) -> Result<T, String>
+where
+    T: serde::de::DeserializeOwned,
+{
+    match first {
         Ok(resp) => {
             // Check if payment is required (402)
             if resp.status == 402 {
@@ -670,6 +849,433 @@ where
     }
 }
 
+/// Default API server, from the build-time SNAPRAG_API_URL or the public instance
+pub fn default_api_url() -> String {
+    option_env!("SNAPRAG_API_URL")
+        .unwrap_or("https://snaprag.0xbase.ai")
+        .trim_end_matches('/')
+        .to_string()
+}
+
+/// Showcase FID whose report is offered as an example
+/// A `<meta name="polyjuice:demo-fid">` tag in the served page wins over the build-time DEMO_FID
+pub fn demo_fid() -> Option<i64> {
+    let runtime = web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|doc| doc.query_selector(DEMO_FID_META_SELECTOR).ok().flatten())
+        .and_then(|meta| meta.get_attribute("content"));
+    match runtime {
+        Some(value) => parse_demo_fid(Some(&value)),
+        None => parse_demo_fid(option_env!("DEMO_FID")),
+    }
+}
+
+/// Runtime override for DEMO_FID; an empty `content` disables the demo
+const DEMO_FID_META_SELECTOR: &str = r#"meta[name="polyjuice:demo-fid"]"#;
+
+/// Parse a DEMO_FID value; unset, empty, or non-positive disables the demo
+pub fn parse_demo_fid(raw: Option<&str>) -> Option<i64> {
+    raw.and_then(|value| value.trim().parse::<i64>().ok())
+        .filter(|fid| *fid > 0)
+}
+
+/// localStorage key for an API server set from the settings panel
+const API_URL_STORAGE_KEY: &str = "polyjuice_api_url";
+
+/// Validate a user-entered API server URL and normalize it (trimmed, no trailing slash)
+pub fn normalize_api_url(input: &str) -> Result<String, String> {
+    let url = input.trim().trim_end_matches('/');
+    let host = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .ok_or("Invalid URL format. Must start with http:// or https://".to_string())?;
+    let host = host.split('/').next().unwrap_or("");
+    if host.is_empty() || host.contains(char::is_whitespace) {
+        return Err("URL is missing a host name".to_string());
+    }
+    Ok(url.to_string())
+}
+
+/// API server saved from the settings panel, if any
+pub fn load_saved_api_url() -> Option<String> {
+    web_sys::window()?
+        .local_storage()
+        .ok()
+        .flatten()?
+        .get_item(API_URL_STORAGE_KEY)
+        .ok()
+        .flatten()
+        .and_then(|url| normalize_api_url(&url).ok())
+}
+
+/// Persist the active API server; `None` goes back to `default_api_url`
+pub fn save_api_url(url: Option<&str>) -> Result<(), String> {
+    let storage = web_sys::window()
+        .ok_or("No window available".to_string())?
+        .local_storage()
+        .map_err(|_| "Failed to get localStorage".to_string())?
+        .ok_or("localStorage not available".to_string())?;
+    match url {
+        Some(url) => storage
+            .set_item(API_URL_STORAGE_KEY, url)
+            .map_err(|_| "Failed to save API URL to localStorage".to_string()),
+        None => storage
+            .remove_item(API_URL_STORAGE_KEY)
+            .map_err(|_| "Failed to clear saved API URL".to_string()),
+    }
+}
+
+/// How long a successful health check is trusted before pinging again
+const ENDPOINT_HEALTH_TTL_MS: f64 = 5.0 * 60.0 * 1000.0;
+
+thread_local! {
+    // Last successful ping time (ms since epoch) per endpoint URL
+    static ENDPOINT_LAST_GOOD: RefCell<HashMap<String, f64>> = RefCell::new(HashMap::new());
+}
+
+/// Check that an endpoint is reachable, pinging it only if it hasn't been
+/// successfully pinged within ENDPOINT_HEALTH_TTL_MS
+pub async fn check_endpoint_health(api_url: &str) -> Result<(), String> {
+    let now = js_sys::Date::now();
+    let last_good = ENDPOINT_LAST_GOOD.with(|cache| cache.borrow().get(api_url).copied());
+    if let Some(last_good) = last_good {
+        if now - last_good < ENDPOINT_HEALTH_TTL_MS {
+            return Ok(());
+        }
+    }
+
+    crate::wallet::ping_endpoint_service(api_url).await?;
+    ENDPOINT_LAST_GOOD.with(|cache| {
+        cache
+            .borrow_mut()
+            .insert(api_url.to_string(), js_sys::Date::now())
+    });
+    Ok(())
+}
+
+/// How long a resolved FID -> username/avatar entry is trusted
+const USERNAME_CACHE_TTL_MS: f64 = 30.0 * 60.0 * 1000.0;
+
+/// Profile lookups `resolve_profiles` runs at once
+const PROFILE_RESOLVE_CONCURRENCY: usize = 6;
+
+/// The parts of a profile shown next to a FID (reactor bubbles, search, chat, report)
+#[derive(Debug, Clone, PartialEq)]
+pub struct CachedProfile {
+    pub fid: i64,
+    pub username: Option<String>,
+    pub display_name: Option<String>,
+    pub pfp_url: Option<String>,
+}
+
+/// Most FIDs sent in one batch profile request
+const PROFILES_BATCH_MAX: usize = 50;
+
+thread_local! {
+    // FID -> (profile, time it was stored in ms since epoch)
+    static USERNAME_CACHE: RefCell<HashMap<i64, (CachedProfile, f64)>> = RefCell::new(HashMap::new());
+    // API URLs whose backend has no batch profile route; they get single lookups only
+    static PROFILES_BATCH_UNSUPPORTED: RefCell<std::collections::HashSet<String>> =
+        RefCell::new(std::collections::HashSet::new());
+}
+
+/// Whether a cache entry stored at `stored_at` is still usable at `now` (both ms since epoch)
+pub fn username_cache_entry_is_fresh(stored_at: f64, now: f64) -> bool {
+    now >= stored_at && now - stored_at < USERNAME_CACHE_TTL_MS
+}
+
+/// Cached username/avatar for a FID, if resolved within the TTL
+pub fn cached_profile(fid: i64) -> Option<CachedProfile> {
+    let now = js_sys::Date::now();
+    USERNAME_CACHE.with(|cache| {
+        cache
+            .borrow()
+            .get(&fid)
+            .filter(|(_, stored_at)| username_cache_entry_is_fresh(*stored_at, now))
+            .map(|(profile, _)| profile.clone())
+    })
+}
+
+/// Store a profile we already have (e.g. from a report payload) so later lookups skip the API
+/// Entries without a username or avatar aren't worth caching
+pub fn cache_profile(profile: CachedProfile) {
+    if profile.username.is_none() && profile.pfp_url.is_none() {
+        return;
+    }
+    let now = js_sys::Date::now();
+    USERNAME_CACHE.with(|cache| cache.borrow_mut().insert(profile.fid, (profile, now)));
+}
+
+/// FIDs from `fids` that still need a lookup: valid, deduplicated, in order, and not `cached`
+pub fn fids_to_resolve(fids: &[i64], cached: impl Fn(i64) -> bool) -> Vec<i64> {
+    let mut seen = std::collections::HashSet::new();
+    fids.iter()
+        .copied()
+        .filter(|fid| *fid > 0 && seen.insert(*fid) && !cached(*fid))
+        .collect()
+}
+
+impl From<ProfileData> for CachedProfile {
+    fn from(profile: ProfileData) -> Self {
+        CachedProfile {
+            fid: profile.fid,
+            username: profile.username,
+            display_name: profile.display_name,
+            pfp_url: profile.pfp_url,
+        }
+    }
+}
+
+/// Resolve usernames/avatars for `fids`, filling the username cache
+/// Cached FIDs are answered without a request. The rest go through the batch profile route,
+/// or concurrent single lookups (a few at a time) when the backend has no batch route or a
+/// batch fails. Lookups never pay: a profile behind a 402 is simply left out, as are failed
+/// lookups and FIDs the batch didn't return
+pub async fn resolve_profiles(api_url: &str, fids: &[i64]) -> HashMap<i64, CachedProfile> {
+    let mut resolved: HashMap<i64, CachedProfile> = fids
+        .iter()
+        .filter_map(|fid| cached_profile(*fid).map(|profile| (*fid, profile)))
+        .collect();
+    let missing = fids_to_resolve(fids, |fid| resolved.contains_key(&fid));
+
+    let batch_supported =
+        !PROFILES_BATCH_UNSUPPORTED.with(|unsupported| unsupported.borrow().contains(api_url));
+    let mut singles = Vec::new();
+    if batch_supported {
+        for chunk in missing.chunks(PROFILES_BATCH_MAX) {
+            let endpoint = create_profiles_batch_endpoint(chunk);
+            match make_request_with_payment::<ProfilesBatchResponse>(
+                api_url, &endpoint, None, None, None, None,
+            )
+            .await
+            {
+                Ok(response) => {
+                    for profile in response.into_profiles() {
+                        if chunk.contains(&profile.fid) {
+                            let profile = CachedProfile::from(profile);
+                            cache_profile(profile.clone());
+                            resolved.insert(profile.fid, profile);
+                        }
+                    }
+                }
+                Err(e) => {
+                    if e.is_not_found() {
+                        web_sys::console::log_1(
+                            &"ℹ️ No batch profile route, using single lookups".into(),
+                        );
+                        PROFILES_BATCH_UNSUPPORTED.with(|unsupported| {
+                            unsupported.borrow_mut().insert(api_url.to_string())
+                        });
+                    } else {
+                        web_sys::console::warn_1(
+                            &format!("⚠️ Batch profile lookup failed: {}", e).into(),
+                        );
+                    }
+                    singles.extend_from_slice(chunk);
+                }
+            }
+        }
+    } else {
+        singles = missing;
+    }
+
+    for chunk in singles.chunks(PROFILE_RESOLVE_CONCURRENCY) {
+        let lookups = chunk.iter().map(|fid| async move {
+            let endpoint = create_profile_endpoint(&fid.to_string(), true);
+            let result = make_request_with_payment::<ProfileData>(
+                api_url, &endpoint, None, None, None, None,
+            )
+            .await;
+            (*fid, result)
+        });
+        for (fid, result) in futures::future::join_all(lookups).await {
+            match result {
+                Ok(profile) => {
+                    let profile = CachedProfile {
+                        fid,
+                        ..CachedProfile::from(profile)
+                    };
+                    cache_profile(profile.clone());
+                    resolved.insert(fid, profile);
+                }
+                Err(e) => {
+                    web_sys::console::warn_1(
+                        &format!("⚠️ Couldn't resolve profile for FID {}: {}", fid, e).into(),
+                    );
+                }
+            }
+        }
+    }
+
+    resolved
+}
+
+/// Append a `t={stamp}` query param so browsers and CDNs can't serve a stale copy
+pub fn with_cache_buster(url: &str, stamp: u64) -> String {
+    let separator = if url.contains('?') { '&' } else { '?' };
+    format!("{}{}t={}", url, separator, stamp)
+}
+
+/// How long a download's object URL stays valid after the anchor click
+const BLOB_URL_REVOKE_DELAY_MS: u32 = 1_000;
+
+/// Save `bytes` as a file: wraps them in a Blob, clicks a hidden `download` anchor, then
+/// revokes the object URL once the browser has had time to start the download
+pub fn blob_download(
+    filename: &str,
+    bytes: &[u8],
+    mime: &str,
+) -> Result<(), wasm_bindgen::JsValue> {
+    let window = web_sys::window().ok_or("No window available")?;
+    let document = window.document().ok_or("No document available")?;
+    let body = document.body().ok_or("No document body available")?;
+
+    let parts = js_sys::Array::of1(&js_sys::Uint8Array::from(bytes));
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type(mime);
+    let blob = web_sys::Blob::new_with_u8_array_sequence_and_options(&parts, &options)?;
+    let object_url = web_sys::Url::create_object_url_with_blob(&blob)?;
+
+    let result = (|| {
+        let anchor: web_sys::HtmlAnchorElement = document.create_element("a")?.dyn_into()?;
+        anchor.set_href(&object_url);
+        anchor.set_download(filename);
+        anchor.style().set_property("display", "none")?;
+        body.append_child(&anchor)?;
+        anchor.click();
+        body.remove_child(&anchor)?;
+        Ok(())
+    })();
+
+    // Revoke even if the click failed so the blob isn't leaked; revoking right after the
+    // click can cancel the download in some browsers, so wait a moment first
+    gloo_timers::callback::Timeout::new(BLOB_URL_REVOKE_DELAY_MS, move || {
+        let _ = web_sys::Url::revoke_object_url(&object_url);
+    })
+    .forget();
+    result
+}
+
+/// Copy text to the clipboard: Clipboard API first, `execCommand("copy")` as a fallback
+pub async fn copy_to_clipboard_async(text: &str) -> bool {
+    let Some(window) = web_sys::window() else {
+        return false;
+    };
+
+    // Try modern Clipboard API first using js_sys::Reflect
+    if let Ok(navigator_val) = js_sys::Reflect::get(&window, &"navigator".into()) {
+        if !navigator_val.is_null() && !navigator_val.is_undefined() {
+            if let Ok(clipboard_val) = js_sys::Reflect::get(&navigator_val, &"clipboard".into()) {
+                if !clipboard_val.is_null() && !clipboard_val.is_undefined() {
+                    if let Ok(write_text_fn) =
+                        js_sys::Reflect::get(&clipboard_val, &"writeText".into())
+                    {
+                        if let Some(write_fn) = write_text_fn.dyn_ref::<js_sys::Function>() {
+                            if let Ok(promise_val) = write_fn.call1(&clipboard_val, &text.into()) {
+                                if let Ok(promise) = promise_val.dyn_into::<js_sys::Promise>() {
+                                    match wasm_bindgen_futures::JsFuture::from(promise).await {
+                                        Ok(_) => {
+                                            web_sys::console::log_1(
+                                                &"✅ Text copied using Clipboard API".into(),
+                                            );
+                                            return true;
+                                        }
+                                        Err(e) => {
+                                            web_sys::console::warn_1(
+                                                &format!("⚠️ Clipboard API failed: {:?}", e).into(),
+                                            );
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // Fallback: use document.execCommand
+    let Some(document) = window.document() else {
+        return false;
+    };
+    let Some(body) = document.body() else {
+        return false;
+    };
+    let Ok(textarea) = document.create_element("textarea") else {
+        return false;
+    };
+    let textarea_js: &wasm_bindgen::JsValue = textarea.as_ref();
+
+    if js_sys::Reflect::set(textarea_js, &"value".into(), &text.into()).is_err() {
+        return false;
+    }
+
+    let style = match js_sys::Reflect::get(textarea_js, &"style".into()) {
+        Ok(s) => s,
+        Err(_) => return false,
+    };
+
+    js_sys::Reflect::set(&style, &"position".into(), &"fixed".into()).ok();
+    js_sys::Reflect::set(&style, &"left".into(), &"-9999px".into()).ok();
+
+    if body.append_child(&textarea).is_err() {
+        return false;
+    }
+
+    js_sys::Reflect::get(textarea_js, &"select".into())
+        .and_then(|f| js_sys::Function::from(f).call0(textarea_js))
+        .ok();
+
+    let success = js_sys::Reflect::get(&document, &"execCommand".into())
+        .and_then(|f| {
+            js_sys::Function::from(f)
+                .call2(&document, &"copy".into(), &wasm_bindgen::JsValue::FALSE)
+                .map(|_| true)
+        })
+        .unwrap_or(false);
+
+    body.remove_child(&textarea).ok();
+    success
+}
+
+/// Whether the page was opened with `?debug=1` (enables support/dev tools)
+pub fn is_debug_mode() -> bool {
+    web_sys::window()
+        .and_then(|w| w.location().search().ok())
+        .map(|search| query_has_debug_flag(&search))
+        .unwrap_or(false)
+}
+
+/// Check a `location.search` string for `debug=1`
+pub fn query_has_debug_flag(search: &str) -> bool {
+    search
+        .trim_start_matches('?')
+        .split('&')
+        .any(|pair| pair == "debug=1" || pair == "debug=true")
+}
+
+/// Snapshot of app state for bug reports, copied by the `?debug=1` "Copy debug info" button
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DebugBundle {
+    pub app_version: &'static str,
+    pub page_url: Option<String>,
+    pub user_agent: Option<String>,
+    pub api_url: String,
+    pub selected_endpoint: Option<String>,
+    /// (endpoint, latency in ms or None if unreachable)
+    pub ping_results: Vec<(String, Option<f64>)>,
+    pub is_farcaster_env: bool,
+    pub resolved_fid: Option<i64>,
+    pub last_error: Option<String>,
+}
+
+impl DebugBundle {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_else(|e| format!("{{\"error\": \"{}\"}}", e))
+    }
+}
+
 /// Create profile endpoint info
 pub fn create_profile_endpoint(search_query: &str, is_fid: bool) -> EndpointInfo {
     EndpointInfo {
@@ -687,6 +1293,25 @@ pub fn create_profile_endpoint(search_query: &str, is_fid: bool) -> EndpointInfo
     }
 }
 
+/// Create batch profile endpoint info (`fids` joined as a comma-separated list)
+/// Callers keep batches to PROFILES_BATCH_MAX FIDs
+pub fn create_profiles_batch_endpoint(fids: &[i64]) -> EndpointInfo {
+    let fids = fids
+        .iter()
+        .map(|fid| fid.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+    EndpointInfo {
+        path: format!("/api/profiles/batch?fids={}", fids),
+        method: "GET".to_string(),
+        name: "Get Profiles".to_string(),
+        description: "Get several user profiles at once".to_string(),
+        tier: "Basic".to_string(),
+        requires_payment: true,
+        default_body: None,
+    }
+}
+
 /// Create social data endpoint info
 pub fn create_social_endpoint(search_query: &str, is_fid: bool) -> EndpointInfo {
     EndpointInfo {
@@ -704,6 +1329,20 @@ pub fn create_social_endpoint(search_query: &str, is_fid: bool) -> EndpointInfo
     }
 }
 
+/// Create leaderboard endpoint info
+/// `metric` is "casts" or "followers"
+pub fn create_leaderboard_endpoint(metric: &str, limit: usize) -> EndpointInfo {
+    EndpointInfo {
+        path: format!("/api/leaderboard?metric={}&limit={}", metric, limit),
+        method: "GET".to_string(),
+        name: "Get Leaderboard".to_string(),
+        description: "Get most active users".to_string(),
+        tier: "Basic".to_string(),
+        requires_payment: false,
+        default_body: None,
+    }
+}
+
 /// Create chat session endpoint info
 pub fn create_chat_session_endpoint() -> EndpointInfo {
     EndpointInfo {
@@ -730,6 +1369,244 @@ pub fn create_chat_message_endpoint() -> EndpointInfo {
     }
 }
 
+/// Create streaming chat message endpoint info (server-sent events)
+pub fn create_chat_stream_endpoint() -> EndpointInfo {
+    EndpointInfo {
+        path: "/api/chat/message/stream".to_string(),
+        method: "POST".to_string(),
+        name: "Stream Chat Message".to_string(),
+        description: "Send chat message and stream the reply".to_string(),
+        tier: "Premium".to_string(),
+        requires_payment: true,
+        default_body: None,
+    }
+}
+
+/// Outcome of `stream_chat`
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChatStreamOutcome {
+    /// The reply was streamed through the callback
+    Completed,
+    /// The server answered with a plain (non-SSE) body holding the whole reply
+    Reply(String),
+    /// The server has no streaming endpoint (404/405) or wants a payment first (402);
+    /// nothing was sent to the model, so the caller should use the buffered endpoint
+    Unsupported,
+}
+
+/// The reply text from a non-streamed chat body: an `ApiResponse` wrapping a chat
+/// message, a bare `{"message": ...}`-style object, a JSON string, or plain text
+pub fn parse_chat_reply_body(body: &str) -> Result<String, String> {
+    let value = match serde_json::from_str::<serde_json::Value>(body) {
+        Ok(value) => value,
+        Err(_) => return Ok(body.trim().to_string()),
+    };
+    let reply = |obj: &serde_json::Map<String, serde_json::Value>| {
+        ["message", "content", "delta"]
+            .iter()
+            .find_map(|key| obj.get(*key).and_then(|v| v.as_str()))
+            .map(|s| s.to_string())
+    };
+    match value {
+        serde_json::Value::String(text) => Ok(text),
+        serde_json::Value::Object(obj) => {
+            if obj.get("success").and_then(|v| v.as_bool()) == Some(false) {
+                let error = obj
+                    .get("error")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("Chat request failed");
+                return Err(error.to_string());
+            }
+            obj.get("data")
+                .and_then(|data| data.as_object())
+                .and_then(reply)
+                .or_else(|| reply(&obj))
+                .ok_or_else(|| "Chat response has no message".to_string())
+        }
+        _ => Err("Chat response has no message".to_string()),
+    }
+}
+
+/// Incremental parser for a server-sent events chat stream
+/// Each event's `data:` lines are joined with "\n"; the payload is either JSON
+/// (`{"delta": ...}`, `{"content": ...}`, `{"message": ...}`) or raw text, and
+/// `data: [DONE]` ends the stream
+#[derive(Debug, Default)]
+pub struct ChatStreamParser {
+    buffer: Vec<u8>,
+    /// `data:` lines of the event being read, dispatched at the next blank line
+    data: Option<String>,
+    done: bool,
+}
+
+impl ChatStreamParser {
+    /// Feed raw bytes, returning the text deltas from every complete line
+    pub fn push(&mut self, bytes: &[u8]) -> Vec<String> {
+        self.buffer.extend_from_slice(bytes);
+        let mut deltas = Vec::new();
+        // Lines end in '\n', which never occurs inside a multi-byte UTF-8 sequence
+        while let Some(pos) = self.buffer.iter().position(|b| *b == b'\n') {
+            let line: Vec<u8> = self.buffer.drain(..=pos).collect();
+            let line = String::from_utf8_lossy(&line);
+            if let Some(delta) = self.parse_line(line.trim_end_matches(['\r', '\n'])) {
+                deltas.push(delta);
+            }
+        }
+        deltas
+    }
+
+    /// Flush a final line and event the server ended without a trailing blank line
+    pub fn finish(&mut self) -> Vec<String> {
+        self.push(b"\n\n")
+    }
+
+    /// Whether `data: [DONE]` has been seen
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+
+    fn parse_line(&mut self, line: &str) -> Option<String> {
+        if line.is_empty() {
+            let data = self.data.take()?;
+            return self.dispatch(&data);
+        }
+        // Comments and the other fields (event:, id:, retry:) carry no text
+        let data = line.strip_prefix("data:")?;
+        let data = data.strip_prefix(' ').unwrap_or(data);
+        match &mut self.data {
+            Some(pending) => {
+                pending.push('\n');
+                pending.push_str(data);
+            }
+            None => self.data = Some(data.to_string()),
+        }
+        None
+    }
+
+    fn dispatch(&mut self, data: &str) -> Option<String> {
+        if data == "[DONE]" {
+            self.done = true;
+            return None;
+        }
+        if self.done || data.is_empty() {
+            return None;
+        }
+        match serde_json::from_str::<serde_json::Value>(data) {
+            Ok(serde_json::Value::Object(obj)) => ["delta", "content", "message"]
+                .iter()
+                .find_map(|key| obj.get(*key).and_then(|v| v.as_str()))
+                .map(|s| s.to_string()),
+            Ok(serde_json::Value::String(text)) => Some(text),
+            _ => Some(data.to_string()),
+        }
+    }
+}
+
+/// Resolves once `signal` is aborted (immediately if it already is)
+pub async fn until_aborted(signal: &web_sys::AbortSignal) {
+    if signal.aborted() {
+        return;
+    }
+    let promise = js_sys::Promise::new(&mut |resolve, _| signal.set_onabort(Some(&resolve)));
+    let _ = wasm_bindgen_futures::JsFuture::from(promise).await;
+}
+
+/// Send a chat message to the streaming endpoint, calling `on_delta` for each chunk of the
+/// reply as it arrives; aborting `signal` stops the request (the stream then returns an error)
+pub async fn stream_chat(
+    api_url: &str,
+    request_json: &str,
+    signal: &web_sys::AbortSignal,
+    mut on_delta: impl FnMut(&str),
+) -> Result<ChatStreamOutcome, String> {
+    let endpoint = create_chat_stream_endpoint();
+    let request =
+        crate::api::build_request(api_url, &endpoint, Some(request_json), None, signal)?;
+    request
+        .headers()
+        .set("Accept", "text/event-stream")
+        .map_err(|e| format!("Failed to set Accept header: {:?}", e))?;
+
+    let window = web_sys::window().ok_or("No window object")?;
+    let resp: web_sys::Response =
+        wasm_bindgen_futures::JsFuture::from(window.fetch_with_request(&request))
+            .await
+            .map_err(|e| format!("Fetch failed: {:?}", e))?
+            .dyn_into()
+            .map_err(|_| "Response is not a Response object")?;
+
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .ok()
+        .flatten()
+        .unwrap_or_default();
+    match resp.status() {
+        200 => {}
+        // Rejected before reaching the model, so the buffered endpoint can't send it twice
+        402 | 404 | 405 => {
+            web_sys::console::log_1(
+                &format!("ℹ️ Chat streaming unavailable (status {})", resp.status()).into(),
+            );
+            return Ok(ChatStreamOutcome::Unsupported);
+        }
+        status => return Err(format!("Request failed with status: {}", status)),
+    }
+
+    let body = js_sys::Reflect::get(&resp, &"body".into()).unwrap_or_default();
+    if !content_type.contains("text/event-stream") || body.is_null() {
+        // The server answered in one piece; that body is the reply
+        let text_promise = resp
+            .text()
+            .map_err(|e| format!("Failed to read response body: {:?}", e))?;
+        let text = wasm_bindgen_futures::JsFuture::from(text_promise)
+            .await
+            .map_err(|e| format!("Failed to read response body: {:?}", e))?
+            .as_string()
+            .unwrap_or_default();
+        return parse_chat_reply_body(&text).map(ChatStreamOutcome::Reply);
+    }
+
+    let reader = js_sys::Reflect::get(&body, &"getReader".into())
+        .ok()
+        .and_then(|f| f.dyn_into::<js_sys::Function>().ok())
+        .and_then(|f| f.call0(&body).ok())
+        .ok_or("Response body is not readable")?;
+    let read_fn: js_sys::Function = js_sys::Reflect::get(&reader, &"read".into())
+        .ok()
+        .and_then(|f| f.dyn_into().ok())
+        .ok_or("Stream reader has no read()")?;
+
+    let mut parser = ChatStreamParser::default();
+    while !parser.is_done() {
+        let promise: js_sys::Promise = read_fn
+            .call0(&reader)
+            .map_err(|e| format!("Stream read failed: {:?}", e))?
+            .dyn_into()
+            .map_err(|_| "Stream read did not return a promise")?;
+        let chunk = wasm_bindgen_futures::JsFuture::from(promise)
+            .await
+            .map_err(|e| format!("Stream interrupted: {:?}", e))?;
+        let done = js_sys::Reflect::get(&chunk, &"done".into())
+            .map(|d| d.is_truthy())
+            .unwrap_or(true);
+        if done {
+            break;
+        }
+        if let Ok(value) = js_sys::Reflect::get(&chunk, &"value".into()) {
+            let bytes = js_sys::Uint8Array::new(&value).to_vec();
+            for delta in parser.push(&bytes) {
+                on_delta(&delta);
+            }
+        }
+    }
+    for delta in parser.finish() {
+        on_delta(&delta);
+    }
+
+    Ok(ChatStreamOutcome::Completed)
+}
+
 /// Create get chat session endpoint info
 pub fn create_get_chat_session_endpoint(session_id: &str) -> EndpointInfo {
     EndpointInfo {
@@ -761,7 +1638,7 @@ pub fn create_mbti_endpoint(search_query: &str, is_fid: bool) -> EndpointInfo {
 }
 
 /// Update URL path using History API (supports browser back/forward)
-/// Format: /profile/{query}, /chat/{query}, or /annual-report/{fid}
+/// Format: /profile/{query}, /chat/{query}, or /annual-report/{fid or username}
 pub fn update_url_path(query: &str, view: &str) {
     let window = web_sys::window().unwrap();
     let history = window.history().unwrap();
@@ -769,6 +1646,10 @@ pub fn update_url_path(query: &str, view: &str) {
         format!("/chat/{}", query)
     } else if view == "annual-report" {
         format!("/annual-report/{}", query)
+    } else if view == "compare" {
+        format!("/compare/{}", query)
+    } else if view == "leaderboard" {
+        "/leaderboard".to_string()
     } else {
         format!("/profile/{}", query)
     };
@@ -781,9 +1662,72 @@ pub fn update_url_path(query: &str, view: &str) {
     web_sys::console::log_1(&format!("📍 Updated URL path: {}", path).into());
 }
 
-/// Update URL to annual report path
-pub fn update_annual_report_url(fid: i64) {
-    update_url_path(&fid.to_string(), "annual-report");
+/// Who an /annual-report/{target} route is for: a FID, or a username still to be resolved
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReportTarget {
+    Fid(i64),
+    Username(String),
+}
+
+impl ReportTarget {
+    /// Path segment for the route (usernames without the `@`)
+    pub fn path_segment(&self) -> String {
+        match self {
+            ReportTarget::Fid(fid) => fid.to_string(),
+            ReportTarget::Username(username) => username.clone(),
+        }
+    }
+}
+
+impl From<i64> for ReportTarget {
+    fn from(fid: i64) -> Self {
+        ReportTarget::Fid(fid)
+    }
+}
+
+/// Parse the query of an /annual-report route: a positive FID, or a username (optionally
+/// `@`-prefixed) of 1-32 letters, digits, `-`, `_` or `.` starting with a letter or digit.
+/// One trailing slash is allowed; usernames are lowercased. Must match extract_report_target
+/// in worker/src/lib.rs
+pub fn parse_report_target(query: &str) -> Option<ReportTarget> {
+    let segment = query.strip_suffix('/').unwrap_or(query);
+    if !segment.is_empty() && segment.bytes().all(|b| b.is_ascii_digit()) {
+        return segment
+            .parse()
+            .ok()
+            .filter(|fid| *fid > 0)
+            .map(ReportTarget::Fid);
+    }
+    let username = segment.strip_prefix('@').unwrap_or(segment);
+    let valid = (1..=32).contains(&username.len())
+        && username.starts_with(|c: char| c.is_ascii_alphanumeric())
+        && username
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        && !username.bytes().all(|b| b.is_ascii_digit());
+    valid.then(|| ReportTarget::Username(username.to_ascii_lowercase()))
+}
+
+/// Resolve a username to its FID through the profile route; like `resolve_profiles` this
+/// never pays, so a profile behind a 402 fails the lookup
+pub async fn resolve_username_fid(api_url: &str, username: &str) -> Result<i64, String> {
+    let endpoint = create_profile_endpoint(username, false);
+    let profile =
+        make_request_with_payment::<ProfileData>(api_url, &endpoint, None, None, None, None)
+            .await?;
+    Ok(profile.fid)
+}
+
+/// Update URL to annual report path (a FID or a username)
+pub fn update_annual_report_url(target: impl Into<ReportTarget>) {
+    update_url_path(&target.into().path_segment(), "annual-report");
+}
+
+/// Parse the `{fid1}/{fid2}` query of a /compare route; both FIDs must be positive
+pub fn parse_compare_fids(query: &str) -> Option<(i64, i64)> {
+    let (fid_a, fid_b) = query.trim_end_matches('/').split_once('/')?;
+    let parse = |fid: &str| fid.parse::<i64>().ok().filter(|fid| *fid > 0);
+    Some((parse(fid_a)?, parse(fid_b)?))
 }
 
 /// Clear URL path (return to home)
@@ -799,8 +1743,8 @@ pub fn clear_url_path() {
 }
 
 /// Get current URL path and parse it
-/// Returns (query, view) where view is "profile", "chat", or "annual-report"
-/// For annual-report, query is the FID
+/// Returns (query, view) where view is "profile", "chat", "annual-report", "compare", or "leaderboard"
+/// For annual-report, query is the FID or username (see `parse_report_target`)
 pub fn get_url_path() -> Option<(String, String)> {
     let window = web_sys::window().unwrap();
     let location = window.location();
@@ -810,10 +1754,14 @@ pub fn get_url_path() -> Option<(String, String)> {
         return None;
     }
 
-    // Parse format: /profile/{query}, /chat/{query}, or /annual-report/{fid}
+    // Parse format: /profile/{query}, /chat/{query}, /annual-report/{fid}, /compare/{fid1}/{fid2},
+    // or /leaderboard (no query)
     if let Some(path) = pathname.strip_prefix("/") {
+        if path.trim_end_matches('/') == "leaderboard" {
+            return Some((String::new(), "leaderboard".to_string()));
+        }
         if let Some((view, query)) = path.split_once('/') {
-            if view == "profile" || view == "chat" || view == "annual-report" {
+            if view == "profile" || view == "chat" || view == "annual-report" || view == "compare" {
                 return Some((query.to_string(), view.to_string()));
             }
         }
@@ -841,9 +1789,15 @@ pub fn setup_popstate_listener(callback: impl Fn(Option<(String, String)>) + 'st
 }
 
 /// Create annual report endpoint info
-pub fn create_annual_report_endpoint(fid: i64, year: i32) -> EndpointInfo {
+/// `refresh` asks the backend to recompute the report instead of returning the completed one
+pub fn create_annual_report_endpoint(fid: i64, year: i32, refresh: bool) -> EndpointInfo {
     EndpointInfo {
-        path: format!("/api/users/{}/annual-report/{}", fid, year),
+        path: format!(
+            "/api/users/{}/annual-report/{}{}",
+            fid,
+            year,
+            if refresh { "?refresh=true" } else { "" }
+        ),
         method: "GET".to_string(),
         name: "Get Annual Report".to_string(),
         description: format!("Get annual report for {}", year),
@@ -853,6 +1807,19 @@ pub fn create_annual_report_endpoint(fid: i64, year: i32) -> EndpointInfo {
     }
 }
 
+/// Create endpoint info for registering a "report is ready" ping
+pub fn create_report_notify_endpoint(fid: i64, year: i32) -> EndpointInfo {
+    EndpointInfo {
+        path: format!("/api/users/{}/annual-report/{}/notify", fid, year),
+        method: "POST".to_string(),
+        name: "Notify When Report Ready".to_string(),
+        description: format!("Get notified when the {} annual report is ready", year),
+        tier: "Basic".to_string(),
+        requires_payment: false,
+        default_body: None,
+    }
+}
+
 /// Create casts stats endpoint info
 pub fn create_casts_stats_endpoint(
     fid: i64,
@@ -893,3 +1860,212 @@ pub fn get_2025_timestamps() -> (i64, i64) {
     let end = 1767225600;
     (start, end)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_has_debug_flag() {
+        assert!(query_has_debug_flag("?debug=1"));
+        assert!(query_has_debug_flag("?params=abc&debug=1"));
+        assert!(!query_has_debug_flag(""));
+        assert!(!query_has_debug_flag("?debug=0"));
+        assert!(!query_has_debug_flag("?nodebug=1"));
+    }
+
+    #[test]
+    fn test_chat_stream_parser() {
+        let mut parser = ChatStreamParser::default();
+        // Lines split across chunks, including inside a multi-byte character
+        let stream = "data: {\"delta\":\"Hel\"}\n\ndata: lo ✨\n: keep-alive\n\ndata: {\"content\":\"!\"}\r\n\r\ndata: [DONE]\n\ndata: ignored\n\n";
+        let bytes = stream.as_bytes();
+        let mut deltas = Vec::new();
+        for chunk in bytes.chunks(7) {
+            deltas.extend(parser.push(chunk));
+        }
+
+        assert_eq!(deltas, vec!["Hel", "lo ✨", "!"]);
+        assert!(parser.is_done());
+
+        // Multi-line data fields join with "\n"; a final event without a blank line is flushed
+        let mut parser = ChatStreamParser::default();
+        let mut deltas = parser.push(b"data: first\ndata: second\n\ndata: last");
+        deltas.extend(parser.finish());
+        assert_eq!(deltas, vec!["first\nsecond", "last"]);
+    }
+
+    #[test]
+    fn test_parse_chat_reply_body() {
+        assert_eq!(
+            parse_chat_reply_body(r#"{"success":true,"data":{"session_id":"s","message":"gm"}}"#),
+            Ok("gm".to_string())
+        );
+        assert_eq!(
+            parse_chat_reply_body(r#"{"content":"gm"}"#),
+            Ok("gm".to_string())
+        );
+        assert_eq!(parse_chat_reply_body(r#""gm""#), Ok("gm".to_string()));
+        assert_eq!(parse_chat_reply_body(" gm\n"), Ok("gm".to_string()));
+        assert_eq!(
+            parse_chat_reply_body(r#"{"success":false,"error":"boom"}"#),
+            Err("boom".to_string())
+        );
+        assert!(parse_chat_reply_body(r#"{"session_id":"s"}"#).is_err());
+    }
+
+    #[test]
+    fn test_payment_receipt_key_and_expiry() {
+        let api = "https://api.example";
+        let report = create_annual_report_endpoint(3, 2024, false);
+        let other_report = create_annual_report_endpoint(4, 2024, false);
+        let chat = create_chat_message_endpoint();
+
+        // Same request, same key; different params or bodies never share a proof
+        assert_eq!(
+            payment_receipt_key(api, &report, None),
+            payment_receipt_key(api, &report, None)
+        );
+        assert_ne!(
+            payment_receipt_key(api, &report, None),
+            payment_receipt_key(api, &other_report, None)
+        );
+        assert_ne!(
+            payment_receipt_key(api, &chat, Some("{\"message\":\"a\"}")),
+            payment_receipt_key(api, &chat, Some("{\"message\":\"b\"}"))
+        );
+        assert_ne!(
+            payment_receipt_key(api, &report, None),
+            payment_receipt_key("https://other.example", &report, None)
+        );
+
+        assert!(payment_receipt_is_reusable(1_060, 1_000));
+        assert!(!payment_receipt_is_reusable(1_004, 1_000));
+        assert!(!payment_receipt_is_reusable(1_000, 1_000));
+    }
+
+    #[test]
+    fn test_normalize_api_url() {
+        assert_eq!(
+            normalize_api_url(" https://api.example.com/ ").as_deref(),
+            Ok("https://api.example.com")
+        );
+        assert_eq!(
+            normalize_api_url("http://localhost:3000/v1").as_deref(),
+            Ok("http://localhost:3000/v1")
+        );
+        assert!(normalize_api_url("api.example.com").is_err());
+        assert!(normalize_api_url("ftp://api.example.com").is_err());
+        assert!(normalize_api_url("https://").is_err());
+        assert!(normalize_api_url("https://bad host").is_err());
+    }
+
+    #[test]
+    fn test_username_cache_helpers() {
+        // Deduplicated, in order, invalid and already-cached FIDs skipped
+        assert_eq!(
+            fids_to_resolve(&[3, 0, 5, 3, -1, 7, 5], |fid| fid == 7),
+            vec![3, 5]
+        );
+        assert!(fids_to_resolve(&[], |_| false).is_empty());
+
+        let stored_at = 1_700_000_000_000.0;
+        assert!(username_cache_entry_is_fresh(stored_at, stored_at));
+        assert!(username_cache_entry_is_fresh(
+            stored_at,
+            stored_at + 29.0 * 60_000.0
+        ));
+        assert!(!username_cache_entry_is_fresh(
+            stored_at,
+            stored_at + 30.0 * 60_000.0
+        ));
+        // A clock that went backwards doesn't keep entries alive
+        assert!(!username_cache_entry_is_fresh(stored_at, stored_at - 1.0));
+    }
+
+    #[test]
+    fn test_profiles_batch_endpoint_and_response() {
+        let endpoint = create_profiles_batch_endpoint(&[3, 194, 3621]);
+        assert_eq!(endpoint.path, "/api/profiles/batch?fids=3,194,3621");
+        assert_eq!(endpoint.method, "GET");
+
+        // Bare array, or wrapped under `profiles`; missing optional fields are None
+        let list: ProfilesBatchResponse =
+            serde_json::from_str(r#"[{"fid":3,"username":"dwr"},{"fid":194}]"#).unwrap();
+        let profiles = list.into_profiles();
+        assert_eq!(profiles.len(), 2);
+        assert_eq!(profiles[0].username.as_deref(), Some("dwr"));
+        assert_eq!(profiles[1].pfp_url, None);
+        let wrapped: ProfilesBatchResponse =
+            serde_json::from_str(r#"{"profiles":[{"fid":3621,"pfp_url":"https://x.io/a.png"}]}"#)
+                .unwrap();
+        assert_eq!(wrapped.into_profiles()[0].fid, 3621);
+    }
+
+    #[test]
+    fn test_parse_demo_fid() {
+        assert_eq!(parse_demo_fid(Some("3")), Some(3));
+        assert_eq!(parse_demo_fid(Some(" 194 ")), Some(194));
+        assert_eq!(parse_demo_fid(None), None);
+        assert_eq!(parse_demo_fid(Some("")), None);
+        assert_eq!(parse_demo_fid(Some("0")), None);
+        assert_eq!(parse_demo_fid(Some("-5")), None);
+        assert_eq!(parse_demo_fid(Some("dwr")), None);
+    }
+
+    #[test]
+    fn test_parse_report_target() {
+        assert_eq!(parse_report_target("3621"), Some(ReportTarget::Fid(3621)));
+        assert_eq!(parse_report_target("3621/"), Some(ReportTarget::Fid(3621)));
+        assert_eq!(
+            parse_report_target("dwr"),
+            Some(ReportTarget::Username("dwr".to_string()))
+        );
+        assert_eq!(
+            parse_report_target("@Vitalik.eth/"),
+            Some(ReportTarget::Username("vitalik.eth".to_string()))
+        );
+        assert_eq!(
+            ReportTarget::Username("dwr".to_string()).path_segment(),
+            "dwr"
+        );
+        assert_eq!(ReportTarget::from(3).path_segment(), "3");
+
+        // Not a positive FID, and too many digits to be a username
+        assert_eq!(parse_report_target("0"), None);
+        assert_eq!(parse_report_target("99999999999999999999"), None);
+        // Nested paths, bad characters and empty names are rejected
+        assert_eq!(parse_report_target("dwr/casts"), None);
+        assert_eq!(parse_report_target("-dwr"), None);
+        assert_eq!(parse_report_target("dw r"), None);
+        assert_eq!(parse_report_target("@"), None);
+        assert_eq!(parse_report_target(""), None);
+        assert_eq!(parse_report_target(&"a".repeat(33)), None);
+    }
+
+    #[test]
+    fn test_parse_compare_fids() {
+        assert_eq!(parse_compare_fids("3/194"), Some((3, 194)));
+        assert_eq!(parse_compare_fids("3/194/"), Some((3, 194)));
+        assert_eq!(parse_compare_fids("3/3"), Some((3, 3)));
+
+        assert_eq!(parse_compare_fids("0/194"), None);
+        assert_eq!(parse_compare_fids("3/-1"), None);
+        assert_eq!(parse_compare_fids("3"), None);
+        assert_eq!(parse_compare_fids("3/"), None);
+        assert_eq!(parse_compare_fids("3/dwr"), None);
+        assert_eq!(parse_compare_fids("3/194/5"), None);
+    }
+
+    #[test]
+    fn test_annual_report_endpoint_refresh() {
+        assert_eq!(
+            create_annual_report_endpoint(3, 2025, false).path,
+            "/api/users/3/annual-report/2025"
+        );
+        assert_eq!(
+            create_annual_report_endpoint(3, 2025, true).path,
+            "/api/users/3/annual-report/2025?refresh=true"
+        );
+    }
+}
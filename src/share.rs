@@ -5,6 +5,120 @@ use yew::prelude::*;
 
 use crate::farcaster;
 use crate::icons;
+use crate::services::copy_to_clipboard_async;
+
+/// localStorage key for the preferred tweet intent domain
+const TWITTER_DOMAIN_STORAGE_KEY: &str = "polyjuice_twitter_domain";
+
+/// Which domain tweet intents open on; some users prefer x.com and either may be blocked
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum TwitterShareDomain {
+    #[default]
+    Twitter,
+    X,
+}
+
+impl TwitterShareDomain {
+    pub fn host(self) -> &'static str {
+        match self {
+            TwitterShareDomain::Twitter => "twitter.com",
+            TwitterShareDomain::X => "x.com",
+        }
+    }
+
+    pub fn toggled(self) -> Self {
+        match self {
+            TwitterShareDomain::Twitter => TwitterShareDomain::X,
+            TwitterShareDomain::X => TwitterShareDomain::Twitter,
+        }
+    }
+
+    /// Parse a stored host, falling back to twitter.com for anything unknown
+    pub fn from_host(host: &str) -> Self {
+        if host == TwitterShareDomain::X.host() {
+            TwitterShareDomain::X
+        } else {
+            TwitterShareDomain::Twitter
+        }
+    }
+
+    /// Tweet intent URL; `query` must already be URL-encoded
+    pub fn intent_url(self, query: &str) -> String {
+        format!("https://{}/intent/tweet?{}", self.host(), query)
+    }
+}
+
+/// Load the preferred tweet intent domain from localStorage (twitter.com if unset)
+pub fn load_twitter_share_domain() -> TwitterShareDomain {
+    web_sys::window()
+        .and_then(|w| w.local_storage().ok().flatten())
+        .and_then(|storage| storage.get_item(TWITTER_DOMAIN_STORAGE_KEY).ok().flatten())
+        .map(|host| TwitterShareDomain::from_host(&host))
+        .unwrap_or_default()
+}
+
+/// Persist the preferred tweet intent domain to localStorage
+pub fn save_twitter_share_domain(domain: TwitterShareDomain) -> Result<(), String> {
+    let storage = web_sys::window()
+        .ok_or("No window available".to_string())?
+        .local_storage()
+        .map_err(|_| "Failed to get localStorage".to_string())?
+        .ok_or("localStorage not available".to_string())?;
+    storage
+        .set_item(TWITTER_DOMAIN_STORAGE_KEY, domain.host())
+        .map_err(|_| "Failed to save share domain to localStorage".to_string())
+}
+
+/// Preferred tweet intent domain, loaded once and saved whenever it changes
+#[hook]
+pub fn use_twitter_share_domain() -> UseStateHandle<TwitterShareDomain> {
+    let domain = use_state(load_twitter_share_domain);
+    use_effect_with(*domain, |domain| {
+        if let Err(e) = save_twitter_share_domain(*domain) {
+            web_sys::console::warn_1(&format!("⚠️ {}", e).into());
+        }
+        || ()
+    });
+    domain
+}
+
+/// Small "Opens on twitter.com · use x.com" switch shown under tweet buttons
+#[derive(Properties, PartialEq, Clone)]
+pub struct TwitterDomainToggleProps {
+    pub domain: UseStateHandle<TwitterShareDomain>,
+    #[prop_or_default]
+    pub style: String,
+}
+
+#[function_component]
+pub fn TwitterDomainToggle(props: &TwitterDomainToggleProps) -> Html {
+    let current = *props.domain;
+    let on_toggle = {
+        let domain = props.domain.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.stop_propagation();
+            domain.set(current.toggled());
+        })
+    };
+
+    html! {
+        <button
+            onclick={on_toggle}
+            title="Choose which domain tweet links open on"
+            style={format!("
+                background: none;
+                border: none;
+                padding: 2px 4px;
+                font-size: 12px;
+                cursor: pointer;
+                text-decoration: underline;
+                {}
+            ", props.style)}
+        >
+            {format!("Opens on {} · use {}", current.host(), current.toggled().host())}
+        </button>
+    }
+}
 
 /// Share options component
 #[derive(Properties, PartialEq, Clone)]
@@ -19,6 +133,7 @@ pub struct ShareButtonProps {
 #[function_component]
 pub fn ShareButton(props: &ShareButtonProps) -> Html {
     let show_share_menu = use_state(|| false);
+    let twitter_domain = use_twitter_share_domain();
     let menu_ref = use_node_ref();
     let button_ref = use_node_ref();
 
@@ -109,9 +224,9 @@ pub fn ShareButton(props: &ShareButtonProps) -> Html {
                 // Include URL in the text
                 let text_with_url = format!("{}\n\n{}", text_clone, url_clone);
                 // Also pass URL as embed for rich preview
-                if let Err(e) =
-                    farcaster::compose_cast(&text_with_url, Some(vec![url_clone.clone()])).await
-                {
+                let embeds = farcaster::share_embeds(Some(url_clone.clone()), None);
+                // No channel: the user picks one in the composer
+                if let Err(e) = farcaster::compose_cast(&text_with_url, embeds, None).await {
                     web_sys::console::error_1(&format!("Failed to compose cast: {}", e).into());
                 }
                 show_share_menu_clone.set(false);
@@ -123,15 +238,16 @@ pub fn ShareButton(props: &ShareButtonProps) -> Html {
         let url = url.clone();
         let text = text.clone();
         let show_share_menu = show_share_menu.clone();
+        let twitter_domain = *twitter_domain;
         Callback::from(move |_| {
             // Use JavaScript's encodeURIComponent
             let encoded_text = js_sys::encode_uri_component(&text);
             let encoded_url = js_sys::encode_uri_component(&url);
-            let twitter_url = format!(
-                "https://twitter.com/intent/tweet?text={}&url={}",
+            let twitter_url = twitter_domain.intent_url(&format!(
+                "text={}&url={}",
                 encoded_text.as_string().unwrap_or_default(),
                 encoded_url.as_string().unwrap_or_default()
-            );
+            ));
             if let Some(window) = web_sys::window() {
                 if let Err(e) = window.open_with_url_and_target(&twitter_url, "_blank") {
                     web_sys::console::error_1(&format!("Failed to open Twitter: {:?}", e).into());
@@ -148,41 +264,8 @@ pub fn ShareButton(props: &ShareButtonProps) -> Html {
             let url_clone = url.clone();
             let show_share_menu_clone = show_share_menu.clone();
             spawn_local(async move {
-                let window = web_sys::window().unwrap();
-                // Try modern Clipboard API using js_sys::Reflect
-                if let Ok(navigator_val) = js_sys::Reflect::get(&window, &"navigator".into()) {
-                    if !navigator_val.is_null() && !navigator_val.is_undefined() {
-                        if let Ok(clipboard_val) =
-                            js_sys::Reflect::get(&navigator_val, &"clipboard".into())
-                        {
-                            if !clipboard_val.is_null() && !clipboard_val.is_undefined() {
-                                if let Ok(write_text_fn) =
-                                    js_sys::Reflect::get(&clipboard_val, &"writeText".into())
-                                {
-                                    if let Some(write_fn) =
-                                        write_text_fn.dyn_ref::<js_sys::Function>()
-                                    {
-                                        if let Ok(promise_val) =
-                                            write_fn.call1(&clipboard_val, &url_clone.into())
-                                        {
-                                            if let Ok(promise) =
-                                                promise_val.dyn_into::<js_sys::Promise>()
-                                            {
-                                                if wasm_bindgen_futures::JsFuture::from(promise)
-                                                    .await
-                                                    .is_ok()
-                                                {
-                                                    web_sys::console::log_1(
-                                                        &"URL copied to clipboard".into(),
-                                                    );
-                                                }
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
+                if copy_to_clipboard_async(&url_clone).await {
+                    web_sys::console::log_1(&"URL copied to clipboard".into());
                 }
                 show_share_menu_clone.set(false);
             });
@@ -282,6 +365,10 @@ pub fn ShareButton(props: &ShareButtonProps) -> Html {
                                 <i class="fab fa-twitter" style="color: #1DA1F2; font-size: 18px;"></i>
                                 <span>{"Share on Twitter"}</span>
                             </button>
+                            <TwitterDomainToggle
+                                domain={twitter_domain.clone()}
+                                style="display: block; margin: 0 auto 4px; color: #666;"
+                            />
                             <div style="height: 1px; background: rgba(0, 0, 0, 0.1); margin: 4px 0;"></div>
                             <button
                                 onclick={on_copy_link}
@@ -314,3 +401,29 @@ pub fn ShareButton(props: &ShareButtonProps) -> Html {
         </div>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_twitter_share_domain() {
+        assert_eq!(TwitterShareDomain::default(), TwitterShareDomain::Twitter);
+        assert_eq!(
+            TwitterShareDomain::Twitter.intent_url("text=gm%20all"),
+            "https://twitter.com/intent/tweet?text=gm%20all"
+        );
+        assert_eq!(
+            TwitterShareDomain::X.intent_url("text=gm%20all"),
+            "https://x.com/intent/tweet?text=gm%20all"
+        );
+        assert_eq!(
+            TwitterShareDomain::from_host("x.com"),
+            TwitterShareDomain::X
+        );
+        assert_eq!(
+            TwitterShareDomain::from_host("bogus"),
+            TwitterShareDomain::Twitter
+        );
+    }
+}
@@ -0,0 +1,205 @@
+use std::rc::Rc;
+
+use gloo_timers::callback::Timeout;
+use yew::prelude::*;
+
+/// How long a toast stays on screen before dismissing itself
+const TOAST_DURATION_MS: u32 = 3500;
+
+/// Maximum number of toasts shown at once; older ones are dropped first
+const MAX_VISIBLE_TOASTS: usize = 3;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ToastKind {
+    Success,
+    Error,
+    Info,
+}
+
+impl ToastKind {
+    fn icon(self) -> &'static str {
+        match self {
+            ToastKind::Success => "✅",
+            ToastKind::Error => "⚠️",
+            ToastKind::Info => "ℹ️",
+        }
+    }
+
+    fn background(self) -> &'static str {
+        match self {
+            ToastKind::Success => "rgba(34, 139, 84, 0.95)",
+            ToastKind::Error => "rgba(200, 50, 50, 0.95)",
+            ToastKind::Info => "rgba(40, 40, 60, 0.95)",
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Toast {
+    pub id: u32,
+    pub kind: ToastKind,
+    pub message: String,
+}
+
+pub enum ToastAction {
+    Push(ToastKind, String),
+    Dismiss(u32),
+}
+
+/// Reducer state for the app-wide toast stack
+#[derive(Default, PartialEq)]
+pub struct ToastState {
+    pub toasts: Vec<Toast>,
+    next_id: u32,
+}
+
+impl Reducible for ToastState {
+    type Action = ToastAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut toasts = self.toasts.clone();
+        let mut next_id = self.next_id;
+        match action {
+            ToastAction::Push(kind, message) => {
+                toasts.push(Toast {
+                    id: next_id,
+                    kind,
+                    message,
+                });
+                next_id = next_id.wrapping_add(1);
+                if toasts.len() > MAX_VISIBLE_TOASTS {
+                    toasts.remove(0);
+                }
+            }
+            ToastAction::Dismiss(id) => toasts.retain(|t| t.id != id),
+        }
+        Rc::new(Self { toasts, next_id })
+    }
+}
+
+/// Handle for raising toasts, provided to the whole app through a `ContextProvider`
+#[derive(Clone, PartialEq)]
+pub struct Toaster {
+    dispatcher: Option<UseReducerDispatcher<ToastState>>,
+}
+
+impl Toaster {
+    pub fn new(dispatcher: UseReducerDispatcher<ToastState>) -> Self {
+        Self {
+            dispatcher: Some(dispatcher),
+        }
+    }
+
+    pub fn show(&self, kind: ToastKind, message: impl Into<String>) {
+        let message = message.into();
+        match &self.dispatcher {
+            Some(dispatcher) => dispatcher.dispatch(ToastAction::Push(kind, message)),
+            // Rendered outside the provider: keep the message visible in the console at least
+            None => web_sys::console::log_1(&format!("{} {}", kind.icon(), message).into()),
+        }
+    }
+
+    pub fn success(&self, message: impl Into<String>) {
+        self.show(ToastKind::Success, message);
+    }
+
+    pub fn error(&self, message: impl Into<String>) {
+        self.show(ToastKind::Error, message);
+    }
+
+    pub fn info(&self, message: impl Into<String>) {
+        self.show(ToastKind::Info, message);
+    }
+}
+
+/// Get the app-wide toaster; falls back to console logging outside the provider
+#[hook]
+pub fn use_toaster() -> Toaster {
+    use_context::<Toaster>().unwrap_or(Toaster { dispatcher: None })
+}
+
+#[derive(Properties, PartialEq)]
+struct ToastItemProps {
+    toast: Toast,
+    on_dismiss: Callback<u32>,
+}
+
+#[function_component]
+fn ToastItem(props: &ToastItemProps) -> Html {
+    // Auto-dismiss; dropping the timeout on unmount cancels it
+    {
+        let on_dismiss = props.on_dismiss.clone();
+        use_effect_with(props.toast.id, move |id| {
+            let id = *id;
+            let timeout = Timeout::new(TOAST_DURATION_MS, move || on_dismiss.emit(id));
+            move || drop(timeout)
+        });
+    }
+
+    let id = props.toast.id;
+    let on_click = props.on_dismiss.reform(move |_: MouseEvent| id);
+    let role = if props.toast.kind == ToastKind::Error {
+        "alert"
+    } else {
+        "status"
+    };
+
+    html! {
+        <div
+            class="toast"
+            role={role}
+            onclick={on_click}
+            style={format!("
+                display: flex;
+                align-items: center;
+                gap: 8px;
+                padding: 12px 16px;
+                border-radius: 12px;
+                background: {};
+                color: white;
+                font-size: 14px;
+                box-shadow: 0 8px 24px rgba(0, 0, 0, 0.3);
+                cursor: pointer;
+                pointer-events: auto;
+                max-width: 100%;
+                word-break: break-word;
+            ", props.toast.kind.background())}
+        >
+            <span>{props.toast.kind.icon()}</span>
+            <span>{props.toast.message.clone()}</span>
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ToastContainerProps {
+    pub toasts: Vec<Toast>,
+    pub on_dismiss: Callback<u32>,
+}
+
+/// Fixed stack of toasts at the bottom of the screen, above the bottom tab bar
+#[function_component]
+pub fn ToastContainer(props: &ToastContainerProps) -> Html {
+    html! {
+        <div class="toast-container" aria-live="polite" style="
+            position: fixed;
+            left: 50%;
+            bottom: 88px;
+            transform: translateX(-50%);
+            width: min(420px, calc(100% - 32px));
+            display: flex;
+            flex-direction: column;
+            gap: 8px;
+            z-index: 20000;
+            pointer-events: none;
+        ">
+            {for props.toasts.iter().map(|toast| html! {
+                <ToastItem
+                    key={toast.id}
+                    toast={toast.clone()}
+                    on_dismiss={props.on_dismiss.clone()}
+                />
+            })}
+        </div>
+    }
+}
@@ -127,6 +127,155 @@ pub fn clear_wallet_from_storage() -> Result<(), String> {
     Ok(())
 }
 
+// Attempts at restoring a saved connection before giving up
+const RESTORE_ATTEMPTS: u32 = 3;
+
+// Delay before restore attempt `attempt` (0-based): nothing for the first, then doubling from 500ms
+fn restore_backoff_ms(attempt: u32) -> u32 {
+    match attempt {
+        0 => 0,
+        n => 500 << (n - 1).min(4),
+    }
+}
+
+// EIP-1193 error code for a request the user rejected
+const USER_REJECTED_REQUEST: f64 = 4001.0;
+
+// Result of restoring the saved wallet connection; AddressMismatch, Rejected and
+// ConnectFailed should clear the saved connection
+pub enum RestoreOutcome {
+    // Reconnected to the saved address
+    Restored(WalletAccount),
+    // The wallet is connected to a different address; the saved connection is stale
+    AddressMismatch(Option<String>),
+    // The user rejected the wallet's request (EIP-1193 4001); don't ask again
+    Rejected,
+    // The saved wallet never showed up or failed on every attempt; it is likely gone
+    ConnectFailed(String),
+    // No authorized account (extension locked or site disconnected); worth keeping
+    NotConnected,
+}
+
+// Why a restore probe failed; everything but a rejection is retried
+enum RestoreError {
+    ProviderMissing(String),
+    Rejected,
+    Failed(String),
+}
+
+// Ask the saved wallet for the accounts it already authorized, via `eth_accounts` so
+// restoring never opens a wallet popup
+async fn probe_saved_wallet(
+    window: &Window,
+    saved_uuid: &str,
+) -> Result<(JsValue, Vec<String>), RestoreError> {
+    let provider = find_provider_by_uuid(window, saved_uuid)
+        .ok()
+        .filter(|provider| !provider.is_null() && !provider.is_undefined())
+        .ok_or_else(|| {
+            RestoreError::ProviderMissing(format!("Wallet provider {} not found", saved_uuid))
+        })?;
+
+    let request_fn = Reflect::get(&provider, &"request".into())
+        .ok()
+        .and_then(|f| f.dyn_ref::<Function>().cloned())
+        .ok_or(RestoreError::Failed(
+            "request method not found on provider".to_string(),
+        ))?;
+    let request_params = Object::new();
+    Reflect::set(&request_params, &"method".into(), &"eth_accounts".into())
+        .map_err(|_| RestoreError::Failed("Failed to set method".to_string()))?;
+
+    let accounts_promise = request_fn
+        .call1(&provider, &request_params.into())
+        .map_err(|e| RestoreError::Failed(format!("Failed to call request: {:?}", e)))?;
+    let accounts = JsFuture::from(Promise::from(accounts_promise))
+        .await
+        .map_err(|e| {
+            let code = Reflect::get(&e, &"code".into())
+                .ok()
+                .and_then(|c| c.as_f64());
+            if code == Some(USER_REJECTED_REQUEST) {
+                RestoreError::Rejected
+            } else {
+                RestoreError::Failed(format!("Failed to get accounts: {:?}", e))
+            }
+        })?;
+
+    let accounts = accounts
+        .dyn_ref::<Array>()
+        .map(|accounts| accounts.iter().filter_map(|a| a.as_string()).collect())
+        .unwrap_or_default();
+    Ok((provider, accounts))
+}
+
+// Reconnect to a saved wallet without prompting, retrying with backoff while its provider
+// hasn't been announced yet (extension still initializing) or fails to answer. Returns
+// ConnectFailed only once every attempt has failed
+pub async fn restore_wallet_connection(saved_uuid: &str, saved_address: &str) -> RestoreOutcome {
+    let window = match get_window() {
+        Ok(window) => window,
+        Err(e) => return RestoreOutcome::ConnectFailed(e),
+    };
+
+    let mut last_error = String::new();
+    for attempt in 0..RESTORE_ATTEMPTS {
+        let delay = restore_backoff_ms(attempt);
+        if delay > 0 {
+            web_sys::console::log_1(
+                &format!(
+                    "🔄 Retrying wallet restore in {}ms (attempt {}/{})",
+                    delay,
+                    attempt + 1,
+                    RESTORE_ATTEMPTS
+                )
+                .into(),
+            );
+            gloo_timers::future::TimeoutFuture::new(delay).await;
+        }
+
+        let (provider, accounts) = match probe_saved_wallet(&window, saved_uuid).await {
+            Ok(probe) => probe,
+            Err(RestoreError::Rejected) => return RestoreOutcome::Rejected,
+            Err(RestoreError::ProviderMissing(e) | RestoreError::Failed(e)) => {
+                web_sys::console::log_1(
+                    &format!("⚠️ Wallet restore attempt {} failed: {}", attempt + 1, e).into(),
+                );
+                last_error = e;
+                continue;
+            }
+        };
+
+        match accounts.first() {
+            None => return RestoreOutcome::NotConnected,
+            Some(address) if address != saved_address => {
+                return RestoreOutcome::AddressMismatch(Some(address.clone()));
+            }
+            Some(_) => {}
+        }
+        let connected = set_current_provider(&window, &provider)
+            .and_then(|_| setup_provider_events(&window, &provider));
+        match connected {
+            Ok(()) => match get_account().await {
+                Ok(account) if account.is_connected => return RestoreOutcome::Restored(account),
+                Ok(_) => return RestoreOutcome::NotConnected,
+                Err(e) => last_error = e,
+            },
+            Err(e) => last_error = e,
+        }
+        web_sys::console::log_1(
+            &format!(
+                "⚠️ Wallet restore attempt {} failed: {}",
+                attempt + 1,
+                last_error
+            )
+            .into(),
+        );
+    }
+
+    RestoreOutcome::ConnectFailed(last_error)
+}
+
 // Initialize wallet system - discover wallets via EIP-6963
 pub async fn initialize() -> Result<(), String> {
     web_sys::console::log_1(&"🔌 Initializing EIP-6963 wallet discovery...".into());
@@ -224,6 +373,7 @@ pub async fn discover_wallets() -> Result<Vec<DiscoveredWallet>, String> {
         .and_then(|w| w.dyn_ref::<Array>().cloned());
 
     let mut wallets = Vec::new();
+    let mut announced = Vec::new();
     // Track wallet names and UUIDs to avoid duplicates
     let mut seen_wallets = std::collections::HashSet::<String>::new();
 
@@ -259,27 +409,14 @@ pub async fn discover_wallets() -> Result<Vec<DiscoveredWallet>, String> {
                                 .ok()
                                 .and_then(|v| v.as_string());
 
-                            // Add to seen_wallets to prevent duplicates
-                            if !seen_wallets.contains(&name) {
-                                seen_wallets.insert(name.clone());
-                                wallets.push(DiscoveredWallet {
-                                    info: WalletInfo {
-                                        uuid: uuid.clone(),
-                                        name: name.clone(),
-                                        icon: icon.clone(),
-                                        rdns: rdns.clone(),
-                                    },
-                                });
-                                web_sys::console::log_1(
-                                    &format!("✅ Added EIP-6963 wallet: {} (uuid: {})", name, uuid)
-                                        .into(),
-                                );
-                            } else {
-                                web_sys::console::log_1(
-                                    &format!("⏭️ Skipped duplicate EIP-6963 wallet: {}", name)
-                                        .into(),
-                                );
-                            }
+                            announced.push(DiscoveredWallet {
+                                info: WalletInfo {
+                                    uuid,
+                                    name,
+                                    icon,
+                                    rdns,
+                                },
+                            });
                         }
                     }
                 }
@@ -287,6 +424,30 @@ pub async fn discover_wallets() -> Result<Vec<DiscoveredWallet>, String> {
         }
     }
 
+    // Wallets re-announce on every requestProvider, so collapse repeats before listing them
+    let announced_count = announced.len();
+    let announced = dedupe_announced_wallets(announced);
+    if announced.len() < announced_count {
+        web_sys::console::log_1(
+            &format!(
+                "⏭️ Skipped {} duplicate EIP-6963 announcements",
+                announced_count - announced.len()
+            )
+            .into(),
+        );
+    }
+    for wallet in announced {
+        web_sys::console::log_1(
+            &format!(
+                "✅ Added EIP-6963 wallet: {} (uuid: {})",
+                wallet.info.name, wallet.info.uuid
+            )
+            .into(),
+        );
+        seen_wallets.insert(wallet.info.name.clone());
+        wallets.push(wallet);
+    }
+
     // Check window.rabby (Rabby Wallet) - priority check before window.ethereum
     // Only add if not already added via EIP-6963 or other methods
     web_sys::console::log_1(&"🔍 Checking window.rabby...".into());
@@ -557,12 +718,43 @@ pub async fn discover_wallets() -> Result<Vec<DiscoveredWallet>, String> {
 
     web_sys::console::log_1(&format!("✅ Total wallets discovered: {}", wallets.len()).into());
 
+    // Stable order regardless of which wallet announced first
+    sort_wallets_by_name(&mut wallets);
+
     // Get all supported wallets and merge with discovered ones
     let all_wallets = get_all_supported_wallets(wallets, seen_wallets);
 
     Ok(all_wallets)
 }
 
+// De-duplicate EIP-6963 announcements by RDNS (uuid when there is none); the most recent
+// announcement replaces earlier ones but keeps the position of the first
+fn dedupe_announced_wallets(announced: Vec<DiscoveredWallet>) -> Vec<DiscoveredWallet> {
+    let mut wallets: Vec<DiscoveredWallet> = Vec::new();
+    let mut positions = std::collections::HashMap::<String, usize>::new();
+    for wallet in announced {
+        let key = wallet
+            .info
+            .rdns
+            .clone()
+            .filter(|rdns| !rdns.is_empty())
+            .unwrap_or_else(|| wallet.info.uuid.clone());
+        match positions.get(&key) {
+            Some(&index) => wallets[index] = wallet,
+            None => {
+                positions.insert(key, wallets.len());
+                wallets.push(wallet);
+            }
+        }
+    }
+    wallets
+}
+
+// Sort wallets by name, case-insensitively; the sort is stable so equal names keep their order
+fn sort_wallets_by_name(wallets: &mut [DiscoveredWallet]) {
+    wallets.sort_by_key(|wallet| wallet.info.name.to_lowercase());
+}
+
 // Get all supported wallets list
 // Returns wallets with detected ones first, then undetected ones
 fn get_all_supported_wallets(
@@ -1657,3 +1849,55 @@ pub async fn get_fid_for_address(api_url: &str, address: &str) -> Result<Option<
         Ok(None)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wallet(uuid: &str, name: &str, rdns: Option<&str>) -> DiscoveredWallet {
+        DiscoveredWallet {
+            info: WalletInfo {
+                uuid: uuid.to_string(),
+                name: name.to_string(),
+                icon: String::new(),
+                rdns: rdns.map(str::to_string),
+            },
+        }
+    }
+
+    #[test]
+    fn test_dedupe_announced_wallets() {
+        let wallets = dedupe_announced_wallets(vec![
+            wallet("a", "MetaMask", Some("io.metamask")),
+            wallet("b", "Rabby", Some("io.rabby")),
+            wallet("c", "MetaMask Flask", Some("io.metamask")),
+            wallet("d", "Local", None),
+            wallet("d", "Local", Some("")),
+        ]);
+        let names: Vec<_> = wallets.iter().map(|w| w.info.name.as_str()).collect();
+        // The latest announcement wins but stays where the first one was
+        assert_eq!(names, vec!["MetaMask Flask", "Rabby", "Local"]);
+        assert_eq!(wallets[0].info.uuid, "c");
+    }
+
+    #[test]
+    fn test_restore_backoff_ms() {
+        let delays: Vec<_> = (0..RESTORE_ATTEMPTS).map(restore_backoff_ms).collect();
+        assert_eq!(delays, vec![0, 500, 1000]);
+        // Capped so a larger attempt count can't wait forever
+        assert_eq!(restore_backoff_ms(20), 8000);
+    }
+
+    #[test]
+    fn test_sort_wallets_by_name() {
+        let mut wallets = vec![
+            wallet("1", "rainbow", None),
+            wallet("2", "Base", None),
+            wallet("3", "MetaMask", None),
+            wallet("4", "base", None),
+        ];
+        sort_wallets_by_name(&mut wallets);
+        let uuids: Vec<_> = wallets.iter().map(|w| w.info.uuid.as_str()).collect();
+        assert_eq!(uuids, vec!["2", "4", "3", "1"]);
+    }
+}
@@ -1,6 +1,12 @@
 use serde_json::json;
 use worker::*;
-use image::{Rgba, RgbaImage};
+use image::{RgbImage, Rgba, RgbaImage};
+
+// Share caption templates, shared with the frontend so /api/share-text matches the app
+#[path = "../../shared/share_text.rs"]
+mod share_text;
+
+use share_text::{builtin_share_template, render_share_template, ShareTextTemplate, ShareTextValues};
 
 // Tarot card mapping: index 0-21 corresponds to 22 tarot cards
 // This matches the TAROT_CARDS constant in src/pages/annual_report/sections.rs
@@ -30,34 +36,134 @@ const TAROT_CARDS: &[(&str, &str)] = &[
     ("The World", "21-world.jpg"),
 ];
 
+/// FNV-1a (64-bit), stable across Rust releases unlike DefaultHasher
+/// Must stay identical to fnv1a_64 in src/pages/annual_report/sections.rs
+fn fnv1a_64(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
 /// Calculate tarot card based on FID hash mod 22
 /// This matches the logic in src/pages/annual_report/sections.rs::calculate_personality_tag
-fn calculate_tarot_card(fid: i64) -> (&'static str, &'static str) {
-    use std::collections::hash_map::DefaultHasher;
-    use std::hash::{Hash, Hasher};
-
-    // Calculate hash of FID
-    let mut hasher = DefaultHasher::new();
-    fid.hash(&mut hasher);
-    let hash = hasher.finish();
-
-    // Get index by mod 22 (0-21)
-    let index = (hash % 22) as usize;
+/// `salt` must be 0 for the canonical card (shared images always use 0)
+fn calculate_tarot_card(fid: i64, salt: u64) -> (&'static str, &'static str) {
+    // FNV-1a over the little-endian FID bytes (plus salt bytes when non-zero), mod 22
+    let mut bytes = fid.to_le_bytes().to_vec();
+    if salt != 0 {
+        bytes.extend_from_slice(&salt.to_le_bytes());
+    }
+    let index = (fnv1a_64(&bytes) % TAROT_CARDS.len() as u64) as usize;
 
     // Get tarot card name and filename
     TAROT_CARDS[index]
 }
 
+/// App name and splash screen used in the `fc:miniapp`/`fc:frame` launch actions
+/// Configurable so forks can rebrand without code edits
+#[derive(Debug, Clone)]
+struct EmbedBranding {
+    /// EMBED_APP_NAME
+    app_name: String,
+    /// EMBED_SPLASH_PATH: path under BASE_URL, or an absolute URL
+    splash_path: String,
+    /// EMBED_SPLASH_BG
+    splash_background: String,
+}
+
+impl Default for EmbedBranding {
+    fn default() -> Self {
+        Self {
+            app_name: "polyjuice".to_string(),
+            splash_path: "/imgs/splash.png".to_string(),
+            splash_background: "#667eea".to_string(),
+        }
+    }
+}
+
+impl EmbedBranding {
+    fn splash_image_url(&self, base_url: &str) -> String {
+        if self.splash_path.starts_with("https://") || self.splash_path.starts_with("http://") {
+            self.splash_path.clone()
+        } else {
+            format!("{}/{}", base_url, self.splash_path.trim_start_matches('/'))
+        }
+    }
+}
+
+/// Report card banner and background colors, and the year shown in the banner title
+/// Configurable so the generator can be reused for other years and themed cards
+#[derive(Debug, Clone, PartialEq)]
+struct CardTheme {
+    /// CARD_YEAR: title year when the share params don't carry one
+    default_year: u16,
+    /// CARD_BANNER_HEIGHT: landscape card banner height at 1x
+    banner_height: u32,
+    /// CARD_BANNER_COLOR
+    banner_color: [u8; 3],
+    /// CARD_GRADIENT_START / CARD_GRADIENT_END: top and bottom of the background gradient
+    gradient_start: [u8; 3],
+    gradient_end: [u8; 3],
+}
+
+impl Default for CardTheme {
+    fn default() -> Self {
+        Self {
+            default_year: 2025,
+            banner_height: 80,
+            banner_color: [0x00, 0x00, 0x00],
+            gradient_start: [0x66, 0x7e, 0xea],
+            gradient_end: [0x76, 0x4b, 0xa2],
+        }
+    }
+}
+
+impl CardTheme {
+    /// Banner title, using the year from the share params if present
+    fn banner_title(&self, year: Option<u16>) -> String {
+        format!("My {} Annual Report", year.unwrap_or(self.default_year))
+    }
+    
+    /// Background gradient color at `ratio` (0.0 = top, 1.0 = bottom)
+    fn gradient_at(&self, ratio: f32) -> Rgba<u8> {
+        let ratio = ratio.clamp(0.0, 1.0);
+        let channel = |i: usize| {
+            let (start, end) = (self.gradient_start[i] as f32, self.gradient_end[i] as f32);
+            (start + (end - start) * ratio) as u8
+        };
+        Rgba([channel(0), channel(1), channel(2), 255])
+    }
+}
+
+/// Parse a `#rrggbb` (or `rrggbb`) color
+fn parse_hex_color(value: &str) -> Option<[u8; 3]> {
+    let hex = value.trim().trim_start_matches('#');
+    if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    Some([channel(0)?, channel(2)?, channel(4)?])
+}
+
 /// Generate meta tags for annual report based on FID
 /// If params_base64 is provided, use generated report card image instead of tarot card
-fn generate_annual_report_meta_tags(fid: i64, base_url: &str, pathname: &str, params_base64: Option<&str>) -> String {
+fn generate_annual_report_meta_tags(
+    fid: i64,
+    base_url: &str,
+    pathname: &str,
+    params_base64: Option<&str>,
+    branding: &EmbedBranding,
+) -> String {
     // Determine image URL: use generated report card if params are provided, otherwise use tarot card
     let image_url = if let Some(params) = params_base64 {
         // Use generated report card image
         format!("{}/api/generate?params={}", base_url, params)
     } else {
         // Use tarot card image
-        let (_tarot_name, tarot_filename) = calculate_tarot_card(fid);
+        let (_tarot_name, tarot_filename) = calculate_tarot_card(fid, 0);
         format!("{}/imgs/tarot/{}", base_url, tarot_filename)
     };
     let target_url = format!("{}{}", base_url, pathname);
@@ -71,9 +177,9 @@ fn generate_annual_report_meta_tags(fid: i64, base_url: &str, pathname: &str, pa
             "action": {
                 "type": "launch_miniapp",
                 "url": target_url,
-                "name": "polyjuice",
-                "splashImageUrl": format!("{}/imgs/splash.png", base_url),
-                "splashBackgroundColor": "#667eea"
+                "name": branding.app_name,
+                "splashImageUrl": branding.splash_image_url(base_url),
+                "splashBackgroundColor": branding.splash_background
             }
         }
     });
@@ -89,9 +195,9 @@ fn generate_annual_report_meta_tags(fid: i64, base_url: &str, pathname: &str, pa
             "action": {
                 "type": "launch_frame",
                 "url": target_url,
-                "name": "polyjuice",
-                "splashImageUrl": format!("{}/imgs/splash.png", base_url),
-                "splashBackgroundColor": "#667eea"
+                "name": branding.app_name,
+                "splashImageUrl": branding.splash_image_url(base_url),
+                "splashBackgroundColor": branding.splash_background
             }
         }
     });
@@ -115,39 +221,245 @@ fn generate_annual_report_meta_tags(fid: i64, base_url: &str, pathname: &str, pa
     )
 }
 
+/// Generate meta tags for a plain profile page, pointing at the generated profile card
+fn generate_profile_meta_tags(fid: i64, base_url: &str, pathname: &str, branding: &EmbedBranding) -> String {
+    let image_url = format!("{}/api/profile-card?fid={}", base_url, fid);
+    let target_url = format!("{}{}", base_url, pathname);
+
+    let embed_json = json!({
+        "version": "1",
+        "imageUrl": image_url,
+        "button": {
+            "title": "View Profile",
+            "action": {
+                "type": "launch_miniapp",
+                "url": target_url,
+                "name": branding.app_name,
+                "splashImageUrl": branding.splash_image_url(base_url),
+                "splashBackgroundColor": branding.splash_background
+            }
+        }
+    });
+
+    let embed_json_str = serde_json::to_string(&embed_json).unwrap_or_default();
+
+    format!(
+        r#"<meta name="fc:miniapp" content='{}' />
+<meta property="og:title" content="Farcaster Profile - Polyjuice" />
+<meta property="og:description" content="View this Farcaster profile on Polyjuice" />
+<meta property="og:image" content="{}" />
+<meta property="og:url" content="{}" />
+<meta property="og:type" content="profile" />
+<meta name="twitter:card" content="summary_large_image" />
+<meta name="twitter:title" content="Farcaster Profile - Polyjuice" />
+<meta name="twitter:description" content="View this Farcaster profile on Polyjuice" />
+<meta name="twitter:image" content="{}" />"#,
+        embed_json_str, image_url, target_url, image_url
+    )
+}
+
 /// Check if the request is from a Farcaster crawler/bot
 fn is_farcaster_bot(user_agent: Option<&str>, headers: &Headers) -> bool {
-    // Check User-Agent
-    if let Some(ua) = user_agent {
-        let ua_lower = ua.to_lowercase();
-        if ua_lower.contains("farcaster")
-            || ua_lower.contains("bot")
-            || ua_lower.contains("crawler")
-            || ua_lower.contains("spider")
-        {
-            return true;
+    // Check for custom headers that Farcaster might send (get() is Ok(None) when absent)
+    is_bot_user_agent(user_agent) || matches!(headers.get("x-farcaster-bot"), Ok(Some(_)))
+}
+
+/// Check whether a User-Agent looks like a Farcaster crawler or other bot
+fn is_bot_user_agent(user_agent: Option<&str>) -> bool {
+    match user_agent {
+        Some(ua) => {
+            let ua_lower = ua.to_lowercase();
+            ua_lower.contains("farcaster")
+                || ua_lower.contains("bot")
+                || ua_lower.contains("crawler")
+                || ua_lower.contains("spider")
         }
+        None => false,
     }
+}
 
-    // Check for custom headers that Farcaster might send
-    if headers.get("x-farcaster-bot").is_ok() {
-        return true;
+/// Map a request path to the path fetched from the source origin
+fn proxy_path(pathname: &str) -> &str {
+    if pathname == "/" || pathname.is_empty() {
+        "/index.html"
+    } else {
+        pathname
+    }
+}
+
+/// Header added to proxied responses that didn't come back clean from the source origin
+const PROXY_STATUS_HEADER: &str = "x-proxy-status";
+
+/// `x-proxy-status` value for an origin response: `origin-error; status=N` for HTTP errors,
+/// None for anything under 400 (successful responses are passed through untouched)
+fn proxy_status_value(status: u16) -> Option<String> {
+    (status >= 400).then(|| format!("origin-error; status={}", status))
+}
+
+/// Minimal standalone document carrying only the embed meta tags
+/// Served to bots when the source origin doesn't return HTML (e.g. a maintenance JSON)
+fn fallback_meta_html(meta_tags: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\" />\n<title>Polyjuice</title>\n{}\n</head>\n<body></body>\n</html>",
+        meta_tags
+    )
+}
+
+/// Meta attributes owned by the worker; matching tags in the source head are replaced
+const INJECTED_META_PREFIXES: [&str; 4] = [
+    "name=\"fc:miniapp\"",
+    "name=\"fc:frame\"",
+    "property=\"og:",
+    "name=\"twitter:",
+];
+
+/// Find the end (index just past `>`) of the tag starting at `start`, skipping `>` inside quoted attributes
+fn find_tag_end(html: &str, start: usize) -> Option<usize> {
+    let mut quote = None;
+    for (offset, c) in html[start..].char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"') | (None, '\'') => quote = Some(c),
+            (None, '>') => return Some(start + offset + 1),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Find the first `<head>` / `<head ...>` opening tag (not `<header>`), returning (tag start, tag end)
+fn find_head_open(lower: &str) -> Option<(usize, usize)> {
+    let mut search_from = 0;
+    while let Some(pos) = lower[search_from..].find("<head") {
+        let start = search_from + pos;
+        let next = lower[start + 5..].chars().next();
+        if matches!(next, Some('>') | Some(' ') | Some('\t') | Some('\n') | Some('\r')) {
+            return find_tag_end(lower, start).map(|end| (start, end));
+        }
+        search_from = start + 5;
+    }
+    None
+}
+
+/// Drop worker-owned meta elements from a head section, wherever they sit on a line
+/// Elements that occupied a whole line take their line with them
+fn strip_injected_meta(head: &str) -> String {
+    let lower = head.to_ascii_lowercase();
+    let mut result = String::with_capacity(head.len());
+    let mut cursor = 0;
+    
+    while let Some(pos) = lower[cursor..].find("<meta") {
+        let start = cursor + pos;
+        let Some(end) = find_tag_end(&lower, start) else {
+            break;
+        };
+        let tag = lower[start..end].replace('\'', "\"");
+        if !INJECTED_META_PREFIXES.iter().any(|prefix| tag.contains(prefix)) {
+            result.push_str(&head[cursor..end]);
+            cursor = end;
+            continue;
+        }
+        
+        // Remove the whole line when the element is alone on it
+        let line_start = head[..start].rfind('\n').map(|i| i + 1).unwrap_or(0).max(cursor);
+        let line_end = head[end..].find('\n').map(|i| end + i + 1).unwrap_or(head.len());
+        let alone_on_line = head[line_start..start].trim().is_empty() && head[end..line_end].trim().is_empty();
+        if alone_on_line {
+            result.push_str(&head[cursor..line_start]);
+            cursor = line_end;
+        } else {
+            result.push_str(&head[cursor..start]);
+            cursor = end;
+        }
+    }
+    result.push_str(&head[cursor..]);
+    result
+}
+
+/// Replace fc:miniapp, fc:frame, og:* and twitter:* meta tags in the source HTML's first head
+/// section with `meta_tags`, inserted before its closing tag (creating a head if needed)
+/// Everything outside the head is left untouched, and injecting twice gives the same result
+fn inject_meta_tags(html: &str, meta_tags: &str) -> String {
+    let lower = html.to_ascii_lowercase();
+    
+    if let Some((_, open_end)) = find_head_open(&lower) {
+        // Head content runs to the first closing tag after the opening tag
+        let Some(close_pos) = lower[open_end..].find("</head>").map(|i| open_end + i) else {
+            // Unclosed head: insert right after the opening tag
+            return format!("{}\n{}{}", &html[..open_end], meta_tags, &html[open_end..]);
+        };
+        let head_content = strip_injected_meta(&html[open_end..close_pos]);
+        let separator = if head_content.is_empty() || head_content.ends_with('\n') { "" } else { "\n" };
+        return format!(
+            "{}{}{}{}\n{}",
+            &html[..open_end],
+            head_content,
+            separator,
+            meta_tags,
+            &html[close_pos..]
+        );
     }
+    
+    if let Some(body_pos) = lower.find("<body") {
+        // If no head tag, prepend to body
+        format!("{}<head>{}</head>\n{}", &html[..body_pos], meta_tags, &html[body_pos..])
+    } else {
+        format!("<head>{}</head>\n{}", meta_tags, html)
+    }
+}
 
-    false
+/// Parse the FID segment that ends a route path: one optional trailing slash, then a
+/// positive decimal number and nothing else (no sign, nested segments, or query)
+fn parse_path_fid(segment: &str) -> Option<i64> {
+    let fid_str = segment.strip_suffix('/').unwrap_or(segment);
+    if fid_str.is_empty() || !fid_str.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    fid_str.parse().ok().filter(|fid| *fid > 0)
 }
 
 /// Extract FID from annual report URL path
-/// Format: /annual-report/{fid}
+/// Format: /annual-report/{fid} or /annual-report/{fid}/; nested paths are rejected
+/// `pathname` is `Url::path()`, so query strings never reach it
 fn extract_fid_from_path(pathname: &str) -> Option<i64> {
-    if pathname.starts_with("/annual-report/") {
-        let fid_str = pathname.strip_prefix("/annual-report/")?;
-        // Remove trailing slash if present
-        let fid_str = fid_str.trim_end_matches('/');
-        fid_str.parse().ok()
-    } else {
-        None
+    parse_path_fid(pathname.strip_prefix("/annual-report/")?)
+}
+
+/// Who an annual report path is for: a FID, or a username that still has to be resolved
+#[derive(Debug, PartialEq)]
+enum ReportTarget {
+    Fid(i64),
+    Username(String),
+}
+
+/// Parse the username segment that ends a route path: one optional trailing slash, an optional
+/// `@`, then 1-32 letters, digits, `-`, `_` or `.` starting with a letter or digit (lowercased).
+/// All-digit segments are FIDs, never usernames
+fn parse_path_username(segment: &str) -> Option<String> {
+    let segment = segment.strip_suffix('/').unwrap_or(segment);
+    let username = segment.strip_prefix('@').unwrap_or(segment);
+    let valid = (1..=32).contains(&username.len())
+        && username.starts_with(|c: char| c.is_ascii_alphanumeric())
+        && username.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        && !username.bytes().all(|b| b.is_ascii_digit());
+    valid.then(|| username.to_ascii_lowercase())
+}
+
+/// Extract the report target from an annual report URL path
+/// Format: /annual-report/{fid} or /annual-report/{username} (optionally `@`-prefixed)
+/// Must match parse_report_target in src/services.rs
+fn extract_report_target(pathname: &str) -> Option<ReportTarget> {
+    if let Some(fid) = extract_fid_from_path(pathname) {
+        return Some(ReportTarget::Fid(fid));
     }
+    parse_path_username(pathname.strip_prefix("/annual-report/")?).map(ReportTarget::Username)
+}
+
+/// Extract FID from profile URL path
+/// Format: /profile/{fid} (username paths like /profile/@name are not handled)
+fn extract_profile_fid_from_path(pathname: &str) -> Option<i64> {
+    parse_path_fid(pathname.strip_prefix("/profile/")?)
 }
 
 /// Decoded image params with user info and stats
@@ -155,19 +467,80 @@ fn extract_fid_from_path(pathname: &str) -> Option<i64> {
 struct ImageParams {
     fid: i64,
     zodiac_index: u8,      // 0-11
-    social_type_index: u8, // 0=silent, 1=social
+    social_type_index: u8, // Social tier, see get_social_type_url_from_index
     total_casts: usize,
     total_reactions: usize,
     total_followers: usize,
+    hide_stats: bool,      // SHARE_FLAG_HIDE_STATS: render without the stats lines
+    hide_fid: bool,        // SHARE_FLAG_HIDE_FID: render without the "FID: n" line
+    year: Option<u16>,     // Report year for the banner title, CARD_YEAR when absent
+}
+
+/// Share params flag: the sharer opted out of showing their counts
+/// Must match SHARE_FLAG_HIDE_STATS in src/pages/annual_report/sections.rs
+const SHARE_FLAG_HIDE_STATS: u8 = 0x01;
+
+/// Share params flag: the sharer opted out of showing their FID
+/// Must match SHARE_FLAG_HIDE_FID in src/pages/annual_report/sections.rs
+const SHARE_FLAG_HIDE_FID: u8 = 0x02;
+
+/// Profile API response; older deployments wrap the profile in `profile` instead of `data`
+#[derive(Debug, Default, serde::Deserialize)]
+struct ProfileEnvelope {
+    #[serde(default)]
+    data: Option<ProfileApiResponse>,
+    #[serde(default)]
+    profile: Option<ProfileApiResponse>,
+}
+
+impl ProfileEnvelope {
+    fn into_profile(self) -> Option<ProfileApiResponse> {
+        self.data.or(self.profile)
+    }
 }
 
 /// Profile data fetched from API
-#[derive(Debug, serde::Deserialize)]
+/// Both spellings are kept as separate fields since some responses carry both
+#[derive(Debug, Default, serde::Deserialize)]
 struct ProfileApiResponse {
-    fid: i64,
+    #[serde(default)]
+    fid: Option<i64>,
+    #[serde(default)]
     username: Option<String>,
+    #[serde(default)]
     display_name: Option<String>,
+    #[serde(default)]
     pfp_url: Option<String>,
+    #[serde(default)]
+    avatar: Option<String>,
+    #[serde(default)]
+    power_badge: Option<bool>,
+    #[serde(default, rename = "powerBadge")]
+    power_badge_camel: Option<bool>,
+}
+
+impl ProfileApiResponse {
+    fn into_card_profile(self) -> CardProfile {
+        let avatar_url = self.pfp_url
+            .filter(|s| !s.is_empty())
+            .or(self.avatar.filter(|s| !s.is_empty()));
+        CardProfile {
+            username: self.username,
+            display_name: self.display_name,
+            avatar_url,
+            // Absent or null when the API doesn't expose it
+            power_badge: self.power_badge.or(self.power_badge_camel).unwrap_or(false),
+        }
+    }
+}
+
+/// Profile fields used when drawing the generated cards
+#[derive(Debug, Default)]
+struct CardProfile {
+    username: Option<String>,
+    display_name: Option<String>,
+    avatar_url: Option<String>,
+    power_badge: bool,
 }
 
 /// Get zodiac image URL from index (0-11)
@@ -184,17 +557,21 @@ fn get_zodiac_url_from_index(index: u8, base_url: &str) -> String {
     format!("{}/imgs/zodiac/{}.png", base_url, zodiac_name)
 }
 
-/// Get social type image URL from index (0=silent, 1=social)
+/// Get social type image URL from tier index
+/// Must match SOCIAL_TIERS in src/pages/annual_report/sections.rs (0=lurker, 1=social butterfly, 2=casual, 3=chatty)
 fn get_social_type_url_from_index(index: u8, base_url: &str) -> String {
-    if index == 1 {
-        format!("{}/imgs/social_type/social.png", base_url)
-    } else {
-        format!("{}/imgs/social_type/slient.png", base_url)
-    }
+    let image_name = match index {
+        1 => "social",
+        2 => "casual",
+        3 => "chatty",
+        // Unknown tiers from newer clients fall back to the lowest tier
+        _ => "slient",
+    };
+    format!("{}/imgs/social_type/{}.png", base_url, image_name)
 }
 
 /// Fetch profile from API
-async fn fetch_profile_from_api(fid: i64, api_url: &str) -> Result<(Option<String>, Option<String>), String> {
+async fn fetch_profile_from_api(fid: i64, api_url: &str) -> Result<CardProfile, String> {
     let url = format!("{}/api/profiles/fid/{}", api_url.trim_end_matches('/'), fid);
     
     console_log!("📡 Fetching profile for FID {} from: {}", fid, url);
@@ -209,41 +586,91 @@ async fn fetch_profile_from_api(fid: i64, api_url: &str) -> Result<(Option<Strin
     
     if response.status_code() != 200 {
         console_log!("⚠️ Profile API returned status: {}", response.status_code());
-        return Ok((None, None)); // Return None if not found
+        return Ok(CardProfile::default()); // Return empty profile if not found
     }
     
     let text = response.text().await
         .map_err(|e| format!("Failed to read response: {:?}", e))?;
     
-    // Try to parse as ApiResponse<ProfileData>
-    let api_response: serde_json::Value = serde_json::from_str(&text)
+    let envelope: ProfileEnvelope = serde_json::from_str(&text)
         .map_err(|e| format!("Failed to parse JSON: {}", e))?;
     
-    // Extract profile data
-    let profile = api_response.get("data")
-        .or_else(|| api_response.get("profile"));
-    
-    if let Some(profile_data) = profile {
-        let username = profile_data.get("username")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string());
-        
-        let avatar_url = profile_data.get("pfp_url")
-            .or_else(|| profile_data.get("avatar"))
-            .and_then(|v| v.as_str())
-            .filter(|s| !s.is_empty())
-            .map(|s| s.to_string());
-        
-        console_log!("✅ Fetched profile: username={:?}, avatar={:?}", username, avatar_url);
-        Ok((username, avatar_url))
+    if let Some(profile_data) = envelope.into_profile() {
+        let profile = profile_data.into_card_profile();
+        console_log!(
+            "✅ Fetched profile: username={:?}, display_name={:?}, avatar={:?}, power_badge={}",
+            profile.username, profile.display_name, profile.avatar_url, profile.power_badge
+        );
+        Ok(profile)
     } else {
         console_log!("⚠️ No profile data in API response");
-        Ok((None, None))
+        Ok(CardProfile::default())
+    }
+}
+
+/// Resolve a username to its FID via the profile API; Ok(None) when there is no such user
+async fn resolve_username_fid(username: &str, api_url: &str) -> Result<Option<i64>, String> {
+    let url = format!("{}/api/profiles/username/{}", api_url.trim_end_matches('/'), username);
+    
+    console_log!("📡 Resolving username @{} from: {}", username, url);
+    
+    let request = Request::new(&url, Method::Get)
+        .map_err(|e| format!("Failed to create request: {:?}", e))?;
+    
+    let mut response = Fetch::Request(request)
+        .send()
+        .await
+        .map_err(|e| format!("Fetch failed: {:?}", e))?;
+    
+    match response.status_code() {
+        200 => {}
+        404 => return Ok(None),
+        status => return Err(format!("Profile API returned status: {}", status)),
+    }
+    
+    let text = response.text().await
+        .map_err(|e| format!("Failed to read response: {:?}", e))?;
+    
+    let envelope: ProfileEnvelope = serde_json::from_str(&text)
+        .map_err(|e| format!("Failed to parse JSON: {}", e))?;
+    
+    Ok(envelope.into_profile().and_then(|profile| profile.fid).filter(|fid| *fid > 0))
+}
+
+/// Fetch follower count from the social API (profile API doesn't include it)
+async fn fetch_follower_count_from_api(fid: i64, api_url: &str) -> Result<Option<usize>, String> {
+    let url = format!("{}/api/social/{}", api_url.trim_end_matches('/'), fid);
+    
+    console_log!("📡 Fetching follower count for FID {} from: {}", fid, url);
+    
+    let request = Request::new(&url, Method::Get)
+        .map_err(|e| format!("Failed to create request: {:?}", e))?;
+    
+    let mut response = Fetch::Request(request)
+        .send()
+        .await
+        .map_err(|e| format!("Fetch failed: {:?}", e))?;
+    
+    if response.status_code() != 200 {
+        console_log!("⚠️ Social API returned status: {}", response.status_code());
+        return Ok(None);
     }
+    
+    let text = response.text().await
+        .map_err(|e| format!("Failed to read response: {:?}", e))?;
+    
+    let api_response: serde_json::Value = serde_json::from_str(&text)
+        .map_err(|e| format!("Failed to parse JSON: {}", e))?;
+    
+    Ok(api_response
+        .get("data")
+        .and_then(|data| data.get("followers_count"))
+        .and_then(|v| v.as_u64())
+        .map(|count| count as usize))
 }
 
 /// Decode base64 params from compact binary format
-/// Format: [0-7]: FID (i64, little-endian), [8]: Zodiac (u8, 0-11), [9]: Social type (u8, 0=silent, 1=social),
+/// Format: [0-7]: FID (i64, little-endian), [8]: Zodiac (u8, 0-11), [9]: Social tier (u8, 0-3),
 ///         [10-13]: Total casts (u32), [14-17]: Total reactions (u32), [18-21]: Total followers (u32)
 fn decode_image_params(params_base64: &str) -> Result<ImageParams, String> {
     use base64::engine::general_purpose;
@@ -303,6 +730,15 @@ fn decode_image_params(params_base64: &str) -> Result<ImageParams, String> {
     ];
     let total_followers = u32::from_le_bytes(followers_bytes) as usize;
     
+    // Optional flags byte (absent in links created before it existed)
+    let flags = decoded_bytes.get(22).copied().unwrap_or(0);
+    
+    // Optional report year (2 bytes, little-endian) after the flags byte; implausible years are ignored
+    let year = decoded_bytes
+        .get(23..25)
+        .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+        .filter(|year| (2020..=2100).contains(year));
+    
     Ok(ImageParams {
         fid,
         zodiac_index,
@@ -310,11 +746,22 @@ fn decode_image_params(params_base64: &str) -> Result<ImageParams, String> {
         total_casts,
         total_reactions,
         total_followers,
+        hide_stats: flags & SHARE_FLAG_HIDE_STATS != 0,
+        hide_fid: flags & SHARE_FLAG_HIDE_FID != 0,
+        year,
     })
 }
 
 /// Fetch image data from URL using Worker Fetch API
 async fn fetch_image_data(url: &str) -> Result<Vec<u8>, String> {
+    fetch_image_response(url).await.map(|(bytes, _)| bytes)
+}
+
+/// Image bytes plus the content-type they were served with, if known
+type FetchedImage = (Vec<u8>, Option<String>);
+
+/// Fetch image bytes along with the response content-type (used to spot SVG avatars)
+async fn fetch_image_response(url: &str) -> Result<FetchedImage, String> {
     // Parse URL using worker's Request API
     let request = Request::new(url, Method::Get)
         .map_err(|e| format!("Failed to create request: {:?}", e))?;
@@ -328,80 +775,461 @@ async fn fetch_image_data(url: &str) -> Result<Vec<u8>, String> {
         return Err(format!("Failed to fetch image: status {}", response.status_code()));
     }
     
+    let content_type = response.headers().get("content-type").ok().flatten();
     let bytes = response.bytes().await
         .map_err(|e| format!("Failed to read response bytes: {:?}", e))?;
     
-    Ok(bytes.to_vec())
+    // Misconfigured CDNs answer 200 with an HTML error page; fail the fetch instead of the decode
+    ensure_image_payload(&bytes, content_type.as_deref())?;
+    
+    Ok((bytes.to_vec(), content_type))
 }
 
-/// Resize image and add circular border (2px low-saturation blue)
-fn resize_with_circular_border(img: &RgbaImage, size: u32) -> RgbaImage {
-    // Resize image
-    let resized = image::imageops::resize(
-        img,
-        size,
-        size,
-        image::imageops::FilterType::Lanczos3,
-    );
-    
-    // Create canvas with border (size + 4px for 2px border on each side)
-    let canvas_size = size + 4;
-    let mut canvas = RgbaImage::new(canvas_size, canvas_size);
-    
-    // Fill with transparent
-    for pixel in canvas.pixels_mut() {
-        *pixel = Rgba([0, 0, 0, 0]);
+/// Check that fetched bytes are an image: an `image/*` content-type, or (for missing or generic
+/// types like application/octet-stream) image magic bytes or SVG markup. HTML is always rejected
+fn ensure_image_payload(data: &[u8], content_type: Option<&str>) -> Result<(), String> {
+    let media_type = content_type
+        .and_then(|ct| ct.split(';').next())
+        .map(|ct| ct.trim().to_ascii_lowercase())
+        .filter(|ct| !ct.is_empty());
+    let is_image = match media_type.as_deref() {
+        Some(ct) if ct.starts_with("image/") => true,
+        Some("text/html" | "application/xhtml+xml") => false,
+        _ => image::guess_format(data).is_ok() || is_svg(data, None),
+    };
+    if is_image {
+        Ok(())
+    } else {
+        Err(format!(
+            "Expected an image but got {} ({} bytes)",
+            media_type.as_deref().unwrap_or("no content-type"),
+            data.len()
+        ))
     }
+}
+
+/// Decode a `data:` URI into its bytes and media type
+/// Returns None if `url` isn't a data URI
+fn decode_data_uri(url: &str) -> Option<Result<FetchedImage, String>> {
+    use base64::Engine;
     
-    // Draw circular mask and copy resized image
-    let center = (canvas_size as f32 / 2.0, canvas_size as f32 / 2.0);
-    let radius = (size as f32 / 2.0) as f32;
-    let border_radius = radius + 2.0;
+    let rest = url.strip_prefix("data:")?;
+    let Some((meta, payload)) = rest.split_once(',') else {
+        return Some(Err("Malformed data URI: missing ','".to_string()));
+    };
+    let is_base64 = meta.ends_with(";base64");
+    let media_type = meta.split(';').next().filter(|m| !m.is_empty()).map(|m| m.to_string());
     
-    for y in 0..canvas_size {
-        for x in 0..canvas_size {
-            let dx = x as f32 - center.0;
-            let dy = y as f32 - center.1;
-            let dist = (dx * dx + dy * dy).sqrt();
-            
-            if dist <= radius {
-                // Inside circle - copy from resized image
-                let src_x = ((x as f32 - 2.0).max(0.0).min(size as f32 - 1.0)) as u32;
-                let src_y = ((y as f32 - 2.0).max(0.0).min(size as f32 - 1.0)) as u32;
-                canvas.put_pixel(x, y, *resized.get_pixel(src_x, src_y));
-            } else if dist <= border_radius {
-                // Border area - draw low-saturation blue border
-                canvas.put_pixel(x, y, Rgba([122, 156, 198, 255])); // Low-saturation blue #7A9CC6
-            }
+    let bytes = if is_base64 {
+        base64::engine::general_purpose::STANDARD
+            .decode(payload.trim())
+            .map_err(|e| format!("Invalid base64 in data URI: {:?}", e))
+    } else {
+        percent_decode(payload)
+    };
+    Some(bytes.map(|bytes| (bytes, media_type)))
+}
+
+/// Decode %XX escapes (data URIs carrying raw SVG markup are usually percent-encoded)
+fn percent_decode(input: &str) -> Result<Vec<u8>, String> {
+    let bytes = input.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = input.get(i + 1..i + 3).ok_or("Truncated %-escape in data URI")?;
+            let byte = u8::from_str_radix(hex, 16).map_err(|_| format!("Invalid %-escape in data URI: %{}", hex))?;
+            decoded.push(byte);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
         }
     }
-    
-    canvas
+    Ok(decoded)
 }
 
-/// Resize image, crop to circle, and add circular border (2px) - for avatars
-fn resize_with_circular_border_cropped(img: &RgbaImage, size: u32) -> RgbaImage {
-    // Resize image to square first
-    let resized = image::imageops::resize(
-        img,
-        size,
-        size,
-        image::imageops::FilterType::Lanczos3,
-    );
+/// True if the bytes are an SVG document, by content-type or by sniffing for an `<svg` root
+fn is_svg(data: &[u8], content_type: Option<&str>) -> bool {
+    if content_type.is_some_and(|ct| ct.to_ascii_lowercase().starts_with("image/svg")) {
+        return true;
+    }
+    // Raster formats never start with '<'; SVG may be preceded by a BOM, an XML declaration or comments
+    let head = &data[..data.len().min(1024)];
+    let text = String::from_utf8_lossy(head);
+    let trimmed = text.trim_start_matches('\u{feff}').trim_start();
+    trimmed.starts_with('<') && trimmed.to_ascii_lowercase().contains("<svg")
+}
+
+/// Rasterize an SVG to a `size` x `size` RGBA image, scaled to fit and centered
+fn rasterize_svg(data: &[u8], size: u32) -> Result<RgbaImage, String> {
+    use resvg::{tiny_skia, usvg};
     
-    // Create canvas with border (size + 4px for 2px border on each side)
-    let canvas_size = size + 4;
-    let mut canvas = RgbaImage::new(canvas_size, canvas_size);
+    let tree = usvg::Tree::from_data(data, &usvg::Options::default())
+        .map_err(|e| format!("Failed to parse SVG: {:?}", e))?;
+    let svg_size = tree.size();
+    let size = size.max(1);
+    let scale = size as f32 / svg_size.width().max(svg_size.height());
+    let offset_x = (size as f32 - svg_size.width() * scale) / 2.0;
+    let offset_y = (size as f32 - svg_size.height() * scale) / 2.0;
     
-    // Fill with transparent
-    for pixel in canvas.pixels_mut() {
-        *pixel = Rgba([0, 0, 0, 0]);
+    let mut pixmap = tiny_skia::Pixmap::new(size, size)
+        .ok_or_else(|| format!("Failed to allocate {}x{} SVG canvas", size, size))?;
+    let transform = tiny_skia::Transform::from_row(scale, 0.0, 0.0, scale, offset_x, offset_y);
+    resvg::render(&tree, transform, &mut pixmap.as_mut());
+    
+    // tiny-skia stores premultiplied alpha
+    let mut image = RgbaImage::new(size, size);
+    for (pixel, source) in image.pixels_mut().zip(pixmap.pixels()) {
+        let color = source.demultiply();
+        *pixel = Rgba([color.red(), color.green(), color.blue(), color.alpha()]);
     }
+    Ok(image)
+}
+
+/// Fetch (or decode a data URI for) an avatar, rasterizing SVGs at `target_size`
+async fn fetch_avatar_image(
+    url: &str,
+    ipfs_gateways: &[String],
+    target_size: u32,
+    max_dimension: u32,
+) -> Result<RgbaImage, String> {
+    let (data, content_type) = match decode_data_uri(url) {
+        Some(decoded) => decoded?,
+        None => fetch_avatar_response(url, ipfs_gateways).await?,
+    };
     
-    // Draw circular mask and copy resized image (circular crop)
-    let center = (canvas_size as f32 / 2.0, canvas_size as f32 / 2.0);
-    let radius = (size as f32 / 2.0) as f32;
-    let border_radius = radius + 2.0;
+    if is_svg(&data, content_type.as_deref()) {
+        console_log!("🖼️ Avatar is SVG, rasterizing at {}px", target_size);
+        return rasterize_svg(&data, target_size);
+    }
+    
+    decode_image_checked(&data, max_dimension).map(|img| img.to_rgba8())
+}
+
+/// Gateways tried in order for IPFS avatars, overridable via IPFS_GATEWAYS
+const DEFAULT_IPFS_GATEWAYS: [&str; 2] = ["https://ipfs.io", "https://w3s.link"];
+
+/// Path-style gateways avatars are commonly served from; their URLs are retried on the
+/// configured gateways too, since they intermittently time out
+const KNOWN_IPFS_GATEWAY_HOSTS: [&str; 8] = [
+    "ipfs.io", "cloudflare-ipfs.com", "w3s.link", "dweb.link",
+    "gateway.pinata.cloud", "nftstorage.link", "ipfs.infura.io", "ipfs.decentralized-content.com",
+];
+
+/// Extract the `{cid}[/path]` of an IPFS URL: `ipfs://{cid}/...`, `ipfs://ipfs/{cid}/...`, or
+/// `https://{gateway}/ipfs/{cid}/...` on a known or configured gateway
+/// Returns None for anything else, which keeps the single-fetch path
+fn ipfs_content_path(url: &str, ipfs_gateways: &[String]) -> Option<String> {
+    let path = if let Some(rest) = url.strip_prefix("ipfs://") {
+        rest.strip_prefix("ipfs/").unwrap_or(rest).to_string()
+    } else {
+        let parsed = Url::parse(url).ok()?;
+        let host = parsed.host_str()?;
+        let mut configured = ipfs_gateways.iter().filter_map(|gateway| Url::parse(gateway).ok());
+        let known = KNOWN_IPFS_GATEWAY_HOSTS.contains(&host)
+            || configured.any(|gateway| gateway.host_str() == Some(host));
+        if !known {
+            return None;
+        }
+        let mut path = parsed.path().strip_prefix("/ipfs/")?.to_string();
+        if let Some(query) = parsed.query() {
+            path = format!("{}?{}", path, query);
+        }
+        path
+    };
+    let cid = path.split(['/', '?']).next().unwrap_or("");
+    (!cid.is_empty() && cid.chars().all(|c| c.is_ascii_alphanumeric())).then_some(path)
+}
+
+/// URLs to try for an avatar: the original URL first (unless it is `ipfs://`), then each
+/// gateway in order for IPFS content; anything else is just `url`
+fn avatar_fetch_urls(url: &str, ipfs_gateways: &[String]) -> Vec<String> {
+    let Some(path) = ipfs_content_path(url, ipfs_gateways) else {
+        return vec![url.to_string()];
+    };
+    let mut urls = Vec::new();
+    if !url.starts_with("ipfs://") {
+        urls.push(url.to_string());
+    }
+    for gateway in ipfs_gateways {
+        let candidate = format!("{}/ipfs/{}", gateway.trim_end_matches('/'), path);
+        if !urls.contains(&candidate) {
+            urls.push(candidate);
+        }
+    }
+    urls
+}
+
+/// Longest a single avatar fetch may take before the next gateway is tried
+const AVATAR_ATTEMPT_TIMEOUT_MS: u64 = 3_000;
+
+/// Total time spent fetching an avatar across all gateways, so a slow chain can't push
+/// the render past Worker limits
+const AVATAR_FETCH_BUDGET_MS: u64 = 6_000;
+
+/// Run `future`, giving up with None after `timeout_ms`
+async fn with_timeout<F: std::future::Future>(future: F, timeout_ms: u64) -> Option<F::Output> {
+    use std::future::Future;
+    
+    let mut future = std::pin::pin!(future);
+    let mut delay = std::pin::pin!(Delay::from(std::time::Duration::from_millis(timeout_ms)));
+    std::future::poll_fn(|cx| {
+        if let std::task::Poll::Ready(output) = future.as_mut().poll(cx) {
+            return std::task::Poll::Ready(Some(output));
+        }
+        if delay.as_mut().poll(cx).is_ready() {
+            return std::task::Poll::Ready(None);
+        }
+        std::task::Poll::Pending
+    })
+    .await
+}
+
+/// Fetch avatar bytes, walking the IPFS gateway chain until one answers; each attempt and
+/// the whole chain are time-limited
+async fn fetch_avatar_response(url: &str, ipfs_gateways: &[String]) -> Result<FetchedImage, String> {
+    let urls = avatar_fetch_urls(url, ipfs_gateways);
+    let started_ms = Date::now().as_millis();
+    
+    let mut last_error = String::from("No IPFS gateways configured");
+    for candidate in &urls {
+        let elapsed_ms = Date::now().as_millis().saturating_sub(started_ms);
+        let remaining_ms = AVATAR_FETCH_BUDGET_MS.saturating_sub(elapsed_ms);
+        if remaining_ms == 0 {
+            last_error = format!("gave up after {}ms", AVATAR_FETCH_BUDGET_MS);
+            break;
+        }
+        
+        let timeout_ms = remaining_ms.min(AVATAR_ATTEMPT_TIMEOUT_MS);
+        let result = with_timeout(fetch_image_response(candidate), timeout_ms)
+            .await
+            .unwrap_or_else(|| Err(format!("Timed out after {}ms", timeout_ms)));
+        match result {
+            Ok(fetched) => {
+                if urls.len() > 1 {
+                    console_log!("✅ Fetched IPFS avatar via {}", candidate);
+                }
+                return Ok(fetched);
+            }
+            Err(e) if urls.len() == 1 => return Err(e),
+            Err(e) => {
+                console_log!("⚠️ IPFS gateway failed for {}: {}", candidate, e);
+                last_error = e;
+            }
+        }
+    }
+    Err(format!("All {} IPFS gateways failed, last error: {}", urls.len(), last_error))
+}
+
+/// Parse IPFS_GATEWAYS: comma-separated gateway origins, tried in order
+fn parse_ipfs_gateways(raw: &str) -> std::result::Result<Vec<String>, String> {
+    raw.split(',')
+        .map(str::trim)
+        .filter(|gateway| !gateway.is_empty())
+        .map(|gateway| {
+            Url::parse(gateway)
+                .map(|_| gateway.trim_end_matches('/').to_string())
+                .map_err(|e| format!("Invalid IPFS_GATEWAYS entry '{}': {}", gateway, e))
+        })
+        .collect()
+}
+
+/// Default cap on either side of a fetched image, overridable via MAX_IMAGE_DIMENSION
+const DEFAULT_MAX_IMAGE_DIMENSION: u32 = 4096;
+
+/// Decode image bytes, rejecting anything whose declared dimensions exceed `max_dimension`
+/// The header is inspected first so oversized images (decompression bombs) are never decoded
+fn decode_image_checked(data: &[u8], max_dimension: u32) -> Result<image::DynamicImage, String> {
+    let (width, height) = image::io::Reader::new(std::io::Cursor::new(data))
+        .with_guessed_format()
+        .map_err(|e| format!("Failed to guess image format: {:?}", e))?
+        .into_dimensions()
+        .map_err(|e| format!("Failed to read image dimensions: {:?}", e))?;
+    
+    if width > max_dimension || height > max_dimension {
+        return Err(format!(
+            "Image too large: {}x{} exceeds {}x{}",
+            width, height, max_dimension, max_dimension
+        ));
+    }
+    
+    image::load_from_memory(data).map_err(|e| format!("Failed to load image: {:?}", e))
+}
+
+/// Bundled neutral silhouette used when a user has no avatar (or it fails to load)
+const DEFAULT_AVATAR_PNG: &[u8] = include_bytes!("../assets/default-avatar.png");
+
+/// Decode the bundled default avatar
+fn default_avatar_image() -> RgbaImage {
+    image::load_from_memory(DEFAULT_AVATAR_PNG)
+        .map(|img| img.to_rgba8())
+        .unwrap_or_else(|_| RgbaImage::from_pixel(2, 2, Rgba([200, 205, 215, 255])))
+}
+
+/// Fetch and decode an image from URL, enforcing the dimension cap
+async fn fetch_rgba_image(url: &str, max_dimension: u32) -> Result<RgbaImage, String> {
+    let data = fetch_image_data(url).await?;
+    decode_image_checked(&data, max_dimension).map(|img| img.to_rgba8())
+}
+
+/// Where avatars come from besides the user's own URL
+#[derive(Debug, Clone)]
+struct AvatarSources {
+    /// Image used when a user has none or it fails to load (DEFAULT_AVATAR_URL)
+    default_avatar_url: Option<String>,
+    /// Gateways tried in order for IPFS avatars (IPFS_GATEWAYS)
+    ipfs_gateways: Vec<String>,
+}
+
+impl Default for AvatarSources {
+    fn default() -> Self {
+        Self {
+            default_avatar_url: None,
+            ipfs_gateways: DEFAULT_IPFS_GATEWAYS.iter().map(|g| g.to_string()).collect(),
+        }
+    }
+}
+
+/// Load the user's avatar, falling back to DEFAULT_AVATAR_URL and then the bundled silhouette
+/// IPFS avatars go through the gateway chain
+/// SVG avatars (URLs or data URIs) are rasterized at `target_size`
+async fn load_avatar_image(
+    avatar_url: Option<&str>,
+    avatar_sources: &AvatarSources,
+    target_size: u32,
+    max_dimension: u32,
+) -> RgbaImage {
+    if let Some(url) = avatar_url {
+        match fetch_avatar_image(url, &avatar_sources.ipfs_gateways, target_size, max_dimension).await {
+            Ok(img) => return img,
+            Err(e) => console_log!("⚠️ Failed to load avatar: {}, using default", e),
+        }
+    }
+    
+    if let Some(url) = avatar_sources.default_avatar_url.as_deref() {
+        match fetch_avatar_image(url, &avatar_sources.ipfs_gateways, target_size, max_dimension).await {
+            Ok(img) => return img,
+            Err(e) => console_log!("⚠️ Failed to load DEFAULT_AVATAR_URL: {}, using bundled avatar", e),
+        }
+    }
+    
+    default_avatar_image()
+}
+
+/// Center-crop an image to a square using its smaller dimension
+fn center_crop_to_square(img: &RgbaImage) -> RgbaImage {
+    let side = img.width().min(img.height());
+    let x = (img.width() - side) / 2;
+    let y = (img.height() - side) / 2;
+    image::imageops::crop_imm(img, x, y, side, side).to_image()
+}
+
+/// Neutral grey circle with the usual 2px border, used when the source image is degenerate
+fn placeholder_circle(size: u32) -> RgbaImage {
+    let canvas_size = size + 4;
+    let mut canvas = RgbaImage::new(canvas_size, canvas_size);
+    let center = canvas_size as f32 / 2.0;
+    let radius = size as f32 / 2.0;
+    
+    for (x, y, pixel) in canvas.enumerate_pixels_mut() {
+        let dx = x as f32 - center;
+        let dy = y as f32 - center;
+        let dist = (dx * dx + dy * dy).sqrt();
+        if dist <= radius {
+            *pixel = Rgba([200, 205, 215, 255]);
+        } else if dist <= radius + 2.0 {
+            *pixel = Rgba([122, 156, 198, 255]); // Low-saturation blue #7A9CC6
+        }
+    }
+    
+    canvas
+}
+
+/// Resize image and add circular border (2px low-saturation blue)
+fn resize_with_circular_border(img: &RgbaImage, size: u32) -> RgbaImage {
+    // Some broken CDNs return 0x0 or 1x1 images; draw a placeholder instead
+    if img.width() < 2 || img.height() < 2 {
+        return placeholder_circle(size);
+    }
+    
+    // Center-crop to square first so non-square images aren't stretched
+    let square = center_crop_to_square(img);
+    
+    // Resize image
+    let resized = image::imageops::resize(
+        &square,
+        size,
+        size,
+        image::imageops::FilterType::Lanczos3,
+    );
+    
+    // Create canvas with border (size + 4px for 2px border on each side)
+    let canvas_size = size + 4;
+    let mut canvas = RgbaImage::new(canvas_size, canvas_size);
+    
+    // Fill with transparent
+    for pixel in canvas.pixels_mut() {
+        *pixel = Rgba([0, 0, 0, 0]);
+    }
+    
+    // Draw circular mask and copy resized image
+    let center = (canvas_size as f32 / 2.0, canvas_size as f32 / 2.0);
+    let radius = (size as f32 / 2.0) as f32;
+    let border_radius = radius + 2.0;
+    
+    for y in 0..canvas_size {
+        for x in 0..canvas_size {
+            let dx = x as f32 - center.0;
+            let dy = y as f32 - center.1;
+            let dist = (dx * dx + dy * dy).sqrt();
+            
+            if dist <= radius {
+                // Inside circle - copy from resized image
+                let src_x = ((x as f32 - 2.0).max(0.0).min(size as f32 - 1.0)) as u32;
+                let src_y = ((y as f32 - 2.0).max(0.0).min(size as f32 - 1.0)) as u32;
+                canvas.put_pixel(x, y, *resized.get_pixel(src_x, src_y));
+            } else if dist <= border_radius {
+                // Border area - draw low-saturation blue border
+                canvas.put_pixel(x, y, Rgba([122, 156, 198, 255])); // Low-saturation blue #7A9CC6
+            }
+        }
+    }
+    
+    canvas
+}
+
+/// Resize image, crop to circle, and add circular border (2px) - for avatars
+fn resize_with_circular_border_cropped(img: &RgbaImage, size: u32) -> RgbaImage {
+    // Some broken CDNs return 0x0 or 1x1 avatars; draw a placeholder instead
+    if img.width() < 2 || img.height() < 2 {
+        return placeholder_circle(size);
+    }
+    
+    // Center-crop to square first so non-square avatars aren't stretched
+    let square = center_crop_to_square(img);
+    
+    // Resize image to square
+    let resized = image::imageops::resize(
+        &square,
+        size,
+        size,
+        image::imageops::FilterType::Lanczos3,
+    );
+    
+    // Create canvas with border (size + 4px for 2px border on each side)
+    let canvas_size = size + 4;
+    let mut canvas = RgbaImage::new(canvas_size, canvas_size);
+    
+    // Fill with transparent
+    for pixel in canvas.pixels_mut() {
+        *pixel = Rgba([0, 0, 0, 0]);
+    }
+    
+    // Draw circular mask and copy resized image (circular crop)
+    let center = (canvas_size as f32 / 2.0, canvas_size as f32 / 2.0);
+    let radius = (size as f32 / 2.0) as f32;
+    let border_radius = radius + 2.0;
     
     for y in 0..canvas_size {
         for x in 0..canvas_size {
@@ -435,30 +1263,200 @@ fn resize_with_circular_border_cropped(img: &RgbaImage, size: u32) -> RgbaImage
 
 /// Overlay one image onto another at specified position with alpha blending
 fn overlay_image(canvas: &mut RgbaImage, overlay: &RgbaImage, x: u32, y: u32) {
-    for (ox, oy, pixel) in overlay.enumerate_pixels() {
-        let canvas_x = x + ox;
-        let canvas_y = y + oy;
+    let clamp = |v: u32| v.min(i32::MAX as u32) as i32;
+    overlay_image_signed(canvas, overlay, clamp(x), clamp(y));
+}
+
+/// Overlay with a signed position: an overlay hanging off any edge (including left/top)
+/// is clipped to the part that lands on the canvas
+fn overlay_image_signed(canvas: &mut RgbaImage, overlay: &RgbaImage, x: i32, y: i32) {
+    let (x, y) = (x as i64, y as i64);
+    // Visible source sub-rectangle, in overlay coordinates
+    let src_left = (-x).max(0);
+    let src_top = (-y).max(0);
+    let src_right = (canvas.width() as i64 - x).min(overlay.width() as i64);
+    let src_bottom = (canvas.height() as i64 - y).min(overlay.height() as i64);
 
-        if canvas_x < canvas.width() && canvas_y < canvas.height() {
-            let canvas_pixel = canvas.get_pixel_mut(canvas_x, canvas_y);
+    for oy in src_top..src_bottom {
+        for ox in src_left..src_right {
+            let pixel = overlay.get_pixel(ox as u32, oy as u32);
+            let canvas_pixel = canvas.get_pixel_mut((x + ox) as u32, (y + oy) as u32);
             *canvas_pixel = blend_pixels(*canvas_pixel, *pixel);
         }
     }
 }
 
-/// Calculate actual text width using font metrics
+/// Allowed range for the report card's `?scale=` render multiplier
+const MIN_RENDER_SCALE: u32 = 1;
+const MAX_RENDER_SCALE: u32 = 3;
+
+/// Caps on the rendered card itself; the input checks alone don't bound it once the render
+/// scale and layout multiply the tarot size. Every RGBA pixel costs 4 bytes, more while
+/// compositing and encoding
+const MAX_CARD_DIMENSION: u64 = 8192;
+const MAX_CARD_PIXELS: u64 = 16_000_000;
+
+/// Reject a `width` x `height` card canvas that exceeds the card caps
+fn check_card_size(width: u64, height: u64) -> Result<(), String> {
+    if width > MAX_CARD_DIMENSION || height > MAX_CARD_DIMENSION || width * height > MAX_CARD_PIXELS {
+        return Err(format!(
+            "Card too large: {}x{} exceeds {}x{} or {} pixels",
+            width, height, MAX_CARD_DIMENSION, MAX_CARD_DIMENSION, MAX_CARD_PIXELS
+        ));
+    }
+    Ok(())
+}
+
+/// Error returned when the embedded font can't be parsed; the image handlers recognize it
+/// and fall back to the plain tarot image instead of failing the request
+const FONT_LOAD_ERROR: &str = "FONT_LOAD_FAILED: embedded font could not be parsed";
+
+static REGULAR_FONT: std::sync::OnceLock<Option<rusttype::Font<'static>>> = std::sync::OnceLock::new();
+
+/// Embedded Roboto font, parsed once per isolate and borrowed thereafter
+fn regular_font() -> Result<&'static rusttype::Font<'static>, String> {
+    REGULAR_FONT
+        .get_or_init(|| {
+            let font_data: &'static [u8] = include_bytes!("../fonts/Roboto-Regular.ttf");
+            rusttype::Font::try_from_bytes(font_data)
+        })
+        .as_ref()
+        .ok_or_else(|| FONT_LOAD_ERROR.to_string())
+}
+
+/// Calculate text width using font metrics: the sum of each glyph's advance width plus pair
+/// kerning (when the font has a kern table), i.e. how far the pen moves, so trailing spaces
+/// count and side bearings don't skew it
 fn calculate_text_width(font: &rusttype::Font, text: &str, scale: rusttype::Scale) -> f32 {
-    let v_metrics = font.v_metrics(scale);
-    let glyphs: Vec<_> = font.layout(text, scale, rusttype::Point { x: 0.0, y: v_metrics.ascent }).collect();
+    let mut previous = None;
+    let mut width = 0.0;
+    for glyph in font.glyphs_for(text.chars()) {
+        let glyph = glyph.scaled(scale);
+        if let Some(previous) = previous {
+            width += font.pair_kerning(scale, previous, glyph.id());
+        }
+        width += glyph.h_metrics().advance_width;
+        previous = Some(glyph.id());
+    }
+    width
+}
+
+/// Drawn for characters no font in the chain covers, in order of preference; a space when
+/// none of them is covered either. Better than the font's `.notdef` box, which renders as
+/// garbage in some fonts
+const REPLACEMENT_CHARS: [char; 2] = ['\u{25A1}', '?'];
+
+/// Fonts tried in order for each character of user-supplied text (usernames, stat lines):
+/// the first one with a real glyph draws it. Callers take metrics (ascent, line height)
+/// from the first font, so runs in different fonts share one baseline
+struct FontChain<'a> {
+    fonts: Vec<&'a rusttype::Font<'a>>,
+}
+
+/// The embedded Roboto; fallback fonts (CJK, emoji, ...) are appended here once embedded,
+/// guided by the scripts `FontChain::draw_text` logs as missing
+fn font_chain() -> Result<FontChain<'static>, String> {
+    Ok(FontChain::new(vec![regular_font()?]))
+}
+
+impl<'a> FontChain<'a> {
+    fn new(fonts: Vec<&'a rusttype::Font<'a>>) -> Self {
+        Self { fonts }
+    }
+    
+    /// First font with a non-.notdef glyph for `c`
+    fn font_for(&self, c: char) -> Option<usize> {
+        self.fonts.iter().position(|font| font.glyph(c).id().0 != 0)
+    }
+    
+    /// Split `text` into runs drawn with one font each, as (font index, text)
+    /// Zero-width joiners and variation selectors (parts of emoji sequences) are dropped;
+    /// other uncovered characters become a `REPLACEMENT_CHARS` entry or a space
+    fn runs(&self, text: &str) -> Vec<(usize, String)> {
+        let replacement = REPLACEMENT_CHARS
+            .iter()
+            .find_map(|&c| self.font_for(c).map(|index| (index, c)))
+            .unwrap_or((0, ' '));
+        let mut runs: Vec<(usize, String)> = Vec::new();
+        for c in text.chars() {
+            if is_invisible_format_char(c) {
+                continue;
+            }
+            let (index, c) = match self.font_for(c) {
+                Some(index) => (index, c),
+                None if c.is_control() => (0, ' '),
+                None => replacement,
+            };
+            match runs.last_mut() {
+                Some((run_index, run)) if *run_index == index => run.push(c),
+                _ => runs.push((index, c.to_string())),
+            }
+        }
+        runs
+    }
     
-    if let Some(last_glyph) = glyphs.last() {
-        if let Some(bounding_box) = last_glyph.pixel_bounding_box() {
-            return (bounding_box.max.x as f32) + last_glyph.unpositioned().h_metrics().advance_width;
+    /// Scripts of the characters in `text` no font covers, each named once in order
+    fn missing_scripts(&self, text: &str) -> Vec<&'static str> {
+        let mut scripts = Vec::new();
+        for c in text.chars() {
+            if is_invisible_format_char(c) || c.is_control() || self.font_for(c).is_some() {
+                continue;
+            }
+            let script = script_name(c);
+            if !scripts.contains(&script) {
+                scripts.push(script);
+            }
         }
+        scripts
     }
     
-    // Fallback: estimate width
-    text.len() as f32 * scale.x * 0.6
+    /// Pen advance of `text` as drawn by `draw_text`
+    fn text_width(&self, text: &str, scale: rusttype::Scale) -> f32 {
+        self.runs(text)
+            .iter()
+            .map(|(index, run)| calculate_text_width(self.fonts[*index], run, scale))
+            .sum()
+    }
+    
+    /// Draw `text` at (`x`, `y`) run by run, logging scripts that had to be substituted
+    fn draw_text(&self, canvas: &mut RgbaImage, color: Rgba<u8>, x: f32, y: i32, scale: rusttype::Scale, text: &str) {
+        use imageproc::drawing::draw_text_mut;
+        
+        let missing = self.missing_scripts(text);
+        if !missing.is_empty() {
+            console_log!("🔤 No glyphs for {} in {:?}, drawing replacements", missing.join(", "), text);
+        }
+        let mut pen_x = x;
+        for (index, run) in self.runs(text) {
+            let font = self.fonts[index];
+            draw_text_mut(canvas, color, pen_x.round() as i32, y, scale, font, &run);
+            pen_x += calculate_text_width(font, &run, scale);
+        }
+    }
+}
+
+/// Zero-width characters that only shape their neighbors (ZWJ, variation selectors, ...)
+fn is_invisible_format_char(c: char) -> bool {
+    matches!(c, '\u{200B}'..='\u{200F}' | '\u{2060}' | '\u{FE00}'..='\u{FE0F}' | '\u{FEFF}' | '\u{E0020}'..='\u{E007F}')
+}
+
+/// Rough script of a character, for the missing-glyph log
+fn script_name(c: char) -> &'static str {
+    match c as u32 {
+        0x0370..=0x03FF => "Greek",
+        0x0400..=0x052F => "Cyrillic",
+        0x0590..=0x05FF => "Hebrew",
+        0x0600..=0x06FF | 0x0750..=0x077F | 0xFB50..=0xFDFF | 0xFE70..=0xFEFF => "Arabic",
+        0x0900..=0x097F => "Devanagari",
+        0x0980..=0x0DFF => "Indic",
+        0x0E00..=0x0E7F => "Thai",
+        0x1100..=0x11FF | 0xAC00..=0xD7AF => "Hangul",
+        0x3040..=0x30FF => "Kana",
+        0x2E80..=0x2FFF | 0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0xF900..=0xFAFF | 0x20000..=0x3FFFF => "CJK",
+        0x2600..=0x27BF | 0x1F000..=0x1FAFF => "Emoji",
+        0x2000..=0x2BFF => "Symbols",
+        _ => "Other",
+    }
 }
 
 /// Calculate text height using font metrics (ascent + descent)
@@ -467,53 +1465,110 @@ fn calculate_text_height(font: &rusttype::Font, scale: rusttype::Scale) -> f32 {
     v_metrics.ascent - v_metrics.descent + v_metrics.line_gap
 }
 
-/// Draw text with bold numbers (numbers are +3px larger and drawn twice with 2px offset for bold effect)
+/// Abbreviate large counts for display: 999 -> "999", 12847 -> "12.8K", 3_400_000 -> "3.4M"
+/// Must stay identical to the copy in src/pages/annual_report/utils.rs
+fn format_count(n: usize) -> String {
+    const UNITS: [(usize, &str); 3] = [(1_000, "K"), (1_000_000, "M"), (1_000_000_000, "B")];
+
+    if n < 1_000 {
+        return n.to_string();
+    }
+
+    for (idx, (unit, suffix)) in UNITS.iter().enumerate() {
+        // Round to one decimal place using integer math
+        let tenths = (n as u128 * 10 + *unit as u128 / 2) / *unit as u128;
+        // 999_999 rounds to 1000.0K, so promote to the next unit when there is one
+        if tenths >= 10_000 && idx + 1 < UNITS.len() {
+            continue;
+        }
+        let (whole, decimal) = (tenths / 10, tenths % 10);
+        return if decimal == 0 {
+            format!("{}{}", whole, suffix)
+        } else {
+            format!("{}.{}{}", whole, decimal, suffix)
+        };
+    }
+    unreachable!("last unit always returns")
+}
+
+/// Split text into runs of words and numbers, each with the pen x where it starts
+/// Numbers are `number_scale`, the rest `base_scale`; the pen is kept in f32 so segment
+/// widths don't accumulate rounding drift
+/// Abbreviated counts from `format_count` ("12.8K") are kept in the number segment
+fn layout_bold_number_segments(
+    fonts: &FontChain,
+    text: &str,
+    x: f32,
+    base_scale: f32,
+    number_scale: f32,
+) -> Vec<(String, bool, f32)> {
+    let mut segments: Vec<(String, bool)> = Vec::new();
+    let mut is_number_segment = false;
+    for c in text.chars() {
+        // A '.' or K/M/B suffix directly after digits belongs to the number
+        let is_digit = c.is_ascii_digit()
+            || (is_number_segment && matches!(c, '.' | 'K' | 'M' | 'B'));
+        match segments.last_mut() {
+            Some((segment, _)) if is_digit == is_number_segment => segment.push(c),
+            _ => segments.push((c.to_string(), is_digit)),
+        }
+        is_number_segment = is_digit;
+    }
+    
+    let mut x_pos = x;
+    segments
+        .into_iter()
+        .map(|(segment, is_number)| {
+            let start = x_pos;
+            let scale = rusttype::Scale::uniform(if is_number { number_scale } else { base_scale });
+            x_pos += fonts.text_width(&segment, scale);
+            (segment, is_number, start)
+        })
+        .collect()
+}
+
+/// Draw text with bold numbers (numbers are larger and drawn twice, `bold_offset` px apart, for bold effect)
 fn draw_text_with_bold_numbers(
     canvas: &mut RgbaImage,
-    font: &rusttype::Font,
+    fonts: &FontChain,
     text: &str,
     x: i32,
     y: i32,
     base_scale: f32,
     number_scale: f32,
+    bold_offset: i32,
     color: Rgba<u8>,
 ) {
     use rusttype::Scale;
-    use imageproc::drawing::draw_text_mut;
-    
-    let mut x_pos = x;
-    let mut current_segment = String::new();
-    let mut is_number_segment = false;
     
-    for c in text.chars() {
-        let is_digit = c.is_ascii_digit();
-        
-        if is_digit != is_number_segment && !current_segment.is_empty() {
-            // Draw accumulated segment
-            let scale = if is_number_segment { Scale::uniform(number_scale) } else { Scale::uniform(base_scale) };
-            draw_text_mut(canvas, color, x_pos, y, scale, font, &current_segment);
-            // Draw again with 2px offset for bold effect (only for numbers)
-            if is_number_segment {
-                draw_text_mut(canvas, color, x_pos + 2, y, scale, font, &current_segment);
-            }
-            // Calculate actual width
-            let actual_width = calculate_text_width(font, &current_segment, scale);
-            x_pos += actual_width as i32;
-            current_segment.clear();
+    for (segment, is_number, start) in layout_bold_number_segments(fonts, text, x as f32, base_scale, number_scale) {
+        let scale = if is_number { Scale::uniform(number_scale) } else { Scale::uniform(base_scale) };
+        fonts.draw_text(canvas, color, start, y, scale, &segment);
+        // Draw again with an offset for bold effect (only for numbers)
+        if is_number {
+            fonts.draw_text(canvas, color, start + bold_offset as f32, y, scale, &segment);
         }
-        
-        is_number_segment = is_digit;
-        current_segment.push(c);
     }
+}
+
+/// Draw the ⚡ power badge marker: a gold disc with a lightning bolt
+/// The embedded font has no emoji glyphs, so the bolt is drawn as a polygon
+fn draw_power_badge(canvas: &mut RgbaImage, center_x: i32, center_y: i32, radius: i32) {
+    use imageproc::drawing::{draw_filled_circle_mut, draw_polygon_mut};
+    use imageproc::point::Point;
     
-    // Draw remaining segment
-    if !current_segment.is_empty() {
-        let scale = if is_number_segment { Scale::uniform(number_scale) } else { Scale::uniform(base_scale) };
-        draw_text_mut(canvas, color, x_pos, y, scale, font, &current_segment);
-        if is_number_segment {
-            draw_text_mut(canvas, color, x_pos + 2, y, scale, font, &current_segment);
-        }
+    if radius <= 0 {
+        return;
     }
+    draw_filled_circle_mut(canvas, (center_x, center_y), radius, Rgba([255, 215, 0, 255]));
+    
+    // Bolt outline in units of the radius, relative to the center
+    const BOLT: [(f32, f32); 6] = [(0.15, -0.7), (-0.4, 0.1), (-0.02, 0.1), (-0.15, 0.7), (0.4, -0.1), (0.02, -0.1)];
+    let points: Vec<Point<i32>> = BOLT
+        .iter()
+        .map(|(x, y)| Point::new(center_x + (x * radius as f32) as i32, center_y + (y * radius as f32) as i32))
+        .collect();
+    draw_polygon_mut(canvas, &points, Rgba([74, 59, 0, 255]));
 }
 
 /// Alpha blend two pixels
@@ -526,102 +1581,183 @@ fn blend_pixels(bottom: Rgba<u8>, top: Rgba<u8>) -> Rgba<u8> {
         return bottom;
     }
 
+    // Round rather than truncate: 127.99 must become 128, not 127
     let r = ((top[0] as f32 * alpha_top + bottom[0] as f32 * alpha_bottom * (1.0 - alpha_top))
-        / alpha_out) as u8;
+        / alpha_out).round() as u8;
     let g = ((top[1] as f32 * alpha_top + bottom[1] as f32 * alpha_bottom * (1.0 - alpha_top))
-        / alpha_out) as u8;
+        / alpha_out).round() as u8;
     let b = ((top[2] as f32 * alpha_top + bottom[2] as f32 * alpha_bottom * (1.0 - alpha_top))
-        / alpha_out) as u8;
-    let a = (alpha_out * 255.0) as u8;
+        / alpha_out).round() as u8;
+    let a = (alpha_out * 255.0).round() as u8;
 
     Rgba([r, g, b, a])
 }
 
-/// Composite images: overlay zodiac, social type, and avatar badges on tarot card
-/// Returns PNG bytes
-async fn composite_tarot_with_badges(
-    tarot_url: &str,
-    zodiac_url: &str,
-    social_type_url: &str,
-    avatar_url: Option<&str>,
-) -> Result<Vec<u8>, String> {
-    console_log!("📥 Fetching tarot image from: {}", tarot_url);
-    // Fetch all images
-    let tarot_data = fetch_image_data(tarot_url).await
-        .map_err(|e| format!("Failed to fetch tarot image: {}", e))?;
-    console_log!("✅ Fetched tarot image: {} bytes", tarot_data.len());
-    
-    console_log!("📥 Fetching zodiac image from: {}", zodiac_url);
-    let zodiac_data = fetch_image_data(zodiac_url).await
-        .map_err(|e| format!("Failed to fetch zodiac image: {}", e))?;
-    console_log!("✅ Fetched zodiac image: {} bytes", zodiac_data.len());
-    
-    console_log!("📥 Fetching social type image from: {}", social_type_url);
-    let social_type_data = fetch_image_data(social_type_url).await
-        .map_err(|e| format!("Failed to fetch social type image: {}", e))?;
-    console_log!("✅ Fetched social type image: {} bytes", social_type_data.len());
-    
-    let avatar_data = if let Some(url) = avatar_url {
-        console_log!("📥 Fetching avatar image from: {}", url);
-        match fetch_image_data(url).await {
-            Ok(data) => {
-                console_log!("✅ Fetched avatar image: {} bytes", data.len());
-                Some(data)
-            }
-            Err(e) => {
-                console_log!("⚠️ Failed to fetch avatar image: {}, continuing without it", e);
-                None
+/// Guide colors for ?debug=1 layout overlays
+const DEBUG_IMAGE_BOX_COLOR: Rgba<u8> = Rgba([255, 0, 255, 255]);
+const DEBUG_TEXT_BOX_COLOR: Rgba<u8> = Rgba([0, 255, 0, 255]);
+const DEBUG_BASELINE_COLOR: Rgba<u8> = Rgba([0, 255, 255, 255]);
+const DEBUG_LIMIT_COLOR: Rgba<u8> = Rgba([255, 0, 0, 255]);
+
+/// Draw a hollow rectangle outline, clipped to the canvas
+fn draw_debug_rect(canvas: &mut RgbaImage, x: i32, y: i32, width: u32, height: u32, thickness: u32, color: Rgba<u8>) {
+    let (canvas_width, canvas_height) = (canvas.width() as i32, canvas.height() as i32);
+    let (right, bottom) = (x + width as i32, y + height as i32);
+    let thickness = thickness.max(1) as i32;
+    for py in y.max(0)..bottom.min(canvas_height) {
+        for px in x.max(0)..right.min(canvas_width) {
+            let on_edge = px < x + thickness || px >= right - thickness || py < y + thickness || py >= bottom - thickness;
+            if on_edge {
+                canvas.put_pixel(px as u32, py as u32, color);
             }
         }
-    } else {
-        console_log!("ℹ️ No avatar URL provided, skipping avatar");
-        None
-    };
+    }
+}
 
-    // Load images
-    console_log!("🖼️ Loading images from memory...");
-    let tarot_img = image::load_from_memory(&tarot_data)
-        .map_err(|e| format!("Failed to load tarot image: {:?}", e))?
-        .to_rgba8();
-    console_log!("✅ Loaded tarot image: {}x{}", tarot_img.width(), tarot_img.height());
-    
-    let zodiac_img = image::load_from_memory(&zodiac_data)
-        .map_err(|e| format!("Failed to load zodiac image: {:?}", e))?
+/// Layout guides collected while drawing a card, drawn on top at the end when ?debug=1 is set
+struct DebugGuides {
+    enabled: bool,
+    /// (x, y, width, height, color)
+    boxes: Vec<(i32, i32, u32, u32, Rgba<u8>)>,
+}
+
+impl DebugGuides {
+    fn new(enabled: bool) -> Self {
+        Self { enabled, boxes: Vec::new() }
+    }
+    
+    fn add_box(&mut self, x: i32, y: i32, width: u32, height: u32, color: Rgba<u8>) {
+        if self.enabled {
+            self.boxes.push((x, y, width, height, color));
+        }
+    }
+    
+    /// Horizontal guide line, e.g. a text baseline or the badge top limit
+    fn add_line(&mut self, x_start: i32, x_end: i32, y: i32, color: Rgba<u8>) {
+        self.add_box(x_start, y, x_end.saturating_sub(x_start).max(1) as u32, 1, color);
+    }
+    
+    fn draw(&self, canvas: &mut RgbaImage, thickness: u32) {
+        for &(x, y, width, height, color) in &self.boxes {
+            // Lines stay one guide thick regardless of the outline thickness
+            let height = if height == 1 { thickness } else { height };
+            draw_debug_rect(canvas, x, y, width, height, thickness, color);
+        }
+    }
+}
+
+/// Badge and avatar placement for the top section of the composite card
+#[derive(Debug, PartialEq)]
+struct BadgeLayout {
+    padding: u32,
+    badge_size: u32,
+    avatar_size: u32,
+    left_badge_x: u32,
+    avatar_x: u32,
+    right_badge_x: u32,
+    /// True if the tarot is too narrow to fit everything without overlap
+    overlaps: bool,
+}
+
+/// Compute badge/avatar sizes and positions proportionally to the tarot width
+/// (roughly 50px badges, 70px avatar and 20px padding for a ~700px wide tarot)
+fn compute_badge_layout(tarot_width: u32) -> BadgeLayout {
+    let badge_size = ((tarot_width as f32 * 0.07) as u32).clamp(32, 120);
+    let avatar_size = badge_size * 7 / 5; // Avatar stays larger than badges (70:50)
+    let padding = ((tarot_width as f32 * 0.03) as u32).clamp(8, 60);
+    let min_gap = padding / 2; // Minimum gap between a badge and the avatar
+    
+    // Badges and avatar have a 2px border on each side
+    let badge_actual_size = badge_size + 4;
+    let avatar_actual_size = avatar_size + 4;
+    
+    let left_badge_x = padding;
+    let avatar_x = (tarot_width / 2).saturating_sub(avatar_actual_size / 2);
+    // Right badge hugs the right edge, but never moves closer than min_gap to the avatar
+    let right_badge_x = tarot_width
+        .saturating_sub(badge_actual_size + padding)
+        .max(avatar_x + avatar_actual_size + min_gap);
+    
+    let overlaps = left_badge_x + badge_actual_size + min_gap > avatar_x
+        || right_badge_x + badge_actual_size > tarot_width;
+    
+    BadgeLayout {
+        padding,
+        badge_size,
+        avatar_size,
+        left_badge_x,
+        avatar_x,
+        right_badge_x,
+        overlaps,
+    }
+}
+
+/// Composite images: overlay zodiac, social type, and avatar badges on tarot card
+/// Returns PNG bytes; with `shrink_to_target` an oversized PNG is downscaled towards ~200KB.
+/// `transparent_background` leaves the strip behind the badges clear instead of blue
+#[allow(clippy::too_many_arguments)]
+async fn composite_tarot_with_badges(
+    tarot_url: &str,
+    zodiac_url: &str,
+    social_type_url: &str,
+    avatar_url: Option<&str>,
+    avatar_sources: &AvatarSources,
+    max_image_dimension: u32,
+    transparent_background: bool,
+    debug_layout: bool,
+    shrink_to_target: bool,
+) -> Result<Vec<u8>, String> {
+    let mut guides = DebugGuides::new(debug_layout);
+    console_log!("📥 Fetching tarot image from: {}", tarot_url);
+    // Fetch all images
+    let (tarot_data, tarot_type) = fetch_image_response(tarot_url).await
+        .map_err(|e| format!("Failed to fetch tarot image: {}", e))?;
+    console_log!("✅ Fetched tarot image: {} bytes ({})", tarot_data.len(), tarot_type.as_deref().unwrap_or("no content-type"));
+    
+    console_log!("📥 Fetching zodiac image from: {}", zodiac_url);
+    let (zodiac_data, zodiac_type) = fetch_image_response(zodiac_url).await
+        .map_err(|e| format!("Failed to fetch zodiac image: {}", e))?;
+    console_log!("✅ Fetched zodiac image: {} bytes ({})", zodiac_data.len(), zodiac_type.as_deref().unwrap_or("no content-type"));
+    
+    console_log!("📥 Fetching social type image from: {}", social_type_url);
+    let (social_type_data, social_type_type) = fetch_image_response(social_type_url).await
+        .map_err(|e| format!("Failed to fetch social type image: {}", e))?;
+    console_log!("✅ Fetched social type image: {} bytes ({})", social_type_data.len(), social_type_type.as_deref().unwrap_or("no content-type"));
+    
+    // Load images
+    console_log!("🖼️ Loading images from memory...");
+    let tarot_img = decode_image_checked(&tarot_data, max_image_dimension)
+        .map_err(|e| format!("Failed to load tarot image: {}", e))?
+        .to_rgba8();
+    console_log!("✅ Loaded tarot image: {}x{}", tarot_img.width(), tarot_img.height());
+    
+    let zodiac_img = decode_image_checked(&zodiac_data, max_image_dimension)
+        .map_err(|e| format!("Failed to load zodiac image: {}", e))?
         .to_rgba8();
     console_log!("✅ Loaded zodiac image: {}x{}", zodiac_img.width(), zodiac_img.height());
     
-    let social_type_img = image::load_from_memory(&social_type_data)
-        .map_err(|e| format!("Failed to load social type image: {:?}", e))?
+    let social_type_img = decode_image_checked(&social_type_data, max_image_dimension)
+        .map_err(|e| format!("Failed to load social type image: {}", e))?
         .to_rgba8();
     console_log!("✅ Loaded social type image: {}x{}", social_type_img.width(), social_type_img.height());
 
-    let avatar_img = if let Some(data) = avatar_data {
-        console_log!("🖼️ Loading avatar image from memory ({} bytes)...", data.len());
-        match image::load_from_memory(&data) {
-            Ok(img) => {
-                let rgba = img.to_rgba8();
-                console_log!("✅ Loaded avatar image: {}x{}", rgba.width(), rgba.height());
-                Some(rgba)
-            }
-            Err(e) => {
-                console_log!("❌ Failed to load avatar image: {:?}", e);
-                None
-            }
-        }
-    } else {
-        console_log!("ℹ️ No avatar data to load");
-        None
-    };
-
     // Get tarot card dimensions
     let tarot_width = tarot_img.width();
     let tarot_height = tarot_img.height();
     console_log!("📐 Tarot card dimensions: {}x{}", tarot_width, tarot_height);
 
-    // Badge size is fixed at 50px, avatar is larger (70px)
-    let badge_size = 50u32;
-    let avatar_size = 70u32; // Avatar is larger than badges
-    console_log!("📏 Badge size: {}px, Avatar size: {}px", badge_size, avatar_size);
+    // Badge and avatar sizes scale with the tarot width (avatar is larger than badges)
+    let layout = compute_badge_layout(tarot_width);
+    let badge_size = layout.badge_size;
+    let avatar_size = layout.avatar_size;
+    console_log!("📏 Badge size: {}px, Avatar size: {}px, Padding: {}px", badge_size, avatar_size, layout.padding);
+    if layout.overlaps {
+        console_log!("⚠️ Tarot width {}px is too narrow, badges may overlap the avatar or clip", tarot_width);
+    }
+    
+    console_log!("📥 Loading avatar image (with default fallback)...");
+    let avatar_img = load_avatar_image(avatar_url, avatar_sources, avatar_size, max_image_dimension).await;
+    console_log!("✅ Loaded avatar image: {}x{}", avatar_img.width(), avatar_img.height());
     
     // Resize badges to badge_size and make them circular with border
     let zodiac_resized = resize_with_circular_border(
@@ -632,18 +1768,12 @@ async fn composite_tarot_with_badges(
         &social_type_img,
         badge_size,
     );
-    let avatar_resized = if let Some(ref avatar) = avatar_img {
-        console_log!("🔄 Resizing avatar to {}px with circular border...", avatar_size);
-        let resized = resize_with_circular_border_cropped(
-            avatar,
-            avatar_size,
-        );
-        console_log!("✅ Avatar resized to {}x{}", resized.width(), resized.height());
-        Some(resized)
-    } else {
-        console_log!("⚠️ No avatar image to resize");
-        None
-    };
+    console_log!("🔄 Resizing avatar to {}px with circular border...", avatar_size);
+    let avatar_resized = resize_with_circular_border_cropped(
+        &avatar_img,
+        avatar_size,
+    );
+    console_log!("✅ Avatar resized to {}x{}", avatar_resized.width(), avatar_resized.height());
 
     // Calculate top section height = avatar diameter (including border)
     // Avatar has 2px border on each side, so actual size is avatar_size + 4
@@ -664,12 +1794,14 @@ async fn composite_tarot_with_badges(
     
     // Draw low-saturation blue border at top (outside the card, height = avatar diameter)
     // Fill the entire top section with low-saturation blue (#7A9CC6 - soft blue-gray)
-    for y in 0..top_section_height {
-        for x in 0..tarot_width {
-            canvas.put_pixel(x, y, Rgba([122, 156, 198, 255])); // Low-saturation blue #7A9CC6
+    if !transparent_background {
+        for y in 0..top_section_height {
+            for x in 0..tarot_width {
+                canvas.put_pixel(x, y, Rgba([122, 156, 198, 255])); // Low-saturation blue #7A9CC6
+            }
         }
+        console_log!("✅ Low-saturation blue border drawn at top (height: {}px, same as avatar diameter)", top_section_height);
     }
-    console_log!("✅ Low-saturation blue border drawn at top (height: {}px, same as avatar diameter)", top_section_height);
     
     // Copy tarot card image below the border
     for y in 0..tarot_height {
@@ -682,9 +1814,7 @@ async fn composite_tarot_with_badges(
     
     // Calculate positions for badges and avatar in top section (outside the card)
     // Note: badges have 2px border on each side, so actual size is badge_size + 4
-    // Avatar has 2px border on each side, so actual size is avatar_size + 4
     let badge_actual_size = badge_size + 4;
-    let avatar_actual_size = avatar_size + 4;
     
     // Avatar should be positioned so its top edge aligns with top border (y=0)
     // and bottom edge aligns with bottom border (y=top_section_height)
@@ -698,36 +1828,32 @@ async fn composite_tarot_with_badges(
     console_log!("📍 Avatar position: y={} (top edge at border top, bottom edge at border bottom)", avatar_y);
     
     // Horizontal spacing: left badge, center avatar, right badge
-    let padding = 20u32; // Padding from edges
-    let left_badge_x = padding;
-    let right_badge_x = tarot_width.saturating_sub(badge_actual_size + padding);
-    let avatar_x = (tarot_width as i32 / 2) - (avatar_actual_size as i32 / 2);
+    let left_badge_x = layout.left_badge_x;
+    let right_badge_x = layout.right_badge_x;
+    let avatar_x = layout.avatar_x;
     
     console_log!("📍 Positioning: left_badge=({}, {}), avatar=({}, {}), right_badge=({}, {})", 
         left_badge_x, badge_center_y, avatar_x, avatar_y, right_badge_x, badge_center_y);
     
-    // Top-left: zodiac badge (in top section, outside card)
-    if badge_center_y >= 0 {
-        console_log!("📍 Overlaying zodiac badge at ({}, {})", left_badge_x, badge_center_y as u32);
-        overlay_image(&mut canvas, &zodiac_resized, left_badge_x, badge_center_y as u32);
-    }
+    // Top-left: zodiac badge (in top section, outside card), clipped if it pokes above the top
+    console_log!("📍 Overlaying zodiac badge at ({}, {})", left_badge_x, badge_center_y);
+    overlay_image_signed(&mut canvas, &zodiac_resized, left_badge_x as i32, badge_center_y);
+    guides.add_box(left_badge_x as i32, badge_center_y, zodiac_resized.width(), zodiac_resized.height(), DEBUG_IMAGE_BOX_COLOR);
     
     // Top-center: avatar (larger, in top section, outside card)
     // Avatar top edge aligns with border top (y=0), bottom edge aligns with border bottom
-    if let Some(ref avatar) = avatar_resized {
-        if avatar_x >= 0 {
-            console_log!("📍 Overlaying avatar at ({}, {}) - top edge at border top", avatar_x as u32, avatar_y);
-            overlay_image(&mut canvas, avatar, avatar_x as u32, avatar_y);
-        }
-    } else {
-        console_log!("⚠️ No avatar to overlay");
-    }
+    console_log!("📍 Overlaying avatar at ({}, {}) - top edge at border top", avatar_x, avatar_y);
+    overlay_image(&mut canvas, &avatar_resized, avatar_x, avatar_y);
+    guides.add_box(avatar_x as i32, avatar_y as i32, avatar_resized.width(), avatar_resized.height(), DEBUG_IMAGE_BOX_COLOR);
     
     // Top-right: social type badge (in top section, outside card)
-    if badge_center_y >= 0 {
-        console_log!("📍 Overlaying social type badge at ({}, {})", right_badge_x, badge_center_y as u32);
-        overlay_image(&mut canvas, &social_type_resized, right_badge_x, badge_center_y as u32);
-    }
+    console_log!("📍 Overlaying social type badge at ({}, {})", right_badge_x, badge_center_y);
+    overlay_image_signed(&mut canvas, &social_type_resized, right_badge_x as i32, badge_center_y);
+    guides.add_box(right_badge_x as i32, badge_center_y, social_type_resized.width(), social_type_resized.height(), DEBUG_IMAGE_BOX_COLOR);
+    
+    // Bottom edge of the top section, where the tarot card starts
+    guides.add_line(0, tarot_width as i32, top_section_height as i32, DEBUG_LIMIT_COLOR);
+    guides.draw(&mut canvas, 1);
     
     console_log!("✅ All badges and avatar overlaid in top section (outside card)");
 
@@ -772,7 +1898,8 @@ async fn composite_tarot_with_badges(
     console_log!("✅ Encoded PNG: {} bytes ({:.1}KB)", png_bytes.len(), file_size_kb);
     
     // If file is too large (>250KB), resize further to target ~200KB
-    if png_bytes.len() > 250_000 {
+    // (skipped when the caller re-encodes to a format that targets size through quality)
+    if shrink_to_target && png_bytes.len() > 250_000 {
         console_log!("⚠️ File size {:.1}KB exceeds target, resizing further...", file_size_kb);
         let scale = (200_000.0 / png_bytes.len() as f32).sqrt(); // Square root to account for 2D scaling
         let new_width = ((final_width as f32 * scale) as u32).max(400);
@@ -798,87 +1925,263 @@ async fn composite_tarot_with_badges(
     Ok(png_bytes)
 }
 
-/// Generate report card image with user info, stats, and tarot card
+/// JPEG size target when ?target_kb= isn't given, and the range it accepts
+const DEFAULT_JPEG_TARGET_KB: u32 = 200;
+const MIN_JPEG_TARGET_KB: u32 = 20;
+const MAX_JPEG_TARGET_KB: u32 = 2000;
+/// Quality the tuning starts from, and the range it searches
+const JPEG_START_QUALITY: u8 = 85;
+const JPEG_MIN_QUALITY: u8 = 40;
+const JPEG_MAX_QUALITY: u8 = 95;
+/// Encodes spent searching for the quality; each one is a full JPEG pass
+const JPEG_TUNING_STEPS: u32 = 5;
+/// Downscale passes allowed when even JPEG_MIN_QUALITY overshoots the target
+const JPEG_MAX_DOWNSCALES: u32 = 3;
+
+/// Encode an RGB image as JPEG at `quality` (1-100)
+fn encode_jpeg(img: &RgbImage, quality: u8) -> Result<Vec<u8>, String> {
+    let mut bytes = Vec::new();
+    image::codecs::jpeg::JpegEncoder::new_with_quality(&mut bytes, quality)
+        .encode(img.as_raw(), img.width(), img.height(), image::ColorType::Rgb8)
+        .map_err(|e| format!("Failed to encode JPEG: {:?}", e))?;
+    Ok(bytes)
+}
+
+/// Binary-search the quality for the largest encoding within `target_bytes`, starting at
+/// JPEG_START_QUALITY. Returns the bytes and quality, or None when even JPEG_MIN_QUALITY overshoots
+fn tune_jpeg_quality(
+    target_bytes: usize,
+    mut encode: impl FnMut(u8) -> Result<Vec<u8>, String>,
+) -> Result<Option<(Vec<u8>, u8)>, String> {
+    let (mut low, mut high) = (JPEG_MIN_QUALITY, JPEG_MAX_QUALITY);
+    let mut quality = JPEG_START_QUALITY;
+    let mut best: Option<(Vec<u8>, u8)> = None;
+    for _ in 0..JPEG_TUNING_STEPS {
+        let bytes = encode(quality)?;
+        console_log!("🎚️ JPEG q={}: {:.1}KB", quality, bytes.len() as f32 / 1024.0);
+        if bytes.len() <= target_bytes {
+            low = quality + 1;
+            best = Some((bytes, quality));
+        } else {
+            high = quality - 1;
+        }
+        if low > high {
+            break;
+        }
+        quality = low + (high - low) / 2;
+    }
+    // Nothing fit and the steps ran out before the floor was tried
+    if best.is_none() && high >= JPEG_MIN_QUALITY {
+        let bytes = encode(JPEG_MIN_QUALITY)?;
+        if bytes.len() <= target_bytes {
+            best = Some((bytes, JPEG_MIN_QUALITY));
+        }
+    }
+    Ok(best)
+}
+
+/// Encode a card as JPEG near `target_bytes`, keeping its resolution and tuning the quality;
+/// the image is only downscaled when even the lowest quality is too large
+fn encode_jpeg_near_target(mut img: RgbImage, target_bytes: usize) -> Result<Vec<u8>, String> {
+    for _ in 0..=JPEG_MAX_DOWNSCALES {
+        if let Some((bytes, quality)) = tune_jpeg_quality(target_bytes, |q| encode_jpeg(&img, q))? {
+            console_log!("✅ Encoded JPEG {}x{} at q={}: {:.1}KB", img.width(), img.height(), quality, bytes.len() as f32 / 1024.0);
+            return Ok(bytes);
+        }
+        let floor_size = encode_jpeg(&img, JPEG_MIN_QUALITY)?.len();
+        let scale = (target_bytes as f32 / floor_size as f32).sqrt(); // Square root to account for 2D scaling
+        let (new_width, new_height) = (((img.width() as f32 * scale) as u32).max(1), ((img.height() as f32 * scale) as u32).max(1));
+        console_log!("📐 JPEG at q={} is {:.1}KB, resizing to {}x{}", JPEG_MIN_QUALITY, floor_size as f32 / 1024.0, new_width, new_height);
+        img = image::imageops::resize(&img, new_width, new_height, image::imageops::FilterType::Lanczos3);
+    }
+    encode_jpeg(&img, JPEG_MIN_QUALITY)
+}
+
+/// Fill a card with the theme's top banner and background gradient below it
+/// With `transparent_background`, the banner is semi-transparent and the gradient is skipped
+fn draw_card_background(canvas: &mut RgbaImage, banner_height: u32, transparent_background: bool, theme: &CardTheme) {
+    let (card_width, card_height) = canvas.dimensions();
+    let banner_height = banner_height.min(card_height);
+    
+    // Draw banner at top (semi-transparent on transparent backgrounds)
+    let [r, g, b] = theme.banner_color;
+    let banner_color = if transparent_background {
+        Rgba([r, g, b, 128])
+    } else {
+        Rgba([r, g, b, 255])
+    };
+    for y in 0..banner_height {
+        for x in 0..card_width {
+            canvas.put_pixel(x, y, banner_color);
+        }
+    }
+    
+    // Fill rest with the gradient background (left fully transparent if requested)
+    let gradient_height = (card_height - banner_height).max(1);
+    let gradient_rows = if transparent_background { 0..0 } else { banner_height..card_height };
+    for y in gradient_rows {
+        let color = theme.gradient_at((y - banner_height) as f32 / gradient_height as f32);
+        for x in 0..card_width {
+            canvas.put_pixel(x, y, color);
+        }
+    }
+}
+
+/// Where the three stat lines go on a report card, and at what size
+struct StatsPlacement {
+    x: i32,
+    /// Right edge of the left column, for guides
+    right: i32,
+    /// Top of the first line
+    top: f32,
+    /// Top of the text column (the FID line), for guides
+    column_top: f32,
+    /// Badge top the stats must stay above
+    limit_y: f32,
+    ascent: f32,
+    line_height: f32,
+    font_size: f32,
+    number_font_size: f32,
+    bold_offset: i32,
+    render_scale: u32,
+}
+
+/// A report card drawn up to (but not including) its stat lines, which the PNG and
+/// animated exports fill in; debug guides are collected but not yet drawn
+struct ReportCanvas {
+    canvas: RgbaImage,
+    guides: DebugGuides,
+    stats: StatsPlacement,
+}
+
+/// Draw the "Published / Received / Gained" stat lines, or nothing for `None` (hidden stats)
+fn draw_report_stats(
+    canvas: &mut RgbaImage,
+    fonts: &FontChain,
+    stats: &StatsPlacement,
+    counts: Option<[usize; 3]>,
+    guides: &mut DebugGuides,
+) {
+    let mut y_pos = stats.top;
+    if let Some([casts, reactions, followers]) = counts {
+        // Format without spaces around numbers
+        let lines = [
+            format!("Published{}Casts", format_count(casts)),
+            format!("Received{}Reactions", format_count(reactions)),
+            format!("Gained{}Followers", format_count(followers)),
+        ];
+        for line in &lines {
+            let baseline_y = y_pos + stats.ascent;
+            draw_text_with_bold_numbers(canvas, fonts, line, stats.x, baseline_y as i32, stats.font_size, stats.number_font_size, stats.bold_offset, Rgba([255, 255, 255, 255]));
+            guides.add_line(stats.x, stats.right, baseline_y as i32, DEBUG_BASELINE_COLOR);
+            y_pos += stats.line_height;
+        }
+    }
+    
+    // Verify text doesn't overlap with badge
+    if y_pos > stats.limit_y - 10.0 * stats.render_scale as f32 {
+        console_log!("⚠️ Warning: Text area ({:.1}px) may overlap with badge area ({:.1}px)", y_pos, stats.limit_y);
+    }
+    
+    // Text column (FID down to the last stats line) and the badge top it must stay above
+    guides.add_box(stats.x, stats.column_top as i32, (stats.right - stats.x).max(1) as u32, (y_pos - stats.column_top).max(1.0) as u32, DEBUG_TEXT_BOX_COLOR);
+    guides.add_line(stats.x, stats.right, stats.limit_y as i32, DEBUG_LIMIT_COLOR);
+}
+
+/// Draw the report card with user info, badges, and tarot card, leaving the stat lines to
+/// `draw_report_stats`
 /// Layout: Left side (avatar, username, fid, stats, badges), Right side (tarot card)
-async fn generate_report_card(
+/// With `transparent_background`, the gradient is skipped and the banner is semi-transparent
+/// `render_scale` (1-3) multiplies every layout size and font scale for sharper output on
+/// high-DPI displays; the tarot card is upscaled to match. Pixel count (and so CPU time and
+/// PNG size) grows with the square of the scale
+#[allow(clippy::too_many_arguments)]
+async fn render_report_card_canvas(
     tarot_url: &str,
     params: &ImageParams,
     base_url: &str,
     api_url: &str,
-) -> Result<Vec<u8>, String> {
-    use rusttype::{Font, Scale};
+    avatar_sources: &AvatarSources,
+    max_image_dimension: u32,
+    transparent_background: bool,
+    render_scale: u32,
+    debug_layout: bool,
+    theme: &CardTheme,
+) -> Result<ReportCanvas, String> {
+    use rusttype::Scale;
     use imageproc::drawing::draw_text_mut;
     
-    // First, fetch and load tarot card to get its actual dimensions
+    let mut guides = DebugGuides::new(debug_layout);
+    
+    // Borrow the cached font before any network work, so a font failure fails fast
+    let font = regular_font()?;
+    
+    // Then fetch and load tarot card to get its actual dimensions
     let tarot_data = fetch_image_data(tarot_url).await
         .map_err(|e| format!("Failed to fetch tarot card: {}", e))?;
     
-    let tarot_img = image::load_from_memory(&tarot_data)
-        .map_err(|e| format!("Failed to load tarot image: {:?}", e))?
+    let tarot_img = decode_image_checked(&tarot_data, max_image_dimension)
+        .map_err(|e| format!("Failed to load tarot image: {}", e))?
         .to_rgba8();
     
+    // Every layout size below is in 1x pixels and multiplied by the render scale
+    let render_scale = render_scale.clamp(MIN_RENDER_SCALE, MAX_RENDER_SCALE);
+    let px = render_scale as f32;
+    // Check the final canvas (2x the scaled tarot wide, plus the banner) before allocating anything
+    let scaled = |size: u32| size as u64 * render_scale as u64;
+    check_card_size(scaled(tarot_img.width()) * 2, scaled(tarot_img.height()) + scaled(theme.banner_height))?;
+    let tarot_img = if render_scale > 1 {
+        image::imageops::resize(
+            &tarot_img,
+            tarot_img.width() * render_scale,
+            tarot_img.height() * render_scale,
+            image::imageops::FilterType::Lanczos3,
+        )
+    } else {
+        tarot_img
+    };
+    
     let original_tarot_width = tarot_img.width();
     let original_tarot_height = tarot_img.height();
-    console_log!("📐 Original tarot card dimensions: {}x{}", original_tarot_width, original_tarot_height);
-    
-    // Load font first (embedded in binary)
-    let font_data = include_bytes!("../fonts/Roboto-Regular.ttf");
-    let font = Font::try_from_bytes(font_data as &[u8])
-        .ok_or_else(|| "Failed to load font".to_string())?;
+    console_log!("📐 Tarot card dimensions: {}x{} ({}x scale)", original_tarot_width, original_tarot_height, render_scale);
     
     // Card dimensions: height equals tarot card height + banner, width is double tarot card width
     // This creates a 50/50 split: left side for info, right side for tarot card
-    let banner_height = 80u32; // Black banner height
+    let banner_height = theme.banner_height * render_scale;
     let card_height = original_tarot_height + banner_height;
     let card_width = original_tarot_width * 2; // 2 * tarot width for 50/50 split
     let mut canvas = RgbaImage::new(card_width, card_height);
     
     console_log!("📐 Report card dimensions: {}x{} (2x tarot width, with {}px banner)", card_width, card_height, banner_height);
     
-    // 1. Draw black banner at top
-    for y in 0..banner_height {
-        for x in 0..card_width {
-            canvas.put_pixel(x, y, Rgba([0, 0, 0, 255])); // Black
-        }
-    }
-    
-    // 2. Fill rest with blue-purple gradient background
-    // Gradient from blue (#667eea) to purple (#764ba2)
-    for y in banner_height..card_height {
-        let ratio = (y - banner_height) as f32 / original_tarot_height as f32;
-        // Interpolate between blue and purple
-        let r = (102.0 + (118.0 - 102.0) * ratio) as u8; // 102 -> 118
-        let g = (126.0 + (75.0 - 126.0) * ratio) as u8;  // 126 -> 75
-        let b = (234.0 + (162.0 - 234.0) * ratio) as u8; // 234 -> 162
-        for x in 0..card_width {
-            canvas.put_pixel(x, y, Rgba([r, g, b, 255]));
-        }
-    }
+    // 1-2. Banner at top, gradient below
+    draw_card_background(&mut canvas, banner_height, transparent_background, theme);
     
-    // 3. Draw "My 2025 Annual Report" text in banner (centered, positioned higher)
-    let banner_text = "My 2025 Annual Report";
-    let banner_font_size = 48.0; // Larger font
+    // 3. Draw "My {year} Annual Report" text in banner (centered, positioned higher)
+    let banner_text = theme.banner_title(params.year);
+    let banner_text = banner_text.as_str();
+    let banner_font_size = 48.0 * px; // Larger font
     let banner_scale = Scale::uniform(banner_font_size);
     let banner_v_metrics = font.v_metrics(banner_scale);
-    let banner_text_width = calculate_text_width(&font, banner_text, banner_scale);
+    let banner_text_width = calculate_text_width(font, banner_text, banner_scale);
     let banner_text_x = ((card_width as f32 - banner_text_width) / 2.0) as i32;
     // Position text higher in banner - move up by at least half text height
     // draw_text_mut uses baseline, text height = ascent - descent
     // Move up by half text height: baseline = top_offset + (ascent + descent) / 2
-    let top_offset = 10.0; // Smaller offset from banner top
+    let top_offset = 10.0 * px; // Smaller offset from banner top
     let text_height = banner_v_metrics.ascent - banner_v_metrics.descent;
     let banner_text_y = (top_offset + banner_v_metrics.ascent - text_height / 2.0) as i32;
-    draw_text_mut(&mut canvas, Rgba([255, 255, 255, 255]), banner_text_x, banner_text_y, banner_scale, &font, banner_text);
+    draw_text_mut(&mut canvas, Rgba([255, 255, 255, 255]), banner_text_x, banner_text_y, banner_scale, font, banner_text);
     
     // Left side: User info and stats (new layout: avatar + username/fid, then stats)
-    let left_padding = 40u32;
-    let top_padding = 40u32;
-    let bottom_padding = 40u32;
+    let left_padding = 40 * render_scale;
+    let top_padding = 40 * render_scale;
+    let bottom_padding = 40 * render_scale;
     
     // Fixed sizes
-    let avatar_size = 120u32;
-    let badge_size = 90u32;
-    let avatar_text_gap = 20u32; // Gap between avatar and username/fid
+    let avatar_size = 120 * render_scale;
+    let badge_size = 90 * render_scale;
+    let avatar_text_gap = 20 * render_scale; // Gap between avatar and username/fid
     
     // Content area starts after banner
     let content_start_y = banner_height as f32;
@@ -888,20 +2191,21 @@ async fn generate_report_card(
     let badge_top = badge_y as f32;
     
     // Calculate available height for stats (after avatar section and one blank line)
-    let avatar_section_height = avatar_size as f32 + 20.0; // Avatar + username/fid area
-    let blank_line_height = 30.0; // One blank line
+    let avatar_section_height = avatar_size as f32 + 20.0 * px; // Avatar + username/fid area
+    let blank_line_height = 30.0 * px; // One blank line
     let available_height = badge_top - (content_start_y + top_padding as f32) - avatar_section_height - blank_line_height;
     
     // Text elements: 3 stats lines
     let line_height_ratio = 1.3; // Compact line spacing (1.3x font size)
     
     // Calculate optimal font sizes for stats
-    let stats_font_size = (available_height / (3.0 * line_height_ratio)).max(28.0).min(60.0);
-    let stats_number_font_size = stats_font_size + 8.0; // +8px for numbers
+    let stats_font_size = (available_height / (3.0 * line_height_ratio)).max(28.0 * px).min(60.0 * px);
+    let stats_number_font_size = stats_font_size + 8.0 * px; // +8px for numbers
+    let bold_offset = 2 * render_scale as i32;
     
     // Username and FID font sizes (fixed relative to avatar)
-    let username_font_size = 48.0; // Larger size for username
-    let fid_font_size = 24.0; // Fixed size for FID
+    let username_font_size = 48.0 * px; // Larger size for username
+    let fid_font_size = 24.0 * px; // Fixed size for FID
     
     console_log!("📐 Font sizes: username={:.1}px, fid={:.1}px, stats={:.1}px, numbers={:.1}px", 
                  username_font_size, fid_font_size, stats_font_size, stats_number_font_size);
@@ -911,27 +2215,19 @@ async fn generate_report_card(
     let avatar_x = left_padding as f32;
     
     // Fetch profile from API
-    let (username, avatar_url) = match fetch_profile_from_api(params.fid, api_url).await {
+    let CardProfile { username, avatar_url, power_badge, .. } = match fetch_profile_from_api(params.fid, api_url).await {
         Ok(profile) => profile,
         Err(e) => {
             console_log!("⚠️ Failed to fetch profile: {}", e);
-            (None, None)
+            CardProfile::default()
         }
     };
     
-    // 1. Avatar (top-left)
-    if let Some(ref avatar_url) = avatar_url {
-        match fetch_image_data(avatar_url).await {
-            Ok(avatar_data) => {
-                if let Ok(avatar_img) = image::load_from_memory(&avatar_data) {
-                    let avatar_rgba = avatar_img.to_rgba8();
-                    let avatar_resized = resize_with_circular_border_cropped(&avatar_rgba, avatar_size);
-                    overlay_image(&mut canvas, &avatar_resized, avatar_x as u32, avatar_y as u32);
-                }
-            }
-            Err(e) => console_log!("⚠️ Failed to fetch avatar: {}", e),
-        }
-    }
+    // 1. Avatar (top-left), falling back to the default avatar
+    let avatar_rgba = load_avatar_image(avatar_url.as_deref(), avatar_sources, avatar_size, max_image_dimension).await;
+    let avatar_resized = resize_with_circular_border_cropped(&avatar_rgba, avatar_size);
+    overlay_image(&mut canvas, &avatar_resized, avatar_x as u32, avatar_y as u32);
+    guides.add_box(avatar_x as i32, avatar_y as i32, avatar_resized.width(), avatar_resized.height(), DEBUG_IMAGE_BOX_COLOR);
     
     // 2. Username (right of avatar, vertically centered with avatar)
     if let Some(ref username) = username {
@@ -942,72 +2238,74 @@ async fn generate_report_card(
         // Center username vertically with avatar
         let username_baseline_y = avatar_y + (avatar_size as f32 / 2.0) - (v_metrics.ascent - v_metrics.descent) / 2.0;
         let username_x = avatar_x + avatar_size as f32 + avatar_text_gap as f32;
-        draw_text_mut(&mut canvas, Rgba([255, 255, 255, 255]), username_x as i32, username_baseline_y as i32, scale, &font, &username_text);
+        let fonts = font_chain()?;
+        fonts.draw_text(&mut canvas, Rgba([255, 255, 255, 255]), username_x.trunc(), username_baseline_y as i32, scale, &username_text);
+        let username_width = fonts.text_width(&username_text, scale);
+        guides.add_box(username_x as i32, username_baseline_y as i32, username_width as u32, (v_metrics.ascent - v_metrics.descent) as u32, DEBUG_TEXT_BOX_COLOR);
+        guides.add_line(username_x as i32, (username_x + username_width) as i32, (username_baseline_y + v_metrics.ascent) as i32, DEBUG_BASELINE_COLOR);
+        
+        // ⚡ marker after the username for power badge holders
+        if power_badge {
+            let text_height = v_metrics.ascent - v_metrics.descent;
+            let radius = (text_height * 0.35) as i32;
+            let badge_x = username_x + username_width + radius as f32 + 8.0 * px;
+            draw_power_badge(&mut canvas, badge_x as i32, (username_baseline_y + text_height / 2.0) as i32, radius);
+        }
         }
     }
     
-    // 3. FID (below avatar, left-aligned with avatar)
-    let fid_text = format!("FID: {}", params.fid);
+    // 3. FID (below avatar, left-aligned with avatar); a hidden FID keeps its line so the layout doesn't shift
     let scale = Scale::uniform(fid_font_size);
     let v_metrics = font.v_metrics(scale);
-    let fid_baseline_y = avatar_y + avatar_size as f32 + 10.0; // Small gap below avatar
+    let fid_baseline_y = avatar_y + avatar_size as f32 + 10.0 * px; // Small gap below avatar
     let fid_baseline = fid_baseline_y + v_metrics.ascent;
-    draw_text_mut(&mut canvas, Rgba([255, 255, 255, 200]), avatar_x as i32, fid_baseline as i32, scale, &font, &fid_text);
+    if !params.hide_fid {
+        let fid_text = format!("FID: {}", params.fid);
+        draw_text_mut(&mut canvas, Rgba([255, 255, 255, 200]), avatar_x as i32, fid_baseline as i32, scale, font, &fid_text);
+        let fid_width = calculate_text_width(font, &fid_text, scale);
+        guides.add_line(avatar_x as i32, (avatar_x + fid_width) as i32, fid_baseline as i32, DEBUG_BASELINE_COLOR);
+    }
     
     // 4. Blank line (one line height)
-    let mut y_pos = fid_baseline_y + calculate_text_height(&font, scale) * line_height_ratio + blank_line_height;
-    
-    // 5. Stats (using font metrics, numbers bold and larger)
-    let stats_scale = Scale::uniform(stats_font_size);
-    let stats_text_height = calculate_text_height(&font, stats_scale);
-    let stats_v_metrics = font.v_metrics(stats_scale);
-    
-    // Format without spaces around numbers
-    let stats_text = format!("Published{}Casts", params.total_casts);
-    let baseline_y = y_pos + stats_v_metrics.ascent;
-    draw_text_with_bold_numbers(&mut canvas, &font, &stats_text, left_padding as i32, baseline_y as i32, stats_font_size, stats_number_font_size, Rgba([255, 255, 255, 255]));
-    y_pos += stats_text_height * line_height_ratio;
+    let y_pos = fid_baseline_y + calculate_text_height(font, scale) * line_height_ratio + blank_line_height;
     
-    let reactions_text = format!("Received{}Reactions", params.total_reactions);
-    let baseline_y = y_pos + stats_v_metrics.ascent;
-    draw_text_with_bold_numbers(&mut canvas, &font, &reactions_text, left_padding as i32, baseline_y as i32, stats_font_size, stats_number_font_size, Rgba([255, 255, 255, 255]));
-    y_pos += stats_text_height * line_height_ratio;
-    
-    let followers_text = format!("Gained{}Followers", params.total_followers);
-    let baseline_y = y_pos + stats_v_metrics.ascent;
-    draw_text_with_bold_numbers(&mut canvas, &font, &followers_text, left_padding as i32, baseline_y as i32, stats_font_size, stats_number_font_size, Rgba([255, 255, 255, 255]));
-    y_pos += stats_text_height * line_height_ratio;
-    
-    // Verify text doesn't overlap with badge
-    if y_pos > badge_top - 10.0 {
-        console_log!("⚠️ Warning: Text area ({:.1}px) may overlap with badge area ({:.1}px)", y_pos, badge_top);
-    }
+    // 5. Stats (using font metrics, numbers bold and larger) are drawn by draw_report_stats
+    let stats = StatsPlacement {
+        x: left_padding as i32,
+        right: (card_width / 2 - left_padding) as i32,
+        top: y_pos,
+        column_top: fid_baseline_y,
+        limit_y: badge_top,
+        ascent: font.v_metrics(Scale::uniform(stats_font_size)).ascent,
+        line_height: calculate_text_height(font, Scale::uniform(stats_font_size)) * line_height_ratio,
+        font_size: stats_font_size,
+        number_font_size: stats_number_font_size,
+        bold_offset,
+        render_scale,
+    };
     
     // 6. Badges (bottom, already calculated above)
     // Get zodiac URL from index
     let zodiac_url = get_zodiac_url_from_index(params.zodiac_index, base_url);
-    match fetch_image_data(&zodiac_url).await {
-        Ok(zodiac_data) => {
-            if let Ok(zodiac_img) = image::load_from_memory(&zodiac_data) {
-                let zodiac_rgba = zodiac_img.to_rgba8();
-                let zodiac_resized = resize_with_circular_border(&zodiac_rgba, badge_size);
-                overlay_image(&mut canvas, &zodiac_resized, left_padding, badge_y);
-            }
+    match fetch_rgba_image(&zodiac_url, max_image_dimension).await {
+        Ok(zodiac_rgba) => {
+            let zodiac_resized = resize_with_circular_border(&zodiac_rgba, badge_size);
+            overlay_image(&mut canvas, &zodiac_resized, left_padding, badge_y);
+            guides.add_box(left_padding as i32, badge_y as i32, zodiac_resized.width(), zodiac_resized.height(), DEBUG_IMAGE_BOX_COLOR);
         }
-        Err(e) => console_log!("⚠️ Failed to fetch zodiac badge: {}", e),
+        Err(e) => console_log!("⚠️ Failed to load zodiac badge: {}", e),
     }
     
     // Get social type URL from index
     let social_type_url = get_social_type_url_from_index(params.social_type_index, base_url);
-    match fetch_image_data(&social_type_url).await {
-        Ok(social_data) => {
-            if let Ok(social_img) = image::load_from_memory(&social_data) {
-                let social_rgba = social_img.to_rgba8();
-                let social_resized = resize_with_circular_border(&social_rgba, badge_size);
-                overlay_image(&mut canvas, &social_resized, left_padding + badge_size + 20, badge_y);
-            }
+    match fetch_rgba_image(&social_type_url, max_image_dimension).await {
+        Ok(social_rgba) => {
+            let social_resized = resize_with_circular_border(&social_rgba, badge_size);
+            let social_x = left_padding + badge_size + 20 * render_scale;
+            overlay_image(&mut canvas, &social_resized, social_x, badge_y);
+            guides.add_box(social_x as i32, badge_y as i32, social_resized.width(), social_resized.height(), DEBUG_IMAGE_BOX_COLOR);
         }
-        Err(e) => console_log!("⚠️ Failed to fetch social type badge: {}", e),
+        Err(e) => console_log!("⚠️ Failed to load social type badge: {}", e),
     }
     
     // Right side: Tarot card (use original dimensions, no distortion)
@@ -1020,6 +2318,43 @@ async fn generate_report_card(
     console_log!("📍 Placing tarot card at ({}, {}) with original size {}x{}", 
         tarot_x, tarot_y, original_tarot_width, original_tarot_height);
     overlay_image(&mut canvas, &tarot_img, tarot_x, tarot_y);
+    guides.add_box(tarot_x as i32, tarot_y as i32, tarot_img.width(), tarot_img.height(), DEBUG_IMAGE_BOX_COLOR);
+    
+    Ok(ReportCanvas { canvas, guides, stats })
+}
+
+/// Generate report card image with user info, stats, and tarot card, as PNG bytes
+/// See `render_report_card_canvas` for the layout and arguments
+#[allow(clippy::too_many_arguments)]
+async fn generate_report_card(
+    tarot_url: &str,
+    params: &ImageParams,
+    base_url: &str,
+    api_url: &str,
+    avatar_sources: &AvatarSources,
+    max_image_dimension: u32,
+    transparent_background: bool,
+    render_scale: u32,
+    debug_layout: bool,
+    theme: &CardTheme,
+) -> Result<Vec<u8>, String> {
+    let ReportCanvas { mut canvas, mut guides, stats } = render_report_card_canvas(
+        tarot_url,
+        params,
+        base_url,
+        api_url,
+        avatar_sources,
+        max_image_dimension,
+        transparent_background,
+        render_scale,
+        debug_layout,
+        theme,
+    ).await?;
+    
+    // Skipped entirely when the sharer chose to hide their numbers
+    let counts = (!params.hide_stats).then_some([params.total_casts, params.total_reactions, params.total_followers]);
+    draw_report_stats(&mut canvas, &font_chain()?, &stats, counts, &mut guides);
+    guides.draw(&mut canvas, stats.render_scale);
     
     // Encode to PNG
     let mut png_bytes = Vec::new();
@@ -1034,122 +2369,1365 @@ async fn generate_report_card(
     Ok(png_bytes)
 }
 
-/// Handle /api/generate endpoint - generate tarot card image
-async fn handle_generate_image(
-    req: Request,
-    env: &Env,
-) -> Result<Response> {
-    let url = req.url()?;
-    let query_params: std::collections::HashMap<String, String> = url
-        .query_pairs()
-        .into_owned()
-        .collect();
+/// Summary card size at 1x (standard Open Graph proportions)
+const SUMMARY_CARD_WIDTH: u32 = 1200;
+const SUMMARY_CARD_HEIGHT: u32 = 630;
+
+/// Cell placement for the `?layout=summary` card, in output pixels
+/// Left column: avatar + username/FID, the stat lines, then the badges; right: tarot thumbnail
+#[derive(Debug, PartialEq)]
+struct SummaryLayout {
+    card_width: u32,
+    card_height: u32,
+    banner_height: u32,
+    padding: u32,
+    avatar_size: u32,
+    badge_size: u32,
+    /// Width available to the left column's text
+    column_width: u32,
+    content_y: u32,
+    stats_y: u32,
+    badge_y: u32,
+    tarot_x: u32,
+    tarot_width: u32,
+    tarot_height: u32,
+}
+
+/// Lay out the summary card for a tarot image of the given size
+/// The tarot is scaled to the content height (aspect ratio kept, at most half the card wide)
+fn compute_summary_layout(tarot_width: u32, tarot_height: u32, render_scale: u32) -> SummaryLayout {
+    let render_scale = render_scale.clamp(MIN_RENDER_SCALE, MAX_RENDER_SCALE);
+    let card_width = SUMMARY_CARD_WIDTH * render_scale;
+    let card_height = SUMMARY_CARD_HEIGHT * render_scale;
+    let banner_height = 64 * render_scale;
+    let padding = 32 * render_scale;
+    let avatar_size = 112 * render_scale;
+    let badge_size = 88 * render_scale;
     
-    // Get params from query params
-    let params_base64 = query_params
-        .get("params")
-        .ok_or_else(|| "Missing 'params' parameter")?;
+    let content_y = banner_height + padding;
+    let thumb_height = card_height - banner_height - 2 * padding;
+    // A broken tarot image falls back to the usual ~7:12 card proportions
+    let thumb_width = if tarot_width == 0 || tarot_height == 0 {
+        thumb_height * 7 / 12
+    } else {
+        (thumb_height as u64 * tarot_width as u64 / tarot_height as u64) as u32
+    };
+    let (tarot_width, tarot_height) = if thumb_width > card_width / 2 {
+        // Wide art: cap the width and shrink the height to match
+        let capped = card_width / 2;
+        (capped, (thumb_height as u64 * capped as u64 / thumb_width.max(1) as u64) as u32)
+    } else {
+        (thumb_width.max(1), thumb_height)
+    };
+    let tarot_x = card_width - padding - tarot_width;
     
-    // Decode params (fid is included in params now)
-    let params = decode_image_params(params_base64)
-        .map_err(|e| format!("Failed to decode params: {}", e))?;
+    SummaryLayout {
+        card_width,
+        card_height,
+        banner_height,
+        padding,
+        avatar_size,
+        badge_size,
+        column_width: tarot_x.saturating_sub(2 * padding),
+        content_y,
+        stats_y: content_y + avatar_size + padding,
+        // Badges have a 2px border on each side
+        badge_y: card_height - padding - (badge_size + 4),
+        tarot_x,
+        tarot_width,
+        tarot_height,
+    }
+}
+
+/// Largest font size (at most `max_size`, at least `min_size`) that fits `text` in `max_width`
+fn fit_font_size(fonts: &FontChain, text: &str, max_size: f32, min_size: f32, max_width: f32) -> f32 {
+    let width = fonts.text_width(text, rusttype::Scale::uniform(max_size));
+    if width <= max_width || width <= 0.0 {
+        return max_size;
+    }
+    (max_size * max_width / width).max(min_size)
+}
+
+/// Generate the "stats at a glance" summary card: avatar, username, the three stat lines,
+/// zodiac/social badges and a tarot thumbnail on one 1200x630 (x render scale) image
+/// Same inputs as `generate_report_card`, packed into a denser grid
+#[allow(clippy::too_many_arguments)]
+async fn generate_summary_card(
+    tarot_url: &str,
+    params: &ImageParams,
+    base_url: &str,
+    api_url: &str,
+    avatar_sources: &AvatarSources,
+    max_image_dimension: u32,
+    transparent_background: bool,
+    render_scale: u32,
+    debug_layout: bool,
+    theme: &CardTheme,
+) -> Result<Vec<u8>, String> {
+    use rusttype::Scale;
+    use imageproc::drawing::draw_text_mut;
     
-    console_log!("Generating report card for FID: {}", params.fid);
-    console_log!("Zodiac index: {}", params.zodiac_index);
-    console_log!("Social type index: {}", params.social_type_index);
-    console_log!("Stats: {} casts, {} reactions, {} followers", 
-        params.total_casts, params.total_reactions, params.total_followers);
+    let mut guides = DebugGuides::new(debug_layout);
     
-    // Get base URL for constructing image URLs
-    let base_url = env
-        .var("BASE_URL")
-        .map(|v| v.to_string())
-        .unwrap_or_else(|_| "https://miniapp.polyjuice.io".to_string());
+    // Borrow the cached font before any network work, so a font failure fails fast
+    let font = regular_font()?;
     
-    // Get API URL for fetching profile
-    let api_url = env
-        .var("API_URL")
-        .map(|v| v.to_string())
-        .unwrap_or_else(|_| "https://api.polyjuice.io".to_string());
+    let tarot_data = fetch_image_data(tarot_url).await
+        .map_err(|e| format!("Failed to fetch tarot card: {}", e))?;
+    let tarot_img = decode_image_checked(&tarot_data, max_image_dimension)
+        .map_err(|e| format!("Failed to load tarot image: {}", e))?
+        .to_rgba8();
     
-    // Calculate tarot card based on FID
-    let (_tarot_name, tarot_filename) = calculate_tarot_card(params.fid);
-    let tarot_image_url = format!("{}/imgs/tarot/{}", base_url, tarot_filename);
+    let render_scale = render_scale.clamp(MIN_RENDER_SCALE, MAX_RENDER_SCALE);
+    let px = render_scale as f32;
+    let layout = compute_summary_layout(tarot_img.width(), tarot_img.height(), render_scale);
+    console_log!("📐 Summary card dimensions: {}x{} ({}x scale), tarot thumbnail {}x{}",
+        layout.card_width, layout.card_height, render_scale, layout.tarot_width, layout.tarot_height);
     
-    // Generate report card image
-    let png_bytes = generate_report_card(
-        &tarot_image_url,
-        &params,
-        &base_url,
-        &api_url,
-    ).await
-    .map_err(|e| format!("Failed to generate report card: {}", e))?;
+    let mut canvas = RgbaImage::new(layout.card_width, layout.card_height);
+    draw_card_background(&mut canvas, layout.banner_height, transparent_background, theme);
     
-    // Return PNG image directly
-    let mut response = Response::from_bytes(png_bytes)?;
-    response.headers_mut().set("content-type", "image/png")?;
-    response.headers_mut().set("access-control-allow-origin", "*")?;
-    response.headers_mut().set("cache-control", "public, max-age=3600")?;
+    // Banner title, centered in the banner
+    let banner_text = theme.banner_title(params.year);
+    let banner_text = banner_text.as_str();
+    let banner_scale = Scale::uniform(36.0 * px);
+    let banner_text_width = calculate_text_width(font, banner_text, banner_scale);
+    let banner_text_height = calculate_text_height(font, banner_scale);
+    let banner_text_x = ((layout.card_width as f32 - banner_text_width) / 2.0) as i32;
+    let banner_text_y = ((layout.banner_height as f32 - banner_text_height) / 2.0) as i32;
+    draw_text_mut(&mut canvas, Rgba([255, 255, 255, 255]), banner_text_x, banner_text_y, banner_scale, font, banner_text);
     
-    Ok(response)
-}
-
-#[event(fetch)]
-pub async fn main(req: Request, env: Env, _ctx: Context) -> Result<Response> {
-    let url = req.url()?;
-    let pathname = url.path();
-    let user_agent = req.headers().get("user-agent").ok().flatten();
+    let CardProfile { username, avatar_url, power_badge, .. } = match fetch_profile_from_api(params.fid, api_url).await {
+        Ok(profile) => profile,
+        Err(e) => {
+            console_log!("⚠️ Failed to fetch profile: {}", e);
+            CardProfile::default()
+        }
+    };
     
-    // Handle /api/generate endpoint
-    if pathname == "/api/generate" {
-        return handle_generate_image(req, &env).await;
+    // Avatar (top-left), falling back to the default avatar
+    let avatar_x = layout.padding;
+    let avatar_y = layout.content_y;
+    let avatar_rgba = load_avatar_image(avatar_url.as_deref(), avatar_sources, layout.avatar_size, max_image_dimension).await;
+    let avatar_resized = resize_with_circular_border_cropped(&avatar_rgba, layout.avatar_size);
+    overlay_image(&mut canvas, &avatar_resized, avatar_x, avatar_y);
+    guides.add_box(avatar_x as i32, avatar_y as i32, avatar_resized.width(), avatar_resized.height(), DEBUG_IMAGE_BOX_COLOR);
+    
+    // Username with the FID underneath, as one block centered on the avatar
+    let name_x = (avatar_x + layout.avatar_size + 20 * render_scale) as f32;
+    let name_max_width = (layout.padding + layout.column_width) as f32 - name_x;
+    let fonts = font_chain()?;
+    let username_text = username.filter(|u| !u.is_empty()).map(|u| format!("@{}", u));
+    let username_scale = username_text
+        .as_deref()
+        .map(|text| Scale::uniform(fit_font_size(&fonts, text, 44.0 * px, 24.0 * px, name_max_width)));
+    let fid_text = (!params.hide_fid).then(|| format!("FID: {}", params.fid));
+    let fid_scale = Scale::uniform(22.0 * px);
+    let fid_height = if fid_text.is_some() { calculate_text_height(font, fid_scale) } else { 0.0 };
+    let username_height = username_scale.map_or(0.0, |scale| calculate_text_height(font, scale) + 6.0 * px);
+    let mut text_y = avatar_y as f32 + (layout.avatar_size as f32 - username_height - fid_height) / 2.0;
+    
+    if let (Some(text), Some(scale)) = (username_text.as_deref(), username_scale) {
+        fonts.draw_text(&mut canvas, Rgba([255, 255, 255, 255]), name_x.trunc(), text_y as i32, scale, text);
+        let username_width = fonts.text_width(text, scale);
+        guides.add_box(name_x as i32, text_y as i32, username_width as u32, username_height as u32, DEBUG_TEXT_BOX_COLOR);
+        
+        // ⚡ marker after the username for power badge holders
+        if power_badge {
+            let v_metrics = font.v_metrics(scale);
+            let glyph_height = v_metrics.ascent - v_metrics.descent;
+            let radius = (glyph_height * 0.35) as i32;
+            let badge_x = name_x + username_width + radius as f32 + 8.0 * px;
+            draw_power_badge(&mut canvas, badge_x as i32, (text_y + glyph_height / 2.0) as i32, radius);
+        }
+        text_y += username_height;
     }
-
-    // Get base URL from environment or default
-    let base_url = env
-        .var("BASE_URL")
-        .map(|v| v.to_string())
-        .unwrap_or_else(|_| "https://miniapp.polyjuice.io".to_string());
-
-    // Check if this is a Farcaster bot request
-    let is_bot = is_farcaster_bot(user_agent.as_deref(), req.headers());
-
-    // Only process annual report routes for bots
-    if is_bot && pathname.starts_with("/annual-report/") {
-        // Extract FID from path
-        let fid = match extract_fid_from_path(&pathname) {
-            Some(fid) => fid,
-            None => {
-                console_log!("Failed to extract FID from path: {}", pathname);
-                return Response::error("Invalid FID in URL path", 400);
-            }
-        };
-
-        // Get source URL from environment or use default GitHub Pages format
-        let source_url = match env.var("SOURCE_URL") {
-            Ok(url) => {
-                // If SOURCE_URL is set, use it directly
-                url.to_string()
-            }
-            Err(_) => {
-                // Fallback to GitHub Pages format
-                let github_username = env
-                    .var("GITHUB_USERNAME")
-                    .map(|v| v.to_string())
-                    .unwrap_or_else(|_| "your-username".to_string());
-                format!("https://{}.github.io/index.html", github_username)
+    if let Some(fid_text) = &fid_text {
+        draw_text_mut(&mut canvas, Rgba([255, 255, 255, 200]), name_x as i32, text_y as i32, fid_scale, font, fid_text);
+    }
+    
+    // Stat lines (numbers bold and larger), skipped when the sharer hid their numbers
+    let stats_x = layout.padding as i32;
+    let column_right = (layout.padding + layout.column_width) as i32;
+    if !params.hide_stats {
+        let stats_font_size = 34.0 * px;
+        let stats_number_font_size = 42.0 * px;
+        let line_height = stats_number_font_size * 1.25;
+        let bold_offset = 2 * render_scale as i32;
+        let stats_lines = [
+            format!("Published{}Casts", format_count(params.total_casts)),
+            format!("Received{}Reactions", format_count(params.total_reactions)),
+            format!("Gained{}Followers", format_count(params.total_followers)),
+        ];
+        for (i, line) in stats_lines.iter().enumerate() {
+            let line_y = layout.stats_y as f32 + i as f32 * line_height;
+            draw_text_with_bold_numbers(&mut canvas, &fonts, line, stats_x, line_y as i32, stats_font_size, stats_number_font_size, bold_offset, Rgba([255, 255, 255, 255]));
+            guides.add_line(stats_x, column_right, line_y as i32, DEBUG_BASELINE_COLOR);
+        }
+        let stats_bottom = layout.stats_y as f32 + stats_lines.len() as f32 * line_height;
+        if stats_bottom > layout.badge_y as f32 {
+            console_log!("⚠️ Warning: Stats ({:.1}px) may overlap with badge area ({}px)", stats_bottom, layout.badge_y);
+        }
+    }
+    guides.add_line(stats_x, column_right, layout.badge_y as i32, DEBUG_LIMIT_COLOR);
+    
+    // Zodiac and social type badges along the bottom of the left column
+    let badge_urls = [
+        get_zodiac_url_from_index(params.zodiac_index, base_url),
+        get_social_type_url_from_index(params.social_type_index, base_url),
+    ];
+    let mut badge_x = layout.padding;
+    for badge_url in &badge_urls {
+        match fetch_rgba_image(badge_url, max_image_dimension).await {
+            Ok(badge_rgba) => {
+                let badge_resized = resize_with_circular_border(&badge_rgba, layout.badge_size);
+                overlay_image(&mut canvas, &badge_resized, badge_x, layout.badge_y);
+                guides.add_box(badge_x as i32, layout.badge_y as i32, badge_resized.width(), badge_resized.height(), DEBUG_IMAGE_BOX_COLOR);
             }
-        };
-
+            Err(e) => console_log!("⚠️ Failed to load badge {}: {}", badge_url, e),
+        }
+        badge_x += layout.badge_size + 20 * render_scale;
+    }
+    
+    // Tarot thumbnail on the right
+    let tarot_thumb = image::imageops::resize(
+        &tarot_img,
+        layout.tarot_width,
+        layout.tarot_height,
+        image::imageops::FilterType::Lanczos3,
+    );
+    overlay_image(&mut canvas, &tarot_thumb, layout.tarot_x, layout.content_y);
+    guides.add_box(layout.tarot_x as i32, layout.content_y as i32, tarot_thumb.width(), tarot_thumb.height(), DEBUG_IMAGE_BOX_COLOR);
+    guides.draw(&mut canvas, render_scale);
+    
+    // Encode to PNG
+    let mut png_bytes = Vec::new();
+    {
+        let mut cursor = std::io::Cursor::new(&mut png_bytes);
+        image::DynamicImage::ImageRgba8(canvas)
+            .write_to(&mut cursor, image::ImageOutputFormat::Png)
+            .map_err(|e| format!("Failed to encode PNG: {:?}", e))?;
+    }
+    
+    console_log!("✅ Summary card generated: {} bytes", png_bytes.len());
+    Ok(png_bytes)
+}
+
+/// Generate a simple OG card for a profile page: avatar, username, and follower count
+/// Size is 1200x630 (standard Open Graph image size)
+#[allow(clippy::too_many_arguments)]
+async fn generate_profile_card(
+    fid: i64,
+    username: Option<&str>,
+    avatar_url: Option<&str>,
+    avatar_sources: &AvatarSources,
+    max_image_dimension: u32,
+    followers: Option<usize>,
+    power_badge: bool,
+    theme: &CardTheme,
+) -> Result<Vec<u8>, String> {
+    use rusttype::Scale;
+    use imageproc::drawing::draw_text_mut;
+    
+    let card_width = 1200u32;
+    let card_height = 630u32;
+    let avatar_size = 240u32;
+    let left_padding = 100u32;
+    
+    let font = regular_font()?;
+    
+    let mut canvas = RgbaImage::new(card_width, card_height);
+    
+    // Same gradient as the report card
+    for y in 0..card_height {
+        let color = theme.gradient_at(y as f32 / card_height as f32);
+        for x in 0..card_width {
+            canvas.put_pixel(x, y, color);
+        }
+    }
+    
+    // Avatar (left, vertically centered), falling back to the default avatar
+    let avatar_y = (card_height - avatar_size) / 2;
+    let avatar_img = load_avatar_image(avatar_url, avatar_sources, avatar_size, max_image_dimension).await;
+    let avatar_resized = resize_with_circular_border_cropped(&avatar_img, avatar_size);
+    overlay_image(&mut canvas, &avatar_resized, left_padding, avatar_y);
+    
+    // Username and FID (right of avatar)
+    let text_x = (left_padding + avatar_size + 60) as i32;
+    let username_scale = Scale::uniform(72.0);
+    let username_text = match username {
+        Some(username) if !username.is_empty() => format!("@{}", username),
+        _ => format!("FID {}", fid),
+    };
+    let username_baseline = avatar_y as f32 + font.v_metrics(username_scale).ascent;
+    let fonts = font_chain()?;
+    fonts.draw_text(&mut canvas, Rgba([255, 255, 255, 255]), text_x as f32, username_baseline as i32, username_scale, &username_text);
+    if power_badge {
+        let username_v_metrics = font.v_metrics(username_scale);
+        let text_height = username_v_metrics.ascent - username_v_metrics.descent;
+        let radius = (text_height * 0.35) as i32;
+        let badge_x = text_x as f32 + fonts.text_width(&username_text, username_scale) + radius as f32 + 12.0;
+        draw_power_badge(&mut canvas, badge_x as i32, (username_baseline + text_height / 2.0) as i32, radius);
+    }
+    
+    let fid_scale = Scale::uniform(32.0);
+    let fid_baseline = username_baseline + calculate_text_height(font, fid_scale) * 1.3;
+    draw_text_mut(&mut canvas, Rgba([255, 255, 255, 200]), text_x, fid_baseline as i32, fid_scale, font, &format!("FID: {}", fid));
+    
+    // Follower count (numbers bold and larger)
+    if let Some(followers) = followers {
+        let followers_baseline = avatar_y as f32 + avatar_size as f32;
+        draw_text_with_bold_numbers(&mut canvas, &fonts, &format!("{} Followers", format_count(followers)), text_x, followers_baseline as i32 - 48, 40.0, 48.0, 2, Rgba([255, 255, 255, 255]));
+    }
+    
+    let mut png_bytes = Vec::new();
+    {
+        let mut cursor = std::io::Cursor::new(&mut png_bytes);
+        image::DynamicImage::ImageRgba8(canvas)
+            .write_to(&mut cursor, image::ImageOutputFormat::Png)
+            .map_err(|e| format!("Failed to encode PNG: {:?}", e))?;
+    }
+    
+    console_log!("✅ Profile card generated: {} bytes", png_bytes.len());
+    Ok(png_bytes)
+}
+
+/// Handle /api/profile-card endpoint - generate OG image for a profile page
+async fn handle_profile_card(
+    req: Request,
+    config: &Config,
+) -> Result<Response> {
+    let url = req.url()?;
+    let fid = match url
+        .query_pairs()
+        .find(|(key, _)| key == "fid")
+        .and_then(|(_, value)| value.parse::<i64>().ok())
+    {
+        Some(fid) => fid,
+        None => return Response::error("Missing or invalid 'fid' parameter", 400),
+    };
+    
+    console_log!("Generating profile card for FID: {}", fid);
+    
+    let api_url = &config.api_url;
+    
+    let profile = match fetch_profile_from_api(fid, api_url).await {
+        Ok(profile) => profile,
+        Err(e) => {
+            console_log!("⚠️ Failed to fetch profile: {}", e);
+            CardProfile::default()
+        }
+    };
+    let followers = match fetch_follower_count_from_api(fid, api_url).await {
+        Ok(followers) => followers,
+        Err(e) => {
+            console_log!("⚠️ Failed to fetch follower count: {}", e);
+            None
+        }
+    };
+    
+    let png_bytes = generate_profile_card(
+        fid,
+        profile.username.as_deref(),
+        profile.avatar_url.as_deref(),
+        &config.avatar_sources,
+        config.max_image_dimension,
+        followers,
+        profile.power_badge,
+        &config.card_theme,
+    )
+        .await
+        .map_err(|e| format!("Failed to generate profile card: {}", e))?;
+    
+    let content_length = png_bytes.len().to_string();
+    let mut response = Response::from_bytes(png_bytes)?;
+    response.headers_mut().set("content-type", "image/png")?;
+    response.headers_mut().set("content-length", &content_length)?;
+    response.headers_mut().set("access-control-allow-origin", "*")?;
+    response.headers_mut().set("cache-control", "public, max-age=3600")?;
+    
+    Ok(response)
+}
+
+/// Workers rate limiting binding counting /api/generate and /api/generate.gif renders
+/// The limit and period are set on the binding in wrangler.toml; without it the limit is skipped
+const GENERATE_RATE_LIMITER_BINDING: &str = "GENERATE_RATE_LIMITER";
+
+/// Retry-After for a limited render, matching the binding's `period`
+const GENERATE_RATE_LIMIT_PERIOD_SECS: u64 = 60;
+
+/// Rate limit key for rendering `fid` for a client: the FID plus the connecting IP.
+/// Embed proxies share IPs across many users, and the FID alone is caller-chosen
+fn rate_limit_key(fid: i64, client_ip: Option<&str>) -> String {
+    format!("generate:{}:{}", fid, client_ip.unwrap_or("unknown"))
+}
+
+/// The 429 response when this FID and client are over the generate limit, or None to go ahead
+/// Embed fetchers unfurl cards on behalf of many users and are never limited.
+/// Fails open if the binding is missing or erroring
+async fn generate_rate_limit_response(env: &Env, req: &Request, fid: i64) -> Result<Option<Response>> {
+    let user_agent = req.headers().get("user-agent").ok().flatten();
+    if is_farcaster_bot(user_agent.as_deref(), req.headers()) {
+        return Ok(None);
+    }
+    let limiter = match env.rate_limiter(GENERATE_RATE_LIMITER_BINDING) {
+        Ok(limiter) => limiter,
+        Err(e) => {
+            console_log!("⚠️ Rate limit check skipped, {} binding unavailable: {:?}", GENERATE_RATE_LIMITER_BINDING, e);
+            return Ok(None);
+        }
+    };
+    let client_ip = req.headers().get("CF-Connecting-IP").ok().flatten();
+    let key = rate_limit_key(fid, client_ip.as_deref());
+    match limiter.limit(key.clone()).await {
+        Ok(outcome) if !outcome.success => {
+            console_log!("🚦 Rate limit exceeded for {}", key);
+            let mut response = Response::error("Too many report card generations, please retry later", 429)?;
+            response
+                .headers_mut()
+                .set("retry-after", &GENERATE_RATE_LIMIT_PERIOD_SECS.to_string())?;
+            Ok(Some(response))
+        }
+        Ok(_) => Ok(None),
+        Err(e) => {
+            console_log!("⚠️ Rate limit check skipped: {:?}", e);
+            Ok(None)
+        }
+    }
+}
+
+/// A card already rendered for this exact URL, from the edge cache
+/// Checked before the rate limit so re-fetching identical params never counts as a generation
+async fn cached_card(cache_key: &str) -> Option<Response> {
+    match Cache::default().get(cache_key, false).await {
+        Ok(cached) => cached,
+        Err(e) => {
+            console_log!("⚠️ Card cache lookup failed: {:?}", e);
+            None
+        }
+    }
+}
+
+/// Store a rendered card in the edge cache; a failure only costs a re-render next time
+async fn cache_card(cache_key: &str, response: &mut Response) {
+    let stored = match response.cloned() {
+        Ok(copy) => Cache::default().put(cache_key, copy).await,
+        Err(e) => Err(e),
+    };
+    if let Err(e) = stored {
+        console_log!("⚠️ Failed to cache card: {:?}", e);
+    }
+}
+
+/// Card variants served by /api/generate (`?layout=`)
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CardLayout {
+    /// Stats on the left, full tarot card on the right (default)
+    Landscape,
+    /// Tarot card with the avatar and badges above it
+    Portrait,
+    /// Compact "stats at a glance" card
+    Summary,
+}
+
+/// Handle /api/generate endpoint - generate tarot card image
+async fn handle_generate_image(
+    req: Request,
+    env: &Env,
+    config: &Config,
+) -> Result<Response> {
+    let url = req.url()?;
+    let query_params: std::collections::HashMap<String, String> = url
+        .query_pairs()
+        .into_owned()
+        .collect();
+    
+    // Get params from query params
+    let params_base64 = query_params
+        .get("params")
+        .ok_or_else(|| "Missing 'params' parameter")?;
+    
+    // Decode params (fid is included in params now)
+    let params = decode_image_params(params_base64)
+        .map_err(|e| format!("Failed to decode params: {}", e))?;
+    
+    // Optional background mode: ?bg=transparent keeps the alpha channel
+    let transparent_background = match query_params.get("bg").map(|v| v.as_str()) {
+        None | Some("gradient") => false,
+        Some("transparent") => true,
+        Some(other) => return Response::error(format!("Invalid 'bg' parameter: {}", other), 400),
+    };
+    
+    // Optional retina rendering: ?scale=2 draws everything at 2x (clamped to 1-3)
+    let render_scale = match query_params.get("scale") {
+        None => MIN_RENDER_SCALE,
+        Some(value) => match value.parse::<u32>() {
+            Ok(scale) => scale.clamp(MIN_RENDER_SCALE, MAX_RENDER_SCALE),
+            Err(_) => return Response::error(format!("Invalid 'scale' parameter: {}", value), 400),
+        },
+    };
+    
+    // Optional layout: ?layout=portrait renders the tarot-with-badges card instead of stats + tarot,
+    // ?layout=summary a single compact card with every key stat
+    let layout = match query_params.get("layout").map(|v| v.as_str()) {
+        None | Some("landscape") => CardLayout::Landscape,
+        Some("portrait") => CardLayout::Portrait,
+        Some("summary") => CardLayout::Summary,
+        Some(other) => return Response::error(format!("Invalid 'layout' parameter: {}", other), 400),
+    };
+    
+    // The portrait card is sized from the tarot image and downscaled, so it has no render scale
+    if layout == CardLayout::Portrait && render_scale > MIN_RENDER_SCALE {
+        return Response::error("The 'scale' parameter is not supported for the portrait layout", 400);
+    }
+    
+    // Only PNG keeps transparency; JPEG has no alpha channel
+    let wants_jpeg = matches!(
+        query_params.get("format").map(|v| v.to_lowercase()).as_deref(),
+        Some("jpeg") | Some("jpg")
+    );
+    if transparent_background && wants_jpeg {
+        return Response::error("Transparent background is not supported for JPEG output", 400);
+    }
+    
+    // ?target_kb= sets the size JPEG quality is tuned towards
+    let jpeg_target_kb = match query_params.get("target_kb") {
+        None => DEFAULT_JPEG_TARGET_KB,
+        Some(value) => match value.parse::<u32>() {
+            Ok(kb) if (MIN_JPEG_TARGET_KB..=MAX_JPEG_TARGET_KB).contains(&kb) => kb,
+            _ => return Response::error(
+                format!("Invalid 'target_kb' parameter: {} (expected {}-{})", value, MIN_JPEG_TARGET_KB, MAX_JPEG_TARGET_KB),
+                400,
+            ),
+        },
+    };
+    
+    // ?debug=1 overlays layout guides; such images are never cached
+    let debug_layout = matches!(query_params.get("debug").map(|v| v.as_str()), Some("1") | Some("true"));
+    
+    // Identical params are served from the edge cache without counting against the limit
+    let cache_key = url.to_string();
+    if !debug_layout {
+        if let Some(cached) = cached_card(&cache_key).await {
+            return Ok(cached);
+        }
+    }
+    
+    // Per-FID-and-client limit on actual renders; fails open without the rate limiting binding
+    if let Some(response) = generate_rate_limit_response(env, &req, params.fid).await? {
+        return Ok(response);
+    }
+    
+    console_log!("Generating report card for FID: {}", params.fid);
+    console_log!("Zodiac index: {}", params.zodiac_index);
+    console_log!("Social type index: {}", params.social_type_index);
+    console_log!("Stats: {} casts, {} reactions, {} followers", 
+        params.total_casts, params.total_reactions, params.total_followers);
+    
+    // Base URL for constructing image URLs, API URL for fetching profile
+    let base_url = &config.base_url;
+    let api_url = &config.api_url;
+    
+    // Calculate tarot card based on FID
+    let (_tarot_name, tarot_filename) = calculate_tarot_card(params.fid, 0);
+    let tarot_image_url = format!("{}/imgs/tarot/{}", base_url, tarot_filename);
+    
+    // Generate report card image
+    let card_result = match layout {
+        CardLayout::Portrait => {
+            // Portrait card: badges and avatar above the tarot; no text, so no font needed
+            let avatar_url = match fetch_profile_from_api(params.fid, api_url).await {
+                Ok(profile) => profile.avatar_url,
+                Err(e) => {
+                    console_log!("⚠️ Failed to fetch profile: {}", e);
+                    None
+                }
+            };
+            composite_tarot_with_badges(
+                &tarot_image_url,
+                &get_zodiac_url_from_index(params.zodiac_index, base_url),
+                &get_social_type_url_from_index(params.social_type_index, base_url),
+                avatar_url.as_deref(),
+                &config.avatar_sources,
+                config.max_image_dimension,
+                transparent_background,
+                debug_layout,
+                // JPEG output hits its size target through quality instead
+                !wants_jpeg,
+            ).await
+        }
+        CardLayout::Summary => generate_summary_card(
+            &tarot_image_url,
+            &params,
+            base_url,
+            api_url,
+            &config.avatar_sources,
+            config.max_image_dimension,
+            transparent_background,
+            render_scale,
+            debug_layout,
+            &config.card_theme,
+        ).await,
+        CardLayout::Landscape => generate_report_card(
+            &tarot_image_url,
+            &params,
+            base_url,
+            api_url,
+            &config.avatar_sources,
+            config.max_image_dimension,
+            transparent_background,
+            render_scale,
+            debug_layout,
+            &config.card_theme,
+        ).await,
+    };
+    let png_bytes = match card_result {
+        Ok(png_bytes) => png_bytes,
+        Err(e) if e == FONT_LOAD_ERROR => {
+            // Without a font no text can be drawn; the bare tarot card is still a usable preview
+            console_log!("⚠️ {}, redirecting to plain tarot image", e);
+            return Response::redirect(Url::parse(&tarot_image_url)?);
+        }
+        Err(e) => return Err(format!("Failed to generate report card: {}", e).into()),
+    };
+    
+    let (image_bytes, content_type) = if wants_jpeg {
+        let card = image::load_from_memory_with_format(&png_bytes, image::ImageFormat::Png)
+            .map_err(|e| format!("Failed to decode rendered card: {:?}", e))?
+            .to_rgb8();
+        (encode_jpeg_near_target(card, jpeg_target_kb as usize * 1024)?, "image/jpeg")
+    } else {
+        (png_bytes, "image/png")
+    };
+    
+    // Return the image directly
+    let content_length = image_bytes.len().to_string();
+    let mut response = Response::from_bytes(image_bytes)?;
+    response.headers_mut().set("content-type", content_type)?;
+    response.headers_mut().set("content-length", &content_length)?;
+    response.headers_mut().set("access-control-allow-origin", "*")?;
+    let cache_control = if debug_layout { "no-store" } else { "public, max-age=3600" };
+    response.headers_mut().set("cache-control", cache_control)?;
+    if !debug_layout {
+        cache_card(&cache_key, &mut response).await;
+    }
+    
+    Ok(response)
+}
+
+/// Frames in the animated report card; each one is a full quantize + LZW pass, so keep it
+/// small to bound CPU time and file size
+const GIF_FRAME_COUNT: u32 = 10;
+/// Delay between count-up frames, and how long the final numbers hold before looping
+const GIF_FRAME_DELAY_MS: u32 = 120;
+const GIF_FINAL_FRAME_DELAY_MS: u32 = 2500;
+/// Animated cards are downscaled to at most this width
+const GIF_MAX_WIDTH: u32 = 800;
+
+/// Value shown on animation frame `frame` (of `frame_count`) while counting up to `target`
+/// Eases out (cubic) from 0 on the first frame to exactly `target` on the last
+fn count_up_frame_value(target: usize, frame: u32, frame_count: u32) -> usize {
+    if frame_count <= 1 || frame + 1 >= frame_count {
+        return target;
+    }
+    let progress = frame as f64 / (frame_count - 1) as f64;
+    let eased = 1.0 - (1.0 - progress).powi(3);
+    (target as f64 * eased).round() as usize
+}
+
+/// Generate the landscape report card as a looping GIF with its stat lines counting up
+/// The card itself is rendered once at 1x; each frame only redraws the stats
+async fn generate_report_gif(
+    tarot_url: &str,
+    params: &ImageParams,
+    base_url: &str,
+    api_url: &str,
+    avatar_sources: &AvatarSources,
+    max_image_dimension: u32,
+    theme: &CardTheme,
+) -> Result<Vec<u8>, String> {
+    use image::codecs::gif::{GifEncoder, Repeat};
+    
+    let ReportCanvas { canvas: base, stats, .. } = render_report_card_canvas(
+        tarot_url,
+        params,
+        base_url,
+        api_url,
+        avatar_sources,
+        max_image_dimension,
+        false,
+        MIN_RENDER_SCALE,
+        false,
+        theme,
+    ).await?;
+    let fonts = font_chain()?;
+    
+    let (width, height) = if base.width() > GIF_MAX_WIDTH {
+        (GIF_MAX_WIDTH, (base.height() as u64 * GIF_MAX_WIDTH as u64 / base.width() as u64) as u32)
+    } else {
+        base.dimensions()
+    };
+    // With hidden stats there is nothing to count up, so a single still frame
+    let frame_count = if params.hide_stats { 1 } else { GIF_FRAME_COUNT };
+    console_log!("🎞️ Encoding {} GIF frames at {}x{}", frame_count, width, height);
+    
+    let mut gif_bytes = Vec::new();
+    {
+        let mut encoder = GifEncoder::new_with_speed(&mut gif_bytes, 10);
+        encoder.set_repeat(Repeat::Infinite)
+            .map_err(|e| format!("Failed to start GIF: {:?}", e))?;
+        for frame in 0..frame_count {
+            let mut canvas = base.clone();
+            let counts = (!params.hide_stats).then(|| {
+                [params.total_casts, params.total_reactions, params.total_followers]
+                    .map(|target| count_up_frame_value(target, frame, frame_count))
+            });
+            draw_report_stats(&mut canvas, &fonts, &stats, counts, &mut DebugGuides::new(false));
+            let canvas = if (width, height) != canvas.dimensions() {
+                image::imageops::resize(&canvas, width, height, image::imageops::FilterType::Triangle)
+            } else {
+                canvas
+            };
+            let delay_ms = if frame + 1 == frame_count { GIF_FINAL_FRAME_DELAY_MS } else { GIF_FRAME_DELAY_MS };
+            encoder
+                .encode_frame(image::Frame::from_parts(canvas, 0, 0, image::Delay::from_numer_denom_ms(delay_ms, 1)))
+                .map_err(|e| format!("Failed to encode GIF frame {}: {:?}", frame, e))?;
+        }
+    }
+    
+    console_log!("✅ Report GIF generated: {} bytes", gif_bytes.len());
+    Ok(gif_bytes)
+}
+
+/// Handle /api/generate.gif endpoint - the landscape report card with its stats counting up
+/// Serves the static PNG card (as /api/generate would) when GIF export is disabled (GIF_EXPORT)
+async fn handle_generate_gif(
+    req: Request,
+    env: &Env,
+    config: &Config,
+) -> Result<Response> {
+    if !config.gif_export_enabled {
+        console_log!("⚙️ GIF export disabled, serving the PNG report card");
+        return handle_generate_image(req, env, config).await;
+    }
+    
+    let url = req.url()?;
+    let query_params: std::collections::HashMap<String, String> = url
+        .query_pairs()
+        .into_owned()
+        .collect();
+    
+    let params_base64 = query_params
+        .get("params")
+        .ok_or("Missing 'params' parameter")?;
+    let params = decode_image_params(params_base64)
+        .map_err(|e| format!("Failed to decode params: {}", e))?;
+    
+    let cache_key = url.to_string();
+    if let Some(cached) = cached_card(&cache_key).await {
+        return Ok(cached);
+    }
+    if let Some(response) = generate_rate_limit_response(env, &req, params.fid).await? {
+        return Ok(response);
+    }
+    
+    console_log!("Generating report GIF for FID: {}", params.fid);
+    let (_tarot_name, tarot_filename) = calculate_tarot_card(params.fid, 0);
+    let tarot_image_url = format!("{}/imgs/tarot/{}", config.base_url, tarot_filename);
+    
+    let gif_bytes = match generate_report_gif(
+        &tarot_image_url,
+        &params,
+        &config.base_url,
+        &config.api_url,
+        &config.avatar_sources,
+        config.max_image_dimension,
+        &config.card_theme,
+    ).await {
+        Ok(gif_bytes) => gif_bytes,
+        Err(e) if e == FONT_LOAD_ERROR => {
+            console_log!("⚠️ {}, redirecting to plain tarot image", e);
+            return Response::redirect(Url::parse(&tarot_image_url)?);
+        }
+        Err(e) => return Err(format!("Failed to generate report GIF: {}", e).into()),
+    };
+    
+    let content_length = gif_bytes.len().to_string();
+    let mut response = Response::from_bytes(gif_bytes)?;
+    response.headers_mut().set("content-type", "image/gif")?;
+    response.headers_mut().set("content-length", &content_length)?;
+    response.headers_mut().set("access-control-allow-origin", "*")?;
+    response.headers_mut().set("cache-control", "public, max-age=3600")?;
+    cache_card(&cache_key, &mut response).await;
+    
+    Ok(response)
+}
+
+/// Build the share caption for a report from the app's templates (shared/share_text.rs)
+/// The params only carry casts/reactions/followers, so the template drops the recasts,
+/// most-active-month and emoji parts of the in-app caption. The year is the report's,
+/// or `default_year` (CARD_YEAR) for params from before it was encoded
+fn build_share_text(
+    params: &ImageParams,
+    tarot_card_name: &str,
+    share_url: &str,
+    template: &ShareTextTemplate,
+    default_year: u16,
+) -> String {
+    let mut values = ShareTextValues {
+        tarot: Some(tarot_card_name.to_string()),
+        url: Some(share_url.to_string()),
+        year: Some(params.year.unwrap_or(default_year).to_string()),
+        ..ShareTextValues::default()
+    };
+    let text = if params.hide_stats {
+        &template.private
+    } else {
+        values.casts = Some(format_count(params.total_casts));
+        values.likes = Some(format_count(params.total_reactions));
+        &template.public
+    };
+    render_share_template(text, &template.separator, &values)
+}
+
+/// Locale for the share caption: `?locale=`, else the first Accept-Language tag, else English
+fn share_text_locale(query_locale: Option<&str>, accept_language: Option<&str>) -> String {
+    query_locale
+        .or_else(|| accept_language.and_then(|header| header.split([',', ';']).next()))
+        .map(str::trim)
+        .filter(|locale| !locale.is_empty())
+        .unwrap_or("en")
+        .to_string()
+}
+
+/// Handle /api/share-text endpoint - return the canonical share caption as JSON
+async fn handle_share_text(
+    req: Request,
+    config: &Config,
+) -> Result<Response> {
+    let url = req.url()?;
+    let params_base64 = match url.query_pairs().find(|(key, _)| key == "params") {
+        Some((_, value)) => value.into_owned(),
+        None => return Response::error("Missing 'params' parameter", 400),
+    };
+    
+    let params = match decode_image_params(&params_base64) {
+        Ok(params) => params,
+        Err(e) => return Response::error(format!("Failed to decode params: {}", e), 400),
+    };
+    
+    let query_locale = url
+        .query_pairs()
+        .find(|(key, _)| key == "locale")
+        .map(|(_, value)| value.into_owned());
+    let accept_language = req.headers().get("accept-language").ok().flatten();
+    let locale = share_text_locale(query_locale.as_deref(), accept_language.as_deref());
+    let template = builtin_share_template(&locale);
+    
+    // The username lets callers mention the user in their post; a failed lookup only drops it
+    let username = match fetch_profile_from_api(params.fid, &config.api_url).await {
+        Ok(profile) => profile.username,
+        Err(e) => {
+            console_log!("⚠️ Failed to fetch profile: {}", e);
+            None
+        }
+    };
+    
+    let (tarot_name, _tarot_filename) = calculate_tarot_card(params.fid, 0);
+    let share_url = format!("{}/annual-report/{}?params={}", config.base_url, params.fid, params_base64);
+    let text = build_share_text(
+        &params,
+        tarot_name,
+        &share_url,
+        &template,
+        config.card_theme.default_year,
+    );
+    
+    let mut response = Response::from_json(&json!({ "text": text, "username": username }))?;
+    response.headers_mut().set("access-control-allow-origin", "*")?;
+    response.headers_mut().set("cache-control", "public, max-age=3600")?;
+    
+    Ok(response)
+}
+
+/// Synthetic share params for /api/selftest: FID 0 (no real profile), zodiac 7, social tier 1,
+/// 1234 casts, 56789 reactions, 4321 followers
+const SELFTEST_PARAMS: &str = "AAAAAAAAAAAHAdIEAADV3QAA4RAAAA";
+
+/// Smallest width/height a rendered report card may have (the banner alone is 80px tall)
+const SELFTEST_MIN_DIMENSION: u32 = 100;
+
+/// Decode a rendered card and check it's a plausible report card: a PNG, landscape,
+/// not tiny, and not a single flat color. Returns (width, height) or the failing step and reason
+fn verify_selftest_png(png_bytes: &[u8]) -> std::result::Result<(u32, u32), (&'static str, String)> {
+    if png_bytes.is_empty() {
+        return Err(("encode", "Rendered image is empty".to_string()));
+    }
+    let img = image::load_from_memory_with_format(png_bytes, image::ImageFormat::Png)
+        .map_err(|e| ("decode", format!("Rendered image is not a valid PNG: {}", e)))?
+        .to_rgba8();
+    let (width, height) = img.dimensions();
+    if width < SELFTEST_MIN_DIMENSION || height < SELFTEST_MIN_DIMENSION || width <= height {
+        return Err(("dimensions", format!("Unexpected card size {}x{}", width, height)));
+    }
+    let first = *img.get_pixel(0, 0);
+    if img.pixels().all(|p| *p == first) {
+        return Err(("content", "Rendered image is a single flat color".to_string()));
+    }
+    Ok((width, height))
+}
+
+/// Handle /api/selftest - render a card for SELFTEST_PARAMS through the full pipeline
+/// (fetch, resize, draw, encode) and check the result. Only served when SELFTEST_TOKEN is set,
+/// and the caller must present it as ?token= or an x-selftest-token header
+async fn handle_selftest(
+    req: Request,
+    config: &Config,
+) -> Result<Response> {
+    let expected_token = match config.selftest_token.as_deref() {
+        Some(token) => token,
+        None => return Response::error("Not Found", 404),
+    };
+    let url = req.url()?;
+    let provided_token = req
+        .headers()
+        .get("x-selftest-token")
+        .ok()
+        .flatten()
+        .or_else(|| url.query_pairs().find(|(key, _)| key == "token").map(|(_, value)| value.into_owned()));
+    if provided_token.as_deref() != Some(expected_token) {
+        return Response::error("Unauthorized", 401);
+    }
+    
+    let fail = |step: &str, error: String| -> Result<Response> {
+        console_log!("❌ Self-test failed at {}: {}", step, error);
+        let mut response = Response::from_json(&json!({ "ok": false, "step": step, "error": error }))?
+            .with_status(500);
+        response.headers_mut().set("cache-control", "no-store")?;
+        Ok(response)
+    };
+    
+    let params = match decode_image_params(SELFTEST_PARAMS) {
+        Ok(params) => params,
+        Err(e) => return fail("params", e),
+    };
+    let (_tarot_name, tarot_filename) = calculate_tarot_card(params.fid, 0);
+    let tarot_image_url = format!("{}/imgs/tarot/{}", config.base_url, tarot_filename);
+    
+    let png_bytes = match generate_report_card(
+        &tarot_image_url,
+        &params,
+        &config.base_url,
+        &config.api_url,
+        &config.avatar_sources,
+        config.max_image_dimension,
+        false,
+        MIN_RENDER_SCALE,
+        false,
+        &config.card_theme,
+    ).await {
+        Ok(png_bytes) => png_bytes,
+        Err(e) => return fail("render", e),
+    };
+    
+    match verify_selftest_png(&png_bytes) {
+        Ok((width, height)) => {
+            console_log!("✅ Self-test passed: {}x{}, {} bytes", width, height, png_bytes.len());
+            let mut response = Response::from_json(&json!({
+                "ok": true,
+                "width": width,
+                "height": height,
+                "bytes": png_bytes.len(),
+            }))?;
+            response.headers_mut().set("cache-control", "no-store")?;
+            Ok(response)
+        }
+        Err((step, e)) => fail(step, e),
+    }
+}
+
+/// Worker configuration, read and validated from environment variables
+struct Config {
+    /// Public origin of the mini app (BASE_URL)
+    base_url: String,
+    /// Snaprag API used for profile lookups (API_URL)
+    api_url: String,
+    /// HTML page fetched for bot meta injection (SOURCE_URL or GitHub Pages index.html)
+    source_url: String,
+    /// Origin that non-bot requests are proxied to (SOURCE_URL or GitHub Pages)
+    source_base_url: String,
+    /// Fallback avatar and IPFS gateway chain (DEFAULT_AVATAR_URL, IPFS_GATEWAYS)
+    avatar_sources: AvatarSources,
+    /// Cap on either side of fetched images before decoding (MAX_IMAGE_DIMENSION)
+    max_image_dimension: u32,
+    /// App name and splash screen for embed launch actions (EMBED_APP_NAME, EMBED_SPLASH_PATH, EMBED_SPLASH_BG)
+    embed_branding: EmbedBranding,
+    /// Enables /api/selftest and is required to call it (SELFTEST_TOKEN)
+    selftest_token: Option<String>,
+    /// Serve animated GIFs from /api/generate.gif; off falls back to the PNG card (GIF_EXPORT)
+    gif_export_enabled: bool,
+    /// Banner title year and card colors (CARD_YEAR, CARD_BANNER_HEIGHT, CARD_BANNER_COLOR, CARD_GRADIENT_START, CARD_GRADIENT_END)
+    card_theme: CardTheme,
+    /// Required variables that failed to parse; only routes that depend on one of them fail
+    invalid_vars: Vec<&'static str>,
+}
+
+impl Config {
+    /// Generic 500 for a route that needs one of `vars` while it is misconfigured
+    /// Details go to the log only, so configuration values never reach the response body
+    fn misconfigured(&self, vars: &[&str]) -> Option<Result<Response>> {
+        let broken: Vec<&str> = self
+            .invalid_vars
+            .iter()
+            .copied()
+            .filter(|name| vars.contains(name))
+            .collect();
+        if broken.is_empty() {
+            return None;
+        }
+        console_log!(
+            "❌ Rejecting request, invalid worker configuration: {}",
+            broken.join(", ")
+        );
+        Some(Response::error("Worker misconfigured", 500))
+    }
+}
+
+/// Variables the /api/* routes need
+const API_ROUTE_VARS: [&str; 2] = ["BASE_URL", "API_URL"];
+
+/// Variables the source origin (bot meta page and proxy) is built from
+const SOURCE_VARS: [&str; 2] = ["SOURCE_URL", "GITHUB_USERNAME"];
+
+const DEFAULT_BASE_URL: &str = "https://miniapp.polyjuice.io";
+const DEFAULT_API_URL: &str = "https://api.polyjuice.io";
+
+/// Read a URL env var, falling back to a default, and check that it parses
+/// Returns None (after logging) when the value is not a valid URL
+fn read_url_var(env: &Env, name: &str, default: &str) -> Option<String> {
+    let value = match env.var(name) {
+        Ok(v) => v.to_string(),
+        Err(_) => {
+            console_log!("⚙️ {} not set, using default: {}", name, default);
+            default.to_string()
+        }
+    };
+    match Url::parse(&value) {
+        Ok(_) => Some(value.trim_end_matches('/').to_string()),
+        Err(e) => {
+            console_log!("❌ Invalid {} '{}': {}", name, value, e);
+            None
+        }
+    }
+}
+
+/// Parse and validate the worker configuration once per request
+/// Invalid optional variables fall back to their defaults; invalid required ones are
+/// recorded in `invalid_vars` so only the routes that need them fail
+fn validate_env(env: &Env) -> Config {
+    let mut invalid_vars = Vec::new();
+    let base_url = read_url_var(env, "BASE_URL", DEFAULT_BASE_URL).unwrap_or_else(|| {
+        invalid_vars.push("BASE_URL");
+        DEFAULT_BASE_URL.to_string()
+    });
+    let api_url = read_url_var(env, "API_URL", DEFAULT_API_URL).unwrap_or_else(|| {
+        invalid_vars.push("API_URL");
+        DEFAULT_API_URL.to_string()
+    });
+
+    let (source_url, source_base_url) = match env.var("SOURCE_URL") {
+        Ok(url) => {
+            // If SOURCE_URL is set, use it directly (and as the proxy base)
+            let url = url.to_string();
+            if let Err(e) = Url::parse(&url) {
+                console_log!("❌ Invalid SOURCE_URL '{}': {}", url, e);
+                invalid_vars.push("SOURCE_URL");
+            }
+            (url.clone(), url.trim_end_matches('/').to_string())
+        }
+        Err(_) => {
+            // Fallback to GitHub Pages format
+            let github_username = env
+                .var("GITHUB_USERNAME")
+                .map(|v| v.to_string())
+                .unwrap_or_else(|_| {
+                    console_log!("⚙️ SOURCE_URL and GITHUB_USERNAME not set, using placeholder GitHub Pages origin");
+                    "your-username".to_string()
+                });
+            let source_base_url = format!("https://{}.github.io", github_username);
+            if let Err(e) = Url::parse(&source_base_url) {
+                console_log!("❌ Invalid GITHUB_USERNAME '{}': {}", github_username, e);
+                invalid_vars.push("GITHUB_USERNAME");
+            }
+            (format!("{}/index.html", source_base_url), source_base_url)
+        }
+    };
+
+    let default_avatar_url = match env.var("DEFAULT_AVATAR_URL") {
+        Ok(url) => {
+            let url = url.to_string();
+            match Url::parse(&url) {
+                Ok(_) => Some(url),
+                Err(e) => {
+                    console_log!(
+                        "⚠️ Invalid DEFAULT_AVATAR_URL '{}': {}, using bundled avatar",
+                        url,
+                        e
+                    );
+                    None
+                }
+            }
+        }
+        Err(_) => None,
+    };
+
+    let mut avatar_sources = AvatarSources { default_avatar_url, ..AvatarSources::default() };
+    if let Ok(raw) = env.var("IPFS_GATEWAYS") {
+        match parse_ipfs_gateways(&raw.to_string()) {
+            Ok(gateways) if !gateways.is_empty() => avatar_sources.ipfs_gateways = gateways,
+            Ok(_) => {}
+            Err(e) => console_log!("⚠️ {}, using default gateways", e),
+        }
+    }
+
+    let max_image_dimension = match env.var("MAX_IMAGE_DIMENSION") {
+        Ok(v) => {
+            let v = v.to_string();
+            v.trim()
+                .parse::<u32>()
+                .ok()
+                .filter(|n| *n > 0)
+                .unwrap_or_else(|| {
+                    console_log!(
+                        "⚠️ Invalid MAX_IMAGE_DIMENSION '{}', using default {}",
+                        v,
+                        DEFAULT_MAX_IMAGE_DIMENSION
+                    );
+                    DEFAULT_MAX_IMAGE_DIMENSION
+                })
+        }
+        Err(_) => DEFAULT_MAX_IMAGE_DIMENSION,
+    };
+
+    let mut embed_branding = EmbedBranding::default();
+    let read_optional_var = |name: &str| {
+        env.var(name)
+            .ok()
+            .map(|v| v.to_string().trim().to_string())
+            .filter(|v| !v.is_empty())
+    };
+    if let Some(app_name) = read_optional_var("EMBED_APP_NAME") {
+        embed_branding.app_name = app_name;
+    }
+    if let Some(splash_path) = read_optional_var("EMBED_SPLASH_PATH") {
+        embed_branding.splash_path = splash_path;
+    }
+    if let Some(splash_background) = read_optional_var("EMBED_SPLASH_BG") {
+        embed_branding.splash_background = splash_background;
+    }
+    if let Err(e) = Url::parse(&embed_branding.splash_image_url(&base_url)) {
+        console_log!(
+            "⚠️ Invalid EMBED_SPLASH_PATH '{}': {}, using default",
+            embed_branding.splash_path,
+            e
+        );
+        embed_branding.splash_path = EmbedBranding::default().splash_path;
+    }
+
+    let selftest_token = env
+        .var("SELFTEST_TOKEN")
+        .ok()
+        .map(|v| v.to_string().trim().to_string())
+        .filter(|v| !v.is_empty());
+
+    let gif_export_enabled = match env.var("GIF_EXPORT") {
+        Ok(v) => match v.to_string().trim().to_ascii_lowercase().as_str() {
+            "on" | "true" | "1" => true,
+            "off" | "false" | "0" => false,
+            other => {
+                console_log!("⚠️ Invalid GIF_EXPORT '{}', leaving GIF export on", other);
+                true
+            }
+        },
+        Err(_) => true,
+    };
+
+    let mut card_theme = CardTheme::default();
+    if let Some(year) = read_optional_var("CARD_YEAR") {
+        match year
+            .parse::<u16>()
+            .ok()
+            .filter(|year| (2020..=2100).contains(year))
+        {
+            Some(year) => card_theme.default_year = year,
+            None => console_log!(
+                "⚠️ Invalid CARD_YEAR '{}', using default {}",
+                year,
+                card_theme.default_year
+            ),
+        }
+    }
+    if let Some(height) = read_optional_var("CARD_BANNER_HEIGHT") {
+        match height
+            .parse::<u32>()
+            .ok()
+            .filter(|height| (40..=200).contains(height))
+        {
+            Some(height) => card_theme.banner_height = height,
+            None => console_log!(
+                "⚠️ Invalid CARD_BANNER_HEIGHT '{}' (expected 40-200), using default {}",
+                height,
+                card_theme.banner_height
+            ),
+        }
+    }
+    for (name, color) in [
+        ("CARD_BANNER_COLOR", &mut card_theme.banner_color),
+        ("CARD_GRADIENT_START", &mut card_theme.gradient_start),
+        ("CARD_GRADIENT_END", &mut card_theme.gradient_end),
+    ] {
+        if let Some(value) = read_optional_var(name) {
+            match parse_hex_color(&value) {
+                Some(parsed) => *color = parsed,
+                None => console_log!(
+                    "⚠️ Invalid {} '{}' (expected #rrggbb), using default",
+                    name,
+                    value
+                ),
+            }
+        }
+    }
+
+    Config {
+        base_url,
+        api_url,
+        source_url,
+        source_base_url,
+        avatar_sources,
+        max_image_dimension,
+        embed_branding,
+        selftest_token,
+        gif_export_enabled,
+        card_theme,
+        invalid_vars,
+    }
+}
+
+#[event(fetch)]
+pub async fn main(req: Request, env: Env, _ctx: Context) -> Result<Response> {
+    // Validate configuration up front; routes check only the variables they depend on
+    let config = validate_env(&env);
+
+    let url = req.url()?;
+    let pathname = url.path();
+    let user_agent = req.headers().get("user-agent").ok().flatten();
+    
+    let is_api_route = matches!(
+        pathname,
+        "/api/generate" | "/api/generate.gif" | "/api/profile-card" | "/api/share-text" | "/api/selftest"
+    );
+    if is_api_route {
+        if let Some(response) = config.misconfigured(&API_ROUTE_VARS) {
+            return response;
+        }
+    }
+
+    // Handle /api/generate endpoint
+    if pathname == "/api/generate" {
+        return handle_generate_image(req, &env, &config).await;
+    }
+    
+    // Handle /api/generate.gif endpoint (animated report card)
+    if pathname == "/api/generate.gif" {
+        return handle_generate_gif(req, &env, &config).await;
+    }
+
+    // Handle /api/profile-card endpoint
+    if pathname == "/api/profile-card" {
+        return handle_profile_card(req, &config).await;
+    }
+
+    // Handle /api/share-text endpoint
+    if pathname == "/api/share-text" {
+        return handle_share_text(req, &config).await;
+    }
+
+    // Handle /api/selftest endpoint (disabled unless SELFTEST_TOKEN is set)
+    if pathname == "/api/selftest" {
+        return handle_selftest(req, &config).await;
+    }
+
+    let base_url = &config.base_url;
+
+    // Check if this is a Farcaster bot request
+    let is_bot = is_farcaster_bot(user_agent.as_deref(), req.headers());
+
+    // Only process annual report and profile routes for bots
+    let bot_meta_tags = if !is_bot {
+        None
+    } else if pathname.starts_with("/annual-report/") {
+        // Extract FID from path, resolving usernames through the profile API
+        let fid = match extract_report_target(pathname) {
+            Some(ReportTarget::Fid(fid)) => fid,
+            Some(ReportTarget::Username(username)) => {
+                if let Some(response) = config.misconfigured(&["API_URL"]) {
+                    return response;
+                }
+                match resolve_username_fid(&username, &config.api_url).await {
+                    Ok(Some(fid)) => {
+                        console_log!("✅ Resolved @{} to FID {}", username, fid);
+                        fid
+                    }
+                    Ok(None) => {
+                        console_log!("⚠️ No user for @{}", username);
+                        return Response::error("User not found", 404);
+                    }
+                    Err(e) => {
+                        // A transient lookup failure must not look like a missing user to crawlers
+                        console_log!("⚠️ Failed to resolve @{}: {}", username, e);
+                        return Response::error("Failed to resolve username", 502);
+                    }
+                }
+            }
+            None => {
+                console_log!("Failed to extract FID from path: {}", pathname);
+                return Response::error("Invalid FID in URL path", 400);
+            }
+        };
+
+        // Extract params from URL if present
+        let params_base64 = url.query_pairs()
+            .find(|(key, _)| key == "params")
+            .map(|(_, value)| value.to_string());
+        
+        console_log!("📦 Meta generation - FID: {}, Has params: {}", fid, params_base64.is_some());
+        
+        // Generate meta tags based on FID and params
+        Some(generate_annual_report_meta_tags(
+            fid,
+            base_url,
+            pathname,
+            params_base64.as_deref(),
+            &config.embed_branding,
+        ))
+    } else {
+        extract_profile_fid_from_path(pathname).map(|fid| {
+            console_log!("📦 Profile meta generation - FID: {}", fid);
+            generate_profile_meta_tags(fid, base_url, pathname, &config.embed_branding)
+        })
+    };
+
+    if let Some(meta_tags) = bot_meta_tags {
+        if let Some(response) = config.misconfigured(&["BASE_URL", SOURCE_VARS[0], SOURCE_VARS[1]]) {
+            return response;
+        }
+
         // Try to fetch from the original source
-        let source_url_parsed = match source_url.parse() {
+        let source_url_parsed = match config.source_url.parse() {
             Ok(url) => url,
             Err(e) => {
                 console_log!("Failed to parse source URL: {:?}", e);
-                return Response::error(
-                    format!("Invalid source URL configuration: {}", e),
-                    500,
-                );
+                return Response::error("Worker misconfigured", 500);
             }
         };
 
@@ -1177,57 +3755,21 @@ pub async fn main(req: Request, env: Env, _ctx: Context) -> Result<Response> {
                     .flatten()
                     .unwrap_or_default();
 
-                if !content_type.contains("text/html") {
-                    console_log!("Source is not HTML, content-type: {}", content_type);
-                    return Response::error("Source is not HTML", 502);
-                }
-
-                // Read the HTML
-                let html = match response.text().await {
-                    Ok(html) => html,
-                    Err(e) => {
-                        console_log!("Error reading HTML: {:?}", e);
-                        return Response::error("Failed to read source HTML", 502);
-                    }
-                };
-
-                // Extract params from URL if present
-                let params_base64 = url.query_pairs()
-                    .find(|(key, _)| key == "params")
-                    .map(|(_, value)| value.to_string());
-                
-                console_log!("📦 Meta generation - FID: {}, Has params: {}", fid, params_base64.is_some());
-                
-                // Generate meta tags based on FID and params
-                let meta_tags = generate_annual_report_meta_tags(fid, &base_url, &pathname, params_base64.as_deref());
-
-                // Remove existing fc:miniapp, fc:frame, og:*, and twitter:* meta tags
-                let html_cleaned = html
-                    .lines()
-                    .filter(|line| {
-                        !line.contains("name=\"fc:miniapp\"")
-                            && !line.contains("name=\"fc:frame\"")
-                            && !line.contains("property=\"og:")
-                            && !line.contains("name=\"twitter:")
-                    })
-                    .collect::<Vec<_>>()
-                    .join("\n");
-
-                // Inject meta tags before </head>
-                let modified_html = if html_cleaned.contains("</head>") {
-                    html_cleaned.replace("</head>", &format!("{}\n</head>", meta_tags))
-                } else if html_cleaned.contains("<head>") {
-                    html_cleaned.replace("<head>", &format!("<head>\n{}", meta_tags))
+                // Non-HTML source: still serve the meta tags so the embed renders
+                let modified_html = if !content_type.contains("text/html") {
+                    console_log!("Source is not HTML, content-type: {}, serving fallback page", content_type);
+                    fallback_meta_html(&meta_tags)
                 } else {
-                    // If no head tag, prepend to body or html
-                    if html_cleaned.contains("<body>") {
-                        html_cleaned.replace(
-                            "<body>",
-                            &format!("<head>{}</head>\n<body>", meta_tags),
-                        )
-                    } else {
-                        format!("<head>{}</head>\n{}", meta_tags, html_cleaned)
-                    }
+                    // Read the HTML
+                    let html = match response.text().await {
+                        Ok(html) => html,
+                        Err(e) => {
+                            console_log!("Error reading HTML: {:?}", e);
+                            return Response::error("Failed to read source HTML", 502);
+                        }
+                    };
+                    
+                    inject_meta_tags(&html, &meta_tags)
                 };
 
                 // Return modified HTML with proper headers
@@ -1244,49 +3786,833 @@ pub async fn main(req: Request, env: Env, _ctx: Context) -> Result<Response> {
         }
     }
 
-    // For non-bot requests or non-annual-report routes, proxy the request
-    // Get source URL from environment or use default GitHub Pages format
-    let source_base_url = match env.var("SOURCE_URL") {
-        Ok(url) => {
-            // If SOURCE_URL is set, use it as base and append path
-            let base = url.to_string();
-            // Remove trailing slash if present
-            let base = base.trim_end_matches('/');
-            format!(
-                "{}{}",
-                base,
-                if pathname == "/" || pathname.is_empty() {
-                    "/index.html"
-                } else {
-                    pathname
-                }
-            )
-        }
-        Err(_) => {
-            // Fallback to GitHub Pages format
-            let github_username = env
-                .var("GITHUB_USERNAME")
-                .map(|v| v.to_string())
-                .unwrap_or_else(|_| "your-username".to_string());
-            format!(
-                "https://{}.github.io{}",
-                github_username,
-                if pathname == "/" || pathname.is_empty() {
-                    "/index.html"
-                } else {
-                    pathname
-                }
-            )
-        }
-    };
+    // For non-bot requests or other routes, proxy the request to the source origin
+    if let Some(response) = config.misconfigured(&SOURCE_VARS) {
+        return response;
+    }
+    let source_base_url = format!("{}{}", config.source_base_url, proxy_path(pathname));
 
     // Forward the request using Fetch
-    match source_base_url.parse() {
-        Ok(url) => Fetch::Url(url).send().await,
+    let proxy_url = match source_base_url.parse() {
+        Ok(url) => url,
         Err(e) => {
             console_log!("Failed to parse proxy URL: {:?}", e);
-            Response::error(format!("Invalid proxy URL: {}", e), 500)
+            return Response::error("Invalid proxy URL", 500);
+        }
+    };
+    match Fetch::Url(proxy_url).send().await {
+        Ok(response) => match proxy_status_value(response.status_code()) {
+            // Origin HTTP errors pass through, tagged so they can be told apart from worker errors
+            Some(proxy_status) => {
+                console_log!("⚠️ Origin returned HTTP {} for {}", response.status_code(), source_base_url);
+                // Fetched responses have immutable headers, so set them on a copy
+                let mut headers = response.headers().clone();
+                headers.set(PROXY_STATUS_HEADER, &proxy_status)?;
+                Ok(response.with_headers(headers))
+            }
+            None => Ok(response),
+        },
+        Err(e) => {
+            // Network failure reaching the origin: a gateway error, not a worker 500
+            console_log!("❌ Error fetching {} from origin: {:?}", source_base_url, e);
+            let mut response = Response::error("Failed to reach source origin", 502)?;
+            response.headers_mut().set(PROXY_STATUS_HEADER, "fetch-error")?;
+            Ok(response)
         }
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_tarot_card_is_pinned() {
+        // Must match the frontend mapping in sections.rs
+        assert_eq!(calculate_tarot_card(1, 0).0, "The Fool");
+        assert_eq!(calculate_tarot_card(2, 0).0, "The Magician");
+        assert_eq!(calculate_tarot_card(3, 0).0, "Strength");
+        assert_eq!(calculate_tarot_card(194, 0).0, "Justice");
+        assert_eq!(calculate_tarot_card(3621, 0).0, "Judgement");
+        assert_eq!(calculate_tarot_card(3621, 1).0, "The World");
+    }
+
+    #[test]
+    fn test_bot_annual_report_injects_meta_tags() {
+        let source = r#"<html>
+<head>
+<title>Polyjuice</title>
+<meta name="fc:miniapp" content='{"old":true}' />
+<meta name="fc:frame" content='{"old":true}' />
+<meta property="og:title" content="Old title" />
+<meta name="twitter:card" content="summary" />
+</head>
+<body><div id="app">Loading...</div></body>
+</html>"#;
+        assert!(is_bot_user_agent(Some("FarcasterBot/1.0")));
+
+        let meta_tags = generate_annual_report_meta_tags(3, "https://example.com", "/annual-report/3", None, &EmbedBranding::default());
+        let html = inject_meta_tags(source, &meta_tags);
+
+        assert_eq!(html.matches("name=\"fc:miniapp\"").count(), 1);
+        assert_eq!(html.matches("name=\"fc:frame\"").count(), 1);
+        assert!(!html.contains("old"));
+        assert!(!html.contains("Old title"));
+        assert!(html.contains("https://example.com/imgs/tarot/08-strength.jpg"));
+        assert!(html.contains("<title>Polyjuice</title>"));
+        assert!(html.contains(r#"<body><div id="app">Loading...</div></body>"#));
+        assert!(html.find("fc:miniapp").unwrap() < html.find("</head>").unwrap());
+    }
+
+    #[test]
+    fn test_inject_meta_tags_without_head() {
+        let html = inject_meta_tags("<body>hi</body>", "<meta name=\"fc:miniapp\" />");
+        assert_eq!(html, "<head><meta name=\"fc:miniapp\" /></head>\n<body>hi</body>");
+    }
+
+    #[test]
+    fn test_inject_meta_tags_is_idempotent() {
+        let source = "<!DOCTYPE html>\n<html>\n<head>\n<title>Polyjuice</title>\n<meta property=\"og:title\" content=\"Old\" />\n</head>\n<body>x</body>\n</html>";
+        let meta_tags = generate_annual_report_meta_tags(3, "https://example.com", "/annual-report/3", None, &EmbedBranding::default());
+        let once = inject_meta_tags(source, &meta_tags);
+        
+        assert_eq!(inject_meta_tags(&once, &meta_tags), once);
+        assert_eq!(once.matches("property=\"og:title\"").count(), 1);
+        assert!(once.starts_with("<!DOCTYPE html>\n<html>\n<head>\n<title>Polyjuice</title>\n<meta name=\"fc:miniapp\""));
+        assert!(once.ends_with("\" />\n</head>\n<body>x</body>\n</html>"));
+    }
+
+    #[test]
+    fn test_inject_meta_tags_malformed_head() {
+        // Several tags on one line, attributes split across lines, a `>` inside a quoted
+        // attribute, a <header> outside the head and a stray second </head>
+        let source = "<html><head lang=\"en\"><meta charset=\"utf-8\"><meta name=\"fc:frame\" content='{\"a\":\"->\"}'><meta\n  property=\"og:image\"\n  content=\"old.png\"></head>\n<body><header><meta name=\"twitter:card\" content=\"keep\"></header></head></body></html>";
+        let html = inject_meta_tags(source, "<meta name=\"fc:miniapp\" />");
+        
+        assert_eq!(
+            html,
+            "<html><head lang=\"en\"><meta charset=\"utf-8\">\n<meta name=\"fc:miniapp\" />\n</head>\n<body><header><meta name=\"twitter:card\" content=\"keep\"></header></head></body></html>"
+        );
+    }
+
+    #[test]
+    fn test_fallback_meta_html() {
+        let meta_tags = generate_annual_report_meta_tags(3, "https://example.com", "/annual-report/3", None, &EmbedBranding::default());
+        let html = fallback_meta_html(&meta_tags);
+        
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert_eq!(html.matches("name=\"fc:miniapp\"").count(), 1);
+        assert!(html.contains("https://example.com/imgs/tarot/08-strength.jpg"));
+        assert!(html.find("fc:miniapp").unwrap() < html.find("</head>").unwrap());
+    }
+
+    #[test]
+    fn test_non_bot_routing() {
+        assert!(!is_bot_user_agent(Some("Mozilla/5.0 (iPhone; CPU iPhone OS 17_0 like Mac OS X)")));
+        assert!(!is_bot_user_agent(None));
+        assert_eq!(proxy_path("/"), "/index.html");
+        assert_eq!(proxy_path(""), "/index.html");
+        assert_eq!(proxy_path("/annual-report/3"), "/annual-report/3");
+    }
+
+    #[test]
+    fn test_extract_fid_from_path() {
+        assert_eq!(extract_fid_from_path("/annual-report/3"), Some(3));
+        assert_eq!(extract_fid_from_path("/annual-report/3/"), Some(3));
+        assert_eq!(extract_fid_from_path("/annual-report/3621"), Some(3621));
+        
+        // Only one trailing slash, no nested segments
+        assert_eq!(extract_fid_from_path("/annual-report/3//"), None);
+        assert_eq!(extract_fid_from_path("/annual-report/3/extra"), None);
+        assert_eq!(extract_fid_from_path("/annual-report/3/extra/"), None);
+        assert_eq!(extract_fid_from_path("/annual-report//"), None);
+        assert_eq!(extract_fid_from_path("/annual-report/"), None);
+        assert_eq!(extract_fid_from_path("/annual-report"), None);
+        assert_eq!(extract_fid_from_path("/annual-report//3"), None);
+        
+        // Queries never reach path(); if one did, it isn't part of the FID
+        assert_eq!(extract_fid_from_path("/annual-report/3?params=abc"), None);
+        
+        // Only positive decimal FIDs
+        assert_eq!(extract_fid_from_path("/annual-report/0"), None);
+        assert_eq!(extract_fid_from_path("/annual-report/-3"), None);
+        assert_eq!(extract_fid_from_path("/annual-report/+3"), None);
+        assert_eq!(extract_fid_from_path("/annual-report/ 3"), None);
+        assert_eq!(extract_fid_from_path("/annual-report/99999999999999999999"), None);
+        assert_eq!(extract_fid_from_path("/other/3"), None);
+        
+        // Profile paths follow the same rules
+        assert_eq!(extract_profile_fid_from_path("/profile/3/"), Some(3));
+        assert_eq!(extract_profile_fid_from_path("/profile/3/casts"), None);
+        assert_eq!(extract_profile_fid_from_path("/profile/@dwr"), None);
+        
+        // Username routes, resolved to a FID before the meta tags are built
+        assert_eq!(extract_report_target("/annual-report/3621"), Some(ReportTarget::Fid(3621)));
+        assert_eq!(extract_report_target("/annual-report/dwr"), Some(ReportTarget::Username("dwr".to_string())));
+        assert_eq!(extract_report_target("/annual-report/@Vitalik.eth/"), Some(ReportTarget::Username("vitalik.eth".to_string())));
+        assert_eq!(extract_report_target("/annual-report/0"), None);
+        assert_eq!(extract_report_target("/annual-report/99999999999999999999"), None);
+        assert_eq!(extract_report_target("/annual-report/dwr/casts"), None);
+        assert_eq!(extract_report_target("/annual-report/-dwr"), None);
+        assert_eq!(extract_report_target("/annual-report/@"), None);
+        assert_eq!(extract_report_target("/annual-report/"), None);
+        assert_eq!(extract_report_target("/other/dwr"), None);
+    }
+
+    fn decode(params: &str) -> (i64, u8, u8, usize, usize, usize) {
+        let p = decode_image_params(params).unwrap();
+        (p.fid, p.zodiac_index, p.social_type_index, p.total_casts, p.total_reactions, p.total_followers)
+    }
+
+    // Golden vectors produced by encode_image_params_for_share in the frontend
+    // (see the matching tests in src/pages/annual_report/sections.rs)
+    #[test]
+    fn test_decode_image_params_golden_vectors() {
+        assert_eq!(decode("JQ4AAAAAAAAHAdIEAADV3QAA4RAAAA"), (3621, 7, 1, 1234, 56789, 4321));
+        assert_eq!(decode("_v_______38AAAAAAAAAAAAAAAAAAA"), (i64::MAX - 1, 0, 0, 0, 0, 0));
+        let max = u32::MAX as usize;
+        assert_eq!(decode("AQAAAAAAAAALAf_______________w"), (1, 11, 1, max, max, max));
+        assert_eq!(
+            decode("OTAAAAAAAAALAb__-wD-_v4APz8_AA"),
+            (12345, 11, 1, 0xfbffbf, 0xfefefe, 0x3f3f3f)
+        );
+    }
+
+    #[test]
+    fn test_decode_image_params_padding_and_alphabet() {
+        let expected = (12345, 11, 1, 0xfbffbf, 0xfefefe, 0x3f3f3f);
+        // Padded base64url
+        assert_eq!(decode("OTAAAAAAAAALAb__-wD-_v4APz8_AA=="), expected);
+        // Standard alphabet, with and without padding
+        assert_eq!(decode("OTAAAAAAAAALAb//+wD+/v4APz8/AA"), expected);
+        assert_eq!(decode("OTAAAAAAAAALAb//+wD+/v4APz8/AA=="), expected);
+    }
+
+    #[test]
+    fn test_center_crop_to_square() {
+        let mut img = RgbaImage::new(30, 10);
+        // Mark the center column so we can check the crop is centered
+        img.put_pixel(15, 5, Rgba([255, 0, 0, 255]));
+        let square = center_crop_to_square(&img);
+        assert_eq!((square.width(), square.height()), (10, 10));
+        assert_eq!(*square.get_pixel(5, 5), Rgba([255, 0, 0, 255]));
+
+        let tall = center_crop_to_square(&RgbaImage::new(8, 20));
+        assert_eq!((tall.width(), tall.height()), (8, 8));
+    }
+
+    #[test]
+    fn test_circular_border_handles_degenerate_images() {
+        for (w, h) in [(0, 0), (1, 1), (1, 50)] {
+            let img = RgbaImage::new(w, h);
+            assert_eq!(resize_with_circular_border_cropped(&img, 40).dimensions(), (44, 44));
+            assert_eq!(resize_with_circular_border(&img, 40).dimensions(), (44, 44));
+        }
+    }
+
+    #[test]
+    fn test_circular_border_non_square_avatar() {
+        let img = RgbaImage::from_pixel(300, 100, Rgba([10, 20, 30, 255]));
+        let avatar = resize_with_circular_border_cropped(&img, 60);
+        assert_eq!(avatar.dimensions(), (64, 64));
+        // Center is image content, corner is transparent
+        assert_eq!(*avatar.get_pixel(32, 32), Rgba([10, 20, 30, 255]));
+        assert_eq!(avatar.get_pixel(0, 0)[3], 0);
+    }
+
+    #[test]
+    fn test_default_avatar_decodes() {
+        let avatar = default_avatar_image();
+        assert_eq!(avatar.dimensions(), (256, 256));
+    }
+
+    #[test]
+    fn test_decode_image_checked_rejects_oversized() {
+        let mut png = Vec::new();
+        image::DynamicImage::ImageRgba8(RgbaImage::new(64, 32))
+            .write_to(&mut std::io::Cursor::new(&mut png), image::ImageOutputFormat::Png)
+            .unwrap();
+        
+        assert_eq!(decode_image_checked(&png, 64).unwrap().width(), 64);
+        assert!(decode_image_checked(&png, 63).unwrap_err().contains("too large"));
+        assert!(decode_image_checked(b"not an image", 4096).is_err());
+    }
+
+    #[test]
+    fn test_check_card_size() {
+        // The bundled 687x1024 tarot at 3x with the 80px banner fits
+        assert!(check_card_size(687 * 3 * 2, (1024 + 80) * 3).is_ok());
+        // A tarot at the input cap doesn't, even though it passed decode_image_checked
+        assert!(check_card_size(4096 * 3 * 2, (4096 + 80) * 3).unwrap_err().contains("too large"));
+        assert!(check_card_size(8193, 10).is_err());
+        assert!(check_card_size(5000, 5000).is_err());
+    }
+
+    #[test]
+    fn test_regular_font_parses_once() {
+        let first = regular_font().expect("embedded font should parse");
+        let second = regular_font().expect("embedded font should parse");
+        assert!(std::ptr::eq(first, second));
+        assert!(calculate_text_width(first, "Polyjuice", rusttype::Scale::uniform(24.0)) > 0.0);
+    }
+
+    #[test]
+    fn test_format_count_boundaries() {
+        assert_eq!(format_count(999), "999");
+        assert_eq!(format_count(1_000), "1K");
+        assert_eq!(format_count(12_847), "12.8K");
+        assert_eq!(format_count(999_999), "1M");
+        assert_eq!(format_count(1_000_000), "1M");
+        assert_eq!(format_count(u32::MAX as usize), "4.3B");
+    }
+
+    #[test]
+    fn test_build_share_text() {
+        let params = decode_image_params("JQ4AAAAAAAAHAdIEAADV3QAA4RAAAA").unwrap();
+        let en = builtin_share_template("en");
+        let share_url = "https://example.com/annual-report/3621";
+        let text = build_share_text(&params, "The World", share_url, &en, 2025);
+        assert_eq!(
+            text,
+            "My Annual Report: This year I Published 1.2K Casts this year, Received 56.8K likes\n\n\
+             My Annual Tarot Card is The World\n\n\
+             url: https://example.com/annual-report/3621\n\n\
+             #MyFarcaster2025 #polyjuice"
+        );
+        
+        // The report year in the params wins over CARD_YEAR
+        let params = decode_image_params("JQ4AAAAAAAAHAdIEAADV3QAA4RAAAADqBw").unwrap();
+        let text = build_share_text(&params, "The World", "https://example.com/r", &en, 2025);
+        assert!(text.ends_with("#MyFarcaster2026 #polyjuice"));
+        
+        let zh = builtin_share_template(&share_text_locale(None, Some("zh-CN,zh;q=0.9,en;q=0.8")));
+        let text = build_share_text(&params, "The World", "https://example.com/r", &zh, 2025);
+        assert!(text.starts_with("我的年度报告：今年发布了 1.2K 条 Cast，收到 56.8K 个赞"));
+        assert_eq!(share_text_locale(Some("fr"), Some("zh-CN")), "fr");
+        assert_eq!(share_text_locale(None, None), "en");
+    }
+
+    #[test]
+    fn test_compute_badge_layout_typical_tarot() {
+        let layout = compute_badge_layout(687);
+        assert_eq!(layout.badge_size, 48);
+        assert_eq!(layout.avatar_size, 67);
+        assert_eq!(layout.padding, 20);
+        assert!(!layout.overlaps);
+        assert!(layout.right_badge_x + layout.badge_size + 4 <= 687);
+    }
+
+    #[test]
+    fn test_compute_badge_layout_narrow_tarot() {
+        // Too narrow to fit: right badge keeps its gap from the avatar and overlap is reported
+        let layout = compute_badge_layout(100);
+        assert!(layout.overlaps);
+        assert!(layout.right_badge_x >= layout.avatar_x + layout.avatar_size + 4);
+
+        assert!(compute_badge_layout(0).overlaps);
+    }
+
+    #[test]
+    fn test_overlay_image_signed_clips_every_edge() {
+        let red = Rgba([255, 0, 0, 255]);
+        let blank = Rgba([0, 0, 0, 0]);
+        let overlay = RgbaImage::from_pixel(4, 4, red);
+        let painted = |canvas: &RgbaImage| canvas.pixels().filter(|p| **p == red).count();
+
+        // Off the top-left corner: only the bottom-right 2x2 of the overlay lands
+        let mut canvas = RgbaImage::from_pixel(10, 10, blank);
+        overlay_image_signed(&mut canvas, &overlay, -2, -2);
+        assert_eq!(painted(&canvas), 4);
+        assert_eq!(*canvas.get_pixel(1, 1), red);
+        assert_eq!(*canvas.get_pixel(2, 2), blank);
+
+        // Off the left edge only
+        let mut canvas = RgbaImage::from_pixel(10, 10, blank);
+        overlay_image_signed(&mut canvas, &overlay, -3, 5);
+        assert_eq!(painted(&canvas), 4);
+        assert_eq!(*canvas.get_pixel(0, 5), red);
+        assert_eq!(*canvas.get_pixel(0, 4), blank);
+
+        // Off the bottom-right corner
+        let mut canvas = RgbaImage::from_pixel(10, 10, blank);
+        overlay_image_signed(&mut canvas, &overlay, 8, 7);
+        assert_eq!(painted(&canvas), 6);
+        assert_eq!(*canvas.get_pixel(9, 9), red);
+
+        // Entirely off-canvas (and extreme offsets) draw nothing and don't panic
+        for (x, y) in [(-4, 0), (0, -4), (10, 0), (i32::MIN, i32::MIN), (i32::MAX, i32::MAX)] {
+            let mut canvas = RgbaImage::from_pixel(10, 10, blank);
+            overlay_image_signed(&mut canvas, &overlay, x, y);
+            assert_eq!(painted(&canvas), 0);
+        }
+
+        // The unsigned wrapper behaves the same for on-canvas positions
+        let mut canvas = RgbaImage::from_pixel(10, 10, blank);
+        overlay_image(&mut canvas, &overlay, 8, 8);
+        assert_eq!(painted(&canvas), 4);
+        overlay_image(&mut canvas, &overlay, u32::MAX, 0);
+        assert_eq!(painted(&canvas), 4);
+    }
+
+    #[test]
+    fn test_calculate_text_width() {
+        let font = regular_font().expect("embedded font parses");
+        let scale = rusttype::Scale::uniform(40.0);
+        let width = |text: &str| calculate_text_width(font, text, scale);
+
+        assert_eq!(width(""), 0.0);
+
+        // A single digit is narrower than the em box but not collapsed
+        let digit = width("7");
+        assert!(digit > 0.0 && digit <= scale.x * 1.2, "digit width {}", digit);
+
+        // Trailing spaces still advance the pen (they have no bounding box)
+        let word = width("ab");
+        assert!(width("ab ") > word);
+        assert!(width("ab  ") > width("ab "));
+        assert!(width(" ") > 0.0);
+
+        // Widths only grow as characters are appended, and stay within a sane per-char range
+        let mixed = "Published12.8KCasts";
+        let mut previous = 0.0;
+        for end in 1..=mixed.len() {
+            let current = width(&mixed[..end]);
+            assert!(current >= previous, "width shrank at {:?}: {} < {}", &mixed[..end], current, previous);
+            previous = current;
+        }
+        let chars = mixed.chars().count() as f32;
+        assert!(previous > chars * scale.x * 0.3 && previous < chars * scale.x * 0.8, "mixed width {}", previous);
+    }
+
+    #[test]
+    fn test_layout_bold_number_segments() {
+        let font = regular_font().expect("embedded font parses");
+        let fonts = FontChain::new(vec![font]);
+        let (base, number) = (34.0, 42.0);
+        let segments = layout_bold_number_segments(&fonts, "Published12.8KCasts", 10.0, base, number);
+        let parts: Vec<(&str, bool)> = segments.iter().map(|(text, is_number, _)| (text.as_str(), *is_number)).collect();
+        assert_eq!(parts, vec![("Published", false), ("12.8K", true), ("Casts", false)]);
+
+        // Each segment starts exactly where the previous one's advance ends (no rounding drift)
+        assert_eq!(segments[0].2, 10.0);
+        let published = calculate_text_width(font, "Published", rusttype::Scale::uniform(base));
+        let count = calculate_text_width(font, "12.8K", rusttype::Scale::uniform(number));
+        assert_eq!(segments[1].2, 10.0 + published);
+        assert_eq!(segments[2].2, 10.0 + published + count);
+
+        assert!(layout_bold_number_segments(&fonts, "", 0.0, base, number).is_empty());
+        assert_eq!(layout_bold_number_segments(&fonts, "42", 0.0, base, number).len(), 1);
+    }
+
+    #[test]
+    fn test_font_chain_runs_and_missing_scripts() {
+        let font = regular_font().expect("embedded font parses");
+        let fonts = FontChain::new(vec![font]);
+        let replacement = REPLACEMENT_CHARS
+            .iter()
+            .copied()
+            .find(|&c| fonts.font_for(c).is_some())
+            .unwrap_or(' ');
+
+        // Covered Latin text is a single run in the first font
+        assert_eq!(fonts.runs("@alice"), vec![(0, "@alice".to_string())]);
+        assert!(fonts.missing_scripts("@alice").is_empty());
+
+        // Uncovered characters become the replacement, joiners/selectors vanish
+        let runs = fonts.runs("a\u{0645}\u{200D}\u{FE0F}b");
+        let drawn: String = runs.iter().map(|(_, run)| run.as_str()).collect();
+        assert_eq!(drawn, format!("a{}b", replacement));
+        assert!(!drawn.chars().any(|c| fonts.font_for(c).is_none() && c != ' '));
+
+        assert_eq!(fonts.missing_scripts("\u{0645}\u{0928}\u{1F600}\u{0645}"), vec!["Arabic", "Devanagari", "Emoji"]);
+        assert_eq!(script_name('\u{4E2D}'), "CJK");
+
+        assert_eq!(fonts.text_width("ab", rusttype::Scale::uniform(20.0)), calculate_text_width(font, "ab", rusttype::Scale::uniform(20.0)));
+    }
+
+    #[test]
+    fn test_count_up_frame_value() {
+        // Starts at zero, lands exactly on the target, never overshoots
+        assert_eq!(count_up_frame_value(1234, 0, GIF_FRAME_COUNT), 0);
+        assert_eq!(count_up_frame_value(1234, GIF_FRAME_COUNT - 1, GIF_FRAME_COUNT), 1234);
+        let values: Vec<usize> = (0..GIF_FRAME_COUNT)
+            .map(|frame| count_up_frame_value(1234, frame, GIF_FRAME_COUNT))
+            .collect();
+        assert!(values.windows(2).all(|w| w[0] <= w[1] && w[1] <= 1234));
+        // Ease-out: past halfway by the middle frame
+        assert!(values[GIF_FRAME_COUNT as usize / 2] > 617);
+
+        // Degenerate frame counts just show the target
+        assert_eq!(count_up_frame_value(42, 0, 1), 42);
+        assert_eq!(count_up_frame_value(42, 0, 0), 42);
+        assert_eq!(count_up_frame_value(0, 3, GIF_FRAME_COUNT), 0);
+    }
+
+    #[test]
+    fn test_blend_pixels() {
+        let red = Rgba([255, 0, 0, 255]);
+        let blue = Rgba([0, 0, 255, 255]);
+        let clear = Rgba([0, 0, 0, 0]);
+
+        // Opaque over opaque: top wins
+        assert_eq!(blend_pixels(blue, red), red);
+        // Fully transparent over opaque: bottom unchanged
+        assert_eq!(blend_pixels(blue, Rgba([255, 0, 0, 0])), blue);
+        // 50% red over opaque blue: channel midpoint, still opaque (128/255 of red, 127/255 of blue)
+        assert_eq!(blend_pixels(blue, Rgba([255, 0, 0, 128])), Rgba([128, 0, 127, 255]));
+        // Transparent over transparent stays transparent (the alpha_out == 0 early return)
+        assert_eq!(blend_pixels(clear, Rgba([10, 20, 30, 0])), clear);
+        // 50% over 50%: alpha_out = 0.5 + 0.5 * 0.5, colour weighted 2:1 towards the top
+        assert_eq!(
+            blend_pixels(Rgba([0, 0, 255, 128]), Rgba([255, 0, 0, 128])),
+            Rgba([170, 0, 85, 192])
+        );
+        // Opaque over transparent: top unchanged
+        assert_eq!(blend_pixels(clear, red), red);
+    }
+
+    #[test]
+    fn test_compute_summary_layout() {
+        // Typical 687x1200 tarot: scaled to the content height, right-aligned
+        let layout = compute_summary_layout(687, 1200, 1);
+        assert_eq!((layout.card_width, layout.card_height), (1200, 630));
+        assert_eq!(layout.tarot_height, 630 - 64 - 2 * 32);
+        assert_eq!(layout.tarot_width, 502 * 687 / 1200);
+        assert_eq!(layout.tarot_x + layout.tarot_width + layout.padding, layout.card_width);
+        // Left column: stats start below the avatar and the badges stay on the card
+        assert!(layout.stats_y > layout.content_y + layout.avatar_size);
+        assert!(layout.badge_y + layout.badge_size + 4 <= layout.card_height);
+        assert!(layout.padding + layout.column_width < layout.tarot_x);
+
+        // Everything scales with the render scale
+        let retina = compute_summary_layout(687, 1200, 2);
+        assert_eq!(retina.card_width, 2400);
+        assert_eq!(retina.avatar_size, layout.avatar_size * 2);
+
+        // Wide art is capped at half the card; a broken image still gets a thumbnail
+        let wide = compute_summary_layout(4000, 1000, 1);
+        assert_eq!(wide.tarot_width, 600);
+        assert!(wide.tarot_height < layout.tarot_height);
+        let broken = compute_summary_layout(0, 0, 1);
+        assert!(broken.tarot_width > 0 && broken.tarot_x < broken.card_width);
+    }
+
+    #[test]
+    fn test_social_type_tiers() {
+        // Golden vector from test_encode_image_params_social_tier in sections.rs
+        let params = decode_image_params("JQ4AAAAAAAAHA9IEAADV3QAA4RAAAA").unwrap();
+        assert_eq!(params.social_type_index, 3);
+        assert_eq!(
+            get_social_type_url_from_index(params.social_type_index, "https://example.com"),
+            "https://example.com/imgs/social_type/chatty.png"
+        );
+        
+        // Original 0/1 links keep their images
+        assert_eq!(get_social_type_url_from_index(0, ""), "/imgs/social_type/slient.png");
+        assert_eq!(get_social_type_url_from_index(1, ""), "/imgs/social_type/social.png");
+        assert_eq!(get_social_type_url_from_index(2, ""), "/imgs/social_type/casual.png");
+        assert_eq!(get_social_type_url_from_index(200, ""), "/imgs/social_type/slient.png");
+    }
+
+    #[test]
+    fn test_decode_image_params_hide_stats_flag() {
+        // Golden vector from test_encode_image_params_hide_stats in sections.rs
+        let params = decode_image_params("JQ4AAAAAAAAHAQAAAAAAAAAAAAAAAAE").unwrap();
+        assert_eq!(params.fid, 3621);
+        assert!(params.hide_stats);
+        assert_eq!(params.total_casts, 0);
+        let en = builtin_share_template("en");
+        assert!(!build_share_text(&params, "Judgement", "https://example.com", &en, 2025).contains("Casts"));
+        
+        // Links without the flags byte keep showing stats
+        assert!(!decode_image_params("JQ4AAAAAAAAHAdIEAADV3QAA4RAAAA").unwrap().hide_stats);
+    }
+
+    #[test]
+    fn test_tune_jpeg_quality() {
+        // Fake encoder whose size grows with quality: 1KB per quality step
+        let fake = |q: u8| Ok(vec![0u8; q as usize * 1000]);
+        let (bytes, quality) = tune_jpeg_quality(70_000, fake).unwrap().unwrap();
+        assert_eq!(quality, 70);
+        assert_eq!(bytes.len(), 70_000);
+        assert_eq!(tune_jpeg_quality(1_000_000, fake).unwrap().unwrap().1, JPEG_MAX_QUALITY);
+        // The floor itself fits...
+        assert_eq!(tune_jpeg_quality(40_000, fake).unwrap().unwrap().1, JPEG_MIN_QUALITY);
+        // ...or nothing does, which leaves resizing to the caller
+        assert!(tune_jpeg_quality(10_000, fake).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_encode_jpeg_near_target() {
+        let img = RgbImage::from_fn(200, 120, |x, y| image::Rgb([(x * 7 % 256) as u8, (y * 13 % 256) as u8, ((x ^ y) % 256) as u8]));
+        
+        // A roomy target keeps the resolution
+        let bytes = encode_jpeg_near_target(img.clone(), 500_000).unwrap();
+        assert_eq!(&bytes[..2], &[0xFF, 0xD8]);
+        assert_eq!(image::load_from_memory(&bytes).unwrap().width(), 200);
+        
+        // A target below the lowest quality's size downscales instead
+        let bytes = encode_jpeg_near_target(img, 2_000).unwrap();
+        assert!(image::load_from_memory(&bytes).unwrap().width() < 200);
+    }
+
+    #[test]
+    fn test_ensure_image_payload() {
+        let png = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+        assert!(ensure_image_payload(png, Some("image/png")).is_ok());
+        // Generic or missing types fall back to sniffing
+        assert!(ensure_image_payload(png, Some("application/octet-stream")).is_ok());
+        assert!(ensure_image_payload(png, None).is_ok());
+        assert!(ensure_image_payload(b"<svg xmlns='http://www.w3.org/2000/svg'/>", None).is_ok());
+        
+        // An HTML error page served with 200 is a fetch failure, even with an inline <svg>
+        let html = b"<!doctype html><html><body><svg></svg>502 Bad Gateway</body></html>";
+        let err = ensure_image_payload(html, Some("text/html; charset=utf-8")).unwrap_err();
+        assert!(err.contains("text/html"), "{}", err);
+        assert!(ensure_image_payload(b"{\"error\":1}", Some("application/json")).is_err());
+        assert!(ensure_image_payload(b"oops", None).unwrap_err().contains("no content-type"));
+    }
+
+    #[test]
+    fn test_avatar_fetch_urls() {
+        let gateways = AvatarSources::default().ipfs_gateways;
+        let cid = "QmYwAPJzv5CZsnAzt8auVZRn1pfejmS3ZkM4hMjbVgY9Xp";
+        
+        // ipfs:// URLs only go through the gateways
+        assert_eq!(
+            avatar_fetch_urls(&format!("ipfs://{}/avatar.png", cid), &gateways),
+            vec![
+                format!("https://ipfs.io/ipfs/{}/avatar.png", cid),
+                format!("https://w3s.link/ipfs/{}/avatar.png", cid),
+            ]
+        );
+        assert_eq!(avatar_fetch_urls(&format!("ipfs://ipfs/{}", cid), &gateways)[0], format!("https://ipfs.io/ipfs/{}", cid));
+        
+        // A known gateway is tried first, before the configured ones
+        let pinata = format!("https://gateway.pinata.cloud/ipfs/{}?img-width=200", cid);
+        let urls = avatar_fetch_urls(&pinata, &gateways);
+        assert_eq!(urls.len(), 3);
+        assert_eq!(urls[0], pinata);
+        assert_eq!(urls[1], format!("https://ipfs.io/ipfs/{}?img-width=200", cid));
+        // ...and isn't repeated when it is one of them
+        let w3s = format!("https://w3s.link/ipfs/{}", cid);
+        assert_eq!(avatar_fetch_urls(&w3s, &gateways), vec![w3s.clone(), format!("https://ipfs.io/ipfs/{}", cid)]);
+        
+        // Everything else keeps the single-fetch path
+        let imgur = "https://i.imgur.com/abc.png";
+        assert_eq!(avatar_fetch_urls(imgur, &gateways), vec![imgur.to_string()]);
+        assert_eq!(avatar_fetch_urls("https://example.com/ipfs/abc", &gateways).len(), 1);
+        assert_eq!(avatar_fetch_urls("ipfs://", &gateways), vec!["ipfs://".to_string()]);
+        
+        assert_eq!(
+            parse_ipfs_gateways(" https://dweb.link/ ,https://ipfs.io,").unwrap(),
+            vec!["https://dweb.link".to_string(), "https://ipfs.io".to_string()]
+        );
+        assert!(parse_ipfs_gateways("not a url").is_err());
+    }
+
+    #[test]
+    fn test_decode_image_params_hide_fid_flag() {
+        // Golden vectors from test_encode_image_params_hide_fid in sections.rs
+        let params = decode_image_params("JQ4AAAAAAAAHAdIEAADV3QAA4RAAAAI").unwrap();
+        assert!(params.hide_fid);
+        assert!(!params.hide_stats);
+        assert_eq!(params.total_casts, 1234);
+        
+        let params = decode_image_params("JQ4AAAAAAAAHAQAAAAAAAAAAAAAAAAM").unwrap();
+        assert!(params.hide_fid && params.hide_stats);
+        
+        assert!(!decode_image_params("JQ4AAAAAAAAHAQAAAAAAAAAAAAAAAAE").unwrap().hide_fid);
+    }
+
+    #[test]
+    fn test_decode_image_params_year() {
+        // Flags byte (0) followed by the year 2026; same vector as the frontend's encoder test
+        assert_eq!(decode_image_params("JQ4AAAAAAAAHAdIEAADV3QAA4RAAAADqBw").unwrap().year, Some(2026));
+        // Implausible years and links without a year fall back to CARD_YEAR
+        assert_eq!(decode_image_params("JQ4AAAAAAAAHAdIEAADV3QAA4RAAAADPBw").unwrap().year, None);
+        assert_eq!(decode_image_params("JQ4AAAAAAAAHAdIEAADV3QAA4RAAAA").unwrap().year, None);
+    }
+
+    #[test]
+    fn test_card_theme() {
+        let theme = CardTheme::default();
+        assert_eq!(theme.banner_title(None), "My 2025 Annual Report");
+        assert_eq!(theme.banner_title(Some(2026)), "My 2026 Annual Report");
+        // Default gradient runs #667eea -> #764ba2
+        assert_eq!(theme.gradient_at(0.0), Rgba([0x66, 0x7e, 0xea, 255]));
+        assert_eq!(theme.gradient_at(1.0), Rgba([0x76, 0x4b, 0xa2, 255]));
+        assert_eq!(theme.gradient_at(7.0), theme.gradient_at(1.0));
+        
+        assert_eq!(parse_hex_color("#1a2B3c"), Some([0x1a, 0x2b, 0x3c]));
+        assert_eq!(parse_hex_color(" 000000 "), Some([0, 0, 0]));
+        assert_eq!(parse_hex_color("#fff"), None);
+        assert_eq!(parse_hex_color("#gg0000"), None);
+        assert_eq!(parse_hex_color("#ééé"), None);
+        
+        let theme = CardTheme { banner_color: [10, 20, 30], gradient_start: [200, 0, 0], gradient_end: [200, 0, 0], ..CardTheme::default() };
+        let mut canvas = RgbaImage::new(4, 10);
+        draw_card_background(&mut canvas, 3, false, &theme);
+        assert_eq!(*canvas.get_pixel(0, 2), Rgba([10, 20, 30, 255]));
+        assert_eq!(*canvas.get_pixel(3, 9), Rgba([200, 0, 0, 255]));
+        draw_card_background(&mut canvas, 3, true, &theme);
+        assert_eq!(*canvas.get_pixel(0, 0), Rgba([10, 20, 30, 128]));
+    }
+
+    #[test]
+    fn test_proxy_status_value() {
+        assert_eq!(proxy_status_value(200), None);
+        assert_eq!(proxy_status_value(304), None);
+        assert_eq!(proxy_status_value(399), None);
+        assert_eq!(proxy_status_value(404).as_deref(), Some("origin-error; status=404"));
+        assert_eq!(proxy_status_value(503).as_deref(), Some("origin-error; status=503"));
+    }
+
+    #[test]
+    fn test_draw_debug_rect_outline_is_clipped() {
+        let mut canvas = RgbaImage::new(10, 10);
+        draw_debug_rect(&mut canvas, 2, 2, 5, 4, 1, DEBUG_TEXT_BOX_COLOR);
+        assert_eq!(*canvas.get_pixel(2, 2), DEBUG_TEXT_BOX_COLOR);
+        assert_eq!(*canvas.get_pixel(6, 5), DEBUG_TEXT_BOX_COLOR);
+        // Interior and outside stay untouched
+        assert_eq!(canvas.get_pixel(4, 3)[3], 0);
+        assert_eq!(canvas.get_pixel(7, 2)[3], 0);
+        
+        // Partially off-canvas boxes don't panic
+        draw_debug_rect(&mut canvas, -3, 8, 20, 5, 2, DEBUG_LIMIT_COLOR);
+        assert_eq!(*canvas.get_pixel(0, 9), DEBUG_LIMIT_COLOR);
+        
+        // Guides are only recorded when enabled
+        let mut guides = DebugGuides::new(false);
+        guides.add_line(0, 10, 5, DEBUG_BASELINE_COLOR);
+        assert!(guides.boxes.is_empty());
+    }
+
+    #[test]
+    fn test_svg_avatar_detection_and_rasterizing() {
+        let svg = r##"<?xml version="1.0"?><svg xmlns="http://www.w3.org/2000/svg" width="10" height="20"><rect width="10" height="20" fill="#ff0000"/></svg>"##;
+        assert!(is_svg(svg.as_bytes(), None));
+        assert!(is_svg(b"anything", Some("image/svg+xml; charset=utf-8")));
+        assert!(!is_svg(DEFAULT_AVATAR_PNG, Some("image/png")));
+        
+        // Fitted into the square and centered: the middle is red, the side margins transparent
+        let image = rasterize_svg(svg.as_bytes(), 40).unwrap();
+        assert_eq!(image.dimensions(), (40, 40));
+        assert_eq!(*image.get_pixel(20, 20), Rgba([255, 0, 0, 255]));
+        assert_eq!(image.get_pixel(2, 20)[3], 0);
+        assert!(rasterize_svg(b"<svg", 40).is_err());
+    }
+
+    #[test]
+    fn test_decode_data_uri() {
+        let (bytes, media_type) = decode_data_uri("data:image/svg+xml,%3Csvg%20%2F%3E").unwrap().unwrap();
+        assert_eq!(bytes, b"<svg />");
+        assert_eq!(media_type.as_deref(), Some("image/svg+xml"));
+        
+        let (bytes, media_type) = decode_data_uri("data:image/svg+xml;base64,PHN2ZyAvPg==").unwrap().unwrap();
+        assert_eq!(bytes, b"<svg />");
+        assert_eq!(media_type.as_deref(), Some("image/svg+xml"));
+        
+        assert!(decode_data_uri("https://example.com/a.svg").is_none());
+        assert!(decode_data_uri("data:image/svg+xml,%3").unwrap().is_err());
+    }
+
+    #[test]
+    fn test_rate_limit_key() {
+        assert_eq!(rate_limit_key(3, Some("203.0.113.7")), "generate:3:203.0.113.7");
+        assert_eq!(rate_limit_key(3, None), "generate:3:unknown");
+    }
+
+    #[test]
+    fn test_draw_power_badge() {
+        let mut canvas = RgbaImage::new(40, 40);
+        draw_power_badge(&mut canvas, 20, 20, 15);
+        // Bolt at the center, gold disc around it, corners untouched
+        assert_eq!(*canvas.get_pixel(20, 20), Rgba([74, 59, 0, 255]));
+        assert_eq!(*canvas.get_pixel(20, 32), Rgba([255, 215, 0, 255]));
+        assert_eq!(*canvas.get_pixel(0, 0), Rgba([0, 0, 0, 0]));
+        
+        // Degenerate radius is a no-op rather than a panic
+        draw_power_badge(&mut canvas, 5, 5, 0);
+    }
+
+    #[test]
+    fn test_decode_image_params_rejects_short_input() {
+        assert!(decode_image_params("JQ4AAAAAAAAHAdIE").is_err());
+        assert!(decode_image_params("not base64!").is_err());
+    }
+
+    #[test]
+    fn test_profile_envelope_shapes() {
+        let parse = |json: &str| {
+            serde_json::from_str::<ProfileEnvelope>(json)
+                .unwrap()
+                .into_profile()
+                .map(ProfileApiResponse::into_card_profile)
+        };
+        
+        let data = parse(r#"{"data":{"username":"alice","pfp_url":"https://a/p.png","power_badge":true}}"#).unwrap();
+        assert_eq!(data.username.as_deref(), Some("alice"));
+        assert_eq!(data.avatar_url.as_deref(), Some("https://a/p.png"));
+        assert!(data.power_badge);
+        
+        // Legacy envelope and field names; an empty pfp_url falls through to avatar
+        let legacy = parse(r#"{"profile":{"display_name":"Bob","pfp_url":"","avatar":"https://b/a.png","powerBadge":true}}"#).unwrap();
+        assert_eq!(legacy.display_name.as_deref(), Some("Bob"));
+        assert_eq!(legacy.avatar_url.as_deref(), Some("https://b/a.png"));
+        assert!(legacy.power_badge);
+        
+        assert!(parse(r#"{"success":true}"#).is_none());
+    }
+
+    #[test]
+    fn test_embed_branding() {
+        let default_tags = generate_annual_report_meta_tags(3, "https://example.com", "/annual-report/3", None, &EmbedBranding::default());
+        assert_eq!(default_tags.matches(r#""name":"polyjuice""#).count(), 2);
+        assert_eq!(default_tags.matches(r##""splashBackgroundColor":"#667eea""##).count(), 2);
+        assert_eq!(default_tags.matches(r#""splashImageUrl":"https://example.com/imgs/splash.png""#).count(), 2);
+        
+        let branding = EmbedBranding {
+            app_name: "forkjuice".to_string(),
+            splash_path: "assets/splash.png".to_string(),
+            splash_background: "#000000".to_string(),
+        };
+        let tags = generate_profile_meta_tags(3, "https://fork.example", "/profile/3", &branding);
+        assert!(tags.contains(r#""name":"forkjuice""#));
+        assert!(tags.contains(r#""splashImageUrl":"https://fork.example/assets/splash.png""#));
+        assert!(tags.contains(r##""splashBackgroundColor":"#000000""##));
+        
+        let absolute = EmbedBranding {
+            splash_path: "https://cdn.example/s.png".to_string(),
+            ..EmbedBranding::default()
+        };
+        assert_eq!(absolute.splash_image_url("https://fork.example"), "https://cdn.example/s.png");
+    }
+
+    #[test]
+    fn test_selftest_params_and_png_checks() {
+        let params = decode_image_params(SELFTEST_PARAMS).unwrap();
+        assert_eq!(params.fid, 0);
+        assert_eq!((params.zodiac_index, params.social_type_index), (7, 1));
+        assert_eq!((params.total_casts, params.total_reactions, params.total_followers), (1234, 56789, 4321));
+        
+        let encode = |img: RgbaImage| {
+            let mut bytes = Vec::new();
+            image::DynamicImage::ImageRgba8(img)
+                .write_to(&mut std::io::Cursor::new(&mut bytes), image::ImageOutputFormat::Png)
+                .unwrap();
+            bytes
+        };
+        
+        let mut card = RgbaImage::from_pixel(400, 200, Rgba([0, 0, 0, 255]));
+        card.put_pixel(10, 10, Rgba([255, 255, 255, 255]));
+        assert_eq!(verify_selftest_png(&encode(card)), Ok((400, 200)));
+        
+        let step = |bytes: &[u8]| verify_selftest_png(bytes).unwrap_err().0;
+        assert_eq!(step(&[]), "encode");
+        assert_eq!(step(b"not a png"), "decode");
+        assert_eq!(step(&encode(RgbaImage::from_pixel(200, 400, Rgba([1, 2, 3, 255])))), "dimensions");
+        assert_eq!(step(&encode(RgbaImage::from_pixel(40, 20, Rgba([1, 2, 3, 255])))), "dimensions");
+        assert_eq!(step(&encode(RgbaImage::from_pixel(400, 200, Rgba([1, 2, 3, 255])))), "content");
+    }
+}